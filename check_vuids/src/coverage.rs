@@ -0,0 +1,401 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::vuid_check::VuidInfo;
+
+/// How a VUID is handled in the source
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// the VUID is checked or otherwise guaranteed
+    Enforced,
+    /// the VUID is not yet handled
+    Todo,
+    /// the VUID cannot apply, since the relevant functionality is not supported
+    NotApplicable,
+}
+
+impl Status {
+    /// parse the identifier used in `check_vuids::status!(...)`
+    pub fn from_marker(marker: &str) -> crate::Result<Self> {
+        match marker {
+            "enforced" => Ok(Status::Enforced),
+            "todo" => Ok(Status::Todo),
+            "not_applicable" => Ok(Status::NotApplicable),
+            _ => Err(format!(
+                "unknown status \"{marker}\", expected enforced, todo, or not_applicable"
+            ))?,
+        }
+    }
+
+    /**
+    Classify a VUID block
+
+    A `check_vuids::status!(...)` marker always takes priority. Otherwise, the body of the block
+    (everything after the description) is checked in order:
+    - `compile_error!` (i.e. new or updated by the vuids bin, and not reviewed) => [`Status::Todo`]
+    - "TODO" => [`Status::Todo`]
+    - "not supported" or "not currently supported" => [`Status::NotApplicable`]
+    - empty => [`Status::Todo`]
+    - anything else (e.g. code or a comment about how it is ensured) => [`Status::Enforced`]
+     */
+    pub fn classify(buffer: &str, vuid_info: &VuidInfo) -> Self {
+        if let Some(status) = vuid_info.status() {
+            return status;
+        }
+
+        let body = &buffer[vuid_info.info_end()..vuid_info.block_end() - 1];
+
+        if body.contains("compile_error!") || body.contains("TODO") {
+            Status::Todo
+        } else if body.contains("not supported") || body.contains("not currently supported") {
+            Status::NotApplicable
+        } else if body.trim().is_empty() {
+            Status::Todo
+        } else {
+            Status::Enforced
+        }
+    }
+}
+
+/// Number of VUIDs with each [`Status`]
+#[derive(Clone, Copy, Default)]
+struct Counts {
+    enforced: usize,
+    todo: usize,
+    not_applicable: usize,
+}
+
+impl Counts {
+    fn add(&mut self, status: Status) {
+        match status {
+            Status::Enforced => self.enforced += 1,
+            Status::Todo => self.todo += 1,
+            Status::NotApplicable => self.not_applicable += 1,
+        }
+    }
+
+    fn add_counts(&mut self, other: &Counts) {
+        self.enforced += other.enforced;
+        self.todo += other.todo;
+        self.not_applicable += other.not_applicable;
+    }
+
+    fn total(&self) -> usize {
+        self.enforced + self.todo + self.not_applicable
+    }
+}
+
+impl fmt::Display for Counts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.total();
+        // VUIDs which are not applicable are covered, since they can never be violated
+        let covered = self.enforced + self.not_applicable;
+        let percent = if total == 0 {
+            100.0
+        } else {
+            covered as f64 * 100.0 / total as f64
+        };
+        write!(
+            f,
+            "{} | {} | {} | {total} | {percent:.1}%",
+            self.enforced, self.todo, self.not_applicable
+        )
+    }
+}
+
+#[derive(Default)]
+struct TargetCoverage {
+    /// the target has VUIDs for a command (VUID_vk...) rather than a structure (VUID_Vk...)
+    is_command: bool,
+    files: Vec<String>,
+    counts: Counts,
+}
+
+/// Coverage of all VUIDs in the source, printed as markdown
+///
+/// ```text
+/// # VUID coverage
+///
+/// | | enforced | todo | not applicable | total | covered |
+/// |---|---|---|---|---|---|
+/// | **all** | 700 | 150 | 27 | 877 | 82.9% |
+///
+/// ## Commands
+///
+/// | target | file | enforced | todo | not applicable | total | covered |
+/// |---|---|---|---|---|---|---|
+/// | CreateInstance | vk-safe/src/entry/create_instance.rs | 2 | 0 | 0 | 2 | 100.0% |
+/// ...
+///
+/// ## Conditions
+///
+/// | requires | enforced | todo | not applicable | total | covered |
+/// |---|---|---|---|---|---|
+/// | `(VK_KHR_external_memory_win32)` | 0 | 0 | 3 | 3 | 100.0% |
+/// ...
+/// ```
+#[derive(Default)]
+pub struct Coverage {
+    targets: BTreeMap<String, TargetCoverage>,
+    /// VUIDs which only apply with certain extensions and/or versions, by condition
+    conditions: BTreeMap<String, Counts>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add the VUID of a target in a file
+    pub fn add(
+        &mut self,
+        target: &str,
+        file: &str,
+        vuid: &str,
+        requires: Option<&str>,
+        status: Status,
+    ) {
+        if let Some(requires) = requires {
+            self.conditions
+                .entry(requires.to_string())
+                .or_default()
+                .add(status);
+        }
+
+        let coverage = self.targets.entry(target.to_string()).or_default();
+        if vuid.starts_with("VUID_vk") {
+            coverage.is_command = true;
+        }
+        if !coverage.files.iter().any(|f| f == file) {
+            coverage.files.push(file.to_string());
+        }
+        coverage.counts.add(status);
+    }
+
+    fn fmt_section(&self, f: &mut fmt::Formatter<'_>, title: &str, commands: bool) -> fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "## {title}")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "| target | file | enforced | todo | not applicable | total | covered |"
+        )?;
+        writeln!(f, "|---|---|---|---|---|---|---|")?;
+        for (target, coverage) in self
+            .targets
+            .iter()
+            .filter(|(_, coverage)| coverage.is_command == commands)
+        {
+            writeln!(
+                f,
+                "| {target} | {} | {} |",
+                coverage.files.join("<br>"),
+                coverage.counts
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut all = Counts::default();
+        let mut commands = Counts::default();
+        let mut structures = Counts::default();
+        for coverage in self.targets.values() {
+            all.add_counts(&coverage.counts);
+            if coverage.is_command {
+                commands.add_counts(&coverage.counts);
+            } else {
+                structures.add_counts(&coverage.counts);
+            }
+        }
+
+        writeln!(f, "# VUID coverage")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "| | enforced | todo | not applicable | total | covered |"
+        )?;
+        writeln!(f, "|---|---|---|---|---|---|")?;
+        writeln!(f, "| **all** | {all} |")?;
+        writeln!(f, "| commands | {commands} |")?;
+        writeln!(f, "| structures | {structures} |")?;
+
+        self.fmt_section(f, "Commands", true)?;
+        self.fmt_section(f, "Structures", false)?;
+
+        writeln!(f)?;
+        writeln!(f, "## Conditions")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "| requires | enforced | todo | not applicable | total | covered |"
+        )?;
+        writeln!(f, "|---|---|---|---|---|---|")?;
+        for (requires, counts) in self.conditions.iter() {
+            writeln!(f, "| `{requires}` | {counts} |")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::vuid_check::GatherVuids;
+
+    fn vuid_block(label: &str, body: &str) -> String {
+        format!(
+            r#"
+    #[allow(unused_labels)]
+    '{label}: {{
+        check_vuids::version!{{"1.3.268"}}
+        check_vuids::description!{{
+        "description"
+        }}
+{body}
+    }}
+"#
+        )
+    }
+
+    fn source(blocks: &[(&str, &str)]) -> String {
+        let mut source = "fn f() {\n    check_vuids::check_vuids!(Target);\n".to_string();
+        for (label, body) in blocks {
+            source.push_str(&vuid_block(label, body));
+        }
+        source.push_str("}\n");
+        source
+    }
+
+    /// the status of each VUID in the source
+    fn classify(blocks: &[(&str, &str)]) -> Vec<(String, Status)> {
+        let source = source(blocks);
+        let file_vuids = crate::parse::RustParser::new(&source)
+            .parse(GatherVuids::new())
+            .unwrap();
+        let target = file_vuids.targets().next().unwrap();
+        target
+            .vuids()
+            .map(|(vuid, vuid_info)| (vuid.to_string(), Status::classify(&source, vuid_info)))
+            .collect()
+    }
+
+    #[test]
+    fn classify_blocks() {
+        let statuses = classify(&[
+            ("enforced", "        // ensured by device creation"),
+            ("todo", "        // TODO: p_next not supported"),
+            ("not_supported", "        // p_next not currently supported"),
+            ("empty", ""),
+            ("new", "        compile_error!(\"new VUID\");"),
+            (
+                "marked",
+                "        check_vuids::status!(not_applicable)\n        // TODO",
+            ),
+            (
+                "marked_todo",
+                "        check_vuids::status!(todo);\n        assert!(true);",
+            ),
+        ]);
+        assert!(
+            statuses
+                == [
+                    ("enforced".to_string(), Status::Enforced),
+                    ("todo".to_string(), Status::Todo),
+                    ("not_supported".to_string(), Status::NotApplicable),
+                    ("empty".to_string(), Status::Todo),
+                    ("new".to_string(), Status::Todo),
+                    ("marked".to_string(), Status::NotApplicable),
+                    ("marked_todo".to_string(), Status::Todo),
+                ]
+        );
+    }
+
+    #[test]
+    fn markers() {
+        assert!(Status::from_marker("enforced").unwrap() == Status::Enforced);
+        assert!(Status::from_marker("todo").unwrap() == Status::Todo);
+        assert!(Status::from_marker("not_applicable").unwrap() == Status::NotApplicable);
+        assert!(Status::from_marker("done").is_err());
+    }
+
+    #[test]
+    fn marker_errors() {
+        let parse = |body| {
+            crate::parse::RustParser::new(&source(&[("a", body)]))
+                .parse(GatherVuids::new())
+                .map(|_| ())
+        };
+        assert!(parse("        check_vuids::status!(enforced)").is_ok());
+        assert!(parse("        check_vuids::status!(done)").is_err());
+        assert!(parse("        check_vuids::status!()").is_err());
+        assert!(parse(
+            "        check_vuids::status!(todo)\n        check_vuids::status!(enforced)"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn markdown() {
+        let mut coverage = Coverage::new();
+        coverage.add(
+            "CreateInstance",
+            "a.rs",
+            "VUID_vkCreateInstance_a",
+            None,
+            Status::Enforced,
+        );
+        coverage.add(
+            "CreateInstance",
+            "b.rs",
+            "VUID_vkCreateInstance_b",
+            Some("(VK_KHR_a)"),
+            Status::Todo,
+        );
+        coverage.add(
+            "MemoryAllocateInfo",
+            "a.rs",
+            "VUID_VkMemoryAllocateInfo_a",
+            Some("(VK_KHR_a)"),
+            Status::NotApplicable,
+        );
+
+        assert_eq!(
+            coverage.to_string(),
+            "# VUID coverage
+
+| | enforced | todo | not applicable | total | covered |
+|---|---|---|---|---|---|
+| **all** | 1 | 1 | 1 | 3 | 66.7% |
+| commands | 1 | 1 | 0 | 2 | 50.0% |
+| structures | 0 | 0 | 1 | 1 | 100.0% |
+
+## Commands
+
+| target | file | enforced | todo | not applicable | total | covered |
+|---|---|---|---|---|---|---|
+| CreateInstance | a.rs<br>b.rs | 1 | 1 | 0 | 2 | 50.0% |
+
+## Structures
+
+| target | file | enforced | todo | not applicable | total | covered |
+|---|---|---|---|---|---|---|
+| MemoryAllocateInfo | a.rs | 0 | 0 | 1 | 1 | 100.0% |
+
+## Conditions
+
+| requires | enforced | todo | not applicable | total | covered |
+|---|---|---|---|---|---|
+| `(VK_KHR_a)` | 0 | 1 | 1 | 2 | 50.0% |
+"
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Counts::default().to_string(), "0 | 0 | 0 | 0 | 100.0%");
+    }
+}
@@ -6,9 +6,14 @@
 //! where you want to check the VUIDs for a particular Vulkan item. Then use `cargo run --bin vuids` and all files
 //! will be checked for check_vuids! and will ensure all VUID descriptions for the specified items are up to date by
 //! automatically adding/updating [`version!`], [`description!`], and [`old_description!`] as appropriate.
+//! VUIDs which are no longer in the spec are marked with [`obsolete!`], and VUIDs which only apply with certain
+//! extensions or versions are marked with [`requires!`].
 //!
 //! A VUID's name is represented as a block label, and the contents of the block is the VUID information.
 //!
+//! The check for each VUID can be classified with [`status!`] for the coverage report of the vuids bin
+//! (`cargo run --bin vuids -- --coverage`).
+//!
 //! *The macros generate no code, and are only meant to provide information to the reader in a structural way.
 //! User must manually ensure that each VUID invariant is satisfied with any appropriate means.*
 //!
@@ -68,3 +73,44 @@ macro_rules! description {
 macro_rules! old_description {
     ($($desc:literal)*) => {};
 }
+
+/// Marks a VUID which is no longer in the spec
+///
+/// The version is the version of the VUIDs in which the VUID could not be found. This is inserted by
+/// the vuids bin together with a `compile_error!("removed VUID")`. Check why the VUID was removed (e.g.
+/// it was merged into another VUID, or the target was renamed), and then delete the whole VUID block.
+///
+/// **DO NOT** manually use this. Use the vuids bin to automatically generate it.
+#[macro_export]
+macro_rules! obsolete {
+    ($ver:literal) => {};
+}
+
+/// The extensions and/or versions that the VUID applies to
+///
+/// This is the condition of the VUID from validusage.json, where `+` means all of, and `,` means any of
+/// (e.g. `"(VK_VERSION_1_1,VK_KHR_device_group)+(VK_KHR_swapchain)"`). A VUID without this always applies.
+///
+/// **DO NOT** manually use this. Use the vuids bin to automatically generate it.
+#[macro_export]
+macro_rules! requires {
+    ($condition:literal) => {};
+}
+
+/// How the VUID is handled
+///
+/// Use in a VUID block, after the [`description!`], to classify the VUID for the coverage
+/// report of the vuids bin (followed by `;` when there is code after it). One of:
+/// - `enforced`: the VUID is checked or otherwise guaranteed
+/// - `todo`: the VUID is not yet handled
+/// - `not_applicable`: the VUID can never apply, since the relevant functionality is not supported
+///
+/// Without this, the vuids bin guesses the status from the rest of the block (e.g. a `TODO` comment).
+///
+/// This macro **is** meant to be manually used.
+#[macro_export]
+macro_rules! status {
+    (enforced) => {};
+    (todo) => {};
+    (not_applicable) => {};
+}
@@ -2,23 +2,51 @@
 Check source files for check_vuids!(target) macro calls.
 
 for each such macro call found, check if the VUIDs in the same block are up to date
-and update as necessary
+and update as necessary. VUIDs which were removed from the spec are marked with
+`check_vuids::obsolete!` and a `compile_error!`. Targets which are not in the spec are listed
+in the error at the end, with any similar targets they were likely renamed to (e.g. promoted
+KHR commands).
 
 With no arguments passed, it will automatically search all source files in the workspace vk-safe/src
 
 One argument for the desired search directory can be provided and searched instead
 
+# --check
+With the `--check` flag, no files are changed. Instead, a JSON report of all VUIDs which
+are out of date is printed to stdout, and the exit code is non-zero if the report is not
+empty. Each entry in the report has the target, VUID, file, line, and kind of drift:
+- `new`: the VUID is in the reference but not in the file
+- `changed`: the VUID in the file has an older version and a different description
+- `removed-from-spec`: the VUID is in the file but not in the reference (`vuid` is null
+  if the whole target is not in the reference)
+- `renamed`: the target is not in the reference, but similar targets are, which are
+  listed in `renamed_to`
+
+This is intended for CI, to check the source against a newer validusage.json.
+
+# --coverage
+With the `--coverage` flag, no files are changed. Instead, a markdown summary of how each VUID
+is handled is printed to stdout, with totals and a per-target table for commands and structures.
+VUIDs which only apply with certain extensions and/or versions (i.e. marked with
+`check_vuids::requires!`) are also grouped by their condition.
+Each VUID is classified as enforced, todo, or not applicable, based on a
+`check_vuids::status!(...)` marker in the VUID block, or on the contents of the block
+otherwise (see `Status::classify`).
+
 # !!NOTE
 Do NOT tamper with the bodies of the generated version! and description! macro calls. You can
 (and should) delete the generated old_description! macro call after you are done checking the updated
 VUID.
  */
-use std::env::{args_os, current_exe};
+use std::env::args_os;
 use std::path::PathBuf;
+use std::process::ExitCode;
 
+mod coverage;
 mod file_edits;
 mod must_next;
 mod parse;
+mod report;
 mod vuid_check;
 mod vuids;
 
@@ -26,7 +54,7 @@ type Error = Box<dyn std::error::Error>;
 type Result<T> = std::result::Result<T, Error>;
 
 const USAGE_ERROR: &'static str =
-    "USAGE: run from workspace root or provide path to directory to check (note: expects exactly zero on one arguments)";
+    "USAGE: run from workspace root or provide path to directory to check (note: expects zero or one path, and optionally one of --check or --coverage)";
 
 fn check_in_workspace() -> Option<PathBuf> {
     let check_dir = PathBuf::from("vk-safe/src");
@@ -38,22 +66,58 @@ fn check_in_workspace() -> Option<PathBuf> {
     }
 }
 
-fn main() -> Result<()> {
-    let check_dir = match args_os().last() {
-        Some(arg) => {
-            if arg == current_exe()? {
-                check_in_workspace().ok_or(USAGE_ERROR)?
-            } else {
-                arg.into()
-            }
+#[derive(PartialEq, Eq)]
+enum Mode {
+    Fix,
+    Check,
+    Coverage,
+}
+
+fn main() -> Result<ExitCode> {
+    let mut mode = Mode::Fix;
+    let mut check_dir = None;
+
+    for arg in args_os().skip(1) {
+        if arg == "--check" && mode == Mode::Fix {
+            mode = Mode::Check;
+        } else if arg == "--coverage" && mode == Mode::Fix {
+            mode = Mode::Coverage;
+        } else if check_dir.is_none() {
+            check_dir = Some(PathBuf::from(arg));
+        } else {
+            Err(USAGE_ERROR)?
         }
+    }
+
+    let check_dir = match check_dir {
+        Some(check_dir) => check_dir,
         None => check_in_workspace().ok_or(USAGE_ERROR)?,
     };
 
     if !check_dir.is_dir() {
         Err(USAGE_ERROR)?
+    }
+
+    if mode == Mode::Coverage {
+        let mut coverage = coverage::Coverage::new();
+        vuid_check::coverage_vuids(check_dir.as_path(), &mut coverage)?;
+        print!("{coverage}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let vuid_collection = vuids::VuidCollection::new()?;
+
+    if mode == Mode::Check {
+        let mut report = report::Report::new(vuid_collection.version_tuple());
+        vuid_check::report_vuids(check_dir.as_path(), &vuid_collection, &mut report)?;
+        println!("{report}");
+        if report.is_empty() {
+            Ok(ExitCode::SUCCESS)
+        } else {
+            Ok(ExitCode::FAILURE)
+        }
     } else {
-        let vuid_collection = vuids::VuidCollection::new()?;
-        vuid_check::check_vuids(check_dir.as_path(), &vuid_collection)
+        vuid_check::check_vuids(check_dir.as_path(), &vuid_collection)?;
+        Ok(ExitCode::SUCCESS)
     }
 }
@@ -280,8 +280,13 @@ impl<'a> RustParser<'a> {
             let mut byte_iter = byte_iter.by_ref().peekable();
             while let Some(byte) = byte_iter.next() {
                 match byte.value {
-                    // visit a comment
-                    b'/' => eat_comment(&mut byte_iter)?,
+                    // visit a comment (otherwise '/' is just division)
+                    b'/' if byte_iter
+                        .peek()
+                        .is_some_and(|b| b.value == b'/' || b.value == b'*') =>
+                    {
+                        eat_comment(&mut byte_iter)?
+                    }
                     // visiting a string
                     b'"' => {
                         let string_start = byte.offset + 1;
@@ -438,13 +443,25 @@ fn eat_comment(iter: &mut impl Iterator<Item = Byte>) -> Result<()> {
         }
         b'*' => {
             // block comment, find end at "*/"
+            // block comments can be nested, so also count each inner "/*"
             const ERROR: &'static str = "ERROR: improper block comment end";
+            let mut depth = 1;
             let mut next_byte = iter.must_next(ERROR)?;
             'star_check: loop {
                 if next_byte.value == b'*' {
                     next_byte = iter.must_next(ERROR)?;
                     if next_byte.value == b'/' {
-                        return Ok(());
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(());
+                        }
+                    } else {
+                        continue 'star_check;
+                    }
+                } else if next_byte.value == b'/' {
+                    next_byte = iter.must_next(ERROR)?;
+                    if next_byte.value == b'*' {
+                        depth += 1;
                     } else {
                         continue 'star_check;
                     }
@@ -0,0 +1,209 @@
+use std::fmt;
+
+/// Kind of difference between a VUID in a source file and the reference VUIDs
+#[derive(Clone, Copy)]
+pub enum DriftKind {
+    /// the reference has a VUID which is not in the file
+    New,
+    /// the reference has a newer version of the VUID with a different description
+    Changed,
+    /// the file has a VUID (or target) which is not in the reference
+    RemovedFromSpec,
+    /// the file has a target which is not in the reference, but the reference has similar targets
+    Renamed,
+}
+
+impl DriftKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            DriftKind::New => "new",
+            DriftKind::Changed => "changed",
+            DriftKind::RemovedFromSpec => "removed-from-spec",
+            DriftKind::Renamed => "renamed",
+        }
+    }
+}
+
+/// A VUID in a source file which is out of date with the reference VUIDs
+pub struct Drift {
+    pub target: String,
+    /// None if the whole target is not in the reference
+    pub vuid: Option<String>,
+    pub file: String,
+    /// 1 based line in the file
+    ///
+    /// For a new VUID, this is the line where the VUID would be inserted
+    pub line: usize,
+    pub kind: DriftKind,
+    /// for [`DriftKind::Renamed`], the targets in the reference which are likely the new name
+    pub renamed_to: Vec<String>,
+}
+
+/// Report of all the VUIDs that are out of date, printed as JSON
+///
+/// ```text
+/// {
+///   "version": "1.3.268",
+///   "drift": [
+///     { "target": "CreateInstance", "vuid": "VUID_vkCreateInstance_ppEnabledExtensionNames_01388", "file": "vk-safe/src/entry/create_instance.rs", "line": 20, "kind": "new" },
+///     { "target": "GetPhysicalDeviceFeatures2KHR", "vuid": null, "file": "vk-safe/src/handles/physical_device/get_physical_device_features2.rs", "line": 12, "kind": "renamed", "renamed_to": ["GetPhysicalDeviceFeatures2"] }
+///   ]
+/// }
+/// ```
+pub struct Report {
+    version: (usize, usize, usize),
+    drift: Vec<Drift>,
+}
+
+impl Report {
+    pub fn new(version: (usize, usize, usize)) -> Self {
+        Self {
+            version,
+            drift: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, drift: Drift) {
+        self.drift.push(drift);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.drift.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (major, minor, patch) = self.version;
+        writeln!(f, "{{")?;
+        writeln!(f, "  \"version\": \"{major}.{minor}.{patch}\",")?;
+        write!(f, "  \"drift\": [")?;
+        let mut drift = self.drift.iter().peekable();
+        while let Some(d) = drift.next() {
+            write!(
+                f,
+                "\n    {{ \"target\": {}, \"vuid\": ",
+                JsonString(&d.target)
+            )?;
+            match d.vuid {
+                Some(ref vuid) => write!(f, "{}", JsonString(vuid))?,
+                None => write!(f, "null")?,
+            }
+            write!(
+                f,
+                ", \"file\": {}, \"line\": {}, \"kind\": \"{}\"",
+                JsonString(&d.file),
+                d.line,
+                d.kind.as_str()
+            )?;
+            if !d.renamed_to.is_empty() {
+                write!(f, ", \"renamed_to\": [")?;
+                let mut renamed_to = d.renamed_to.iter().peekable();
+                while let Some(target) = renamed_to.next() {
+                    write!(f, "{}", JsonString(target))?;
+                    if renamed_to.peek().is_some() {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, "]")?;
+            }
+            write!(f, " }}")?;
+            if drift.peek().is_some() {
+                write!(f, ",")?;
+            } else {
+                write!(f, "\n  ")?;
+            }
+        }
+        writeln!(f, "]")?;
+        write!(f, "}}")
+    }
+}
+
+/// 1 based line of the byte offset in the text
+pub fn line_of(text: &str, offset: usize) -> usize {
+    text.as_bytes()[..offset]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+/// print a str as a quoted and escaped JSON string
+struct JsonString<'a>(&'a str);
+
+impl fmt::Display for JsonString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                '\t' => write!(f, "\\t")?,
+                c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let mut report = Report::new((1, 3, 268));
+        assert!(report.is_empty());
+        assert_eq!(
+            report.to_string(),
+            "{\n  \"version\": \"1.3.268\",\n  \"drift\": []\n}"
+        );
+
+        report.push(Drift {
+            target: "CreateInstance".into(),
+            vuid: Some("VUID_vkCreateInstance_a".into()),
+            file: "src\\\"create\".rs".into(),
+            line: 20,
+            kind: DriftKind::New,
+            renamed_to: vec![],
+        });
+        report.push(Drift {
+            target: "GetPhysicalDeviceFeatures2KHR".into(),
+            vuid: None,
+            file: "features2.rs".into(),
+            line: 12,
+            kind: DriftKind::Renamed,
+            renamed_to: vec!["GetPhysicalDeviceFeatures2".into(), "X".into()],
+        });
+        assert!(!report.is_empty());
+        assert_eq!(
+            report.to_string(),
+            r#"{
+  "version": "1.3.268",
+  "drift": [
+    { "target": "CreateInstance", "vuid": "VUID_vkCreateInstance_a", "file": "src\\\"create\".rs", "line": 20, "kind": "new" },
+    { "target": "GetPhysicalDeviceFeatures2KHR", "vuid": null, "file": "features2.rs", "line": 12, "kind": "renamed", "renamed_to": ["GetPhysicalDeviceFeatures2", "X"] }
+  ]
+}"#
+        );
+    }
+
+    #[test]
+    fn json_string() {
+        assert_eq!(
+            JsonString("a\"b\\c\nd\te\u{1}").to_string(),
+            r#""a\"b\\c\nd\te\u0001""#
+        );
+    }
+
+    #[test]
+    fn line() {
+        let text = "a\nb\n\nc";
+        assert_eq!(line_of(text, 0), 1);
+        assert_eq!(line_of(text, 2), 2);
+        assert_eq!(line_of(text, 5), 4);
+    }
+}
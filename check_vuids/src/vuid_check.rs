@@ -1,100 +1,216 @@
+use std::collections::HashSet;
 use std::convert::TryInto;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Seek};
 use std::path::Path;
 
-use crate::vuids::VuidCollection;
+use generator::VecMap;
 
+use crate::vuids::{ReferenceVuid, VuidCollection};
+
+use crate::coverage::{Coverage, Status};
 use crate::file_edits::FileEdits;
+use crate::report::{line_of, Drift, DriftKind, Report};
 
 mod file_vuids;
-use file_vuids::GatherVuids;
+use file_vuids::TargetInfo;
+pub use file_vuids::{GatherVuids, VuidInfo};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
 /**
 Check all source code files in a rust project for directives to check VUIDs
 
-When found, check if all VUID checks are up-to-date and add any missing VUID check
+When found, check if all VUID checks are up-to-date and add any missing VUID check, and mark
+any VUID which was removed from the reference with `check_vuids::obsolete!`
+
+A target which is not in the reference does not stop the other files from being checked, but
+is returned as an error at the end (with any similar targets it was likely renamed to)
 
 Can be run in a build script (**only for local development**), or manually.
 When run manually, one argument indicating the path of the rust project with the src directory must be provided
  */
 pub fn check_vuids(check_dir: &Path, vuid_collection: &VuidCollection) -> Result<()> {
-    for path in check_dir.read_dir()? {
+    let mut unknown_targets = Vec::new();
+
+    for_each_file(check_dir, &mut |path| {
+        // open and check file
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+
+        check_file(&mut file, path, vuid_collection, &mut unknown_targets)
+    })?;
+
+    if unknown_targets.is_empty() {
+        Ok(())
+    } else {
+        for unknown in unknown_targets {
+            eprintln!("Can't find VUIDs for {unknown}");
+        }
+        Err("Can't find VUIDs for some targets")?
+    }
+}
+
+/**
+Check all source code files in a rust project for VUIDs which are out of date, without changing any file
+
+Each VUID which [`check_vuids`] would add or update, and each VUID (or target) in a file which
+is not in the reference VUIDs, is added to the report.
+ */
+pub fn report_vuids(
+    check_dir: &Path,
+    vuid_collection: &VuidCollection,
+    report: &mut Report,
+) -> Result<()> {
+    for_each_file(check_dir, &mut |path| {
+        let mut file = File::open(path)?;
+
+        report_file(&mut file, path, vuid_collection, report)
+    })
+}
+
+/**
+Gather the [`Status`] of every VUID in all source code files in a rust project
+ */
+pub fn coverage_vuids(check_dir: &Path, coverage: &mut Coverage) -> Result<()> {
+    for_each_file(check_dir, &mut |path| {
+        let mut file = File::open(path)?;
+        let buffer = load_file(&mut file)?;
+        let file_vuids = gather_vuids(&buffer, path)?;
+
+        let file = path.display().to_string();
+        for target in file_vuids.targets() {
+            for (vuid, vuid_info) in target.vuids() {
+                coverage.add(
+                    target.name(),
+                    &file,
+                    vuid,
+                    vuid_info.requires(),
+                    Status::classify(&buffer, vuid_info),
+                );
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// call `f` with the path of each file in `dir` (and all sub directories)
+fn for_each_file(dir: &Path, f: &mut impl FnMut(&Path) -> Result<()>) -> Result<()> {
+    for path in dir.read_dir()? {
         let path = path?;
         eprintln!("{path:?}");
 
         if path.file_type()?.is_dir() {
             // recurse into all directories
-            check_vuids(path.path().as_path(), vuid_collection)?;
+            for_each_file(path.path().as_path(), f)?;
         } else {
-            // open and check file
-            let mut file = OpenOptions::new()
-                .read(true)
-                .write(true)
-                .open(path.path().as_path())?;
-
-            check_file(&mut file, vuid_collection)?;
+            f(path.path().as_path())?;
         }
     }
 
     Ok(())
 }
 
-fn check_file(file: &mut File, vuid_collection: &VuidCollection) -> Result<()> {
-    let buffer = load_file(file)?;
-    let mut parser = crate::parse::RustParser::new(&buffer);
+/// gather the VUIDs in a file, with the path of the file in any error
+fn gather_vuids<'a>(buffer: &'a str, path: &Path) -> Result<GatherVuids<'a>> {
+    let mut parser = crate::parse::RustParser::new(buffer);
+    parser
+        .parse(GatherVuids::new())
+        .map_err(|e| format!("{}: {e}", path.display()).into())
+}
 
-    let file_vuids = parser.parse(GatherVuids::new())?;
+fn check_file(
+    file: &mut File,
+    path: &Path,
+    vuid_collection: &VuidCollection,
+    unknown_targets: &mut Vec<String>,
+) -> Result<()> {
+    let buffer = load_file(file)?;
+    let file_vuids = gather_vuids(&buffer, path)?;
 
     let mut file_edits = FileEdits::new(&buffer);
 
-    // for each target found in the file, compare each target's reference VUID's "version" to the corresponding VUID's "version" in the file
-    // if the reference VUIDs include new VUIDs not in the file (no corresponding in file), add the new VUIDs to the file with a compile_error!("new VUID")
-    // if the reference VUIDs have a higher version, compare the associated descriptions
-    // if the descriptions do not match, update the description of the file VUID and add a old_description!("...") and compile_error!("updated VUID")
+    // if the reference VUIDs include new VUIDs not in the file, add the new VUIDs to the file with a compile_error!("new VUID")
+    // if a VUID changed, update the description of the file VUID and add a old_description!("...") and compile_error!("updated VUID")
+    // if a VUID was removed from the reference, add a obsolete!("version") and compile_error!("removed VUID")
+    // if the condition of a VUID changed, add, update, or remove the requires!("condition")
     for target in file_vuids.targets() {
-        let reference_vuids = vuid_collection
-            .get_target(target.name())
-            .ok_or(format!("Can't find VUIDs for {}", target.name()))?;
-
-        let mut insert_offset = target.start_offset();
-
-        for (vuid, &description) in reference_vuids
-            .ordered_key_value_iter()
-            .expect("vuid collection must use copy keys")
-        {
-            // check if target has this vuid
-            match target.get_vuid(vuid) {
-                Some(vuid_info) => {
-                    // compare versions
-                    if vuid_collection.version_tuple() > vuid_info.version() {
-                        // compare description
-                        if !descriptions_equal(description, vuid_info.description()) {
-                            // update description
-                            file_edits.delete(vuid_info.info_start(), vuid_info.info_end());
-                            file_edits.insert(
-                                updated_vuid_info(
-                                    vuid_collection.version_tuple(),
-                                    description,
-                                    vuid_info.description(),
-                                ),
-                                vuid_info.info_end(),
-                            );
-                        }
-                    }
-                    // I assume the vuids in the file will be in roughly the same order as in the reference
-                    // after each target vuid we find in the file, update the insert offset so we insert new ones after this
-                    insert_offset = vuid_info.block_end();
-                }
-                None => {
+        let Some(reference_vuids) = vuid_collection.get_target(target.name()) else {
+            let line = line_of(&buffer, target.start_offset());
+            let mut unknown = format!("{}:{line}: {}", path.display(), target.name());
+            let renamed_to = vuid_collection.renamed_targets(target.name());
+            if !renamed_to.is_empty() {
+                unknown.push_str(&format!(" (renamed to {}?)", renamed_to.join(" or ")));
+            }
+            unknown_targets.push(unknown);
+            continue;
+        };
+
+        for difference in compare_target(target, reference_vuids, vuid_collection.version_tuple()) {
+            match difference {
+                Difference::New {
+                    vuid,
+                    description,
+                    requires,
+                    insert_offset,
+                } => {
                     // add new vuid
                     file_edits.insert(
-                        new_vuid(vuid, vuid_collection.version_tuple(), description),
+                        new_vuid(vuid, vuid_collection.version_tuple(), description, requires),
                         insert_offset,
                     );
                 }
+                Difference::Changed {
+                    vuid_info,
+                    description,
+                    ..
+                } => {
+                    // update description
+                    file_edits.delete(vuid_info.info_start(), vuid_info.info_end());
+                    file_edits.insert(
+                        updated_vuid_info(
+                            vuid_collection.version_tuple(),
+                            description,
+                            vuid_info.description(),
+                        ),
+                        vuid_info.info_end(),
+                    );
+                }
+                Difference::Removed { vuid_info, .. } => {
+                    // a VUID which is already marked is left as it is
+                    if vuid_info.obsolete().is_none() {
+                        file_edits.insert(
+                            obsolete_vuid_info(vuid_collection.version_tuple()),
+                            vuid_info.info_end(),
+                        );
+                    }
+                }
+                Difference::Requires {
+                    vuid_info,
+                    requires,
+                    ..
+                } => match vuid_info.requires_range() {
+                    Some((start, end)) => {
+                        // delete the whole requires!("condition"), including the check_vuids:: path
+                        let start = if buffer[..start].ends_with("check_vuids::") {
+                            start - "check_vuids::".len()
+                        } else {
+                            start
+                        };
+                        file_edits.delete(start, end);
+                        if let Some(requires) = requires {
+                            file_edits.insert(requires_vuid_info(requires), end);
+                        }
+                    }
+                    None => {
+                        if let Some(requires) = requires {
+                            file_edits.insert(
+                                format!("\n            {}", requires_vuid_info(requires)),
+                                vuid_info.info_end(),
+                            );
+                        }
+                    }
+                },
             }
         }
     }
@@ -104,8 +220,189 @@ fn check_file(file: &mut File, vuid_collection: &VuidCollection) -> Result<()> {
     Ok(())
 }
 
-fn new_vuid(name: &str, (major, minor, patch): (usize, usize, usize), description: &str) -> String {
+fn report_file(
+    file: &mut File,
+    path: &Path,
+    vuid_collection: &VuidCollection,
+    report: &mut Report,
+) -> Result<()> {
+    let buffer = load_file(file)?;
+    let file_vuids = gather_vuids(&buffer, path)?;
+
+    let drift = |target: &TargetInfo, vuid: Option<&str>, offset, kind| Drift {
+        target: target.name().to_string(),
+        vuid: vuid.map(str::to_string),
+        file: path.display().to_string(),
+        line: line_of(&buffer, offset),
+        kind,
+        renamed_to: Vec::new(),
+    };
+
+    for target in file_vuids.targets() {
+        let Some(reference_vuids) = vuid_collection.get_target(target.name()) else {
+            // the whole target is not in the reference
+            let renamed_to = vuid_collection.renamed_targets(target.name());
+            let kind = if renamed_to.is_empty() {
+                DriftKind::RemovedFromSpec
+            } else {
+                DriftKind::Renamed
+            };
+            report.push(Drift {
+                renamed_to: renamed_to.into_iter().map(str::to_string).collect(),
+                ..drift(target, None, target.start_offset(), kind)
+            });
+            continue;
+        };
+
+        for difference in compare_target(target, reference_vuids, vuid_collection.version_tuple()) {
+            report.push(match difference {
+                Difference::New {
+                    vuid,
+                    insert_offset,
+                    ..
+                } => drift(target, Some(vuid), insert_offset, DriftKind::New),
+                Difference::Changed {
+                    vuid, vuid_info, ..
+                } => drift(target, Some(vuid), vuid_info.start(), DriftKind::Changed),
+                Difference::Removed { vuid, vuid_info } => drift(
+                    target,
+                    Some(vuid),
+                    vuid_info.start(),
+                    DriftKind::RemovedFromSpec,
+                ),
+                Difference::Requires {
+                    vuid, vuid_info, ..
+                } => drift(target, Some(vuid), vuid_info.start(), DriftKind::Changed),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// A difference between the VUIDs of a target in a file and the reference VUIDs
+enum Difference<'f, 'a> {
+    /// reference VUID which is not in the file, to be inserted at `insert_offset`
+    New {
+        vuid: &'static str,
+        description: &'static str,
+        requires: Option<&'static str>,
+        insert_offset: usize,
+    },
+    /// VUID in the file with an older version and a different description than the reference
+    Changed {
+        vuid: &'static str,
+        vuid_info: &'f VuidInfo<'a>,
+        description: &'static str,
+    },
+    /// VUID in the file which is not in the reference
+    Removed {
+        vuid: &'a str,
+        vuid_info: &'f VuidInfo<'a>,
+    },
+    /// VUID in the file with a different condition (requires!) than the reference
+    Requires {
+        vuid: &'static str,
+        vuid_info: &'f VuidInfo<'a>,
+        requires: Option<&'static str>,
+    },
+}
+
+/// compare the VUIDs of a target in a file with the reference VUIDs
+///
+/// The differences are in order of the offsets in the file, so that they can be used for [`FileEdits`]
+fn compare_target<'f, 'a>(
+    target: &'f TargetInfo<'a>,
+    reference_vuids: &VecMap<&'static str, ReferenceVuid>,
+    version: (usize, usize, usize),
+) -> Vec<Difference<'f, 'a>> {
+    let mut differences = Vec::new();
+
+    let mut insert_offset = target.start_offset();
+
+    // for each reference VUID, compare the reference VUID's "version" to the corresponding VUID's "version" in the file
+    // if the reference VUID has a higher version, compare the associated descriptions
+    for (
+        vuid,
+        &ReferenceVuid {
+            description,
+            requires,
+        },
+    ) in reference_vuids
+        .ordered_key_value_iter()
+        .expect("vuid collection must use copy keys")
+    {
+        // check if target has this vuid
+        match target.get_vuid(vuid) {
+            Some(vuid_info) => {
+                // compare versions and descriptions
+                if version > vuid_info.version()
+                    && !descriptions_equal(description, vuid_info.description())
+                {
+                    differences.push(Difference::Changed {
+                        vuid,
+                        vuid_info,
+                        description,
+                    });
+                }
+                if requires != vuid_info.requires() {
+                    differences.push(Difference::Requires {
+                        vuid,
+                        vuid_info,
+                        requires,
+                    });
+                }
+                // I assume the vuids in the file will be in roughly the same order as in the reference
+                // after each target vuid we find in the file, update the insert offset so we insert new ones after this
+                insert_offset = vuid_info.block_end();
+            }
+            None => differences.push(Difference::New {
+                vuid,
+                description,
+                requires,
+                insert_offset,
+            }),
+        }
+    }
+
+    let reference_names: HashSet<&str> = reference_vuids
+        .ordered_key_value_iter()
+        .into_iter()
+        .flatten()
+        .map(|(vuid, _)| vuid)
+        .collect();
+
+    for (vuid, vuid_info) in target.vuids() {
+        if !reference_names.contains(vuid) {
+            differences.push(Difference::Removed { vuid, vuid_info });
+        }
+    }
+
+    // stable sort, so new VUIDs with the same insert offset stay in the reference order
+    differences.sort_by_key(|difference| match difference {
+        Difference::New { insert_offset, .. } => *insert_offset,
+        Difference::Changed { vuid_info, .. } => vuid_info.info_start(),
+        Difference::Removed { vuid_info, .. } => vuid_info.info_end(),
+        Difference::Requires { vuid_info, .. } => match vuid_info.requires_range() {
+            Some((start, _)) => start,
+            None => vuid_info.info_end(),
+        },
+    });
+
+    differences
+}
+
+fn new_vuid(
+    name: &str,
+    (major, minor, patch): (usize, usize, usize),
+    description: &str,
+    requires: Option<&str>,
+) -> String {
     let description = DescriptionFormatter { desc: description };
+    let requires = match requires {
+        Some(requires) => format!("\n            {}", requires_vuid_info(requires)),
+        None => String::new(),
+    };
     format!(
         "\n
         #[allow(unused_labels)]
@@ -113,13 +410,17 @@ fn new_vuid(name: &str, (major, minor, patch): (usize, usize, usize), descriptio
             check_vuids::version!{{\"{major}.{minor}.{patch}\"}}
             check_vuids::description!{{
             {description}
-            }}
+            }}{requires}
 
             compile_error!(\"new VUID\");
         }}"
     )
 }
 
+fn requires_vuid_info(requires: &str) -> String {
+    format!("check_vuids::requires!{{\"{requires}\"}}")
+}
+
 fn updated_vuid_info<'a>(
     (major, minor, patch): (usize, usize, usize),
     new_description: &str,
@@ -144,6 +445,15 @@ fn updated_vuid_info<'a>(
     )
 }
 
+fn obsolete_vuid_info((major, minor, patch): (usize, usize, usize)) -> String {
+    format!(
+        "
+            check_vuids::obsolete!{{\"{major}.{minor}.{patch}\"}}
+
+            compile_error!(\"removed VUID\");"
+    )
+}
+
 const DESCRIPTION_WIDTH: usize = 80;
 /// split the description into lines if a predetermined width is passed
 struct DescriptionFormatter<'a> {
@@ -172,6 +482,8 @@ impl std::fmt::Display for DescriptionFormatter<'_> {
         let mut lines = std::iter::from_fn(next_line).peekable();
 
         while let Some(line) = lines.next() {
+            // the description is plain text, which must be escaped in a string literal
+            let line = line.replace('\\', "\\\\").replace('"', "\\\"");
             if lines.peek().is_some() {
                 writeln!(f, "\"{line}\"")?;
             } else {
@@ -203,17 +515,22 @@ impl<'a, I: Iterator<Item = &'a str> + Clone> std::fmt::Display for PrintOldDesc
 
 // compare descriptions without whitespace since the descriptions in the file are formatted
 // and whitespace is assumed to have no significant meaning
+// the descriptions in the file are string literals, so escapes are removed first
 fn descriptions_equal<'a>(reference_desc: &str, file_desc: impl Iterator<Item = &'a str>) -> bool {
-    let mut d1 = reference_desc.chars().filter(|c| !c.is_whitespace());
-    let mut d2 = file_desc
-        .map(|s| s.chars().filter(|c| !c.is_whitespace()))
+    let d1 = reference_desc.chars().filter(|c| !c.is_whitespace());
+    let d2 = file_desc
+        .map(|s| unescape(s).filter(|c| !c.is_whitespace()))
         .flatten();
-    for (d1, d2) in d1.by_ref().zip(d2.by_ref()) {
-        if d1 != d2 {
-            return false;
-        }
-    }
-    d1.next().is_none() && d2.next().is_none()
+    d1.eq(d2)
+}
+
+/// remove the escapes (i.e. \" and \\) from the contents of a string literal
+fn unescape(s: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = s.chars();
+    std::iter::from_fn(move || match chars.next()? {
+        '\\' => chars.next(),
+        c => Some(c),
+    })
 }
 
 fn load_file(file: &mut File) -> Result<String> {
@@ -273,3 +590,275 @@ impl<'a> crate::parse::RustFileVisitor<'a> for PrintlnVisitor {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VERSION: (usize, usize, usize) = (1, 3, 268);
+
+    fn reference(
+        vuids: &[(&'static str, &'static str, Option<&'static str>)],
+    ) -> VecMap<&'static str, ReferenceVuid> {
+        let mut reference = VecMap::default();
+        for &(vuid, description, requires) in vuids {
+            reference.push_copy_key(
+                vuid,
+                ReferenceVuid {
+                    description,
+                    requires,
+                },
+            );
+        }
+        reference
+    }
+
+    /// the kind, VUID and offset (for sorting) of each difference
+    fn differences(
+        source: &str,
+        reference: &VecMap<&'static str, ReferenceVuid>,
+    ) -> Vec<(&'static str, String, usize)> {
+        let file_vuids = crate::parse::RustParser::new(source)
+            .parse(GatherVuids::new())
+            .unwrap();
+        let target = file_vuids.targets().next().unwrap();
+        compare_target(target, reference, VERSION)
+            .into_iter()
+            .map(|difference| match difference {
+                Difference::New {
+                    vuid,
+                    insert_offset,
+                    ..
+                } => ("new", vuid.to_string(), insert_offset),
+                Difference::Changed {
+                    vuid, vuid_info, ..
+                } => ("changed", vuid.to_string(), vuid_info.info_start()),
+                Difference::Removed { vuid, vuid_info } => {
+                    ("removed", vuid.to_string(), vuid_info.info_end())
+                }
+                Difference::Requires {
+                    vuid, vuid_info, ..
+                } => ("requires", vuid.to_string(), vuid_info.info_end()),
+            })
+            .collect()
+    }
+
+    const SOURCE: &str = r#"
+fn create_instance() {
+    check_vuids::check_vuids!(CreateInstance);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateInstance_a: {
+        check_vuids::version!{"1.3.200"}
+        check_vuids::description!{
+        "old"
+        "description"
+        }
+
+        // user added check
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateInstance_same: {
+        check_vuids::version!{"1.3.200"}
+        check_vuids::description!{
+        "the \"same\" text"
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateInstance_gone: {
+        check_vuids::version!{"1.3.200"}
+        check_vuids::description!{
+        "gone"
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn compare() {
+        let reference = reference(&[
+            ("VUID_vkCreateInstance_first", "first", None),
+            ("VUID_vkCreateInstance_a", "new description", None),
+            ("VUID_vkCreateInstance_same", "the\n\"same\"  text", None),
+            ("VUID_vkCreateInstance_b", "b", None),
+        ]);
+        let target_start = SOURCE.find(';').unwrap() + 1;
+        // the end of each VUID block is the only } with 4 spaces of indentation
+        let a_end = SOURCE.find("\n    }\n").unwrap() + 6;
+        let same_end = SOURCE[a_end..].find("\n    }\n").unwrap() + a_end + 6;
+        let gone_end = SOURCE.rfind("\"gone\"").unwrap() + "\"gone\"\n        }".len();
+
+        assert_eq!(
+            differences(SOURCE, &reference),
+            [
+                (
+                    "new",
+                    "VUID_vkCreateInstance_first".to_string(),
+                    target_start
+                ),
+                (
+                    "changed",
+                    "VUID_vkCreateInstance_a".to_string(),
+                    SOURCE.find("version!").unwrap()
+                ),
+                ("new", "VUID_vkCreateInstance_b".to_string(), same_end),
+                (
+                    "removed",
+                    "VUID_vkCreateInstance_gone".to_string(),
+                    gone_end
+                ),
+            ]
+        );
+        assert!(a_end < same_end);
+    }
+
+    #[test]
+    fn up_to_date() {
+        // a newer description in the file is not replaced by an older reference
+        let source = SOURCE.replace("1.3.200", "1.3.300");
+        let reference = reference(&[
+            ("VUID_vkCreateInstance_a", "older description", None),
+            ("VUID_vkCreateInstance_same", "the \"same\" text", None),
+            ("VUID_vkCreateInstance_gone", "gone", None),
+        ]);
+        assert!(differences(&source, &reference).is_empty());
+    }
+
+    #[test]
+    fn obsolete() {
+        let source = SOURCE.replace(
+            "\"gone\"\n        }",
+            "\"gone\"\n        }\n        check_vuids::obsolete!{\"1.3.250\"}",
+        );
+        let file_vuids = gather_vuids(&source, Path::new("a.rs")).unwrap();
+        let target = file_vuids.targets().next().unwrap();
+        let gone = target.get_vuid("VUID_vkCreateInstance_gone").unwrap();
+        assert_eq!(gone.obsolete(), Some((1, 3, 250)));
+
+        // a VUID which is already marked is still removed, but is not marked again by check_file
+        let reference = reference(&[
+            ("VUID_vkCreateInstance_a", "old description", None),
+            ("VUID_vkCreateInstance_same", "the \"same\" text", None),
+        ]);
+        assert!(
+            differences(&source, &reference)
+                == [(
+                    "removed",
+                    "VUID_vkCreateInstance_gone".to_string(),
+                    gone.info_end()
+                )]
+        );
+
+        assert_eq!(
+            obsolete_vuid_info((1, 3, 268)),
+            "
+            check_vuids::obsolete!{\"1.3.268\"}
+
+            compile_error!(\"removed VUID\");"
+        );
+    }
+
+    #[test]
+    fn parse_errors_have_path() {
+        let source = SOURCE.replace(
+            "\"gone\"\n        }",
+            "\"gone\"\n        }\n        check_vuids::obsolete!{\"1.3.250\"}\n        check_vuids::obsolete!{\"1.3.260\"}",
+        );
+        let error = gather_vuids(&source, Path::new("src/a.rs"))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.starts_with("src/a.rs: "));
+        assert!(error.contains("VUID_vkCreateInstance_gone: obsolete!"));
+    }
+
+    #[test]
+    fn requires() {
+        let source = SOURCE.replace(
+            "\"gone\"\n        }",
+            "\"gone\"\n        }\n        check_vuids::requires!{\"(VK_KHR_a)\"}",
+        );
+        let file_vuids = gather_vuids(&source, Path::new("a.rs")).unwrap();
+        let target = file_vuids.targets().next().unwrap();
+        let a = target.get_vuid("VUID_vkCreateInstance_a").unwrap();
+        let gone = target.get_vuid("VUID_vkCreateInstance_gone").unwrap();
+
+        // a condition is added to a, changed for gone, and nothing changes for same
+        let with_requires = reference(&[
+            (
+                "VUID_vkCreateInstance_a",
+                "old description",
+                Some("(VK_KHR_b)"),
+            ),
+            ("VUID_vkCreateInstance_same", "the \"same\" text", None),
+            (
+                "VUID_vkCreateInstance_gone",
+                "gone",
+                Some("(VK_VERSION_1_1,VK_KHR_a)"),
+            ),
+        ]);
+        assert!(
+            differences(&source, &with_requires)
+                == [
+                    (
+                        "requires",
+                        "VUID_vkCreateInstance_a".to_string(),
+                        a.info_end()
+                    ),
+                    (
+                        "requires",
+                        "VUID_vkCreateInstance_gone".to_string(),
+                        gone.info_end()
+                    ),
+                ]
+        );
+
+        // the condition is removed from gone
+        let without_requires = reference(&[
+            ("VUID_vkCreateInstance_a", "old description", None),
+            ("VUID_vkCreateInstance_same", "the \"same\" text", None),
+            ("VUID_vkCreateInstance_gone", "gone", None),
+        ]);
+        assert!(
+            differences(&source, &without_requires)
+                == [(
+                    "requires",
+                    "VUID_vkCreateInstance_gone".to_string(),
+                    gone.info_end()
+                )]
+        );
+
+        assert_eq!(
+            new_vuid("VUID_vkA_b", VERSION, "text", Some("(VK_KHR_a)")),
+            "\n
+        #[allow(unused_labels)]
+        'VUID_vkA_b: {
+            check_vuids::version!{\"1.3.268\"}
+            check_vuids::description!{
+            \"text\"
+            }
+            check_vuids::requires!{\"(VK_KHR_a)\"}
+
+            compile_error!(\"new VUID\");
+        }"
+        );
+    }
+
+    #[test]
+    fn descriptions() {
+        assert!(descriptions_equal(
+            "a \"quoted\" \\ description",
+            ["a \\\"quoted\\\"", "\\\\ description"].into_iter()
+        ));
+        assert!(!descriptions_equal("a b", ["a"].into_iter()));
+        assert!(!descriptions_equal("a", ["a b"].into_iter()));
+        assert!(!descriptions_equal("a b", ["a c"].into_iter()));
+
+        assert_eq!(
+            DescriptionFormatter { desc: "say \"hi\"" }.to_string(),
+            "\"say \\\"hi\\\"\""
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use generator::VecMap;
 
+use crate::coverage::Status;
+
 pub trait Captures<U> {}
 impl<T, U> Captures<U> for T {}
 
@@ -19,6 +21,15 @@ enum CheckVisitorState {
     DescriptionStart,
     GetDescription,
     VuidBlockEnd,
+    StatusStart,
+    GetStatus,
+    StatusEnd,
+    ObsoleteStart,
+    GetObsolete,
+    ObsoleteEnd,
+    RequiresStart,
+    GetRequires,
+    RequiresEnd,
 }
 
 pub struct TargetInfo<'a> {
@@ -54,6 +65,11 @@ impl<'a> TargetInfo<'a> {
         self.vuids.get(vuid)
     }
 
+    /// iterate over the vuids in the order they are in the file
+    pub fn vuids(&self) -> impl Iterator<Item = (&'a str, &VuidInfo<'a>)> {
+        self.vuids.ordered_key_value_iter().into_iter().flatten()
+    }
+
     pub fn start_offset(&self) -> usize {
         self.vuids_start
             .expect("Target parsed with improper syntax")
@@ -64,7 +80,21 @@ pub struct VuidInfo<'a> {
     version: Option<(usize, usize, usize)>,
     description: Option<Vec<&'a str>>,
 
-    #[allow(unused)]
+    /// status from check_vuids::status!(status), if provided
+    status: Option<Status>,
+
+    /// version from check_vuids::obsolete!("ver"), if the VUID was already found to be removed
+    obsolete: Option<(usize, usize, usize)>,
+
+    /// condition from check_vuids::requires!("condition"), if the VUID does not always apply
+    requires: Option<&'a str>,
+
+    /// offset into the file to the first byte of requires!("condition") (not including the check_vuids:: path)
+    requires_start: Option<usize>,
+
+    /// offset into the file to the first byte after requires!("condition")
+    requires_end: Option<usize>,
+
     /// offset into the file to the beginning of the vuid block label (including ')
     start: usize,
 
@@ -85,6 +115,11 @@ impl<'a> VuidInfo<'a> {
         Self {
             version: None,
             description: None,
+            status: None,
+            obsolete: None,
+            requires: None,
+            requires_start: None,
+            requires_end: None,
             start,
             info_start: None,
             info_end: None,
@@ -101,6 +136,22 @@ impl<'a> VuidInfo<'a> {
             .iter()
             .copied()
     }
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+    pub fn obsolete(&self) -> Option<(usize, usize, usize)> {
+        self.obsolete
+    }
+    pub fn requires(&self) -> Option<&'a str> {
+        self.requires
+    }
+    /// start and end offsets of requires!("condition"), if present
+    pub fn requires_range(&self) -> Option<(usize, usize)> {
+        Some((self.requires_start?, self.requires_end?))
+    }
+    pub fn start(&self) -> usize {
+        self.start
+    }
     pub fn info_start(&self) -> usize {
         self.info_start.expect("info start must have been found")
     }
@@ -139,6 +190,14 @@ impl<'a> GatherVuids<'a> {
         self.expect_last_target_mut(expect).last_vuid_mut(expect)
     }
 
+    fn expect_last_vuid_label(&self, expect: &'static str) -> &'a str {
+        self.targets
+            .last()
+            .and_then(|target| target.vuids().last())
+            .map(|(label, _)| label)
+            .expect(expect)
+    }
+
     pub fn targets(&self) -> impl Iterator<Item = &TargetInfo<'a>> {
         self.targets.iter()
     }
@@ -157,6 +216,33 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
 
                 self.state = VersionEnd;
             }
+            GetObsolete => {
+                let label = self.expect_last_vuid_label("GetObsolete state: no vuid");
+                let vuid = self.expect_last_vuid_mut("GetObsolete state: no vuid");
+                if vuid.obsolete.is_some() {
+                    Err(format!(
+                        "{label}: obsolete!{{version}} should only be used once per VUID"
+                    ))?;
+                }
+
+                let version = crate::vuids::parse_version(&range)?;
+                vuid.obsolete = Some(version);
+
+                self.state = ObsoleteEnd;
+            }
+            GetRequires => {
+                let label = self.expect_last_vuid_label("GetRequires state: no vuid");
+                let vuid = self.expect_last_vuid_mut("GetRequires state: no vuid");
+                if vuid.requires.is_some() {
+                    Err(format!(
+                        "{label}: requires!{{condition}} should only be used once per VUID"
+                    ))?;
+                }
+
+                vuid.requires = Some(range.inner());
+
+                self.state = RequiresEnd;
+            }
             GetDescription => {
                 let vuid = self.expect_last_vuid_mut("GetDescription state: no vuid");
 
@@ -177,6 +263,17 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
                 self.targets.push(TargetInfo::new(range.inner()));
                 self.state = TargetEnd;
             }
+            GetStatus => {
+                let label = self.expect_last_vuid_label("GetStatus state: no vuid");
+                let vuid = self.expect_last_vuid_mut("GetStatus state: no vuid");
+                if vuid.status.is_some() {
+                    Err(format!(
+                        "{label}: status!(status) should only be used once per VUID"
+                    ))?;
+                }
+                vuid.status = Some(Status::from_marker(&range)?);
+                self.state = StatusEnd;
+            }
             _ => {}
         }
         Ok(())
@@ -211,6 +308,17 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
                     self.state = DescriptionStart;
                 }
             }
+            VuidBlockEnd => {
+                if &*range == "status" {
+                    self.state = StatusStart;
+                } else if &*range == "obsolete" {
+                    self.state = ObsoleteStart;
+                } else if &*range == "requires" {
+                    self.state = RequiresStart;
+                    self.expect_last_vuid_mut("VuidBlockEnd state: no vuid")
+                        .requires_start = Some(range.start_position());
+                }
+            }
             _ => {}
         }
         Ok(())
@@ -226,7 +334,7 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
             LookingForVuidBlock => {
                 self.expect_last_target_mut("LookingForVuidBlock state: no target")
                     .vuids
-                    .push(range.inner(), VuidInfo::new(label_start));
+                    .push_copy_key(range.inner(), VuidInfo::new(label_start));
                 self.state = VuidBlockStart;
             }
             _ => {}
@@ -260,6 +368,24 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
                 }
                 self.state = GetDescription;
             }
+            StatusStart => {
+                if kind != crate::parse::Delimiter::Parenthesis {
+                    Err("Expect Parenthesis")?;
+                }
+                self.state = GetStatus;
+            }
+            ObsoleteStart => {
+                if kind != crate::parse::Delimiter::Brace {
+                    Err("Expect Brace")?;
+                }
+                self.state = GetObsolete;
+            }
+            RequiresStart => {
+                if kind != crate::parse::Delimiter::Brace {
+                    Err("Expect Brace")?;
+                }
+                self.state = GetRequires;
+            }
             VuidBlockStart => {
                 self.state = LookingForVuidVersion;
                 self.vuid_block_depth = Some(self.block_depth);
@@ -281,6 +407,19 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
             LookingForVuidVersion | GetVersion => Err("could not find version!(version_text)")?,
             LookingForVuidDescription => Err("could not find description!(description_text)")?,
             VersionEnd => self.state = LookingForVuidDescription,
+            GetStatus => Err("check_vuids::status!(...) missing status")?,
+            StatusEnd => self.state = VuidBlockEnd,
+            GetObsolete => Err("check_vuids::obsolete!{...} missing version")?,
+            ObsoleteEnd => self.state = VuidBlockEnd,
+            GetRequires => Err("check_vuids::requires!{...} missing condition")?,
+            RequiresEnd => {
+                let vuid = self.expect_last_vuid_mut("RequiresEnd state: no vuid");
+                assert!(vuid.requires_end.is_none());
+
+                vuid.requires_end = Some(offset + 1);
+
+                self.state = VuidBlockEnd;
+            }
             GetDescription => {
                 if kind != crate::parse::Delimiter::Brace {
                     Err("Expected Brace")?;
@@ -352,3 +491,117 @@ impl<'a> crate::parse::RustFileVisitor<'a> for GatherVuids<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// a VUID block with `body` after the description
+    fn source(body: &str) -> &'static str {
+        let source = format!(
+            r#"
+fn f() {{
+    check_vuids::check_vuids!(Target);
+
+    #[allow(unused_labels)]
+    'VUID_vkTarget_a: {{
+        check_vuids::version!{{"1.3.200"}}
+        check_vuids::description!{{
+        "description"
+        }}
+{body}
+    }}
+}}
+"#
+        );
+        // leak the source, so that the VUIDs can borrow it
+        Box::leak(source.into_boxed_str())
+    }
+
+    fn parse(body: &str) -> crate::Result<GatherVuids<'static>> {
+        crate::parse::RustParser::new(source(body)).parse(GatherVuids::new())
+    }
+
+    fn vuid<'a>(file_vuids: &'a GatherVuids) -> &'a VuidInfo<'a> {
+        let target = file_vuids.targets().next().unwrap();
+        target.get_vuid("VUID_vkTarget_a").unwrap()
+    }
+
+    #[test]
+    fn obsolete() {
+        let file_vuids = parse("").unwrap();
+        assert_eq!(vuid(&file_vuids).obsolete(), None);
+
+        let file_vuids = parse(
+            r#"
+        check_vuids::obsolete!{"1.3.268"}
+
+        compile_error!("removed VUID");"#,
+        )
+        .unwrap();
+        assert_eq!(vuid(&file_vuids).obsolete(), Some((1, 3, 268)));
+        assert_eq!(vuid(&file_vuids).version(), (1, 3, 200));
+    }
+
+    #[test]
+    fn requires() {
+        let file_vuids = parse("").unwrap();
+        assert_eq!(vuid(&file_vuids).requires(), None);
+        assert_eq!(vuid(&file_vuids).requires_range(), None);
+
+        let source = source(
+            r#"        check_vuids::requires!{"(VK_VERSION_1_1,VK_KHR_device_group)"}
+        check_vuids::status!(todo)"#,
+        );
+        let file_vuids = crate::parse::RustParser::new(source)
+            .parse(GatherVuids::new())
+            .unwrap();
+        let vuid = vuid(&file_vuids);
+        assert_eq!(
+            vuid.requires(),
+            Some("(VK_VERSION_1_1,VK_KHR_device_group)")
+        );
+        assert!(vuid.status() == Some(Status::Todo));
+        // the range does not include the check_vuids:: path
+        let (start, end) = vuid.requires_range().unwrap();
+        assert_eq!(
+            &source[start..end],
+            r#"requires!{"(VK_VERSION_1_1,VK_KHR_device_group)"}"#
+        );
+    }
+
+    #[test]
+    fn requires_errors() {
+        let error = parse(
+            r#"
+        check_vuids::requires!{"(VK_KHR_a)"}
+        check_vuids::requires!{"(VK_KHR_b)"}"#,
+        )
+        .err()
+        .unwrap();
+        assert!(error
+            .to_string()
+            .contains("VUID_vkTarget_a: requires!{condition} should only be used once"));
+
+        assert!(parse(r#"check_vuids::requires!{}"#).is_err());
+        assert!(parse(r#"check_vuids::requires!("(VK_KHR_a)")"#).is_err());
+    }
+
+    #[test]
+    fn obsolete_errors() {
+        let error = parse(
+            r#"
+        check_vuids::obsolete!{"1.3.268"}
+        check_vuids::obsolete!{"1.3.270"}"#,
+        )
+        .err()
+        .unwrap();
+        // the error shows where in the file, and which VUID
+        assert!(error
+            .to_string()
+            .contains("VUID_vkTarget_a: obsolete!{version} should only be used once"));
+
+        assert!(parse(r#"check_vuids::obsolete!{}"#).is_err());
+        assert!(parse(r#"check_vuids::obsolete!{"1.3"}"#).is_err());
+    }
+}
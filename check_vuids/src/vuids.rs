@@ -9,8 +9,17 @@ const VUIDS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/vuids.txt"))
 type Target = &'static str;
 type Vuid = &'static str;
 type Description = &'static str;
+type Requires = &'static str;
 type Version = (usize, usize, usize);
 
+/// a VUID in the reference
+#[derive(Clone, Copy)]
+pub struct ReferenceVuid {
+    pub description: Description,
+    /// the extensions and/or versions that the VUID applies to, or None if it always applies
+    pub requires: Option<Requires>,
+}
+
 pub fn parse_version(v_str: &str) -> Result<Version> {
     let mut version_parts = v_str.split('.');
     let mut version_parts = MustNext::new(&mut version_parts);
@@ -28,6 +37,7 @@ enum VuidLine {
     Version(Version),
     Target(Target),
     Vuid(Vuid),
+    Requires(Requires),
     Description(Description),
 }
 
@@ -45,6 +55,7 @@ impl RefFromStr<'static> for VuidLine {
             "A" => Ok(Self::Version(parse_version(&s[2..])?)),
             "T" => Ok(Self::Target(&s[2..])),
             "V" => Ok(Self::Vuid(&s[2..])),
+            "R" => Ok(Self::Requires(&s[2..])),
             "D" => Ok(Self::Description(&s[2..])),
             _ => Err("not a valid line for VUIDS")?,
         }
@@ -68,7 +79,7 @@ impl<'a> MyParse<'a> for str {
 }
 
 pub struct VuidCollection {
-    collection: VecMap<Target, VecMap<Vuid, Description>>,
+    collection: VecMap<Target, VecMap<Vuid, ReferenceVuid>>,
     version: Version,
 }
 
@@ -77,6 +88,7 @@ impl VuidCollection {
         let mut version = None;
         let mut collection = VecMap::default();
         let mut vuid = None;
+        let mut requires = None;
 
         for line in VUIDS.lines().map(MyParse::my_parse) {
             let line = line?;
@@ -84,12 +96,19 @@ impl VuidCollection {
             use VuidLine::*;
             match line {
                 Version(v) => version = Some(v),
-                Target(target) => collection.push(target, VecMap::default()),
+                Target(target) => collection.push_copy_key(target, VecMap::default()),
                 Vuid(id) => vuid = Some(id),
+                Requires(condition) => requires = Some(condition),
                 Description(description) => collection
                     .last_mut()
                     .ok_or("no target for vuid")?
-                    .push_copy_key(vuid.take().ok_or("no vuid for description")?, description),
+                    .push_copy_key(
+                        vuid.take().ok_or("no vuid for description")?,
+                        ReferenceVuid {
+                            description,
+                            requires: requires.take(),
+                        },
+                    ),
             }
         }
 
@@ -98,10 +117,98 @@ impl VuidCollection {
             version: version.ok_or("no version found")?,
         })
     }
-    pub fn get_target<'a>(&'a self, target: &'a str) -> Option<&'a VecMap<Vuid, Description>> {
+    pub fn get_target<'a>(&'a self, target: &'a str) -> Option<&'a VecMap<Vuid, ReferenceVuid>> {
         self.collection.get(target)
     }
     pub fn version_tuple(&self) -> Version {
         self.version
     }
+    /// targets which are likely the same as `target` under a different name
+    ///
+    /// i.e. the names only differ by an author suffix, such as a KHR command which was promoted to
+    /// core (GetPhysicalDeviceFeatures2KHR => GetPhysicalDeviceFeatures2), or the other way around
+    pub fn renamed_targets(&self, target: &str) -> Vec<Target> {
+        let base = without_author_suffix(target);
+        self.collection
+            .ordered_key_value_iter()
+            .expect("vuid collection must use copy keys")
+            .map(|(name, _)| name)
+            .filter(|&name| name != target && without_author_suffix(name) == base)
+            .collect()
+    }
+}
+
+/// remove an author suffix (e.g. KHR, EXT, NV) from a name
+fn without_author_suffix(name: &str) -> &str {
+    let base = name.trim_end_matches(|c: char| c.is_ascii_uppercase());
+    // a single uppercase letter is part of the name (e.g. Image2D)
+    if name.len() - base.len() >= 2 {
+        base
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let line = |s: &'static str| s.my_parse::<VuidLine>().unwrap();
+        assert!(matches!(line("A 1.3.268"), VuidLine::Version((1, 3, 268))));
+        assert!(matches!(
+            line("T CreateInstance"),
+            VuidLine::Target("CreateInstance")
+        ));
+        assert!(matches!(line("V VUID_vkA_b"), VuidLine::Vuid("VUID_vkA_b")));
+        assert!(matches!(
+            line("R (VK_VERSION_1_1,VK_KHR_device_group)"),
+            VuidLine::Requires("(VK_VERSION_1_1,VK_KHR_device_group)")
+        ));
+        assert!(matches!(line("D text"), VuidLine::Description("text")));
+        assert!("X text".my_parse::<VuidLine>().is_err());
+        assert!("A 1.3".my_parse::<VuidLine>().is_err());
+    }
+
+    #[test]
+    fn author_suffix() {
+        assert_eq!(
+            without_author_suffix("GetPhysicalDeviceFeatures2KHR"),
+            "GetPhysicalDeviceFeatures2"
+        );
+        assert_eq!(without_author_suffix("CreateImage2D"), "CreateImage2D");
+        assert_eq!(without_author_suffix("CreateInstance"), "CreateInstance");
+    }
+
+    #[test]
+    fn renamed() {
+        let mut collection = VecMap::default();
+        for target in [
+            "GetPhysicalDeviceFeatures2",
+            "GetPhysicalDeviceFeatures2EXT",
+            "CreateInstance",
+        ] {
+            collection.push_copy_key(target, VecMap::default());
+        }
+        let vuid_collection = VuidCollection {
+            collection,
+            version: (1, 3, 268),
+        };
+
+        assert_eq!(
+            vuid_collection.renamed_targets("GetPhysicalDeviceFeatures2KHR"),
+            [
+                "GetPhysicalDeviceFeatures2",
+                "GetPhysicalDeviceFeatures2EXT"
+            ]
+        );
+        assert_eq!(
+            vuid_collection.renamed_targets("GetPhysicalDeviceFeatures2"),
+            ["GetPhysicalDeviceFeatures2EXT"]
+        );
+        assert!(vuid_collection
+            .renamed_targets("DestroyInstance")
+            .is_empty());
+    }
 }
@@ -297,8 +297,8 @@ impl<'a> VisitVkParse<'a> for Generator {
         cmd_name: &'a str,
         parts: &crate::vk_parse_visitor::VkParseExtensionParts<'a>,
     ) {
-        let cmd_name = utils::VkTyName::new(cmd_name);
-        let cmd_name = self.get_alias_or_name(cmd_name);
+        let load_name = utils::VkTyName::new(cmd_name);
+        let cmd_name = self.get_alias_or_name(load_name);
         let cmd_type = self
             .get_command_type(&cmd_name)
             .expect("error: feature identifies unknown command");
@@ -312,8 +312,8 @@ impl<'a> VisitVkParse<'a> for Generator {
         self.commands.enable_command(cmd_name);
 
         match cmd_type {
-            CommandType::Instance => ex.push_instance_command(cmd_name),
-            CommandType::Device => ex.push_device_command(cmd_name),
+            CommandType::Instance => ex.push_instance_command(cmd_name, load_name),
+            CommandType::Device => ex.push_device_command(cmd_name, load_name),
             CommandType::Entry => {
                 panic!("error: entry level command added by extension not handled")
             }
@@ -402,7 +402,7 @@ impl<'a> VisitVkParse<'a> for Generator {
             crate::vk_parse_visitor::HandleKind::Dispatchable => true,
             crate::vk_parse_visitor::HandleKind::NonDispatchable => false,
         };
-        let handle = types::Handle2::new(def.name, dispatch);
+        let handle = types::Handle2::new(def.name, dispatch, def.object_type);
         self.types.insert_handle(handle);
     }
     fn visit_fptr(&mut self, def: crate::vk_parse_visitor::FptrDef<'a>) {
@@ -227,6 +227,12 @@ impl krs_quote::ToTokens for ReturnType {
     fn to_tokens(&self, tokens: &mut krs_quote::TokenStream) {
         match self {
             ReturnType::None => krs_quote_with!(tokens <- () ),
+            // vkGet*ProcAddr return NULL for unavailable commands
+            ReturnType::Some(ct)
+                if ct.basetype() == "PFN_vkVoidFunction" && !ct.is_pointer() && !ct.is_array() =>
+            {
+                krs_quote_with!(tokens <- Option<{@ct}> )
+            }
             ReturnType::Some(ct) => krs_quote_with!(tokens <- {@ct} ),
         }
     }
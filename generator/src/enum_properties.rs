@@ -13,6 +13,9 @@ use format as VkFormat;
 mod structure_type;
 use structure_type as VkStructureType;
 
+mod result;
+use result as VkResult;
+
 use krs_quote::{krs_quote_with, ToTokens, TokenStream};
 
 use crate::constants::Constant3;
@@ -47,7 +50,7 @@ macro_rules! match_enums {
 
 impl<'a, I: Variants<'a>> ToTokens for Properties<I> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        match_enums!(self tokens: VkFormat, VkStructureType);
+        match_enums!(self tokens: VkFormat, VkStructureType, VkResult);
     }
 }
 
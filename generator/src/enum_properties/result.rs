@@ -0,0 +1,97 @@
+//! Generate `ErrorCode` for the error values of `Result`
+//!
+//! `Result` is represented as a struct with associated constants, which cannot be
+//! matched exhaustively. `ErrorCode` is a real Rust enum with one variant for each
+//! negative `Result` value (i.e. the `ERROR_*` values), so that users can match on
+//! specific errors.
+//!
+//! Each error is also classified as recoverable or fatal. Fatal errors indicate that
+//! the object which the command was used with (or the entire device) cannot be used
+//! normally anymore.
+
+use super::*;
+
+pub struct Delegate;
+
+impl<'a, I: Variants<'a>> ToTokensDelegate<I> for Delegate {
+    fn delegate_to_tokens(params: &Properties<I>, tokens: &mut TokenStream) {
+        let target = params.target;
+        let error_names = params
+            .variants
+            .clone()
+            .filter(|v| !v.is_alias())
+            .map(|v| v.name())
+            .filter(|v| v.normalize().starts_with("ERROR_"));
+
+        let is_fatal = error_names.clone().map(|v| is_fatal(v.normalize()));
+
+        krs_quote_with!(tokens <-
+            /// Error values of [`Result`]
+            ///
+            /// There is one variant for each error value (i.e. the negative values) of [`Result`].
+            /// Negative values which are unknown to these bindings are represented by `Other`.
+            #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+            #[non_exhaustive]
+            pub enum ErrorCode {
+                {@* {@error_names}, }
+                Other({@target}),
+            }
+
+            impl ErrorCode {
+                /// Get the [`Result`] value for the ErrorCode
+                pub const fn result(self) -> {@target} {
+                    match self {
+                        {@* Self::{@error_names} => {@target}::{@error_names}, }
+                        Self::Other(result) => result,
+                    }
+                }
+
+                /// Check if the error is fatal
+                ///
+                /// A fatal error indicates that the object used with the command (or the
+                /// entire device) is lost, or that the implementation is in an unknown state.
+                /// Unknown error values are considered fatal.
+                pub const fn is_fatal(self) -> bool {
+                    match self {
+                        {@* Self::{@error_names} => {@is_fatal}, }
+                        Self::Other(_) => true,
+                    }
+                }
+
+                /// Check if the error is recoverable
+                ///
+                /// The application can handle a recoverable error (e.g. free memory, choose a different format)
+                /// and continue using the objects involved.
+                pub const fn is_recoverable(self) -> bool {
+                    !self.is_fatal()
+                }
+            }
+
+            impl std::fmt::Display for ErrorCode {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    std::fmt::Debug::fmt(self, f)
+                }
+            }
+
+            impl std::error::Error for ErrorCode {}
+
+            impl {@target} {
+                /// Get the [`ErrorCode`] if the Result is an error value
+                pub const fn error_code(self) -> Option<ErrorCode> {
+                    match self {
+                        {@* Self::{@error_names} => Some(ErrorCode::{@error_names}), }
+                        other if other.0 < 0 => Some(ErrorCode::Other(other)),
+                        _ => None,
+                    }
+                }
+            }
+        );
+    }
+}
+
+fn is_fatal(name: &str) -> bool {
+    name.contains("DEVICE_LOST")
+        || name.contains("SURFACE_LOST")
+        || name.starts_with("ERROR_VALIDATION_FAILED")
+        || name == "ERROR_UNKNOWN"
+}
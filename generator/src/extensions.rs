@@ -67,6 +67,7 @@ impl krs_quote::ToTokens for ExtensionCollection {
                 .map(|e| ExtensionCommandStruct {
                     name: e.extension_name,
                     commands: &e.instance_command_names,
+                    load_names: &e.instance_command_load_names,
                 });
         let device_command_structs =
             extensions
@@ -75,6 +76,7 @@ impl krs_quote::ToTokens for ExtensionCollection {
                 .map(|e| ExtensionCommandStruct {
                     name: e.extension_name,
                     commands: &e.device_command_names,
+                    load_names: &e.device_command_load_names,
                 });
 
         // traits
@@ -201,12 +203,14 @@ impl krs_quote::ToTokens for ExtensionCollection {
 struct ExtensionCommandStruct<'a> {
     name: ExtensionName,
     commands: &'a [VkTyName],
+    load_names: &'a [VkTyName],
 }
 
 impl krs_quote::ToTokens for ExtensionCommandStruct<'_> {
     fn to_tokens(&self, tokens: &mut krs_quote::TokenStream) {
         let name = self.name;
         let command = self.commands.iter();
+        let load_name = self.load_names.iter().map(|n| n.as_str());
 
         krs_quote_with!(tokens <-
             #[doc(hidden)]
@@ -222,7 +226,13 @@ impl krs_quote::ToTokens for ExtensionCommandStruct<'_> {
                 #[allow(unused_variables)]
                 pub fn load(loader: impl FunctionLoader) -> std::result::Result<Self, CommandLoadError> {
                     Ok(Self {
-                        {@* {@command} : {@command}::load(loader)?, }
+                        // SAFETY : each name is the name given by the extension for the command
+                        {@*
+                            {@command} : unsafe { load_with_name(
+                                loader,
+                                concat!({@load_name}, '\0').as_ptr().cast(),
+                            )? },
+                        }
                     })
                 }
             }
@@ -285,15 +295,32 @@ impl krs_quote::ToTokens for MacroDependencyTraits<'_> {
             .map(|n| krs_quote::Token::from(format!("O{n}")));
         let options = &options;
 
-        let instance_dependencies = self
+        let mut instance_dependencies = self
             .info
             .dependencies
             .as_ref()
-            .and_then(|dep| get_instance_dependency_terms(self.all_extensions, dep))
-            .map(|deps| {
-                let solutions = DependencyTermSolution::from(deps);
-                RefCell::new(solutions)
+            .and_then(|dep| get_instance_dependency_terms(self.all_extensions, dep));
+
+        // An instance extension depends on itself at the instance level. Its device level
+        // commands (e.g. vkSetDebugUtilsObjectNameEXT from VK_EXT_debug_utils) can be loaded
+        // through a device context which lists the extension (e.g.
+        // `device_context!(C: VERSION_1_0 + EXT_debug_utils)`), but they are provided by the
+        // instance, so they can only be used if the instance also enables the extension. The
+        // extension is never enabled on the device: the device_loads macro of an instance
+        // extension adds nothing to ppEnabledExtensionNames of vkCreateDevice (see
+        // ExtensionLoadsMacros).
+        if let (ExtensionKind::Instance, ExtensionName::Base { name }) = (self.info.kind, name) {
+            let this = DependencyTerm::Single(name);
+            instance_dependencies = Some(match instance_dependencies {
+                Some(deps) => DependencyTerm::And(vec![this, deps]),
+                None => this,
             });
+        }
+
+        let instance_dependencies = instance_dependencies.map(|deps| {
+            let solutions = DependencyTermSolution::from(deps);
+            RefCell::new(solutions)
+        });
 
         let device_dependencies = self
             .info
@@ -893,6 +920,10 @@ pub struct ExtensionInfo {
     extension_name: ExtensionName,
     instance_command_names: Vec<VkTyName>,
     device_command_names: Vec<VkTyName>,
+    // names that the commands are loaded with, which are the names given by the extension
+    // (e.g. vkGetPhysicalDeviceFeatures2KHR for vkGetPhysicalDeviceFeatures2)
+    instance_command_load_names: Vec<VkTyName>,
+    device_command_load_names: Vec<VkTyName>,
     kind: ExtensionKind,
     dependencies: Option<DependencyTerm>,
 }
@@ -903,15 +934,19 @@ impl ExtensionInfo {
             extension_name,
             instance_command_names: Default::default(),
             device_command_names: Default::default(),
+            instance_command_load_names: Default::default(),
+            device_command_load_names: Default::default(),
             kind,
             dependencies: Default::default(),
         }
     }
-    pub fn push_instance_command(&mut self, command: VkTyName) {
+    pub fn push_instance_command(&mut self, command: VkTyName, load_name: VkTyName) {
         self.instance_command_names.push(command);
+        self.instance_command_load_names.push(load_name);
     }
-    pub fn push_device_command(&mut self, command: VkTyName) {
+    pub fn push_device_command(&mut self, command: VkTyName, load_name: VkTyName) {
         self.device_command_names.push(command);
+        self.device_command_load_names.push(load_name);
     }
     pub fn dependencies<'a>(&mut self, dependencies: impl Into<DependencyTerm>) {
         self.dependencies = Some(dependencies.into())
@@ -112,7 +112,7 @@ pub fn generate_feature_and_extension_list(out_dir: &Path, code: &Generator) ->
 
 pub fn generate_vuids_file(out_dir: &Path, validusage_json_path: &Path) -> Result<()> {
     make_output_directory(out_dir)?;
-    let vuids = crate::parse_vuids(validusage_json_path);
+    let vuids = crate::parse_vuids(validusage_json_path)?;
 
     let mut file = OpenOptions::new()
         .write(true)
@@ -74,24 +74,24 @@ pub fn parse_vk_xml(vk_xml_path: impl AsRef<Path>) -> Generator {
 }
 
 /// Parse validusage.json at provided path and provide the generated code for vuid checks
-pub fn parse_vuids(vuid_path: impl AsRef<Path>) -> String {
+pub fn parse_vuids(vuid_path: impl AsRef<Path>) -> Result<String, Box<dyn std::error::Error>> {
     unsafe {
         intern::Interner::init();
     }
 
     // vuids
     let mut vuid_json_string = String::new();
-    File::open(vuid_path)
-        .expect("failed to open vuid file")
+    File::open(vuid_path.as_ref())
+        .map_err(|e| format!("failed to open vuid file {:?}: {e}", vuid_path.as_ref()))?
         .read_to_string(&mut vuid_json_string)
-        .expect("failed to read vuid file");
+        .map_err(|e| format!("failed to read vuid file {:?}: {e}", vuid_path.as_ref()))?;
     let vuid_json_parser = vuid_visitor::VuidJsonStrParser::new(&vuid_json_string);
 
     let mut generator = vuid_generator::VuidGenerator::default();
 
-    vuid_visitor::visit_vuids(vuid_json_parser, &mut generator);
+    vuid_visitor::visit_vuids(vuid_json_parser, &mut generator)?;
 
-    generator.vuids()
+    Ok(generator.vuids())
 }
 
 /// generate all the code parts into files that can be used for the src directory of a standalone crate
@@ -28,10 +28,15 @@ impl ToTokens for VulkanCommand {
             /// ## Safety
             /// 'command' must be set to a valid c string pointer
             /// there is no check for this
+            #[derive(Clone, Copy)]
             pub struct CommandLoadError {
                 command: *const c_char,
             }
 
+            // SAFETY : 'command' always points to a static c string (VulkanCommand::VK_NAME)
+            unsafe impl Send for CommandLoadError {}
+            unsafe impl Sync for CommandLoadError {}
+
             impl std::error::Error for CommandLoadError {}
 
             impl std::fmt::Display for CommandLoadError {
@@ -57,12 +62,28 @@ impl ToTokens for VulkanCommand {
 
             impl<C: VulkanCommand> LoadCommands for C {
                 fn load(loader: impl FunctionLoader) -> std::result::Result<Self, CommandLoadError> {
-                    let fptr = loader(C::VK_NAME).ok_or(CommandLoadError { command: C::VK_NAME })?;
-                    // SAFETY : fptr should be the correct kind of pointer since we loaded it with H::VK_NAME
-                    unsafe { Ok(C::new(fptr)) }
+                    // SAFETY : VK_NAME is a static c string of the name of C
+                    unsafe { load_with_name(loader, C::VK_NAME) }
                 }
             }
 
+            /// Load a command with the name of the command, or of an alias of the command
+            ///
+            /// Commands which are provided by an extension are loaded with the name given by the
+            /// extension (e.g. vkGetPhysicalDeviceFeatures2KHR for vkGetPhysicalDeviceFeatures2)
+            ///
+            /// ## Safety
+            /// 'name' must point to a static c string, which is the name of C or an alias of C
+            #[doc(hidden)]
+            pub unsafe fn load_with_name<C: VulkanCommand>(
+                loader: impl FunctionLoader,
+                name: *const c_char,
+            ) -> std::result::Result<C, CommandLoadError> {
+                let fptr = loader(name).ok_or(CommandLoadError { command: name })?;
+                // SAFETY : fptr should be the correct kind of pointer since an alias has the same signature as the command
+                Ok(C::new(fptr))
+            }
+
             pub trait Version {
                 const VERSION: crate::VkVersion;
             }
@@ -91,6 +112,15 @@ impl ToTokens for VulkanCommand {
 
             /// The implementor can be added to the pNext chain of `T`
             pub unsafe trait StructExtends<T> {}
+
+            /// A handle type, which is identified by `OBJECT_TYPE`
+            ///
+            /// e.g. for naming objects with `VkDebugUtilsObjectNameInfoEXT`
+            pub unsafe trait ObjectHandle: Copy {
+                const OBJECT_TYPE: ObjectType;
+                /// The handle as a 64 bit object handle
+                fn object_handle(self) -> u64;
+            }
         )
     }
 }
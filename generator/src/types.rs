@@ -371,12 +371,20 @@ impl krs_quote::ToTokens for Union {
 pub struct Handle2 {
     name: VkTyName,
     dispatch: bool,
+    // ObjectType variant name
+    object_type: Option<String>,
 }
 
 impl Handle2 {
-    pub fn new(name: impl Into<VkTyName>, dispatch: bool) -> Self {
+    pub fn new(name: impl Into<VkTyName>, dispatch: bool, object_type: Option<&str>) -> Self {
         let name = name.into();
-        Self { name, dispatch }
+        let object_type = object_type
+            .map(|object_type| crate::enumerations::make_variant_name("VkObjectType", object_type));
+        Self {
+            name,
+            dispatch,
+            object_type,
+        }
     }
 }
 
@@ -404,6 +412,24 @@ impl krs_quote::ToTokens for Handle2 {
                 }
             }
         );
+
+        if let Some(object_type) = &self.object_type {
+            let object_type = object_type.as_code();
+            let object_handle = to_tokens_closure!(tokens {
+                match self.dispatch {
+                    true => krs_quote_with!(tokens <- self.handle as usize as u64),
+                    false => krs_quote_with!(tokens <- self.handle),
+                }
+            });
+            krs_quote_with!(tokens <-
+                unsafe impl ObjectHandle for {@name} {
+                    const OBJECT_TYPE: ObjectType = ObjectType::{@object_type};
+                    fn object_handle(self) -> u64 {
+                        {@object_handle}
+                    }
+                }
+            );
+        }
     }
 }
 
@@ -132,8 +132,9 @@ pub fn visit_vk_parse<'a>(registry: &'a vk_parse::Registry, visitor: &mut impl V
                                     },
                                     Some("handle") => match ty.spec {
                                         vk_parse::TypeSpec::Code(ref ty_code) => {
-                                            let handle_def = parse_handle(&ty_code.code)
+                                            let mut handle_def = parse_handle(&ty_code.code)
                                                 .expect("error: can't parse handle");
+                                            handle_def.object_type = ty.objtypeenum.as_deref();
                                             visitor.visit_handle(handle_def);
                                         }
                                         _ => panic!("error: unhandled handle TypSpec node"),
@@ -701,6 +702,8 @@ pub struct VkBasetype<'a> {
 pub struct HandleDef<'a> {
     pub name: &'a str,
     pub kind: HandleKind,
+    /// VkObjectType variant which identifies the handle type
+    pub object_type: Option<&'a str>,
 }
 
 pub enum HandleKind {
@@ -889,10 +892,12 @@ fn parse_handle<'a>(code: &'a str) -> Result<HandleDef<'a>, ()> {
         "VK_DEFINE_HANDLE" => Ok(HandleDef {
             name,
             kind: HandleKind::Dispatchable,
+            object_type: None,
         }),
         "VK_DEFINE_NON_DISPATCHABLE_HANDLE" => Ok(HandleDef {
             name,
             kind: HandleKind::NonDispatchable,
+            object_type: None,
         }),
         _ => panic!("error: unknown handle kind"),
     }
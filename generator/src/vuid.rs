@@ -29,6 +29,7 @@ Display the VUIDs as lines wherein:
 first line indicates the API version with A #.#.#
 followed by target lines with T name_of_target
 and VUID and Description line pairs, for target, as V vuid_name and D description_text
+if a VUID only applies with some extensions and/or versions, there is a R condition line between the V and D lines
 
 this is meant to be extremely simple to parse in the vuid_check tool
  */
@@ -40,6 +41,9 @@ impl std::fmt::Display for Vuids<'_> {
             writeln!(f, "T {}", vuid_group.target)?;
             for pair in vuid_group.vuid_pairs.iter() {
                 writeln!(f, "V {}", pair.name().replace("-", "_").replace("::", "_"))?;
+                if let Some(condition) = pair.condition() {
+                    writeln!(f, "R {condition}")?;
+                }
                 writeln!(f, "D {}", pair.description())?;
             }
         }
@@ -9,6 +9,9 @@ pub trait VuidVisitor<'a> {
     fn visit_vuid_version(&mut self, version: (u32, u32, u32));
 }
 
-pub fn visit_vuids<'a>(parser: impl VuidParser<'a>, visitor: &mut impl VuidVisitor<'a>) {
+pub fn visit_vuids<'a>(
+    parser: impl VuidParser<'a>,
+    visitor: &mut impl VuidVisitor<'a>,
+) -> Result<(), Box<dyn std::error::Error>> {
     parser.parse_with(visitor)
 }
@@ -7,7 +7,7 @@ use std::borrow::Cow;
 /// #internal detail
 /// the fields use Cow to allow for borrowed and owned strings for flexibility with possible parer implementations
 /// there is a json format where the descriptions have html, and I would want to remove the html in my code, so this
-/// will mean creating new Strings with the html removed.
+/// will mean creating new Strings with the html removed. The names and conditions are borrowed unless they have json escapes.
 ///
 /// there is also a version of the vuids available from lunarG that does not include the html, and could be used directly as borrowed data
 /// However, it is also a little harder to obtain the vuids from lunarG
@@ -19,6 +19,11 @@ pub struct VuidPair<'a> {
     name: Cow<'a, str>,
     /// the description of the VUID
     description: Cow<'a, str>,
+    /// the extensions and/or versions that the VUID applies to, or None for "core"
+    ///
+    /// this is the key of the group of VUIDs in validusage.json, where '+' means all of and ',' means any of
+    /// (e.g. "(VK_VERSION_1_1,VK_KHR_device_group)+(VK_KHR_swapchain)")
+    condition: Option<Cow<'a, str>>,
 }
 
 impl VuidPair<'_> {
@@ -28,8 +33,14 @@ impl VuidPair<'_> {
     pub fn description(&self) -> &str {
         &self.description
     }
+    pub fn condition(&self) -> Option<&str> {
+        self.condition.as_deref()
+    }
 }
 
 pub trait VuidParser<'a> {
-    fn parse_with(self, visitor: &mut impl super::VuidVisitor<'a>);
+    fn parse_with(
+        self,
+        visitor: &mut impl super::VuidVisitor<'a>,
+    ) -> Result<(), Box<dyn std::error::Error>>;
 }
@@ -1,10 +1,31 @@
+mod tokenizer;
+
 use super::{VuidPair, VuidParser};
 
-/// This is a simple parser for the validusage.json file provided in https://github.com/KhronosGroup/Vulkan-Headers
+use tokenizer::{Token, Tokenizer};
+
+/// the oldest validusage.json schema version which is supported
 ///
-/// #IMPORTANT NOTE
-/// the expected file includes "vuid"/"text" pairs in adjacent lines
-/// rather than full json parsing, this just takes advantage of such line based layout
+/// newer schema versions are assumed to only add information which is not used here
+const MIN_SCHEMA_VERSION: u32 = 2;
+
+/// This is a parser for the validusage.json file provided in https://github.com/KhronosGroup/Vulkan-Headers
+///
+/// The file is expected to have the layout:
+/// ```text
+/// {
+///   "version info": { "schema version": 2, "api version": "1.3.268", ... },
+///   "validation": {
+///     "vkTarget": {
+///       "core": [ { "vuid": "VUID-vkTarget-...", "text": "...", ... }, ... ],
+///       "(VK_KHR_maintenance1)": [ ... ],
+///       ...
+///     },
+///     ...
+///   }
+/// }
+/// ```
+/// Any formatting of the json is fine (e.g. minified), and keys which are not in the above layout are ignored.
 pub struct VuidJsonStrParser<'a> {
     json: &'a str,
 }
@@ -16,121 +37,231 @@ impl<'a> VuidJsonStrParser<'a> {
 }
 
 impl<'a> VuidParser<'a> for VuidJsonStrParser<'a> {
-    fn parse_with(mut self, visitor: &mut impl crate::vuid_visitor::VuidVisitor<'a>) {
-        let mut get_line = || {
-            for (i, c) in self.json.bytes().enumerate() {
-                if c == b'\n' {
-                    let line = &self.json[..i];
-                    self.json = &self.json[i + 1..];
-                    return Some(line);
+    fn parse_with(
+        self,
+        visitor: &mut impl crate::vuid_visitor::VuidVisitor<'a>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut tokens = Tokenizer::new(self.json);
+
+        let mut schema_version: Option<u32> = None;
+
+        tokens.object(|tokens, key| match &*key {
+            "version info" => tokens.object(|tokens, key| match &*key {
+                "schema version" => {
+                    let version = match tokens.expect_token()? {
+                        Token::Number(n) => n.parse().ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| tokens.error("schema version is not a valid number"))?;
+                    schema_version = Some(version);
+                    Ok(())
                 }
-            }
-            None
-        };
-
-        let mut supported_schema = false;
-
-        // parse all lines
-        while let Some(line) = get_line() {
-            let line = line.trim();
-
-            const VUID_TAG: &'static str = "\"vuid\": ";
-            const TEXT_TAG: &'static str = "\"text\": ";
-            const SCHEMA_TAG: &'static str = "\"schema version\": ";
-            const API_VER_TAG: &'static str = "\"api version\": ";
-
-            // when find vuid line
-            if line.starts_with(VUID_TAG) {
-                // get "vuid" value which is the name of the vuid
-                let vuid_name = &line[VUID_TAG.len() + 1..line.len() - 2]; // this takes the value without the quotation marks and ending comma
-
-                // then get next line and assert that it is the "text" value, which is the description
-                let line = get_line()
-                    .expect("error: no line after vuid line")
-                    .trim()
-                    .strip_prefix(TEXT_TAG)
-                    .expect("error: line after 'vuid' is not 'text'");
-
-                let start = line
-                    .find("\"")
-                    .expect("ERROR: vuid description does not start with a '\"'")
-                    + 1;
-                let end = line
-                    .rfind("\"")
-                    .expect("ERROR: vuid description does not end with a '\"'");
-                assert!(start != end);
-
-                let vuid_description = line[start..end].trim(); // remove quotation marks and whitespace leading/trailing
-
-                // remove HTML from the description
-                let filtered_description: String = HtmlFilter(vuid_description.chars()).collect();
-
-                let vuid_pair = VuidPair {
-                    name: vuid_name.into(),
-                    description: filtered_description.into(),
-                };
-
-                visitor.visit_vuid(vuid_pair);
-            } else if line.starts_with(SCHEMA_TAG) {
-                let schema_version: u32 = line[SCHEMA_TAG.len()..line.len() - 1]
-                    .parse()
-                    .expect("error: can't parse json schema version");
-                if schema_version == 2 {
-                    supported_schema = true;
-                } else {
-                    panic!("unsupported validusage.json schema version");
+                "api version" => {
+                    let version = tokens.expect_string()?;
+                    let version = parse_api_version(&version)
+                        .ok_or_else(|| tokens.error(format!("invalid api version {version:?}")))?;
+                    visitor.visit_vuid_version(version);
+                    Ok(())
                 }
-            } else if line.starts_with(API_VER_TAG) {
-                let api_version_str = &line[API_VER_TAG.len() + 1..line.len() - 2];
-
-                let mut version_parts = api_version_str.split(".");
-
-                let major = version_parts
-                    .next()
-                    .expect("error: api version - no major")
-                    .parse()
-                    .expect("error: can't parse major");
-                let minor = version_parts
-                    .next()
-                    .expect("error: api version - no minor")
-                    .parse()
-                    .expect("error: can't parse minor");
-                let patch = version_parts
-                    .next()
-                    .expect("error: api version - no patch")
-                    .parse()
-                    .expect("error: can't parse patch");
-
-                visitor.visit_vuid_version((major, minor, patch));
-            }
-        }
+                _ => tokens.skip_value(),
+            }),
+            "validation" => tokens.object(|tokens, _target| {
+                tokens.object(|tokens, condition| {
+                    // the key of each list of VUIDs is the condition for the VUIDs to apply
+                    let condition = if condition == "core" {
+                        None
+                    } else {
+                        Some(condition)
+                    };
+                    tokens.array(|tokens| {
+                        let mut vuid = None;
+                        let mut text = None;
+                        tokens.object(|tokens, key| match &*key {
+                            "vuid" => {
+                                vuid = Some(tokens.expect_string()?);
+                                Ok(())
+                            }
+                            "text" => {
+                                text = Some(tokens.expect_string()?);
+                                Ok(())
+                            }
+                            _ => tokens.skip_value(),
+                        })?;
+
+                        let name = vuid.ok_or_else(|| tokens.error("VUID without \"vuid\""))?;
+                        let text = text.ok_or_else(|| tokens.error("VUID without \"text\""))?;
 
-        if !supported_schema {
-            panic!("error: never found supported schema version");
+                        // remove HTML from the description
+                        let description = html_to_text(&text).map_err(|e| tokens.error(e))?;
+
+                        visitor.visit_vuid(VuidPair {
+                            name,
+                            description: description.into(),
+                            condition: condition.clone(),
+                        });
+                        Ok(())
+                    })
+                })
+            }),
+            _ => tokens.skip_value(),
+        })?;
+
+        tokens.expect_end()?;
+
+        match schema_version {
+            Some(version) if version >= MIN_SCHEMA_VERSION => Ok(()),
+            Some(version) => Err(format!(
+                "unsupported validusage.json schema version {version} (expected at least {MIN_SCHEMA_VERSION})"
+            ))?,
+            None => Err("never found validusage.json schema version")?,
         }
     }
 }
 
-/// This is simple html tag filter
+/// parse "major.minor.patch"
+fn parse_api_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.split('.').map(str::parse);
+    let version = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    match parts.next() {
+        None => Some(version),
+        Some(_) => None,
+    }
+}
+
+/// Convert the html of a VUID description to plain text
 ///
-/// this is very naive implementation that does not account for special escape characters in tags (if thats even possible)
-/// just assume everything between '<' and '>' is a tag and filter it out
-struct HtmlFilter<I>(I);
-
-impl<I: Iterator<Item = char>> Iterator for HtmlFilter<I> {
-    type Item = char;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let c = self.0.next()?;
-        if c == '<' {
-            while let Some(c) = self.0.next() {
-                if c == '>' {
-                    return self.next(); // this intentionally recursively call Self::next since we need to continue to check for '<'
+/// tags (everything between '<' and '>') are removed, and character references (e.g. `&lt;` or `&#8217;`)
+/// are decoded. Line breaks are replaced with spaces, so the description is a single line.
+fn html_to_text(html: &str) -> Result<String, String> {
+    let mut text = String::with_capacity(html.len());
+    let mut chars = html.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '<' => {
+                // quoted attribute values can contain '>'
+                let mut quote = None;
+                loop {
+                    match (chars.next(), quote) {
+                        (Some((_, '>')), None) => break,
+                        (Some((_, q @ ('"' | '\''))), None) => quote = Some(q),
+                        (Some((_, c)), Some(q)) if c == q => quote = None,
+                        (Some(_), _) => {}
+                        (None, _) => Err(format!("html tag is not closed in {html:?}"))?,
+                    }
                 }
             }
-            panic!("error: HtmlFilter did not find end og html tag")
-        } else {
-            Some(c)
+            '&' => match decode_reference(&html[i + 1..]) {
+                Some((c, len)) => {
+                    text.push(c);
+                    // skip the rest of the reference
+                    chars.nth(len - 1);
+                }
+                // not a reference which we know, so it is just text
+                None => text.push('&'),
+            },
+            '\n' | '\r' => text.push(' '),
+            c => text.push(c),
+        }
+    }
+
+    Ok(text.trim().to_string())
+}
+
+/// decode a character reference (after the '&'), returning the char and the length (including the ';')
+fn decode_reference(s: &str) -> Option<(char, usize)> {
+    // the longest reference we decode is &#x10FFFF;
+    let end = s.bytes().take(10).position(|b| b == b';')?;
+    let name = &s[..end];
+    let c = match name {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)?
         }
+    };
+    Some((c, end + 1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn html() {
+        assert_eq!(
+            html_to_text(" If <code>pInfo</code>-&gt;<a href=\"#a>b\">flags</a> &amp; X doesn&#8217;t\nmatch &unknown; ").unwrap(),
+            "If pInfo->flags & X doesn\u{2019}t match &unknown;"
+        );
+        assert!(html_to_text("unclosed <code").is_err());
+    }
+
+    #[derive(Default)]
+    struct Collect {
+        version: Option<(u32, u32, u32)>,
+        vuids: Vec<(String, String, Option<String>)>,
+    }
+
+    impl<'a> crate::vuid_visitor::VuidVisitor<'a> for Collect {
+        fn visit_vuid(&mut self, vuid: VuidPair<'a>) {
+            self.vuids.push((
+                vuid.name().to_string(),
+                vuid.description().to_string(),
+                vuid.condition().map(str::to_string),
+            ));
+        }
+        fn visit_vuid_version(&mut self, version: (u32, u32, u32)) {
+            self.version = Some(version);
+        }
+    }
+
+    fn parse(json: &str) -> Result<Collect, Box<dyn std::error::Error>> {
+        let mut collect = Collect::default();
+        VuidJsonStrParser::new(json).parse_with(&mut collect)?;
+        Ok(collect)
+    }
+
+    #[test]
+    fn minified() {
+        let collect = parse(
+            r#"{"version info":{"schema version":3,"api version":"1.3.280","new":[1,{}]},"validation":{"vkA":{"core":[{"vuid":"VUID-vkA-x","text":"a &lt; b","page":"p"}],"(VK_KHR_b)+(VK_VERSION_1_1,VK_KHR_c)":[{"text":"\"c\"","vuid":"VUID-vkA-y"}]}}}"#,
+        )
+        .unwrap();
+        assert_eq!(collect.version, Some((1, 3, 280)));
+        assert_eq!(
+            collect.vuids,
+            [
+                ("VUID-vkA-x".into(), "a < b".into(), None),
+                (
+                    "VUID-vkA-y".into(),
+                    "\"c\"".into(),
+                    Some("(VK_KHR_b)+(VK_VERSION_1_1,VK_KHR_c)".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse(r#"{"version info":{"schema version":1}}"#).is_err());
+        assert!(parse(r#"{"validation":{}}"#).is_err());
+        assert!(parse(
+            r#"{"version info":{"schema version":2},"validation":{"vkA":{"core":[{"vuid":"x"}]}}}"#
+        )
+        .is_err());
+        assert!(parse(r#"{"version info":{"schema version":2}} {}"#).is_err());
     }
 }
@@ -0,0 +1,426 @@
+use std::borrow::Cow;
+
+/// A JSON token
+///
+/// Strings are borrowed from the source unless they contain escape sequences
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token<'a> {
+    BeginObject,
+    EndObject,
+    BeginArray,
+    EndArray,
+    Colon,
+    Comma,
+    String(Cow<'a, str>),
+    Number(&'a str),
+    Bool(bool),
+    Null,
+}
+
+impl std::fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::BeginObject => write!(f, "'{{'"),
+            Token::EndObject => write!(f, "'}}'"),
+            Token::BeginArray => write!(f, "'['"),
+            Token::EndArray => write!(f, "']'"),
+            Token::Colon => write!(f, "':'"),
+            Token::Comma => write!(f, "','"),
+            Token::String(s) => write!(f, "string {s:?}"),
+            Token::Number(n) => write!(f, "number {n}"),
+            Token::Bool(b) => write!(f, "{b}"),
+            Token::Null => write!(f, "null"),
+        }
+    }
+}
+
+/// An error in the JSON source, with the position where it was detected
+#[derive(Debug)]
+pub struct JsonError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl std::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "json error at line {} column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+pub type Result<T> = std::result::Result<T, JsonError>;
+
+/// A streaming JSON tokenizer
+///
+/// Tokens are produced one at a time, and no document tree is built. There are also helpers for
+/// walking objects and arrays, and for skipping values which are not of interest.
+pub struct Tokenizer<'a> {
+    json: &'a str,
+    /// offset of the next byte to tokenize
+    offset: usize,
+    /// offset of the start of the last token, for error positions
+    token_start: usize,
+    peeked: Option<Token<'a>>,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(json: &'a str) -> Self {
+        // a byte order mark is not part of the JSON
+        let offset = if json.starts_with('\u{feff}') {
+            '\u{feff}'.len_utf8()
+        } else {
+            0
+        };
+        Self {
+            json,
+            offset,
+            token_start: offset,
+            peeked: None,
+        }
+    }
+
+    /// make an error at the start of the last token
+    pub fn error(&self, message: impl Into<String>) -> JsonError {
+        let before = &self.json[..self.token_start];
+        let line = before.bytes().filter(|&b| b == b'\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        JsonError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// get the next token, or None at the end of the source
+    pub fn next_token(&mut self) -> Result<Option<Token<'a>>> {
+        if let Some(token) = self.peeked.take() {
+            return Ok(Some(token));
+        }
+
+        let bytes = self.json.as_bytes();
+        while self.offset < bytes.len()
+            && matches!(bytes[self.offset], b' ' | b'\t' | b'\n' | b'\r')
+        {
+            self.offset += 1;
+        }
+        self.token_start = self.offset;
+
+        let Some(&byte) = bytes.get(self.offset) else {
+            return Ok(None);
+        };
+
+        let token = match byte {
+            b'{' => Token::BeginObject,
+            b'}' => Token::EndObject,
+            b'[' => Token::BeginArray,
+            b']' => Token::EndArray,
+            b':' => Token::Colon,
+            b',' => Token::Comma,
+            b'"' => return self.string().map(Some),
+            b'-' | b'0'..=b'9' => return self.number().map(Some),
+            b't' => return self.literal("true", Token::Bool(true)).map(Some),
+            b'f' => return self.literal("false", Token::Bool(false)).map(Some),
+            b'n' => return self.literal("null", Token::Null).map(Some),
+            _ => {
+                let c = self.json[self.offset..].chars().next().unwrap_or_default();
+                Err(self.error(format!("unexpected character {c:?}")))?
+            }
+        };
+        self.offset += 1;
+        Ok(Some(token))
+    }
+
+    /// look at the next token without consuming it
+    pub fn peek_token(&mut self) -> Result<Option<&Token<'a>>> {
+        if self.peeked.is_none() {
+            self.peeked = self.next_token()?;
+        }
+        Ok(self.peeked.as_ref())
+    }
+
+    /// get the next token, which must exist
+    pub fn expect_token(&mut self) -> Result<Token<'a>> {
+        self.next_token()?
+            .ok_or_else(|| self.error("unexpected end of json"))
+    }
+
+    /// get the next token, which must be a string
+    pub fn expect_string(&mut self) -> Result<Cow<'a, str>> {
+        match self.expect_token()? {
+            Token::String(s) => Ok(s),
+            token => Err(self.error(format!("expected string, found {token}"))),
+        }
+    }
+
+    /// check that there are no more tokens
+    pub fn expect_end(&mut self) -> Result<()> {
+        match self.next_token()? {
+            None => Ok(()),
+            Some(token) => Err(self.error(format!("unexpected {token} after json value"))),
+        }
+    }
+
+    /// walk an object, calling `f` with each key
+    ///
+    /// `f` must consume the value of the key
+    pub fn object(
+        &mut self,
+        mut f: impl FnMut(&mut Self, Cow<'a, str>) -> Result<()>,
+    ) -> Result<()> {
+        self.begin(Token::BeginObject)?;
+        if self.end(&Token::EndObject)? {
+            return Ok(());
+        }
+        loop {
+            let key = self.expect_string()?;
+            match self.expect_token()? {
+                Token::Colon => {}
+                token => Err(self.error(format!("expected ':', found {token}")))?,
+            }
+            f(self, key)?;
+            match self.expect_token()? {
+                Token::Comma => {}
+                Token::EndObject => return Ok(()),
+                token => Err(self.error(format!("expected ',' or '}}', found {token}")))?,
+            }
+        }
+    }
+
+    /// walk an array, calling `f` for each element
+    ///
+    /// `f` must consume the element
+    pub fn array(&mut self, mut f: impl FnMut(&mut Self) -> Result<()>) -> Result<()> {
+        self.begin(Token::BeginArray)?;
+        if self.end(&Token::EndArray)? {
+            return Ok(());
+        }
+        loop {
+            f(self)?;
+            match self.expect_token()? {
+                Token::Comma => {}
+                Token::EndArray => return Ok(()),
+                token => Err(self.error(format!("expected ',' or ']', found {token}")))?,
+            }
+        }
+    }
+
+    /// consume a whole value, including nested objects and arrays
+    pub fn skip_value(&mut self) -> Result<()> {
+        match self.peek_token()? {
+            Some(Token::BeginObject) => self.object(|tokens, _| tokens.skip_value()),
+            Some(Token::BeginArray) => self.array(|tokens| tokens.skip_value()),
+            _ => match self.expect_token()? {
+                Token::String(_) | Token::Number(_) | Token::Bool(_) | Token::Null => Ok(()),
+                token => Err(self.error(format!("expected value, found {token}"))),
+            },
+        }
+    }
+
+    fn begin(&mut self, begin: Token<'a>) -> Result<()> {
+        let token = self.expect_token()?;
+        if token == begin {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {begin}, found {token}")))
+        }
+    }
+
+    /// consume the next token if it is `end`
+    fn end(&mut self, end: &Token<'a>) -> Result<bool> {
+        if self.peek_token()? == Some(end) {
+            self.peeked = None;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn literal(&mut self, literal: &str, token: Token<'a>) -> Result<Token<'a>> {
+        if self.json[self.offset..].starts_with(literal) {
+            self.offset += literal.len();
+            Ok(token)
+        } else {
+            Err(self.error(format!("invalid literal, expected {literal}")))
+        }
+    }
+
+    fn number(&mut self) -> Result<Token<'a>> {
+        let start = self.offset;
+        let len = self.json[start..]
+            .bytes()
+            .take_while(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'))
+            .count();
+        let number = &self.json[start..start + len];
+        if number.parse::<f64>().is_err() {
+            Err(self.error(format!("invalid number {number}")))?
+        }
+        self.offset += len;
+        Ok(Token::Number(number))
+    }
+
+    fn string(&mut self) -> Result<Token<'a>> {
+        // skip opening '"'
+        let start = self.offset + 1;
+        let bytes = self.json.as_bytes();
+        let mut i = start;
+        // only allocate if there are escape sequences
+        let mut owned: Option<String> = None;
+        // start of the text which has not yet been copied to `owned`
+        let mut copied_until = start;
+
+        loop {
+            let Some(&byte) = bytes.get(i) else {
+                Err(self.error("unterminated string"))?
+            };
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let s = owned.get_or_insert_with(String::new);
+                    s.push_str(&self.json[copied_until..i]);
+                    let (c, len) = self.escape(i)?;
+                    s.push(c);
+                    i += len;
+                    copied_until = i;
+                }
+                0..=0x1f => Err(self.error("control character in string"))?,
+                _ => i += 1,
+            }
+        }
+
+        self.offset = i + 1;
+        Ok(Token::String(match owned {
+            Some(mut s) => {
+                s.push_str(&self.json[copied_until..i]);
+                Cow::Owned(s)
+            }
+            None => Cow::Borrowed(&self.json[start..i]),
+        }))
+    }
+
+    /// decode the escape sequence at `offset` (the '\\'), returning the char and the length of the sequence
+    fn escape(&self, offset: usize) -> Result<(char, usize)> {
+        let bytes = self.json.as_bytes();
+        let c = match bytes.get(offset + 1) {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let high = self.hex4(offset + 2)?;
+                if !(0xD800..0xDC00).contains(&high) {
+                    let c =
+                        char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"))?;
+                    return Ok((c, 6));
+                }
+                // surrogate pair, which must be followed by the low surrogate
+                if !self.json[offset + 6..].starts_with("\\u") {
+                    Err(self.error("unpaired surrogate in unicode escape"))?
+                }
+                let low = self.hex4(offset + 8)?;
+                if !(0xDC00..0xE000).contains(&low) {
+                    Err(self.error("invalid low surrogate in unicode escape"))?
+                }
+                let c = char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                    .ok_or_else(|| self.error("invalid unicode escape"))?;
+                return Ok((c, 12));
+            }
+            _ => Err(self.error("invalid escape sequence"))?,
+        };
+        Ok((c, 2))
+    }
+
+    fn hex4(&self, offset: usize) -> Result<u32> {
+        self.json
+            .get(offset..offset + 4)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tokens(json: &str) -> Result<Vec<Token<'_>>> {
+        let mut tokenizer = Tokenizer::new(json);
+        let mut tokens = Vec::new();
+        while let Some(token) = tokenizer.next_token()? {
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+
+    #[test]
+    fn minified() {
+        let tokens = tokens(r#"{"a":[1,-2.5e3,true,false,null],"b":{}}"#).unwrap();
+        assert_eq!(
+            tokens,
+            [
+                Token::BeginObject,
+                Token::String("a".into()),
+                Token::Colon,
+                Token::BeginArray,
+                Token::Number("1"),
+                Token::Comma,
+                Token::Number("-2.5e3"),
+                Token::Comma,
+                Token::Bool(true),
+                Token::Comma,
+                Token::Bool(false),
+                Token::Comma,
+                Token::Null,
+                Token::EndArray,
+                Token::Comma,
+                Token::String("b".into()),
+                Token::Colon,
+                Token::BeginObject,
+                Token::EndObject,
+                Token::EndObject,
+            ]
+        );
+    }
+
+    #[test]
+    fn string_escapes() {
+        let escaped = tokens(r#""a \"b\" \\ \/ \n é 😀""#).unwrap();
+        assert_eq!(escaped, [Token::String("a \"b\" \\ / \n é 😀".into())]);
+        assert!(matches!(escaped[0], Token::String(Cow::Owned(_))));
+
+        let borrowed = tokens("\"no escapes ❤\"").unwrap();
+        assert!(matches!(
+            borrowed[0],
+            Token::String(Cow::Borrowed("no escapes ❤"))
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(tokens(r#""unterminated"#).is_err());
+        assert!(tokens(r#""bad \q escape""#).is_err());
+        assert!(tokens(r#""\ud83d alone""#).is_err());
+        assert!(tokens("nul").is_err());
+        assert!(tokens("-").is_err());
+
+        let mut tokenizer = Tokenizer::new("{\n  \"a\" 1\n}");
+        let error = tokenizer.object(|t, _| t.skip_value()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+
+    #[test]
+    fn skip_nested() {
+        let mut tokenizer = Tokenizer::new(r#"[{"a": [1, {"b": []}]}, "c"] 5"#);
+        tokenizer.skip_value().unwrap();
+        assert_eq!(tokenizer.next_token().unwrap(), Some(Token::Number("5")));
+        tokenizer.expect_end().unwrap();
+    }
+}
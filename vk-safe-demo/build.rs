@@ -7,6 +7,7 @@ fn main() {
     let children = [
         compile_glsl_resource("shader.vert", "vertex.spv"),
         compile_glsl_resource("shader.frag", "fragment.spv"),
+        compile_glsl_resource("shader.comp", "compute.spv"),
     ];
 
     for (mut child, src_file) in children {
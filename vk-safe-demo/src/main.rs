@@ -39,7 +39,7 @@ fn main() {
         .app_name(vk_str!("Example App"))
         .app_version(vk_safe::VkVersion::new(0, 0, 1));
     let instance_info = vk::InstanceCreateInfo::new(&app_info);
-    let instance = vk::create_instance(&instance_info, instance_tag).unwrap();
+    let instance = vk::create_instance(&instance_info, (), instance_tag).unwrap();
 
     println!("--Example Instance handle--");
     println!("{instance:?}");
@@ -64,8 +64,9 @@ fn main() {
 }
 
 fn run_physical_device(pd: impl PhysicalDevice<Commands: vk::instance::VERSION_1_0>) {
+    let properties = pd.get_physical_device_properties();
     println!("-------");
-    println!("{:#?}", pd.get_physical_device_properties());
+    println!("{:#?}", properties);
 
     println!("-------");
     println!("{:#?}", pd.get_physical_device_features());
@@ -143,7 +144,7 @@ fn run_physical_device(pd: impl PhysicalDevice<Commands: vk::instance::VERSION_1
     let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
 
     vk::tag!(dt);
-    let device = vk::create_device(&pd, &device_create_info, dt).unwrap();
+    let device = vk::create_device(&pd, &device_create_info, (), dt).unwrap();
 
     println!("--Example Device handle--");
     println!("{device:#?}");
@@ -155,19 +156,149 @@ fn run_physical_device(pd: impl PhysicalDevice<Commands: vk::instance::VERSION_1
         )
         .unwrap();
     let alloc_info = vk::MemoryAllocateInfo::new(std::num::NonZeroU64::new(100).unwrap(), mem_type);
-    let mem = vk::allocate_memory(&device, &alloc_info).unwrap();
+    let mem = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
     println!("--Example allocated memory handle--");
     println!("{mem:?}");
 
-    let mapped_memory = device.map_memory(mem).unwrap();
+    let mut mapped_memory = device
+        .map_memory(mem, vk::MemoryMapRange::whole(&properties))
+        .unwrap();
     println!("--Example mapped memory handle--");
     println!("{mapped_memory:#?}");
 
-    let ranges = [vk::MappedMemoryRange::whole_range(&mapped_memory)];
-    device.flush_mapped_memory_ranges(&ranges).unwrap();
+    // SAFETY: the memory is not used by the device
+    unsafe {
+        mapped_memory.as_mut_slice().fill(1);
+        if let Some(words) = mapped_memory.as_mut_slice_of::<u32>() {
+            words[0] = 42;
+        }
+    }
 
     let _memory = device.unmap_memory(mapped_memory);
 
+    // memory which is not host coherent must be flushed / invalidated
+    let non_coherent_mem_type = mem_props.find_ty(
+        vk::flags!(MemoryPropertyFlags + HOST_VISIBLE_BIT - HOST_COHERENT_BIT),
+        vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+    );
+    if let Some(mem_type) = non_coherent_mem_type {
+        let alloc_info =
+            vk::MemoryAllocateInfo::new(std::num::NonZeroU64::new(100).unwrap(), mem_type);
+        let mem = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
+
+        let mut mapped_memory = device
+            .map_memory(mem, vk::MemoryMapRange::whole(&properties))
+            .unwrap();
+
+        // SAFETY: the memory is not used by the device
+        unsafe { mapped_memory.as_mut_slice().fill(1) };
+
+        let ranges = [
+            vk::MappedMemoryRange::whole_range(&mapped_memory),
+            vk::MappedMemoryRange::sub_range(
+                &mapped_memory,
+                0,
+                std::num::NonZeroU64::new(100).unwrap(),
+            )
+            .unwrap(),
+        ];
+        device.flush_mapped_memory_ranges(&ranges).unwrap();
+        device.invalidate_mapped_memory_ranges(&ranges).unwrap();
+
+        let _memory = device.unmap_memory(mapped_memory);
+    }
+
+    vk::tag!(buffer_tag);
+    let buffer_info = vk::BufferCreateInfo::new(
+        std::num::NonZeroU64::new(256).unwrap(),
+        vk::flags!(BufferUsageFlags + TRANSFER_SRC_BIT + STORAGE_BUFFER_BIT),
+    );
+    let buffer = vk::create_buffer(&device, &buffer_info, (), buffer_tag).unwrap();
+    println!("--Example Buffer handle--");
+    println!("{buffer:#?}");
+
+    let buffer_requirements = device.get_buffer_memory_requirements(&buffer);
+    println!("{buffer_requirements:#?}");
+    let buffer_mem_type = buffer_requirements
+        .find_ty(
+            &mem_props,
+            vk::flags!(MemoryPropertyFlags + DEVICE_LOCAL_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let buffer_alloc_info = vk::MemoryAllocateInfo::new(
+        std::num::NonZeroU64::new(buffer_requirements.size).unwrap(),
+        buffer_mem_type,
+    );
+    let buffer_mem = vk::allocate_memory(&device, &buffer_alloc_info, ()).unwrap();
+    let bound_buffer = device
+        .bind_buffer_memory(buffer, &buffer_mem, 0, &buffer_requirements)
+        .unwrap();
+    println!("{bound_buffer:#?}");
+
+    vk::tag!(image_tag);
+    let image_info = vk::ImageCreateInfo::new(
+        &tst_image_format_properties,
+        &properties,
+        vk::Extent3D {
+            width: 64,
+            height: 64,
+            depth: 1,
+        },
+        std::num::NonZeroU32::new(1).unwrap(),
+        std::num::NonZeroU32::new(1).unwrap(),
+        vk::SampleCountFlags::TYPE_1_BIT,
+    )
+    .unwrap();
+    let image = vk::create_image(&device, &image_info, (), image_tag).unwrap();
+    println!("--Example Image handle--");
+    println!("{image:#?}");
+
+    let image_requirements = device.get_image_memory_requirements(&image);
+    let image_mem_type = image_requirements
+        .find_ty(
+            &mem_props,
+            vk::flags!(MemoryPropertyFlags + DEVICE_LOCAL_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let image_alloc_info = vk::MemoryAllocateInfo::new(
+        std::num::NonZeroU64::new(image_requirements.size).unwrap(),
+        image_mem_type,
+    );
+    let image_mem = vk::allocate_memory(&device, &image_alloc_info, ()).unwrap();
+    let mut bound_image = device
+        .bind_image_memory(image, &image_mem, 0, &image_requirements)
+        .unwrap();
+    println!("{bound_image:#?}");
+
+    // the layout is tracked from here on, and the BoundImage is accessed through the TrackedImage
+    let tracked_image = bound_image.track_layout();
+
+    let image_view_info = vk::ImageViewCreateInfo::new(
+        tracked_image.image(),
+        vk::ImageViewType::TYPE_2D,
+        vk::flags!(ImageAspectFlags + COLOR_BIT),
+        0..1,
+        0..1,
+    )
+    .unwrap();
+    let image_view = vk::create_image_view(&device, &image_view_info, ()).unwrap();
+    println!("--Example ImageView handle--");
+    println!("{image_view:#?}");
+
+    let sampler_info = vk::SamplerCreateInfo::new(
+        vk::Filter::LINEAR,
+        vk::Filter::LINEAR,
+        vk::SamplerMipmapMode::LINEAR,
+        vk::SamplerAddressMode::CLAMP_TO_EDGE,
+    )
+    .lod(&properties, 0.0, 0.0, 0.0)
+    .unwrap();
+    let sampler = vk::create_sampler(&device, &sampler_info, ()).unwrap();
+    println!("--Example Sampler handle--");
+    println!("{sampler:#?}");
+
     for queue_config in queue_configs {
         vk::tag!(family_tag);
         let (queue_family_marker, queues_iter) = vk::get_device_queues(
@@ -180,7 +311,7 @@ fn run_physical_device(pd: impl PhysicalDevice<Commands: vk::instance::VERSION_1
         .unwrap();
         println!("Configured Queue Family: {:#?}", queue_family_marker);
 
-        let queues: Vec<_> = queues_iter.collect();
+        let mut queues: Vec<_> = queues_iter.collect();
 
         for q in queues.iter() {
             println!("{q:#?}");
@@ -198,23 +329,75 @@ fn run_physical_device(pd: impl PhysicalDevice<Commands: vk::instance::VERSION_1
         let vertex_shader = vk::create_shader_module(
             &device,
             &vk::ShaderModuleCreateInfo::from_spirv_binary(&vertex_shader_spirv),
+            (),
         )
         .unwrap();
         let fragment_shader = vk::create_shader_module(
             &device,
             &vk::ShaderModuleCreateInfo::from_spirv_binary(&fragment_shader_spirv),
+            (),
         )
         .unwrap();
 
         println!("Vertex Shader: {vertex_shader:?}");
         println!("Fragment Shader: {fragment_shader:?}");
 
+        let compute_shader_spirv = unsafe {
+            vk::SpirvBinary::load_from_file_path(build_dir.join("compute.spv"))
+                .expect("could not load compute shader from file")
+        };
+        let compute_shader = vk::create_shader_module(
+            &device,
+            &vk::ShaderModuleCreateInfo::from_spirv_binary(&compute_shader_spirv),
+            (),
+        )
+        .unwrap();
+
+        let set_layout_bindings = [vk::DescriptorSetLayoutBinding::new(
+            0,
+            vk::DescriptorType::STORAGE_BUFFER,
+            1,
+            vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+        )];
+        let set_layout = vk::create_descriptor_set_layout(
+            &device,
+            &vk::DescriptorSetLayoutCreateInfo::new(&set_layout_bindings).unwrap(),
+            (),
+        )
+        .unwrap();
+        let push_constant_ranges = [vk::PushConstantRange::new(
+            &properties,
+            vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+            0..16,
+        )
+        .unwrap()];
+        let pipeline_layout = vk::create_pipeline_layout(
+            &device,
+            &vk::PipelineLayoutCreateInfo::new(&properties, [&set_layout], &push_constant_ranges)
+                .unwrap(),
+            (),
+        )
+        .unwrap();
+        println!("{pipeline_layout:#?}");
+
+        // Safety: shader.comp has a "main" entry point, and does not use any resources
+        let compute_pipeline_info = unsafe {
+            vk::ComputePipelineCreateInfo::new(
+                vk::PipelineShaderStageCreateInfo::compute(&compute_shader, vk_str!("main")),
+                &pipeline_layout,
+            )
+        };
+        let [compute_pipeline] =
+            vk::create_compute_pipelines(&device, &[compute_pipeline_info], ()).unwrap();
+        println!("{compute_pipeline:#?}");
+
         let command_pool = vk::create_command_pool(
             &device,
             &vk::CommandPoolCreateInfo::new(
                 vk::flags!(CommandPoolCreateFlags + RESET_COMMAND_BUFFER_BIT - PROTECTED_BIT),
                 &queue_family_marker,
             ),
+            (),
         )
         .unwrap();
 
@@ -231,7 +414,129 @@ fn run_physical_device(pd: impl PhysicalDevice<Commands: vk::instance::VERSION_1
             .unwrap();
         for command_buffer in command_buffers {
             println!("{command_buffer:#?}");
+
+            let begin_info = vk::CommandBufferBeginInfo::new(vk::flags!(
+                CommandBufferUsageFlags + ONE_TIME_SUBMIT_BIT
+            ));
+            let executable = command_buffer
+                .begin_command_buffer(&begin_info)
+                .unwrap()
+                .end_command_buffer()
+                .unwrap();
+
+            // the command pool allows resetting individual command buffers, so an
+            // executable command buffer can begin recording again
+            let executable = executable
+                .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+                .unwrap()
+                .end_command_buffer()
+                .unwrap();
+            println!("{executable:#?}");
         }
+
+        let secondary_buffer_info = vk::CommandBufferAllocateInfo::new(
+            &command_pool,
+            vk::CommandBufferLevel::SECONDARY,
+            Vec::with_capacity(1),
+        )
+        .unwrap();
+        let primary_buffer_info = vk::CommandBufferAllocateInfo::new(
+            &command_pool,
+            vk::CommandBufferLevel::PRIMARY,
+            Vec::with_capacity(1),
+        )
+        .unwrap();
+        let secondary = device
+            .allocate_command_buffers(secondary_buffer_info)
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let primary = device
+            .allocate_command_buffers(primary_buffer_info)
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let inheritance_info = vk::CommandBufferInheritanceInfo::new();
+        let secondary = secondary
+            .begin_command_buffer(&vk::CommandBufferBeginInfo::new_secondary(
+                (),
+                &inheritance_info,
+            ))
+            .unwrap()
+            .end_command_buffer()
+            .unwrap();
+        let mut primary = primary
+            .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+            .unwrap();
+
+        // transition the image for transfers, which updates the tracked layout when recorded
+        let image_transition = vk::ImageMemoryBarrier::transition(
+            tracked_image,
+            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+            (),
+            vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+        );
+        let (transfer_dst_image,) = primary.cmd_pipeline_barrier(
+            vk::flags!(PipelineStageFlags + TOP_OF_PIPE_BIT),
+            vk::flags!(PipelineStageFlags + TRANSFER_BIT),
+            (),
+            (),
+            (image_transition,),
+        );
+        println!("{transfer_dst_image:#?}");
+
+        // make transfer writes to the buffer visible to the host
+        let buffer_barrier = vk::BufferMemoryBarrier::new(
+            &bound_buffer,
+            vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+            vk::flags!(AccessFlags + HOST_READ_BIT),
+        );
+        primary.cmd_pipeline_barrier(
+            vk::flags!(PipelineStageFlags + TRANSFER_BIT),
+            vk::flags!(PipelineStageFlags + HOST_BIT),
+            (),
+            [buffer_barrier],
+            (),
+        );
+
+        let mut bound_pipeline = primary.cmd_bind_pipeline(&compute_pipeline);
+        // Safety: shader.comp does not use any descriptor sets or push constants
+        unsafe { bound_pipeline.cmd_dispatch(&properties, 4, 1, 1).unwrap() };
+
+        primary.cmd_execute_commands(secondary);
+        let primary = primary.end_command_buffer().unwrap();
+        println!("{primary:#?}");
+
+        let mut fence = vk::create_fence(&device, &vk::FenceCreateInfo::new(), ()).unwrap();
+        let semaphore = vk::create_semaphore(&device, &vk::SemaphoreCreateInfo::new(), ()).unwrap();
+        let queue = &mut queues[0];
+
+        // the command buffer and semaphore are held by the submission until it completes
+        let completed = queue
+            .queue_submit(
+                vk::SubmitInfo::new([primary]).signal_semaphores([semaphore]),
+                &mut fence,
+                |_queue, submission| submission.wait(u64::MAX).unwrap().unwrap(),
+            )
+            .unwrap();
+        let [primary] = completed.command_buffers;
+        let [semaphore] = completed.signal_semaphores;
+
+        // submit again, waiting on the now signaled semaphore
+        let completed = queue
+            .queue_submit(
+                vk::SubmitInfo::new([primary]).wait_semaphores(
+                    [semaphore],
+                    vk::flags!(PipelineStageFlags + ALL_COMMANDS_BIT),
+                ),
+                &mut fence,
+                |_queue, submission| submission.wait(u64::MAX).unwrap().unwrap(),
+            )
+            .unwrap();
+        println!("{completed:#?}");
     }
 
     unsafe {
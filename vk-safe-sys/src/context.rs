@@ -11,6 +11,8 @@ pub trait Context {
 pub unsafe trait InstanceDependencies<I, O> {}
 
 pub unsafe trait Extensions {
+    /// names of the extensions as given to the context macro (e.g. `"EXT_debug_utils"`), for checks at compile time
+    const NAMES: &'static [&'static str];
     fn list_of_extensions() -> impl AsRef<[VkStrRaw]>;
 }
 
@@ -65,6 +67,7 @@ macro_rules! instance_context {
             unsafe impl $crate::CommandProvider for $name {}
 
             unsafe impl $crate::context::Extensions for $name {
+                const NAMES: &'static [&'static str] = &[ $( stringify!($e_provider) ),* ];
                 fn list_of_extensions() -> impl AsRef<[$crate::VkStrRaw]> {
                     use std::ffi::c_char;
                     use $crate::context::macro_helper::*;
@@ -159,6 +162,7 @@ macro_rules! device_context {
                 where I: $crate::CommandProvider $( + $crate::dependencies::device::$e_provider::instance::HasDependency<$e_provider> )* {}
 
             unsafe impl $crate::context::Extensions for $name {
+                const NAMES: &'static [&'static str] = &[ $( stringify!($e_provider) ),* ];
                 fn list_of_extensions() -> impl AsRef<[$crate::VkStrRaw]> {
                     use std::ffi::c_char;
                     use $crate::context::macro_helper::*;
@@ -11,6 +11,19 @@
 #[macro_use]
 pub mod generated_vulkan;
 
+/// Function pointer type of `vkGetInstanceProcAddr`
+pub type GetInstanceProcAddrFn = unsafe extern "system" fn(
+    instance: generated_vulkan::Instance,
+    p_name: *const std::ffi::c_char,
+) -> Option<generated_vulkan::PFN_vkVoidFunction>;
+
+/// Function pointer type of `vkGetDeviceProcAddr`
+pub type GetDeviceProcAddrFn = unsafe extern "system" fn(
+    device: generated_vulkan::Device,
+    p_name: *const std::ffi::c_char,
+) -> Option<generated_vulkan::PFN_vkVoidFunction>;
+
+#[cfg(feature = "linked")]
 #[cfg_attr(target_os = "linux", link(name = "vulkan"))]
 #[cfg_attr(target_os = "windows", link(name = "vulkan-1"))]
 extern "system" {
@@ -29,6 +42,9 @@ extern "system" {
 
 pub mod context;
 
+#[cfg(feature = "dlopen")]
+pub mod library;
+
 pub use generated_vulkan::has_command;
 pub use generated_vulkan::*;
 
@@ -0,0 +1,218 @@
+//! Load the Vulkan library at runtime
+//!
+//! Enabled with the `dlopen` feature. Instead of linking to the system Vulkan loader
+//! when the program is built, the loader (or any library which exports
+//! `vkGetInstanceProcAddr`, such as a mock ICD) is opened when the program runs.
+
+use crate::GetInstanceProcAddrFn;
+
+use std::ffi::{c_char, c_int, c_void, CStr, CString, OsStr};
+use std::fmt;
+
+/// Name of the Vulkan loader which is opened by [`VulkanLibrary::open_default`]
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios"))))]
+pub const DEFAULT_LIBRARY_NAME: &str = "libvulkan.so.1";
+/// Name of the Vulkan loader which is opened by [`VulkanLibrary::open_default`]
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub const DEFAULT_LIBRARY_NAME: &str = "libvulkan.1.dylib";
+/// Name of the Vulkan loader which is opened by [`VulkanLibrary::open_default`]
+#[cfg(windows)]
+pub const DEFAULT_LIBRARY_NAME: &str = "vulkan-1.dll";
+
+/// Error when loading the Vulkan library
+#[derive(Debug, Clone)]
+pub enum LibraryLoadError {
+    /// The library could not be opened
+    ///
+    /// Contains the name of the library, and the reason reported by the system (if any)
+    Open { library: String, reason: String },
+    /// The library does not export `vkGetInstanceProcAddr`
+    MissingGetInstanceProcAddr { library: String },
+}
+
+impl fmt::Display for LibraryLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open { library, reason } => {
+                write!(f, "failed to open Vulkan library {library:?}: {reason}")
+            }
+            Self::MissingGetInstanceProcAddr { library } => {
+                write!(
+                    f,
+                    "Vulkan library {library:?} does not export vkGetInstanceProcAddr"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LibraryLoadError {}
+
+/// An opened Vulkan library
+///
+/// The library is closed when this is dropped. Any function loaded from the library
+/// (directly or indirectly through [`get_instance_proc_addr`](Self::get_instance_proc_addr))
+/// must not be used after that.
+pub struct VulkanLibrary {
+    handle: sys::Handle,
+    get_instance_proc_addr: GetInstanceProcAddrFn,
+}
+
+// SAFETY: the library handle is only used to close the library on drop, and Vulkan
+// commands are safe to call from any thread (subject to external synchronization rules
+// which are handled by the users of the commands)
+unsafe impl Send for VulkanLibrary {}
+unsafe impl Sync for VulkanLibrary {}
+
+impl VulkanLibrary {
+    /// Open the Vulkan loader installed on the system
+    ///
+    /// Opens [`DEFAULT_LIBRARY_NAME`] using the normal library search rules of the platform.
+    pub fn open_default() -> Result<Self, LibraryLoadError> {
+        // SAFETY: the system Vulkan loader is assumed to be a conforming implementation,
+        // which is the same assumption that is made when linking to it at build time
+        unsafe { Self::open(DEFAULT_LIBRARY_NAME) }
+    }
+
+    /// Open a Vulkan library by name or path
+    ///
+    /// # Safety
+    /// Opening a library runs its initialization routines. The library must be a Vulkan
+    /// loader or ICD which exports a conforming `vkGetInstanceProcAddr`.
+    pub unsafe fn open(library: impl AsRef<OsStr>) -> Result<Self, LibraryLoadError> {
+        let library = library.as_ref();
+        let name = library.to_string_lossy().into_owned();
+
+        let handle = unsafe { sys::open(library) }.map_err(|reason| LibraryLoadError::Open {
+            library: name.clone(),
+            reason,
+        })?;
+
+        match unsafe { sys::symbol(handle, c"vkGetInstanceProcAddr") } {
+            Some(fptr) => Ok(Self {
+                handle,
+                // SAFETY: vkGetInstanceProcAddr is promised by the caller to be the Vulkan command
+                get_instance_proc_addr: unsafe {
+                    std::mem::transmute::<*mut c_void, GetInstanceProcAddrFn>(fptr)
+                },
+            }),
+            None => {
+                unsafe { sys::close(handle) };
+                Err(LibraryLoadError::MissingGetInstanceProcAddr { library: name })
+            }
+        }
+    }
+
+    /// `vkGetInstanceProcAddr` exported by the library
+    ///
+    /// The function is only valid while the library is open.
+    pub fn get_instance_proc_addr(&self) -> GetInstanceProcAddrFn {
+        self.get_instance_proc_addr
+    }
+}
+
+impl fmt::Debug for VulkanLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VulkanLibrary")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl Drop for VulkanLibrary {
+    fn drop(&mut self) {
+        unsafe { sys::close(self.handle) }
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use super::*;
+
+    use std::os::unix::ffi::OsStrExt;
+
+    pub type Handle = *mut c_void;
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    const RTLD_LOCAL: c_int = 0x4;
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    const RTLD_LOCAL: c_int = 0;
+    const RTLD_NOW: c_int = 0x2;
+
+    #[cfg_attr(target_os = "linux", link(name = "dl"))]
+    extern "C" {
+        fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+        fn dlclose(handle: *mut c_void) -> c_int;
+        fn dlerror() -> *const c_char;
+    }
+
+    pub unsafe fn open(library: &OsStr) -> Result<Handle, String> {
+        let name = CString::new(library.as_bytes()).map_err(|e| e.to_string())?;
+        let handle = unsafe { dlopen(name.as_ptr(), RTLD_NOW | RTLD_LOCAL) };
+        if handle.is_null() {
+            Err(unsafe { last_error() })
+        } else {
+            Ok(handle)
+        }
+    }
+
+    pub unsafe fn symbol(handle: Handle, name: &CStr) -> Option<*mut c_void> {
+        let fptr = unsafe { dlsym(handle, name.as_ptr()) };
+        (!fptr.is_null()).then_some(fptr)
+    }
+
+    pub unsafe fn close(handle: Handle) {
+        unsafe { dlclose(handle) };
+    }
+
+    unsafe fn last_error() -> String {
+        let error = unsafe { dlerror() };
+        if error.is_null() {
+            "unknown error".to_string()
+        } else {
+            unsafe { CStr::from_ptr(error) }
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use super::*;
+
+    use std::os::windows::ffi::OsStrExt;
+
+    pub type Handle = *mut c_void;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn LoadLibraryW(file_name: *const u16) -> *mut c_void;
+        fn GetProcAddress(module: *mut c_void, proc_name: *const c_char) -> *mut c_void;
+        fn FreeLibrary(module: *mut c_void) -> c_int;
+        fn GetLastError() -> u32;
+    }
+
+    pub unsafe fn open(library: &OsStr) -> Result<Handle, String> {
+        let name: Vec<u16> = library.encode_wide().chain(Some(0)).collect();
+        if name[..name.len() - 1].contains(&0) {
+            return Err("library name contains a nul character".to_string());
+        }
+        let handle = unsafe { LoadLibraryW(name.as_ptr()) };
+        if handle.is_null() {
+            Err(format!("error code {}", unsafe { GetLastError() }))
+        } else {
+            Ok(handle)
+        }
+    }
+
+    pub unsafe fn symbol(handle: Handle, name: &CStr) -> Option<*mut c_void> {
+        let fptr = unsafe { GetProcAddress(handle, name.as_ptr()) };
+        (!fptr.is_null()).then_some(fptr)
+    }
+
+    pub unsafe fn close(handle: Handle) {
+        unsafe { FreeLibrary(handle) };
+    }
+}
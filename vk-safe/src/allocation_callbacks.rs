@@ -0,0 +1,296 @@
+//! Host memory allocation callbacks
+//!
+//! Vulkan implementations allocate host memory for objects (and for internal purposes)
+//! when commands are called. By default, the implementation uses its own allocator. With
+//! [`AllocationCallbacks`], the application can provide its own [`HostAllocator`], which
+//! can be used to track or budget the host memory used by the implementation.
+//!
+//! Every command which creates or destroys an object takes an [`Allocator`], which is
+//! either `()` to use the default allocator of the implementation, or [`AllocationCallbacks`].
+//! The created handle keeps the Allocator as part of its type, and passes the same
+//! callbacks when it is destroyed.
+//!
+//! <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkAllocationCallbacks.html>
+
+use std::ffi::c_void;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
+use vk_safe_sys as vk;
+
+/// Application provided host memory allocator
+///
+/// The methods of this trait are called by the Vulkan implementation, possibly from
+/// multiple threads at the same time, during any command which uses [`AllocationCallbacks`]
+/// made with this allocator. The methods must not call back into Vulkan.
+///
+/// A panic must not unwind into the Vulkan implementation, so it is caught when the method
+/// returns. If [`allocate`](Self::allocate) or [`reallocate`](Self::reallocate) panics, the
+/// allocation fails (i.e. null is returned to the implementation). If any other method panics,
+/// the panic is ignored. In either case, the panic is still reported by the panic hook.
+///
+/// # Safety
+/// - [`allocate`](Self::allocate) must return either null, or a pointer to at least `size`
+///   bytes which are aligned to `alignment` and not used for anything else until freed.
+/// - [`reallocate`](Self::reallocate) must behave as described in the Vulkan documentation
+///   for `PFN_vkReallocationFunction`.
+/// - [`free`](Self::free) must accept null, and any pointer returned by `allocate` or
+///   `reallocate` which was not already freed.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/PFN_vkAllocationFunction.html>
+pub unsafe trait HostAllocator: Send + Sync + 'static {
+    /// Allocate `size` bytes aligned to `alignment`
+    ///
+    /// Return null if the allocation fails.
+    fn allocate(
+        &self,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut c_void;
+
+    /// Change the size of an allocation
+    ///
+    /// If `original` is null, this must behave as [`allocate`](Self::allocate). If `size`
+    /// is zero, this must behave as [`free`](Self::free) and return null. Otherwise, the
+    /// contents of `original` (up to the smaller of the old and new size) are moved to the
+    /// returned allocation, and `original` is freed. If the allocation fails, return null
+    /// and leave `original` unchanged.
+    ///
+    /// # Safety
+    /// `original` is null, or was returned by `allocate` or `reallocate` of this allocator,
+    /// and was not freed.
+    unsafe fn reallocate(
+        &self,
+        original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut c_void;
+
+    /// Free an allocation
+    ///
+    /// # Safety
+    /// `memory` is null, or was returned by `allocate` or `reallocate` of this allocator,
+    /// and was not freed.
+    unsafe fn free(&self, memory: *mut c_void);
+
+    /// Notification that the implementation allocated memory with its own allocator
+    ///
+    /// This is informational only. The default implementation does nothing.
+    fn internal_allocation(
+        &self,
+        _size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _scope: vk::SystemAllocationScope,
+    ) {
+    }
+
+    /// Notification that the implementation freed memory which was allocated with its own allocator
+    ///
+    /// This is informational only. The default implementation does nothing.
+    fn internal_free(
+        &self,
+        _size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _scope: vk::SystemAllocationScope,
+    ) {
+    }
+}
+
+/// Host memory allocation callbacks backed by a [`HostAllocator`]
+///
+/// Cloning is cheap, and all clones use the same allocator. The allocator stays alive for
+/// as long as any clone exists, including the clones kept by handles which were created
+/// with these callbacks.
+///
+/// ```
+/// # use vk_safe::vk;
+/// # use std::ffi::c_void;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// struct Counting(AtomicUsize);
+///
+/// unsafe impl vk::HostAllocator for Counting {
+///     fn allocate(&self, size: usize, alignment: usize, _: vk_safe::raw::SystemAllocationScope) -> *mut c_void {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///         // some allocator
+/// #       std::ptr::null_mut()
+///     }
+///     // reallocate and free
+/// #   unsafe fn reallocate(&self, _: *mut c_void, _: usize, _: usize, _: vk_safe::raw::SystemAllocationScope) -> *mut c_void { std::ptr::null_mut() }
+/// #   unsafe fn free(&self, _: *mut c_void) {}
+/// }
+///
+/// let callbacks = vk::AllocationCallbacks::new(Counting(AtomicUsize::new(0)));
+/// let allocations = callbacks.allocator().0.load(Ordering::Relaxed);
+/// ```
+pub struct AllocationCallbacks<A> {
+    allocator: Arc<A>,
+    callbacks: vk::AllocationCallbacks,
+}
+
+// SAFETY: the raw callbacks only point to the allocator, which is Send and Sync
+unsafe impl<A: HostAllocator> Send for AllocationCallbacks<A> {}
+unsafe impl<A: HostAllocator> Sync for AllocationCallbacks<A> {}
+
+/// Make a raw PFN type from a function with the signature of the PFN
+macro_rules! pfn {
+    ( $pfn:ident($fptr_ty:ident) = $function:expr ) => {
+        vk::$pfn::new(std::mem::transmute::<vk::$fptr_ty, vk::PFN_vkVoidFunction>(
+            $function,
+        ))
+    };
+}
+
+impl<A: HostAllocator> AllocationCallbacks<A> {
+    /// Make AllocationCallbacks which call `allocator`
+    pub fn new(allocator: A) -> Self {
+        let allocator = Arc::new(allocator);
+        // SAFETY: each function has the signature of the respective PFN type
+        let callbacks = unsafe {
+            vk::AllocationCallbacks {
+                p_user_data: Arc::as_ptr(&allocator).cast_mut().cast(),
+                pfn_allocation: pfn!(
+                    PFN_vkAllocationFunction(FptrTyPFN_vkAllocationFunction) = allocation::<A>
+                ),
+                pfn_reallocation: pfn!(
+                    PFN_vkReallocationFunction(FptrTyPFN_vkReallocationFunction) =
+                        reallocation::<A>
+                ),
+                pfn_free: pfn!(PFN_vkFreeFunction(FptrTyPFN_vkFreeFunction) = free::<A>),
+                pfn_internal_allocation: pfn!(
+                    PFN_vkInternalAllocationNotification(
+                        FptrTyPFN_vkInternalAllocationNotification
+                    ) = internal_allocation::<A>
+                ),
+                pfn_internal_free: pfn!(
+                    PFN_vkInternalFreeNotification(FptrTyPFN_vkInternalFreeNotification) =
+                        internal_free::<A>
+                ),
+            }
+        };
+        Self {
+            allocator,
+            callbacks,
+        }
+    }
+
+    /// The allocator which is called by these callbacks
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+}
+
+impl<A> Clone for AllocationCallbacks<A> {
+    fn clone(&self) -> Self {
+        Self {
+            allocator: self.allocator.clone(),
+            callbacks: self.callbacks,
+        }
+    }
+}
+
+impl<A> fmt::Debug for AllocationCallbacks<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AllocationCallbacks")
+            .field("allocator", &Arc::as_ptr(&self.allocator))
+            .finish()
+    }
+}
+
+/// Choice of host memory allocator for creating and destroying objects
+///
+/// Implemented by `()`, which uses the default allocator of the Vulkan implementation
+/// (i.e. `pAllocator` is NULL), and [`AllocationCallbacks`].
+pub trait Allocator: private::RawCallbacks + Clone + Send + Sync {}
+
+impl Allocator for () {}
+
+impl<A: HostAllocator> Allocator for AllocationCallbacks<A> {}
+
+pub(crate) mod private {
+    use super::*;
+
+    pub trait RawCallbacks {
+        /// pAllocator to pass to Vulkan commands
+        ///
+        /// The pointer is valid for as long as self is borrowed, and the callbacks it points
+        /// to stay valid for as long as self (or a clone of self) exists.
+        fn callbacks(&self) -> *const vk::AllocationCallbacks;
+    }
+
+    impl RawCallbacks for () {
+        fn callbacks(&self) -> *const vk::AllocationCallbacks {
+            std::ptr::null()
+        }
+    }
+
+    impl<A: HostAllocator> RawCallbacks for AllocationCallbacks<A> {
+        fn callbacks(&self) -> *const vk::AllocationCallbacks {
+            &self.callbacks
+        }
+    }
+}
+
+// The following are called by the Vulkan implementation with the p_user_data set in
+// AllocationCallbacks::new, which points to the allocator in the Arc
+//
+// a panic must not unwind into Vulkan; it is already reported by the panic hook, so a failed
+// allocation is returned, or the panic is ignored for functions which do not return anything
+
+unsafe extern "system" fn allocation<A: HostAllocator>(
+    user_data: *mut c_void,
+    size: usize,
+    alignment: usize,
+    scope: vk::SystemAllocationScope,
+) -> *mut c_void {
+    let allocator = unsafe { &*user_data.cast::<A>() };
+    catch_unwind(AssertUnwindSafe(|| {
+        allocator.allocate(size, alignment, scope)
+    }))
+    .unwrap_or(std::ptr::null_mut())
+}
+
+unsafe extern "system" fn reallocation<A: HostAllocator>(
+    user_data: *mut c_void,
+    original: *mut c_void,
+    size: usize,
+    alignment: usize,
+    scope: vk::SystemAllocationScope,
+) -> *mut c_void {
+    let allocator = unsafe { &*user_data.cast::<A>() };
+    catch_unwind(AssertUnwindSafe(|| unsafe {
+        allocator.reallocate(original, size, alignment, scope)
+    }))
+    .unwrap_or(std::ptr::null_mut())
+}
+
+unsafe extern "system" fn free<A: HostAllocator>(user_data: *mut c_void, memory: *mut c_void) {
+    let allocator = unsafe { &*user_data.cast::<A>() };
+    let _ = catch_unwind(AssertUnwindSafe(|| unsafe { allocator.free(memory) }));
+}
+
+unsafe extern "system" fn internal_allocation<A: HostAllocator>(
+    user_data: *mut c_void,
+    size: usize,
+    allocation_type: vk::InternalAllocationType,
+    scope: vk::SystemAllocationScope,
+) {
+    let allocator = unsafe { &*user_data.cast::<A>() };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        allocator.internal_allocation(size, allocation_type, scope)
+    }));
+}
+
+unsafe extern "system" fn internal_free<A: HostAllocator>(
+    user_data: *mut c_void,
+    size: usize,
+    allocation_type: vk::InternalAllocationType,
+    scope: vk::SystemAllocationScope,
+) {
+    let allocator = unsafe { &*user_data.cast::<A>() };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        allocator.internal_free(size, allocation_type, scope)
+    }));
+}
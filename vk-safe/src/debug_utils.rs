@@ -0,0 +1,227 @@
+//! Debug messages from Vulkan (VK_EXT_debug_utils)
+//!
+//! With `EXT_debug_utils` in the instance context, the implementation and layers (e.g. the
+//! validation layer) report messages to a [`DebugUtilsMessengerCallback`]. The callback is any
+//! closure (or trait object) which takes a [`DebugUtilsMessage`], which is the decoded
+//! VkDebugUtilsMessengerCallbackDataEXT, with the message id, VUID, and the objects involved.
+//!
+//! The callback is provided in a [`DebugUtilsMessengerCreateInfoEXT`](crate::vk::DebugUtilsMessengerCreateInfoEXT)
+//! along with the severities and types of messages to report (as type level flags made with
+//! [`flags!`](crate::vk::flags!)), which is used to either:
+//! - create a [`DebugUtilsMessenger`](crate::vk::DebugUtilsMessenger) for an Instance with
+//!   [`create_debug_utils_messenger`](crate::vk::create_debug_utils_messenger), which reports
+//!   messages until it is dropped, or
+//! - report messages from creating and destroying an Instance, with
+//!   [`InstanceCreateInfo::push_debug_utils_messenger`](crate::vk::InstanceCreateInfo::push_debug_utils_messenger).
+//!
+//! The callback can be called by Vulkan from any thread, so it must be `Send` and `Sync`. A panic
+//! in the callback cannot unwind into Vulkan, so it is caught (after being reported by the panic
+//! hook) and the message is otherwise ignored.
+//!
+//! ```
+//! # use vk_safe::vk;
+//! # use vk::traits::*;
+//! # fn tst(instance: impl Instance<Commands: vk::instance::EXT_debug_utils>) {
+//! let info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+//!     vk::flags!(
+//!         DebugUtilsMessageSeverityFlagsEXT
+//!             + DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+//!             + DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT
+//!     ),
+//!     vk::flags!(
+//!         DebugUtilsMessageTypeFlagsEXT
+//!             + DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT
+//!             + DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT
+//!     ),
+//!     |message: &vk::DebugUtilsMessage| {
+//!         if let Some(vuid) = message.vuid() {
+//!             eprintln!("{vuid}: {}", message.message);
+//!         }
+//!     },
+//! );
+//! let messenger = vk::create_debug_utils_messenger(&instance, &info, ()).unwrap();
+//! # }
+//! ```
+//!
+//! ## Object names and labels
+//! With `EXT_debug_utils` also in the device context, objects of a Device can be given a name
+//! with [`Device::set_debug_name`](crate::vk::Device::set_debug_name) (or arbitrary data with
+//! [`Device::set_debug_tag`](crate::vk::Device::set_debug_tag)). The name is then included in
+//! [`DebugUtilsObject::name`] of the messages about the object, instead of only the raw handle.
+//!
+//! Queue operations and CommandBuffer commands can be grouped into labeled regions with
+//! [`Queue::begin_label`](crate::vk::Queue::begin_label) and
+//! [`_CommandBuffer::begin_label`](crate::vk::_CommandBuffer::begin_label). The label is ended
+//! when the returned region is dropped.
+
+use std::borrow::Cow;
+use std::ffi::{c_char, c_void, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
+use vk_safe_sys as vk;
+
+/// An object which a debug message is about
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsObjectNameInfoEXT.html>
+#[derive(Debug, Clone)]
+pub struct DebugUtilsObject<'a> {
+    /// type of the object (e.g. `vk_safe::raw::ObjectType::DEVICE_MEMORY`)
+    pub object_type: vk::ObjectType,
+    /// raw handle of the object
+    pub handle: u64,
+    /// name of the object, if it was named
+    pub name: Option<Cow<'a, str>>,
+}
+
+/// A debug message, decoded from VkDebugUtilsMessengerCallbackDataEXT
+///
+/// The strings are only valid during the callback, so the message must be copied (e.g.
+/// with `to_string`) to keep any part of it.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessengerCallbackDataEXT.html>
+#[derive(Debug, Clone)]
+pub struct DebugUtilsMessage<'a> {
+    /// severity of the message (e.g. `vk_safe::raw::DebugUtilsMessageSeverityFlagsEXT::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT`)
+    pub severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    /// types of the message
+    pub types: vk::DebugUtilsMessageTypeFlagsEXT,
+    /// identifies the particular message (e.g. the VUID for validation errors)
+    pub message_id_name: Option<&'a str>,
+    /// id number of the particular message
+    pub message_id_number: i32,
+    /// the message text
+    pub message: Cow<'a, str>,
+    /// names of the queue labels which are active, most recent first
+    pub queue_labels: Vec<Cow<'a, str>>,
+    /// names of the command buffer labels which are active, most recent first
+    pub cmd_buf_labels: Vec<Cow<'a, str>>,
+    /// objects related to the message
+    pub objects: Vec<DebugUtilsObject<'a>>,
+}
+
+impl<'a> DebugUtilsMessage<'a> {
+    /// The VUID of the valid usage rule which the message is about
+    ///
+    /// e.g. `"VUID-vkDestroyBuffer-buffer-00922"`. Validation errors from the validation layer
+    /// use the VUID as the message id name.
+    pub fn vuid(&self) -> Option<&'a str> {
+        self.message_id_name
+            .filter(|name| name.starts_with("VUID-"))
+    }
+
+    /// Decode the raw callback data
+    ///
+    /// # Safety
+    /// `data` must be valid VkDebugUtilsMessengerCallbackDataEXT as provided to the callback
+    unsafe fn from_raw(
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        types: vk::DebugUtilsMessageTypeFlagsEXT,
+        data: &'a vk::DebugUtilsMessengerCallbackDataEXT,
+    ) -> Self {
+        unsafe {
+            let labels = |labels: &'a [vk::DebugUtilsLabelEXT]| {
+                labels
+                    .iter()
+                    .map(|label| string(label.p_label_name).unwrap_or_default())
+                    .collect()
+            };
+            Self {
+                severity,
+                types,
+                message_id_name: id_name(data.p_message_id_name),
+                message_id_number: data.message_id_number,
+                message: string(data.p_message).unwrap_or_default(),
+                queue_labels: labels(slice(data.p_queue_labels, data.queue_label_count)),
+                cmd_buf_labels: labels(slice(data.p_cmd_buf_labels, data.cmd_buf_label_count)),
+                objects: slice(data.p_objects, data.object_count)
+                    .iter()
+                    .map(|object| DebugUtilsObject {
+                        object_type: object.object_type,
+                        handle: object.object_handle,
+                        name: string(object.p_object_name),
+                    })
+                    .collect(),
+            }
+        }
+    }
+}
+
+/// Make a string from a c string which may be null
+unsafe fn string<'a>(s: *const c_char) -> Option<Cow<'a, str>> {
+    if s.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(s) }.to_string_lossy())
+    }
+}
+
+/// Make a str from a c string which may be null, or None if it is not UTF-8
+unsafe fn id_name<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(s) }.to_str().ok()
+    }
+}
+
+/// Make a slice from a pointer and count, where the pointer may be null if the count is 0
+unsafe fn slice<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+    if count == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(ptr, count as usize) }
+    }
+}
+
+/// Receives debug messages
+///
+/// Implemented for closures which take a [`DebugUtilsMessage`], and for
+/// `Box<dyn DebugUtilsMessengerCallback>` and `Arc<dyn DebugUtilsMessengerCallback>`.
+///
+/// The callback is called by Vulkan, possibly from multiple threads at the same time. It must
+/// not call Vulkan commands.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/PFN_vkDebugUtilsMessengerCallbackEXT.html>
+pub trait DebugUtilsMessengerCallback: Send + Sync + 'static {
+    /// Called with each reported message
+    fn message(&self, message: &DebugUtilsMessage<'_>);
+}
+
+impl<F: Fn(&DebugUtilsMessage<'_>) + Send + Sync + 'static> DebugUtilsMessengerCallback for F {
+    fn message(&self, message: &DebugUtilsMessage<'_>) {
+        self(message)
+    }
+}
+
+impl DebugUtilsMessengerCallback for Box<dyn DebugUtilsMessengerCallback> {
+    fn message(&self, message: &DebugUtilsMessage<'_>) {
+        (**self).message(message)
+    }
+}
+
+impl DebugUtilsMessengerCallback for Arc<dyn DebugUtilsMessengerCallback> {
+    fn message(&self, message: &DebugUtilsMessage<'_>) {
+        (**self).message(message)
+    }
+}
+
+/// Called by Vulkan with the p_user_data set in DebugUtilsMessengerCreateInfoEXT::new, which
+/// points to the callback in an Arc
+pub(crate) unsafe extern "system" fn debug_utils_messenger_callback<
+    F: DebugUtilsMessengerCallback,
+>(
+    severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    types: vk::DebugUtilsMessageTypeFlagsEXT,
+    data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+    user_data: *mut c_void,
+) -> vk::Bool32 {
+    // a panic must not unwind into Vulkan; it is already reported by the panic hook
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let callback = unsafe { &*user_data.cast::<F>() };
+        let message = unsafe { DebugUtilsMessage::from_raw(severity, types, &*data) };
+        callback.message(&message)
+    }));
+    // VK_TRUE is reserved for the development of layers
+    vk::FALSE
+}
@@ -1,21 +1,147 @@
+use crate::error::VulkanError;
+
+use std::fmt;
+
+#[cfg(feature = "dlopen")]
+use std::ffi::OsStr;
+#[cfg(feature = "dlopen")]
+use std::sync::Arc;
+
 use vk_safe_sys as vk;
 
-fn entry_fn_loader<C: vk::VulkanCommand>() -> Option<C> {
-    // Safe because null is valid instance for global/entry commands, and vk::VulkanCommand ensures we provide a proper p_name
-    // https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetInstanceProcAddr.html
-    unsafe {
-        let fptr = vk::GetInstanceProcAddr(
-            vk::Instance {
-                handle: std::ptr::null(),
-            },
-            C::VK_NAME,
-        )?;
-        Some(C::new(fptr))
+#[cfg(feature = "dlopen")]
+pub use vk::library::{LibraryLoadError, VulkanLibrary};
+
+#[cfg(not(any(feature = "linked", feature = "dlopen")))]
+compile_error!("vk-safe needs the `linked` or `dlopen` feature to load Vulkan");
+
+/// Entry point to Vulkan
+///
+/// All Vulkan commands are loaded through `vkGetInstanceProcAddr`, which is provided by
+/// the Vulkan loader. An Entry is the source of `vkGetInstanceProcAddr`, and is used for
+/// the global commands, such as [`Entry::create_instance`].
+///
+/// With the `linked` feature (enabled by default), [`Entry::linked`] uses the Vulkan loader
+/// that the program is linked to when built, and the free functions (e.g. [`create_instance`])
+/// use this Entry.
+///
+/// With the `dlopen` feature, [`Entry::load`] and [`Entry::load_from`] open the Vulkan
+/// loader (or another Vulkan library) when the program runs. The library stays open for as
+/// long as the Entry, or any Instance created from it, exists.
+#[derive(Clone)]
+pub struct Entry {
+    get_instance_proc_addr: vk::GetInstanceProcAddrFn,
+    #[cfg(feature = "dlopen")]
+    library: Option<Arc<VulkanLibrary>>,
+}
+
+impl Entry {
+    /// Entry of the Vulkan loader that is linked when building
+    #[cfg(feature = "linked")]
+    pub fn linked() -> Self {
+        Self {
+            get_instance_proc_addr: vk::GetInstanceProcAddr,
+            #[cfg(feature = "dlopen")]
+            library: None,
+        }
+    }
+
+    /// Entry from a function loader which provides `vkGetInstanceProcAddr`
+    ///
+    /// `loader` is used to load `vkGetInstanceProcAddr`, in the same way as the symbol is looked
+    /// up in a Vulkan library, and all other commands are loaded through `vkGetInstanceProcAddr`.
+    /// This can be used to provide commands from an implementation which is not a shared
+    /// library, such as an in-process mock implementation for testing.
+    ///
+    /// Returns an error if `loader` does not provide `vkGetInstanceProcAddr`.
+    ///
+    /// # Safety
+    /// `loader` must provide a function which behaves as `vkGetInstanceProcAddr`, and all
+    /// commands that it provides must be conforming implementations of the respective Vulkan
+    /// commands.
+    pub unsafe fn from_loader(loader: impl vk::FunctionLoader) -> Result<Self, VulkanError> {
+        let command: vk::generated_vulkan::GetInstanceProcAddr = vk::LoadCommands::load(loader)?;
+        Ok(Self {
+            get_instance_proc_addr: command.get_fptr(),
+            #[cfg(feature = "dlopen")]
+            library: None,
+        })
+    }
+
+    /// Open the Vulkan loader installed on the system
+    ///
+    /// Returns an error if the Vulkan loader is not installed.
+    ///
+    /// ```no_run
+    /// # use vk_safe::vk;
+    /// let entry = vk::Entry::load().expect("Vulkan is not available");
+    /// let version = entry.enumerate_instance_version().unwrap();
+    /// ```
+    #[cfg(feature = "dlopen")]
+    pub fn load() -> Result<Self, LibraryLoadError> {
+        Ok(Self::from_library(VulkanLibrary::open_default()?))
+    }
+
+    /// Open a specific Vulkan library by name or path
+    ///
+    /// This can be used to load a specific Vulkan loader, or a driver (ICD) directly.
+    ///
+    /// # Safety
+    /// Opening a library runs its initialization routines. The library must be a Vulkan
+    /// loader or ICD which exports a conforming `vkGetInstanceProcAddr`.
+    #[cfg(feature = "dlopen")]
+    pub unsafe fn load_from(library: impl AsRef<OsStr>) -> Result<Self, LibraryLoadError> {
+        Ok(Self::from_library(unsafe { VulkanLibrary::open(library)? }))
+    }
+
+    #[cfg(feature = "dlopen")]
+    fn from_library(library: VulkanLibrary) -> Self {
+        Self {
+            get_instance_proc_addr: library.get_instance_proc_addr(),
+            library: Some(Arc::new(library)),
+        }
+    }
+
+    fn entry_fn_loader<C: vk::VulkanCommand>(&self) -> Option<C> {
+        // Safe because null is valid instance for global/entry commands, and vk::VulkanCommand ensures we provide a proper p_name
+        // https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetInstanceProcAddr.html
+        unsafe {
+            let fptr = (self.get_instance_proc_addr)(
+                vk::Instance {
+                    handle: std::ptr::null(),
+                },
+                C::VK_NAME,
+            )?;
+            Some(C::new(fptr))
+        }
+    }
+
+    /// Load `vkGetDeviceProcAddr` for a valid `instance`
+    pub(crate) fn get_device_proc_addr(
+        &self,
+        instance: vk::Instance,
+    ) -> Result<vk::GetDeviceProcAddrFn, VulkanError> {
+        // Safe because instance is a valid instance
+        let loader =
+            |command_name| unsafe { (self.get_instance_proc_addr)(instance, command_name) };
+        let command: vk::generated_vulkan::GetDeviceProcAddr = vk::LoadCommands::load(loader)?;
+        Ok(command.get_fptr())
+    }
+}
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("Entry");
+        f.field("get_instance_proc_addr", &self.get_instance_proc_addr);
+        #[cfg(feature = "dlopen")]
+        f.field("library", &self.library);
+        f.finish()
     }
 }
 
 // The following is imported by each command impl module
 mod command_impl_prelude {
+    pub use super::Entry;
     pub use vk_safe_sys as vk;
 }
 
@@ -1,4 +1,7 @@
-use crate::error::Error;
+use super::Entry;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
 use crate::handles::instance::{make_instance, Instance};
 use crate::scope::{Captures, Tag};
 use crate::structs::InstanceCreateInfo;
@@ -11,77 +14,112 @@ use vk::context::{Context, LoadCommands};
 use vk::has_command::DestroyInstance;
 use vk::Version;
 
-/// Create an instance
-///
-/// In order to create an Instance, you first define the Version and Extensions you will use with [`vk::instance_context!`]. You can then create an
-/// [`ApplicationInfo`](crate::structs::ApplicationInfo) structure, and subsequently create an [`InstanceCreateInfo`] structure for
-/// passing to this function.
-///
-/// See also
-/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateInstance.html>
-pub fn create_instance<'t, C: Context>(
-    create_info: &InstanceCreateInfo<C>,
-    tag: Tag<'t>,
-) -> Result<impl Instance<Commands = C::Commands> + Captures<Tag<'t>>, Error>
-where
-    C::Commands: DestroyInstance + Version + LoadCommands,
-{
-    check_vuids::check_vuids!(CreateInstance);
-
-    #[allow(unused_labels)]
-    'VUID_vkCreateInstance_ppEnabledExtensionNames_01388: {
-        check_vuids::version! {"1.3.268"}
-        check_vuids::description! {
-        "All required extensions for each extension in the VkInstanceCreateInfo::ppEnabledExtensionNames"
-        "list must also be present in that list"
+impl Entry {
+    /// Create an instance
+    ///
+    /// In order to create an Instance, you first define the Version and Extensions you will use with [`vk::instance_context!`]. You can then create an
+    /// [`ApplicationInfo`](crate::structs::ApplicationInfo) structure, and subsequently create an [`InstanceCreateInfo`] structure for
+    /// passing to this function.
+    ///
+    /// Provide `()` as the `allocator` to use the default host memory allocator of the Vulkan
+    /// implementation, or [`AllocationCallbacks`](crate::vk::AllocationCallbacks). The Instance
+    /// keeps the `allocator`, which is also used when the Instance is destroyed.
+    ///
+    /// See also
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateInstance.html>
+    pub fn create_instance<'t, C: Context, N, A: Allocator>(
+        &self,
+        create_info: &InstanceCreateInfo<C, N>,
+        allocator: A,
+        tag: Tag<'t>,
+    ) -> Result<impl Instance<Commands = C::Commands> + Captures<Tag<'t>>, VulkanError>
+    where
+        C::Commands: DestroyInstance + Version + LoadCommands,
+    {
+        check_vuids::check_vuids!(CreateInstance);
+
+        #[allow(unused_labels)]
+        'VUID_vkCreateInstance_ppEnabledExtensionNames_01388: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All required extensions for each extension in the VkInstanceCreateInfo::ppEnabledExtensionNames"
+            "list must also be present in that list"
+            }
+
+            // This is ensured by the context creation macros
         }
 
-        // This is ensured by the context creation macros
-    }
+        #[allow(unused_labels)]
+        'VUID_vkCreateInstance_pCreateInfo_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pCreateInfo must be a valid pointer to a valid VkInstanceCreateInfo structure"
+            }
 
-    #[allow(unused_labels)]
-    'VUID_vkCreateInstance_pCreateInfo_parameter: {
-        check_vuids::version! {"1.3.268"}
-        check_vuids::description! {
-        "pCreateInfo must be a valid pointer to a valid VkInstanceCreateInfo structure"
+            // rust reference; CreateInfo validated on its own
         }
 
-        // rust reference; CreateInfo validated on its own
-    }
+        #[allow(unused_labels)]
+        'VUID_vkCreateInstance_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
 
-    #[allow(unused_labels)]
-    'VUID_vkCreateInstance_pAllocator_parameter: {
-        check_vuids::version! {"1.3.268"}
-        check_vuids::description! {
-        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
-        "structure"
+            // ensured by Allocator
         }
 
-        // TODO: not currently supported, always set to NULL
-    }
+        #[allow(unused_labels)]
+        'VUID_vkCreateInstance_pInstance_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pInstance must be a valid pointer to a VkInstance handle"
+            }
 
-    #[allow(unused_labels)]
-    'VUID_vkCreateInstance_pInstance_parameter: {
-        check_vuids::version! {"1.3.268"}
-        check_vuids::description! {
-        "pInstance must be a valid pointer to a VkInstance handle"
+            // MaybeUninit
         }
 
-        // MaybeUninit
-    }
+        // TODO: return proper error for failing to load the command
+        let command = self
+            .entry_fn_loader::<vk::CreateInstance>()
+            .unwrap()
+            .get_fptr();
 
-    // TODO: return proper error for failing to load the command
-    let command = super::entry_fn_loader::<vk::CreateInstance>()
-        .unwrap()
-        .get_fptr();
-
-    let mut handle = MaybeUninit::uninit();
-    let instance;
-    unsafe {
-        let res = command(&create_info.inner, std::ptr::null(), handle.as_mut_ptr());
-        check_raw_err!(res);
-        instance = handle.assume_init();
+        let mut handle = MaybeUninit::uninit();
+        let instance;
+        unsafe {
+            let res = command(
+                &create_info.inner,
+                allocator.callbacks(),
+                handle.as_mut_ptr(),
+            );
+            check_raw_err!(res);
+            instance = handle.assume_init();
+        }
+        let loader =
+            |command_name| unsafe { (self.get_instance_proc_addr)(instance, command_name) };
+        let commands = C::Commands::load(loader)?;
+        Ok(make_instance(
+            instance,
+            commands,
+            self.clone(),
+            allocator,
+            create_info.keep_alive.clone(),
+            tag,
+        ))
     }
-    let loader = |command_name| unsafe { vk::GetInstanceProcAddr(instance, command_name) };
-    Ok(make_instance(instance, C::Commands::load(loader)?, tag))
+}
+
+/// Same as [`Entry::create_instance`] with [`Entry::linked`]
+#[cfg(feature = "linked")]
+pub fn create_instance<'t, C: Context, N, A: Allocator>(
+    create_info: &InstanceCreateInfo<C, N>,
+    allocator: A,
+    tag: Tag<'t>,
+) -> Result<impl Instance<Commands = C::Commands> + Captures<Tag<'t>>, VulkanError>
+where
+    C::Commands: DestroyInstance + Version + LoadCommands,
+{
+    Entry::linked().create_instance(create_info, allocator, tag)
 }
@@ -1,19 +1,30 @@
 use super::command_impl_prelude::*;
 
 use crate::enumerator::Enumerator;
-use crate::scope::Captures;
 use crate::vk_str::VkStr;
 
 use crate::structs::ExtensionProperties;
 
-/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html>
-pub fn enumerate_instance_extension_properties(
-    layer_name: Option<VkStr>,
-) -> impl Enumerator<ExtensionProperties<()>> + Captures<VkStr> {
-    let command = super::entry_fn_loader::<vk::EnumerateInstanceExtensionProperties>()
-        .unwrap()
-        .get_fptr();
-    make_enumerator!(command; (layer_name))
+impl Entry {
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html>
+    pub fn enumerate_instance_extension_properties<'s>(
+        &self,
+        layer_name: Option<VkStr<'s>>,
+    ) -> impl Enumerator<ExtensionProperties<()>> + use<'s> {
+        let command = self
+            .entry_fn_loader::<vk::EnumerateInstanceExtensionProperties>()
+            .unwrap()
+            .get_fptr();
+        make_enumerator!(command; (layer_name))
+    }
+}
+
+/// Same as [`Entry::enumerate_instance_extension_properties`] with [`Entry::linked`]
+#[cfg(feature = "linked")]
+pub fn enumerate_instance_extension_properties<'s>(
+    layer_name: Option<VkStr<'s>>,
+) -> impl Enumerator<ExtensionProperties<()>> + use<'s> {
+    Entry::linked().enumerate_instance_extension_properties(layer_name)
 }
 
 const _VUIDS: () = {
@@ -4,12 +4,23 @@ use crate::enumerator::Enumerator;
 
 use crate::structs::LayerProperties;
 
-/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html>
+impl Entry {
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html>
+    pub fn enumerate_instance_layer_properties(
+        &self,
+    ) -> impl Enumerator<LayerProperties<()>> + use<> {
+        let command = self
+            .entry_fn_loader::<vk::EnumerateInstanceLayerProperties>()
+            .unwrap()
+            .get_fptr();
+        make_enumerator!(command; ())
+    }
+}
+
+/// Same as [`Entry::enumerate_instance_layer_properties`] with [`Entry::linked`]
+#[cfg(feature = "linked")]
 pub fn enumerate_instance_layer_properties() -> impl Enumerator<LayerProperties<()>> {
-    let command = super::entry_fn_loader::<vk::EnumerateInstanceLayerProperties>()
-        .unwrap()
-        .get_fptr();
-    make_enumerator!(command; ())
+    Entry::linked().enumerate_instance_layer_properties()
 }
 
 // all verified by enumerator_code!()
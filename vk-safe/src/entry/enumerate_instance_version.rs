@@ -2,23 +2,32 @@ use super::command_impl_prelude::*;
 
 use std::mem::MaybeUninit;
 
+use crate::error::VulkanError;
 use crate::VkVersion;
 
-/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceVersion.html>
-pub fn enumerate_instance_version() -> Result<VkVersion, vk::Result> {
-    match super::entry_fn_loader::<vk::EnumerateInstanceVersion>() {
-        Some(command) => {
-            let mut version = MaybeUninit::uninit();
-            unsafe {
-                let res = command.get_fptr()(version.as_mut_ptr());
-                check_raw_err!(res);
-                Ok(VkVersion::from_raw(version.assume_init()))
+impl Entry {
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceVersion.html>
+    pub fn enumerate_instance_version(&self) -> Result<VkVersion, VulkanError> {
+        match self.entry_fn_loader::<vk::EnumerateInstanceVersion>() {
+            Some(command) => {
+                let mut version = MaybeUninit::uninit();
+                unsafe {
+                    let res = command.get_fptr()(version.as_mut_ptr());
+                    check_raw_err!(res);
+                    Ok(VkVersion::from_raw(version.assume_init()))
+                }
             }
+            None => Ok(VkVersion::new(1, 0, 0)),
         }
-        None => Ok(VkVersion::new(1, 0, 0)),
     }
 }
 
+/// Same as [`Entry::enumerate_instance_version`] with [`Entry::linked`]
+#[cfg(feature = "linked")]
+pub fn enumerate_instance_version() -> Result<VkVersion, VulkanError> {
+    Entry::linked().enumerate_instance_version()
+}
+
 const _VUIDS: () = {
     check_vuids::check_vuids!(EnumerateInstanceVersion);
 
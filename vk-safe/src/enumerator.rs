@@ -2,7 +2,7 @@ use std::convert::{TryFrom, TryInto};
 use std::marker::PhantomData;
 
 use crate::buffer::Buffer;
-use crate::error::Error;
+use crate::error::{VkResultExt, VulkanError};
 use crate::type_conversions::ConvertWrapper;
 
 /// Types which enumerate or get items Vulkan
@@ -23,9 +23,20 @@ pub trait Enumerator<I, T: EnumeratorTarget = DefaultTarget> {
     /// space with a [`Vec`] for the number of items, and call the
     /// command a second time with the allocated space to enumerate
     /// or get the items.
-    fn auto_get_enumerate(&self) -> Result<T::Target<Vec<I>>, Error> {
-        let vec = Vec::with_capacity(self.get_len()?);
-        self.get_enumerate(vec)
+    ///
+    /// The number of items can change between the two calls (e.g. if a
+    /// physical device is connected, or a layer is loaded). If the command
+    /// reports `VK_INCOMPLETE`, the number of items is queried again and the
+    /// items are enumerated again with a larger [`Vec`], until all items are
+    /// returned.
+    fn auto_get_enumerate(&self) -> Result<T::Target<Vec<I>>, VulkanError> {
+        let mut len = self.get_len()?;
+        loop {
+            match self.get_enumerate(Vec::with_capacity(len))? {
+                Enumerated::Complete(target) => return Ok(target),
+                Enumerated::Partial(_) => len = self.get_len()?.max(len + 1),
+            }
+        }
     }
 
     /// Use the Vulkan command to query the number of items
@@ -34,7 +45,7 @@ pub trait Enumerator<I, T: EnumeratorTarget = DefaultTarget> {
     /// with a null pointer for the return buffer, which is a signal
     /// to not enumerate or get any items, and only return the number
     /// of items that are available.
-    fn get_len(&self) -> Result<usize, Error>;
+    fn get_len(&self) -> Result<usize, VulkanError>;
 
     /// Enumerate or get the items using the provided buffer
     ///
@@ -46,7 +57,55 @@ pub trait Enumerator<I, T: EnumeratorTarget = DefaultTarget> {
     /// The user can choose to pass ownership of the buffer, or provide
     /// a mutable reference to a buffer to allow temporary usage of the
     /// buffer.
-    fn get_enumerate<B: Buffer<I>>(&self, buffer: B) -> Result<T::Target<B>, Error>;
+    ///
+    /// If the buffer is not large enough for all available items, the command
+    /// reports `VK_INCOMPLETE`, and the buffer is returned as
+    /// [`Enumerated::Partial`] with as many items as fit in the buffer.
+    fn get_enumerate<B: Buffer<I>>(
+        &self,
+        buffer: B,
+    ) -> Result<Enumerated<T::Target<B>>, VulkanError>;
+}
+
+/// Result of [`Enumerator::get_enumerate`]
+///
+/// Vulkan reports `VK_INCOMPLETE` when the provided buffer is too small
+/// for all available items. This is not an error, since the buffer still
+/// contains valid items, but the caller may need to try again with a larger
+/// buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Enumerated<T> {
+    /// All available items were written to the buffer
+    Complete(T),
+    /// Only some of the available items were written to the buffer (`VK_INCOMPLETE`)
+    Partial(T),
+}
+
+impl<T> Enumerated<T> {
+    /// Returns `true` if all available items were returned
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Self::Complete(_))
+    }
+
+    /// Returns `true` if only some of the available items were returned
+    pub fn is_partial(&self) -> bool {
+        matches!(self, Self::Partial(_))
+    }
+
+    /// Get the items only if all available items were returned
+    pub fn complete(self) -> Option<T> {
+        match self {
+            Self::Complete(target) => Some(target),
+            Self::Partial(_) => None,
+        }
+    }
+
+    /// Get the items, whether complete or partial
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Complete(target) | Self::Partial(target) => target,
+        }
+    }
 }
 
 /// Enumerator produces target type which is generic over a buffer type
@@ -132,11 +191,10 @@ where
     I: ConvertWrapper<R, C>,
     F: Fn(*mut L, *mut R) -> O,
     L: TryInto<usize> + TryFrom<usize>,
-    <L as TryFrom<usize>>::Error: std::error::Error + 'static,
-    <L as TryInto<usize>>::Error: std::error::Error + 'static,
-    O: crate::error::VkResultExt,
+    VulkanError: From<<L as TryFrom<usize>>::Error> + From<<L as TryInto<usize>>::Error>,
+    O: VkResultExt,
 {
-    fn get_len(&self) -> Result<usize, Error> {
+    fn get_len(&self) -> Result<usize, VulkanError> {
         let mut len = 0.try_into()?;
         let res;
         unsafe {
@@ -149,16 +207,22 @@ where
     fn get_enumerate<B: Buffer<I>>(
         &self,
         mut buffer: B,
-    ) -> Result<<T as EnumeratorTarget>::Target<B>, Error> {
+    ) -> Result<Enumerated<<T as EnumeratorTarget>::Target<B>>, VulkanError> {
         let mut len = buffer.capacity().try_into()?;
         let res;
         unsafe {
             res = self.call(&mut len, buffer.ptr_mut().to_c());
         }
+        let incomplete = res.is_incomplete();
         check_raw_err!(res);
+        // with VK_INCOMPLETE, len is the number of items that were actually written
         unsafe {
             buffer.set_len(len.try_into()?);
         }
-        Ok(T::make_target(buffer))
+        if incomplete {
+            Ok(Enumerated::Partial(T::make_target(buffer)))
+        } else {
+            Ok(Enumerated::Complete(T::make_target(buffer)))
+        }
     }
 }
@@ -1,29 +1,185 @@
+/*!
+Error type for vk-safe
+
+All vk-safe APIs that can fail return [`VulkanError`] (except for some simple constructors
+which return a more specific error). A [`VulkanError`] is either an [`ErrorCode`] returned by
+a Vulkan command, or a failure detected by vk-safe itself.
+
+Errors are classified as *recoverable* or *fatal* (see [`VulkanError::is_fatal`]). A recoverable
+error can be handled by the application (e.g. free some memory, choose a different format, or
+fix a parameter), and the objects involved can continue to be used. A fatal error indicates that
+the objects involved (or the entire device) are lost.
+
+```
+# use vk_safe::error::{ErrorCode, VulkanError};
+fn handle(error: VulkanError) {
+    match error {
+        VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_DEVICE_MEMORY) => { /* free something and try again */ }
+        e if e.is_fatal() => panic!("{e}"),
+        _ => {}
+    }
+}
+```
+*/
+
 use vk_safe_sys as vk;
 
 use std::fmt::{Debug, Display};
 
-/// custom error type for this crate
-/// may consider using the 'anyhow' crate at some point, but I want to keep dependencies at minimum for now
-///
-/// The main purpose of this is just to ensure everything reports errors consistently and provide one place to make changes
-#[derive(Debug)]
-pub struct Error(Box<dyn std::error::Error + 'static>);
+pub use vk::ErrorCode;
+
+/// Error type for vk-safe
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum VulkanError {
+    /// A Vulkan command returned an error code
+    Vulkan(ErrorCode),
+    /// A Vulkan command could not be loaded (e.g. when creating an Instance or Device)
+    CommandLoad(vk::CommandLoadError),
+    /// A queue priority was not between 0.0 and 1.0 (inclusive)
+    InvalidPriorityValue,
+    /// A length or count could not be converted between `usize` and the integer type used by Vulkan
+    LengthOverflow,
+    /// A buffer used for output has zero capacity
+    ZeroSizedBuffer,
+    /// Exactly one sample count bit must be provided
+    OnlyOneSampleCountAllowed,
+    /// The sample count is not supported for the respective image format properties
+    UnsupportedSampleCount,
+    /// A memory range is not aligned to `VkPhysicalDeviceLimits::nonCoherentAtomSize`
+    MisalignedMemoryRange,
+    /// A memory range is not contained within the memory object or mapping
+    MemoryRangeOutOfBounds,
+    /// A memory type is not allowed by `VkMemoryRequirements::memoryTypeBits`
+    IncompatibleMemoryType,
+    /// A memory offset is not aligned to `VkMemoryRequirements::alignment`
+    MisalignedMemoryOffset,
+    /// An image extent is zero, exceeds the supported limits, or does not match the image type
+    InvalidImageExtent,
+    /// The number of mip levels exceeds the supported limits or the complete mipmap chain
+    InvalidMipLevels,
+    /// The number of array layers exceeds the supported limits or does not match the image type
+    InvalidArrayLayers,
+    /// The image view type is not compatible with the image
+    IncompatibleImageViewType,
+    /// A required feature is not supported
+    UnsupportedFeature,
+    /// A value exceeds a limit of the PhysicalDevice
+    LimitExceeded,
+    /// The minimum LOD is greater than the maximum LOD
+    InvalidLodRange,
+    /// A range is empty or not contained within the buffer
+    BufferRangeOutOfBounds,
+    /// Two bindings of a DescriptorSetLayout have the same binding number
+    DuplicateDescriptorBinding,
+    /// A push constant range is empty, is not a multiple of 4 bytes, or shares a shader stage with another range
+    InvalidPushConstantRange,
+    /// A layer is not in the list of available layers
+    LayerNotPresent,
+    /// The enabled validation features include features which cannot be used together
+    IncompatibleValidationFeatures,
+    /// A debug object tag has no data
+    EmptyDebugTag,
+}
+
+impl VulkanError {
+    /// Check if the error is fatal
+    ///
+    /// Fatal errors are error codes from Vulkan which indicate that the objects involved (or the entire
+    /// device) are lost. Errors detected by vk-safe before calling Vulkan are never fatal.
+    pub const fn is_fatal(&self) -> bool {
+        match self {
+            Self::Vulkan(code) => code.is_fatal(),
+            Self::CommandLoad(_) => false,
+            Self::InvalidPriorityValue => false,
+            Self::LengthOverflow => false,
+            Self::ZeroSizedBuffer => false,
+            Self::OnlyOneSampleCountAllowed => false,
+            Self::UnsupportedSampleCount => false,
+            Self::MisalignedMemoryRange => false,
+            Self::MemoryRangeOutOfBounds => false,
+            Self::IncompatibleMemoryType => false,
+            Self::MisalignedMemoryOffset => false,
+            Self::InvalidImageExtent => false,
+            Self::InvalidMipLevels => false,
+            Self::InvalidArrayLayers => false,
+            Self::IncompatibleImageViewType => false,
+            Self::UnsupportedFeature => false,
+            Self::LimitExceeded => false,
+            Self::InvalidLodRange => false,
+            Self::BufferRangeOutOfBounds => false,
+            Self::DuplicateDescriptorBinding => false,
+            Self::InvalidPushConstantRange => false,
+            Self::LayerNotPresent => false,
+            Self::IncompatibleValidationFeatures => false,
+            Self::EmptyDebugTag => false,
+        }
+    }
 
-impl Display for Error {
+    /// Check if the error is recoverable
+    ///
+    /// Opposite of [`is_fatal`](VulkanError::is_fatal)
+    pub const fn is_recoverable(&self) -> bool {
+        !self.is_fatal()
+    }
+
+    /// Get the Vulkan [`ErrorCode`] if the error was returned by a Vulkan command
+    pub const fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::Vulkan(code) => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+impl Display for VulkanError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <Self as Debug>::fmt(self, f)
+        match self {
+            Self::Vulkan(code) => Display::fmt(code, f),
+            Self::CommandLoad(e) => Display::fmt(e, f),
+            _ => <Self as Debug>::fmt(self, f),
+        }
     }
 }
 
-impl<E: std::error::Error + 'static> From<E> for Error {
-    fn from(e: E) -> Self {
-        Self(e.into())
+impl std::error::Error for VulkanError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Vulkan(code) => Some(code),
+            Self::CommandLoad(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ErrorCode> for VulkanError {
+    fn from(code: ErrorCode) -> Self {
+        Self::Vulkan(code)
+    }
+}
+
+impl From<vk::CommandLoadError> for VulkanError {
+    fn from(e: vk::CommandLoadError) -> Self {
+        Self::CommandLoad(e)
+    }
+}
+
+#[cfg(VK_VERSION_1_0)]
+impl From<crate::structs::InvalidPriorityValue> for VulkanError {
+    fn from(_: crate::structs::InvalidPriorityValue) -> Self {
+        Self::InvalidPriorityValue
     }
 }
 
-impl From<Error> for Box<dyn std::error::Error> {
-    fn from(e: Error) -> Self {
-        e.0
+impl From<std::num::TryFromIntError> for VulkanError {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        Self::LengthOverflow
+    }
+}
+
+impl From<std::convert::Infallible> for VulkanError {
+    fn from(e: std::convert::Infallible) -> Self {
+        match e {}
     }
 }
 
@@ -32,7 +188,8 @@ impl From<Error> for Box<dyn std::error::Error> {
 pub(crate) trait VkResultExt {
     fn is_err(&self) -> bool;
     fn is_success(&self) -> bool;
-    fn get_error(self) -> vk::Result;
+    fn is_incomplete(&self) -> bool;
+    fn get_error(self) -> VulkanError;
 }
 
 impl VkResultExt for vk::Result {
@@ -42,8 +199,14 @@ impl VkResultExt for vk::Result {
     fn is_success(&self) -> bool {
         self.is_success()
     }
-    fn get_error(self) -> vk::Result {
-        self
+    fn is_incomplete(&self) -> bool {
+        *self == vk::Result::INCOMPLETE
+    }
+    fn get_error(self) -> VulkanError {
+        match self.error_code() {
+            Some(code) => VulkanError::Vulkan(code),
+            None => panic!("cannot get error: {self:?} is not an error value"),
+        }
     }
 }
 
@@ -56,7 +219,11 @@ impl VkResultExt for () {
         true
     }
 
-    fn get_error(self) -> vk::Result {
+    fn is_incomplete(&self) -> bool {
+        false
+    }
+
+    fn get_error(self) -> VulkanError {
         panic!("cannot get error: this should be an infallible case")
     }
 }
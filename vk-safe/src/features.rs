@@ -0,0 +1,126 @@
+//! Device features
+//!
+//! A Device is created with a set of enabled features, which must be supported by the
+//! PhysicalDevice (see [`get_physical_device_features`](crate::vk::PhysicalDevice::get_physical_device_features)).
+//! Many Vulkan APIs can only be used in certain ways if a feature is enabled.
+//!
+//! ## Type level Features
+//!
+//! The enabled features are represented by a type which implements [`Features`], so that APIs which
+//! depend on a feature can check at compile time that the feature is enabled. The features are enabled
+//! with [`DeviceCreateInfo::enable_features`](crate::vk::DeviceCreateInfo::enable_features), and the
+//! created [`Device`](crate::vk::Device) keeps the same type as [`Device::Features`](crate::vk::Device::Features).
+//!
+//! Use the [`features!`] macro to create a type that correctly implements [`Features`]. `()` represents
+//! no enabled features.
+//!
+//! Only the features of `VkPhysicalDeviceFeatures` (i.e. Vulkan 1.0 features) are represented. Features
+//! which are enabled with structures in the pNext chain of `VkDeviceCreateInfo` are not tracked.
+
+use vk_safe_sys as vk;
+
+/// A set of `VkPhysicalDeviceFeatures`
+///
+/// Use the [`features!`](crate::features!()) macro to create a type which implements this trait.
+/// It is not recommended to manually implement this trait.
+pub unsafe trait Features {
+    /// The features which are in the set, as `VK_TRUE` members
+    const ENABLED: &'static vk::PhysicalDeviceFeatures;
+}
+
+unsafe impl Features for () {
+    const ENABLED: &'static vk::PhysicalDeviceFeatures = &NONE;
+}
+
+/// No features (i.e. all members are `VK_FALSE`)
+#[doc(hidden)]
+// SAFETY: VkPhysicalDeviceFeatures only has VkBool32 members, and VK_FALSE is 0
+pub const NONE: vk::PhysicalDeviceFeatures = unsafe { std::mem::zeroed() };
+
+/// Number of members of VkPhysicalDeviceFeatures
+const COUNT: usize =
+    std::mem::size_of::<vk::PhysicalDeviceFeatures>() / std::mem::size_of::<vk::Bool32>();
+
+const fn as_array(features: &vk::PhysicalDeviceFeatures) -> &[vk::Bool32; COUNT] {
+    // SAFETY: VkPhysicalDeviceFeatures only has VkBool32 members
+    unsafe { &*(features as *const vk::PhysicalDeviceFeatures).cast() }
+}
+
+/// Check if every feature in `required` is also in `features`
+pub(crate) const fn includes(
+    features: &vk::PhysicalDeviceFeatures,
+    required: &vk::PhysicalDeviceFeatures,
+) -> bool {
+    let features = as_array(features);
+    let required = as_array(required);
+    let mut i = 0;
+    while i < COUNT {
+        if required[i] != vk::FALSE && features[i] == vk::FALSE {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Check if no feature is in `features`
+pub(crate) const fn is_empty(features: &vk::PhysicalDeviceFeatures) -> bool {
+    includes(&NONE, features)
+}
+
+/// Check that features `R` are enabled
+///
+/// Use as `let _ = <F as HasFeatures<R>>::VALID;`, which fails to compile if any feature
+/// in `R` is not in `F`.
+pub(crate) trait HasFeatures<R> {
+    const VALID: ();
+}
+
+impl<F: Features, R: Features> HasFeatures<R> for F {
+    const VALID: () = {
+        if !includes(F::ENABLED, R::ENABLED) {
+            panic!("a required feature is not enabled on the Device")
+        }
+    };
+}
+
+/// Create a type that represents a set of features
+///
+/// This will create a type with your provided name, and properly implement [`Features`]. The
+/// features are the (snake_case) member names of `VkPhysicalDeviceFeatures`, and at least one
+/// feature must be provided.
+///
+/// ## Example
+/// ```
+/// use vk_safe as vk;
+///
+/// vk::features!(MyFeatures: sampler_anisotropy + shader_int64);
+///
+/// // also make a value of an unnamed type
+/// let features = vk::features!(wide_lines);
+/// ```
+#[macro_export]
+macro_rules! features {
+    ( $(#[$($attributes:tt)*])* $vis:vis $name:ident : $first:ident $( + $feature:ident )* ) => {
+        $(#[$($attributes)*])*
+        #[derive(Copy, Clone)]
+        $vis struct $name;
+
+        unsafe impl $crate::features::Features for $name {
+            const ENABLED: &'static $crate::raw::PhysicalDeviceFeatures =
+                &$crate::raw::PhysicalDeviceFeatures {
+                    $first: $crate::raw::TRUE,
+                    $( $feature: $crate::raw::TRUE, )*
+                    ..$crate::features::NONE
+                };
+        }
+    };
+
+    ( $first:ident $( + $feature:ident )* ) => {
+        {
+            $crate::features!(PrivateFeaturesImpl: $first $( + $feature )*);
+            PrivateFeaturesImpl
+        }
+    };
+}
+pub use features;
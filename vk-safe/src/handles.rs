@@ -10,6 +10,18 @@ device_memory;
 command_pool;
 command_buffer;
 shader_module;
+buffer;
+image;
+image_view;
+sampler;
+fence;
+semaphore;
+descriptor_set_layout;
+pipeline_layout;
+pipeline;
+
+#[cfg(VK_EXT_debug_utils)]
+debug_utils_messenger;
 );
 
 /// A handle which can dispatch Vulkan Commands
@@ -30,6 +42,14 @@ pub trait Handle: Debug + Sized {
     fn raw_handle(&self) -> Self::RawHandle;
 }
 
+/// A handle of an object which was created, allocated, or retrieved from the Device `D`
+///
+/// e.g. for naming objects with [`set_debug_name`](crate::vk::Device::set_debug_name)
+///
+/// This is mostly an implementation detail and you are not
+/// intended to implement this yourself.
+pub trait DeviceObject<D>: Handle {}
+
 /// Handles that are safe to use on different threads
 ///
 /// Most handles in Vulkan are thread safe. The primary
@@ -0,0 +1,263 @@
+use super::device::Device;
+use super::device_memory::DeviceMemory;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::BufferUsageFlags;
+use vk::has_command::DestroyBuffer;
+
+/// Buffer handle trait
+///
+/// Represents a *specific* Buffer which has not yet been bound to memory.
+/// Use [`bind_buffer_memory`](Device::bind_buffer_memory) to bind the Buffer
+/// to [`DeviceMemory`], which results in a [`BoundBuffer`].
+pub trait Buffer:
+    Handle<RawHandle = vk::Buffer> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this Buffer belongs
+    type Device: Device;
+    /// The usages that this Buffer was created with
+    type Usage: BufferUsageFlags;
+
+    /// Size of the Buffer in bytes
+    fn size(&self) -> vk::DeviceSize;
+}
+
+/// [`Buffer`] implementor
+struct _Buffer<'a, D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> {
+    handle: vk::Buffer,
+    device: &'a D,
+    allocator: A,
+    size: vk::DeviceSize,
+    usage: PhantomData<U>,
+    tag: PhantomData<T>,
+}
+
+pub(crate) fn make_buffer<
+    'a,
+    D: Device<Commands: DestroyBuffer>,
+    U: BufferUsageFlags,
+    T,
+    A: Allocator,
+>(
+    handle: vk::Buffer,
+    device: &'a D,
+    allocator: A,
+    size: vk::DeviceSize,
+    _tag: T,
+) -> impl Buffer<Device = D, Usage = U> + use<'a, D, U, T, A> {
+    _Buffer {
+        handle,
+        device,
+        allocator,
+        size,
+        usage: PhantomData,
+        tag: PhantomData::<T>,
+    }
+}
+
+impl<D: Device<Commands: DestroyBuffer>, U: BufferUsageFlags, T, A: Allocator> Buffer
+    for _Buffer<'_, D, U, T, A>
+{
+    type Device = D;
+    type Usage = U;
+
+    fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+}
+
+unsafe impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> Send
+    for _Buffer<'_, D, U, T, A>
+{
+}
+unsafe impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> Sync
+    for _Buffer<'_, D, U, T, A>
+{
+}
+impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> ThreadSafeHandle
+    for _Buffer<'_, D, U, T, A>
+{
+}
+
+impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> Handle for _Buffer<'_, D, U, T, A> {
+    type RawHandle = vk::Buffer;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> DeviceObject<D>
+    for _Buffer<'_, D, U, T, A>
+{
+}
+
+impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> fmt::Debug
+    for _Buffer<'_, D, U, T, A>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buffer")
+            .field("handle", &self.handle)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroyBuffer>, U, T, A: Allocator> Drop for _Buffer<'_, D, U, T, A> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyBuffer);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_buffer_00922: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All submitted commands that refer to buffer, either directly or via a VkBufferView,"
+            "must have completed execution"
+            }
+
+            // CommandBuffers borrow the (bound) Buffer for as long as they exist, and queue_submit
+            // does not return until the submitted CommandBuffers complete execution
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_buffer_00923: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when buffer was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the Buffer is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_buffer_00924: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when buffer was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the Buffer is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_buffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If buffer is not VK_NULL_HANDLE, buffer must be a valid VkBuffer handle"
+            }
+
+            // ensured by Buffer creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyBuffer_buffer_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If buffer is a valid handle, it must have been created, allocated, or retrieved from"
+            "device"
+            }
+
+            // ensured by Buffer creation
+        }
+
+        unsafe {
+            self.device.commands().DestroyBuffer().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
+
+/// A [`Buffer`] which is bound to [`DeviceMemory`]
+///
+/// Created with [`bind_buffer_memory`](Device::bind_buffer_memory). The
+/// memory is borrowed for as long as the Buffer is bound to it.
+pub struct BoundBuffer<'m, B, M> {
+    buffer: B,
+    memory: &'m M,
+    offset: vk::DeviceSize,
+}
+
+impl<'m, B: Buffer, M: DeviceMemory<Device = B::Device>> BoundBuffer<'m, B, M> {
+    pub(crate) fn new(buffer: B, memory: &'m M, offset: vk::DeviceSize) -> Self {
+        Self {
+            buffer,
+            memory,
+            offset,
+        }
+    }
+
+    /// The Buffer which is bound
+    pub fn buffer(&self) -> &B {
+        &self.buffer
+    }
+
+    /// The DeviceMemory the Buffer is bound to
+    pub fn memory(&self) -> &'m M {
+        self.memory
+    }
+
+    /// Offset in bytes into the DeviceMemory where the Buffer is bound
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    /// Size of the Buffer in bytes
+    pub fn size(&self) -> vk::DeviceSize {
+        self.buffer.size()
+    }
+}
+
+impl<B: Buffer, M: DeviceMemory> ThreadSafeHandle for BoundBuffer<'_, B, M> {}
+
+impl<B: Buffer, M: DeviceMemory> Handle for BoundBuffer<'_, B, M> {
+    type RawHandle = vk::Buffer;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.buffer.raw_handle()
+    }
+}
+
+impl<B: Buffer, M: DeviceMemory> DeviceObject<B::Device> for BoundBuffer<'_, B, M> {}
+
+impl<B: Buffer, M: DeviceMemory> fmt::Debug for BoundBuffer<'_, B, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundBuffer")
+            .field("buffer", &self.buffer)
+            .field("memory", self.memory)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
@@ -1,7 +1,9 @@
-use super::{DispatchableHandle, Handle};
+use super::command_pool::CommandPool;
+use super::{DeviceObject, DispatchableHandle, Handle};
 
 use std::fmt;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::buffer::Buffer;
 use crate::type_conversions::ConvertWrapper;
@@ -11,9 +13,76 @@ use vk_safe_sys as vk;
 
 use vk::enum_traits::CommandBufferLevel;
 
-pub trait CommandBuffer: DispatchableHandle<RawHandle = vk::CommandBuffer> + Send {
+pub_use_modules!(
+#[cfg(VK_VERSION_1_0)] {
+    begin_command_buffer;
+    end_command_buffer;
+    cmd_execute_commands;
+    cmd_pipeline_barrier;
+    cmd_bind_pipeline;
+    cmd_dispatch;
+};
+#[cfg(VK_EXT_debug_utils)] {
+    cmd_begin_debug_utils_label;
+};
+);
+
+/// Type level representation of the CommandBuffer lifecycle
+///
+/// A CommandBuffer starts in the [`Initial`](crate::vk::command_buffer_state::Initial) state
+/// when allocated. [`begin_command_buffer`](crate::vk::_CommandBuffer::begin_command_buffer)
+/// moves it to the [`Recording`](crate::vk::command_buffer_state::Recording) state, where
+/// commands can be recorded, and [`end_command_buffer`](crate::vk::_CommandBuffer::end_command_buffer)
+/// moves it to the [`Executable`](crate::vk::command_buffer_state::Executable) state.
+///
+/// The usage flags provided when beginning the CommandBuffer are carried in the
+/// Recording and Executable states.
+///
+/// While submitted work is pending, the CommandBuffer is held by a
+/// [`Submission`](crate::vk::Submission). CommandBuffers submitted with
+/// [`SubmitInfo::new_one_time`](crate::vk::SubmitInfo::new_one_time) are returned in the
+/// [`Invalid`](crate::vk::command_buffer_state::Invalid) state.
+///
+/// [`CommandBuffers::iter`] provides CommandBuffers in the
+/// [`Borrowed`](crate::vk::command_buffer_state::Borrowed) state, which only allows
+/// inspecting the CommandBuffers while they are owned elsewhere.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/html/vkspec.html#commandbuffers-lifecycle>
+pub mod command_buffer_state {
+    use std::marker::PhantomData;
+
+    /// CommandBuffer has been allocated (or reset), and can begin recording
+    #[derive(Debug)]
+    pub struct Initial;
+
+    /// CommandBuffer is recording, with the usage flags `U`
+    #[derive(Debug)]
+    pub struct Recording<U>(PhantomData<U>);
+
+    /// CommandBuffer has finished recording, with the usage flags `U`
+    #[derive(Debug)]
+    pub struct Executable<U>(PhantomData<U>);
+
+    /// CommandBuffer was begun with `ONE_TIME_SUBMIT_BIT` and has been submitted
+    #[derive(Debug)]
+    pub struct Invalid;
+
+    /// CommandBuffer is borrowed from [`CommandBuffers`](super::CommandBuffers), and is in an
+    /// unknown state
+    #[derive(Debug)]
+    pub struct Borrowed;
+}
+
+use command_buffer_state::{Borrowed, Initial};
+
+pub trait CommandBuffer:
+    DispatchableHandle<RawHandle = vk::CommandBuffer> + DeviceObject<Self::Device>
+{
     type Device;
+    type Pool: CommandPool;
     type Level: CommandBufferLevel;
+    /// The current state of the CommandBuffer (see [`command_buffer_state`])
+    type State;
 }
 
 /// [`CommandBuffer`] implementor
@@ -23,17 +92,60 @@ pub trait CommandBuffer: DispatchableHandle<RawHandle = vk::CommandBuffer> + Sen
 /// RPITIT. After some kind of precise capturing is possible,
 /// this type will be made private and <code>impl [CommandBuffer]</code>
 /// will be returned.
-pub struct _CommandBuffer<'a, D, L> {
+pub struct _CommandBuffer<'a, D, P, L, S> {
     handle: vk::CommandBuffer,
     device: &'a D,
+    pool: PhantomData<&'a P>,
     level: PhantomData<L>,
+    state: PhantomData<S>,
+    recording: RecordingId,
 }
 
-unsafe impl<'a, D, L> ConvertWrapper<vk::CommandBuffer> for _CommandBuffer<'a, D, L> {}
+impl<'a, D, P, L, S> _CommandBuffer<'a, D, P, L, S> {
+    /// Move to a new state
+    ///
+    /// The caller must ensure that the CommandBuffer is actually in the new state
+    pub(crate) unsafe fn transition<N>(self) -> _CommandBuffer<'a, D, P, L, N> {
+        _CommandBuffer {
+            handle: self.handle,
+            device: self.device,
+            pool: PhantomData,
+            level: PhantomData,
+            state: PhantomData,
+            recording: self.recording,
+        }
+    }
+
+    /// The recording which commands are currently (or were last) recorded into
+    pub(crate) fn recording(&self) -> RecordingId {
+        self.recording
+    }
+}
+
+/// Identifies one recording of a CommandBuffer, from begin to end
+///
+/// Each call to [`begin_command_buffer`](crate::vk::_CommandBuffer::begin_command_buffer)
+/// starts a new recording with an id which is different from all other recordings. Used to
+/// detect when state which is tracked while recording (e.g. the layout of a
+/// [`TrackedImage`](crate::vk::TrackedImage)) is used in a different recording, where the
+/// order of execution is not known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RecordingId(u64);
 
-unsafe impl<D: Sync, L> Send for _CommandBuffer<'_, D, L> {}
+impl RecordingId {
+    /// Not associated with any recording
+    pub(crate) const NONE: Self = Self(0);
 
-impl<'a, D, L> fmt::Debug for _CommandBuffer<'a, D, L> {
+    /// Start a new recording
+    pub(crate) fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+unsafe impl<'a, D, P, L, S> ConvertWrapper<vk::CommandBuffer> for _CommandBuffer<'a, D, P, L, S> {}
+
+impl<'a, D, P, L, S> fmt::Debug for _CommandBuffer<'a, D, P, L, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("CommandBuffer")
             .field("handle", &self.handle)
@@ -43,7 +155,7 @@ impl<'a, D, L> fmt::Debug for _CommandBuffer<'a, D, L> {
     }
 }
 
-impl<'a, D, L> Handle for _CommandBuffer<'a, D, L> {
+impl<'a, D, P, L, S> Handle for _CommandBuffer<'a, D, P, L, S> {
     type RawHandle = vk::CommandBuffer;
 
     fn raw_handle(&self) -> Self::RawHandle {
@@ -51,7 +163,9 @@ impl<'a, D, L> Handle for _CommandBuffer<'a, D, L> {
     }
 }
 
-impl<'a, D: Device, L> DispatchableHandle for _CommandBuffer<'a, D, L> {
+impl<'a, D, P, L, S> DeviceObject<D> for _CommandBuffer<'a, D, P, L, S> {}
+
+impl<'a, D: Device, P, L, S> DispatchableHandle for _CommandBuffer<'a, D, P, L, S> {
     type Commands = D::Commands;
 
     fn commands(&self) -> &Self::Commands {
@@ -59,16 +173,23 @@ impl<'a, D: Device, L> DispatchableHandle for _CommandBuffer<'a, D, L> {
     }
 }
 
-impl<'a, D: Device, L: CommandBufferLevel> CommandBuffer for _CommandBuffer<'a, D, L> {
+impl<'a, D: Device, P: CommandPool, L: CommandBufferLevel, S> CommandBuffer
+    for _CommandBuffer<'a, D, P, L, S>
+{
     type Device = D;
+    type Pool = P;
     type Level = L;
+    type State = S;
 }
 
-pub trait CommandBuffers: IntoIterator<Item = Self::CommandBuffer> + fmt::Debug + Send {
-    type CommandBuffer: CommandBuffer;
+pub trait CommandBuffers: IntoIterator<Item = Self::CommandBuffer> + fmt::Debug {
+    type CommandBuffer: CommandBuffer<State = Initial>;
     /// Provide an iterator over CommandBuffers without consuming
     /// self.
-    fn iter(&self) -> impl Iterator<Item = Self::CommandBuffer>;
+    ///
+    /// The CommandBuffers are provided in the [`Borrowed`] state, since the
+    /// CommandBuffers which are owned by self can change state.
+    fn iter(&self) -> impl Iterator<Item = impl CommandBuffer<State = Borrowed>>;
 }
 
 /// [`CommandBuffers`] implementor
@@ -78,38 +199,41 @@ pub trait CommandBuffers: IntoIterator<Item = Self::CommandBuffer> + fmt::Debug
 /// RPITIT. After some kind of precise capturing is possible,
 /// this type will be made private and <code>impl [CommandBuffers]</code>
 /// will be returned.
-pub struct _CommandBuffers<'a, D, L, B> {
+pub struct _CommandBuffers<'a, D, P, L, B> {
     device: &'a D,
     buffer: B,
+    pool: PhantomData<&'a P>,
     level: PhantomData<L>,
 }
 
-pub(crate) fn make_command_buffers<'a, D, L, B>(
+pub(crate) fn make_command_buffers<'a, D, P, L, B>(
     device: &'a D,
     buffer: B,
-) -> _CommandBuffers<'a, D, L, B> {
+) -> _CommandBuffers<'a, D, P, L, B> {
     _CommandBuffers {
         device,
         buffer,
+        pool: PhantomData,
         level: PhantomData,
     }
 }
 
-impl<'a, D: Sync + Device, L: Send + CommandBufferLevel, B: Send + Buffer<vk::CommandBuffer>>
-    CommandBuffers for _CommandBuffers<'a, D, L, B>
+impl<'a, D: Device, P: CommandPool, L: CommandBufferLevel, B: Buffer<vk::CommandBuffer>>
+    CommandBuffers for _CommandBuffers<'a, D, P, L, B>
 {
-    type CommandBuffer = _CommandBuffer<'a, D, L>;
+    type CommandBuffer = _CommandBuffer<'a, D, P, L, Initial>;
 
-    fn iter(&self) -> impl Iterator<Item = Self::CommandBuffer> {
-        _CommandBufferIterRef {
+    fn iter(&self) -> impl Iterator<Item = impl CommandBuffer<State = Borrowed>> {
+        _CommandBufferIterRef::<D, P, L> {
             device: self.device,
             iter: self.buffer.get_slice().iter().copied(),
+            pool: PhantomData,
             level: PhantomData,
         }
     }
 }
 
-impl<D, L, B: Buffer<vk::CommandBuffer>> fmt::Debug for _CommandBuffers<'_, D, L, B> {
+impl<D, P, L, B: Buffer<vk::CommandBuffer>> fmt::Debug for _CommandBuffers<'_, D, P, L, B> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "CommandBuffers")?;
         f.debug_list()
@@ -118,10 +242,10 @@ impl<D, L, B: Buffer<vk::CommandBuffer>> fmt::Debug for _CommandBuffers<'_, D, L
     }
 }
 
-impl<'a, D, L, B: Buffer<vk::CommandBuffer>> IntoIterator for _CommandBuffers<'a, D, L, B> {
-    type Item = _CommandBuffer<'a, D, L>;
+impl<'a, D, P, L, B: Buffer<vk::CommandBuffer>> IntoIterator for _CommandBuffers<'a, D, P, L, B> {
+    type Item = _CommandBuffer<'a, D, P, L, Initial>;
 
-    type IntoIter = _CommandBufferIter<'a, D, L, B>;
+    type IntoIter = _CommandBufferIter<'a, D, P, L, B>;
 
     fn into_iter(self) -> Self::IntoIter {
         _CommandBufferIter {
@@ -136,13 +260,13 @@ impl<'a, D, L, B: Buffer<vk::CommandBuffer>> IntoIterator for _CommandBuffers<'a
 /// ⚠️ This is **NOT** intended to be public. This is only
 /// exposed as a stopgap solution to over capturing in
 /// RPITIT.
-pub struct _CommandBufferIter<'a, D, L, B> {
-    command_buffers: _CommandBuffers<'a, D, L, B>,
+pub struct _CommandBufferIter<'a, D, P, L, B> {
+    command_buffers: _CommandBuffers<'a, D, P, L, B>,
     next: usize,
 }
 
-impl<'a, D, L, B: Buffer<vk::CommandBuffer>> Iterator for _CommandBufferIter<'a, D, L, B> {
-    type Item = _CommandBuffer<'a, D, L>;
+impl<'a, D, P, L, B: Buffer<vk::CommandBuffer>> Iterator for _CommandBufferIter<'a, D, P, L, B> {
+    type Item = _CommandBuffer<'a, D, P, L, Initial>;
     fn next(&mut self) -> Option<Self::Item> {
         let array = self.command_buffers.buffer.get_slice();
         if self.next >= array.len() {
@@ -153,26 +277,33 @@ impl<'a, D, L, B: Buffer<vk::CommandBuffer>> Iterator for _CommandBufferIter<'a,
             Some(_CommandBuffer {
                 handle: *handle,
                 device: self.command_buffers.device,
+                pool: PhantomData,
                 level: PhantomData,
+                state: PhantomData,
+                recording: RecordingId::NONE,
             })
         }
     }
 }
 
-struct _CommandBufferIterRef<'a, 's, D, L> {
-    device: &'a D,
+struct _CommandBufferIterRef<'s, D, P, L> {
+    device: &'s D,
     iter: std::iter::Copied<std::slice::Iter<'s, vk::CommandBuffer>>,
+    pool: PhantomData<&'s P>,
     level: PhantomData<L>,
 }
 
-impl<'a, D, L> Iterator for _CommandBufferIterRef<'a, '_, D, L> {
-    type Item = _CommandBuffer<'a, D, L>;
+impl<'s, D, P, L> Iterator for _CommandBufferIterRef<'s, D, P, L> {
+    type Item = _CommandBuffer<'s, D, P, L, Borrowed>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|handle| _CommandBuffer {
             handle,
             device: self.device,
+            pool: PhantomData,
             level: PhantomData,
+            state: PhantomData,
+            recording: RecordingId::NONE,
         })
     }
 }
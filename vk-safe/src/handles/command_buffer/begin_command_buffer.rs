@@ -0,0 +1,202 @@
+use super::_CommandBuffer;
+use super::command_buffer_state::{Executable, Initial, Invalid, Recording};
+use super::RecordingId;
+
+use crate::error::VulkanError;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::{DispatchableHandle, Handle};
+use crate::structs::CommandBufferBeginInfo;
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::CommandBufferLevel;
+use vk::flag_traits::CommandBufferUsageFlags;
+use vk::has_command::BeginCommandBuffer;
+
+impl<'a, D: Device<Commands: BeginCommandBuffer>, P: CommandPool, L: CommandBufferLevel>
+    _CommandBuffer<'a, D, P, L, Initial>
+{
+    /**
+    Begin recording a CommandBuffer
+
+    The CommandBuffer moves to the [`Recording`] state, with the usage flags
+    from the provided [`CommandBufferBeginInfo`].
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: vk::Device<Commands: vk::device::VERSION_1_0>, P: vk::CommandPool>
+    #   (command_buffer: vk::_CommandBuffer<D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Initial>) {
+    let begin_info = vk::CommandBufferBeginInfo::new(vk::flags!(CommandBufferUsageFlags + ONE_TIME_SUBMIT_BIT));
+    let recording = command_buffer.begin_command_buffer(&begin_info).unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html>
+    */
+    pub fn begin_command_buffer<U: CommandBufferUsageFlags>(
+        self,
+        info: &CommandBufferBeginInfo<'_, L, U>,
+    ) -> Result<_CommandBuffer<'a, D, P, L, Recording<U>>, VulkanError> {
+        begin_command_buffer(self, info)
+    }
+}
+
+impl<
+        'a,
+        D: Device<Commands: BeginCommandBuffer>,
+        P: CommandPool,
+        L: CommandBufferLevel,
+        U: CommandBufferUsageFlags,
+    > _CommandBuffer<'a, D, P, L, Executable<U>>
+{
+    /**
+    Begin recording a CommandBuffer again, implicitly resetting it
+
+    Only available if the CommandPool was created with `RESET_COMMAND_BUFFER_BIT`.
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html>
+    */
+    pub fn begin_command_buffer<N: CommandBufferUsageFlags>(
+        self,
+        info: &CommandBufferBeginInfo<'_, L, N>,
+    ) -> Result<_CommandBuffer<'a, D, P, L, Recording<N>>, VulkanError> {
+        const {
+            assert!(
+                P::Flags::INCLUDES.contains(vk::CommandPoolCreateFlags::RESET_COMMAND_BUFFER_BIT),
+                "CommandPool must be created with RESET_COMMAND_BUFFER_BIT to begin a CommandBuffer which is not in the initial state"
+            );
+        }
+        begin_command_buffer(self, info)
+    }
+}
+
+impl<'a, D: Device<Commands: BeginCommandBuffer>, P: CommandPool, L: CommandBufferLevel>
+    _CommandBuffer<'a, D, P, L, Invalid>
+{
+    /**
+    Begin recording an invalid CommandBuffer again, implicitly resetting it
+
+    Only available if the CommandPool was created with `RESET_COMMAND_BUFFER_BIT`.
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html>
+    */
+    pub fn begin_command_buffer<N: CommandBufferUsageFlags>(
+        self,
+        info: &CommandBufferBeginInfo<'_, L, N>,
+    ) -> Result<_CommandBuffer<'a, D, P, L, Recording<N>>, VulkanError> {
+        const {
+            assert!(
+                P::Flags::INCLUDES.contains(vk::CommandPoolCreateFlags::RESET_COMMAND_BUFFER_BIT),
+                "CommandPool must be created with RESET_COMMAND_BUFFER_BIT to begin a CommandBuffer which is not in the initial state"
+            );
+        }
+        begin_command_buffer(self, info)
+    }
+}
+
+fn begin_command_buffer<
+    'a,
+    D: Device<Commands: BeginCommandBuffer>,
+    P: CommandPool,
+    L: CommandBufferLevel,
+    S,
+    U: CommandBufferUsageFlags,
+>(
+    command_buffer: _CommandBuffer<'a, D, P, L, S>,
+    info: &CommandBufferBeginInfo<'_, L, U>,
+) -> Result<_CommandBuffer<'a, D, P, L, Recording<U>>, VulkanError> {
+    check_vuids::check_vuids!(BeginCommandBuffer);
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_commandBuffer_00049: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "commandBuffer must not be in the recording or pending state"
+        }
+
+        // only available in the Initial, Executable, or Invalid state
+        // pending CommandBuffers are held by a Submission until completed (or leaked with it)
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_commandBuffer_00050: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If commandBuffer was allocated from a VkCommandPool which did not have the VK_COMMAND_POOL_CREATE_RESET_COMMAND_BUFFER_BIT"
+        "flag set, commandBuffer must be in the initial state"
+        }
+
+        // the Executable and Invalid states require RESET_COMMAND_BUFFER_BIT
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_commandBuffer_00051: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If commandBuffer is a secondary command buffer, the pInheritanceInfo member of pBeginInfo"
+        "must be a valid VkCommandBufferInheritanceInfo structure"
+        }
+
+        // ensured by CommandBufferBeginInfo::new_secondary, which is the only way to
+        // make CommandBufferBeginInfo for the SECONDARY level
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_commandBuffer_00052: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If commandBuffer is a secondary command buffer and either the occlusionQueryEnable"
+        "member of the pInheritanceInfo member of pBeginInfo is VK_FALSE, or the inheritedQueries"
+        "feature is not enabled, the queryFlags member of the pInheritanceInfo member pBeginInfo"
+        "must not contain VK_QUERY_CONTROL_PRECISE_BIT"
+        }
+
+        // CommandBufferInheritanceInfo always has empty queryFlags
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_commandBuffer_02840: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If commandBuffer is a primary command buffer, then pBeginInfo-&gt;flags must not set"
+        "both the VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT and the VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT"
+        "flags"
+        }
+
+        // checked in CommandBufferBeginInfo::new
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_commandBuffer_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "commandBuffer must be a valid VkCommandBuffer handle"
+        }
+
+        // ensured by CommandBuffer allocation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBeginCommandBuffer_pBeginInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pBeginInfo must be a valid pointer to a valid VkCommandBufferBeginInfo structure"
+        }
+
+        // ensured by CommandBufferBeginInfo creation
+    }
+
+    unsafe {
+        let res = command_buffer.commands().BeginCommandBuffer().get_fptr()(
+            command_buffer.raw_handle(),
+            info.to_c(),
+        );
+        check_raw_err!(res);
+        let mut command_buffer = command_buffer.transition();
+        command_buffer.recording = RecordingId::new();
+        Ok(command_buffer)
+    }
+}
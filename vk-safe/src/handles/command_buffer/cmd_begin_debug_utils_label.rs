@@ -0,0 +1,226 @@
+use super::command_buffer_state::Recording;
+use super::{_CommandBuffer, CommandBuffer};
+
+use crate::enumerations::CommandBufferLevel::PRIMARY;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::{DispatchableHandle, Handle};
+use crate::structs::DebugUtilsLabelEXT;
+use crate::type_conversions::ConvertWrapper;
+
+use std::ops::{Deref, DerefMut};
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::CommandBufferUsageFlags;
+use vk::has_command::{CmdBeginDebugUtilsLabelEXT, CmdEndDebugUtilsLabelEXT};
+
+/// A labeled region of commands in a CommandBuffer
+///
+/// Returned by [`begin_label`](crate::vk::_CommandBuffer::begin_label). Commands are recorded
+/// through this type while the label is open, and the label is ended with
+/// [`end_label`](Self::end_label) or when dropped.
+///
+/// The CommandBuffer is mutably borrowed, so recording cannot be ended while the label is open.
+/// If the region is leaked (e.g. with `std::mem::forget`), or the CommandBuffer is replaced (e.g.
+/// with `std::mem::swap`), the label is never ended.
+#[derive(Debug)]
+pub struct CmdLabelRegion<'c, C: CommandBuffer<Commands: CmdEndDebugUtilsLabelEXT>> {
+    command_buffer: &'c mut C,
+    handle: vk::CommandBuffer,
+}
+
+impl<
+        'a,
+        D: Device<Commands: CmdBeginDebugUtilsLabelEXT + CmdEndDebugUtilsLabelEXT>,
+        P: CommandPool,
+        U: CommandBufferUsageFlags,
+    > _CommandBuffer<'a, D, P, PRIMARY, Recording<U>>
+{
+    /**
+    Begin a labeled region of commands, for debugging
+
+    Only available for primary CommandBuffers, since a secondary CommandBuffer
+    must end all the labels it begins, which cannot be ensured if the returned
+    region is leaked. The CommandPool must support graphics or compute operations.
+
+    Commands recorded through the returned [`CmdLabelRegion`] are in the labeled
+    region. The label is ended when the region is dropped. Regions can be nested.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<
+    #   D: vk::Device<Commands: vk::device::VERSION_1_0 + vk::device::EXT_debug_utils>,
+    #   P: vk::CommandPool,
+    # >(
+    #   mut command_buffer: vk::_CommandBuffer<D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Recording<()>>,
+    # ) {
+    let label = vk::DebugUtilsLabelEXT::new(vk_safe::vk_str!("culling"));
+    let mut culling = command_buffer.begin_label(&label);
+    // record commands with culling.cmd_bind_pipeline(...)
+    culling.end_label();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdBeginDebugUtilsLabelEXT.html>
+    */
+    pub fn begin_label(&mut self, label: &DebugUtilsLabelEXT) -> CmdLabelRegion<'_, Self> {
+        check_vuids::check_vuids!(CmdBeginDebugUtilsLabelEXT);
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBeginDebugUtilsLabelEXT_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBeginDebugUtilsLabelEXT_pLabelInfo_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pLabelInfo must be a valid pointer to a valid VkDebugUtilsLabelEXT structure"
+            }
+
+            // ensured by DebugUtilsLabelEXT creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBeginDebugUtilsLabelEXT_commandBuffer_recording: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+
+            // ensured by Recording state type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBeginDebugUtilsLabelEXT_commandBuffer_cmdpool: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkCommandPool that commandBuffer was allocated from must support graphics, or"
+            "compute operations"
+            }
+
+            const {
+                assert!(
+                    P::Capability::INCLUDES
+                        .any_of(vk::QueueFlags::GRAPHICS_BIT.or(vk::QueueFlags::COMPUTE_BIT)),
+                    "CommandPool must support graphics or compute operations to begin a label"
+                );
+            }
+        }
+
+        unsafe {
+            self.commands().CmdBeginDebugUtilsLabelEXT().get_fptr()(
+                self.raw_handle(),
+                label.to_c(),
+            );
+        }
+
+        let handle = self.raw_handle();
+        CmdLabelRegion {
+            command_buffer: self,
+            handle,
+        }
+    }
+}
+
+impl<C: CommandBuffer<Commands: CmdEndDebugUtilsLabelEXT>> CmdLabelRegion<'_, C> {
+    /// End the label
+    ///
+    /// The same as dropping the region, but more explicit.
+    ///
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdEndDebugUtilsLabelEXT.html>
+    pub fn end_label(self) {}
+}
+
+impl<C: CommandBuffer<Commands: CmdEndDebugUtilsLabelEXT>> Drop for CmdLabelRegion<'_, C> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(CmdEndDebugUtilsLabelEXT);
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdEndDebugUtilsLabelEXT_commandBuffer_01912: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "There must be an outstanding vkCmdBeginDebugUtilsLabelEXT command prior to the"
+            "vkCmdEndDebugUtilsLabelEXT on the queue"
+            }
+
+            // CmdLabelRegion is only created by beginning a label in the same CommandBuffer
+            if self.command_buffer.raw_handle().handle != self.handle.handle {
+                // the CommandBuffer was replaced through DerefMut, and the label was not begun in
+                // this CommandBuffer, so leave the label open as if the region was leaked
+                return;
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdEndDebugUtilsLabelEXT_commandBuffer_01913: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a secondary command buffer, there must be an outstanding"
+            "vkCmdBeginDebugUtilsLabelEXT command recorded to commandBuffer that has not previously"
+            "been ended by a call to vkCmdEndDebugUtilsLabelEXT"
+            }
+
+            // labels can only be begun in primary CommandBuffers
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdEndDebugUtilsLabelEXT_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdEndDebugUtilsLabelEXT_commandBuffer_recording: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+
+            // the CommandBuffer is borrowed in the Recording state until the label is ended
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdEndDebugUtilsLabelEXT_commandBuffer_cmdpool: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkCommandPool that commandBuffer was allocated from must support graphics, or"
+            "compute operations"
+            }
+
+            // checked when beginning the label
+        }
+
+        unsafe {
+            self.command_buffer
+                .commands()
+                .CmdEndDebugUtilsLabelEXT()
+                .get_fptr()(self.command_buffer.raw_handle());
+        }
+    }
+}
+
+impl<C: CommandBuffer<Commands: CmdEndDebugUtilsLabelEXT>> Deref for CmdLabelRegion<'_, C> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        self.command_buffer
+    }
+}
+
+impl<C: CommandBuffer<Commands: CmdEndDebugUtilsLabelEXT>> DerefMut for CmdLabelRegion<'_, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.command_buffer
+    }
+}
@@ -0,0 +1,396 @@
+use super::_CommandBuffer;
+use super::command_buffer_state::Recording;
+
+use crate::enumerations::PipelineBindPoint::COMPUTE;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::pipeline::Pipeline;
+use crate::handles::{DispatchableHandle, Handle};
+
+use std::marker::PhantomData;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::CommandBufferUsageFlags;
+use vk::has_command::CmdBindPipeline;
+
+/// A CommandBuffer with a Pipeline bound
+///
+/// Returned by [`cmd_bind_pipeline`](crate::vk::_CommandBuffer::cmd_bind_pipeline). Commands
+/// which use the bound Pipeline (e.g. [`cmd_dispatch`](Self::cmd_dispatch)) are recorded
+/// through this type.
+///
+/// The CommandBuffer is mutably borrowed, so other commands cannot be recorded while the
+/// Pipeline is considered bound. Bind the Pipeline again after recording other commands.
+#[derive(Debug)]
+pub struct BoundPipeline<'c, C, Pl> {
+    pub(crate) command_buffer: &'c mut C,
+    pipeline: PhantomData<Pl>,
+}
+
+impl<'a, D: Device<Commands: CmdBindPipeline>, P: CommandPool, L, U: CommandBufferUsageFlags>
+    _CommandBuffer<'a, D, P, L, Recording<U>>
+{
+    /**
+    Bind a Pipeline to the CommandBuffer
+
+    Only compute Pipelines are currently supported. The CommandPool must support compute
+    operations. The Pipeline is borrowed for as long as this CommandBuffer.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<
+    #   'a,
+    #   D: vk::Device<Commands: vk::device::VERSION_1_0>,
+    #   P: vk::CommandPool,
+    #   Pl: vk::Pipeline<Device = D, BindPoint = vk::PipelineBindPoint::COMPUTE>,
+    # >(
+    #   mut command_buffer: vk::_CommandBuffer<'a, D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Recording<()>>,
+    #   pipeline: &'a Pl,
+    #   properties: vk::PhysicalDeviceProperties<D::PhysicalDevice>,
+    # ) {
+    let mut bound = command_buffer.cmd_bind_pipeline(pipeline);
+    // Safety: the shader does not use any descriptor sets or push constants
+    unsafe { bound.cmd_dispatch(&properties, 64, 1, 1).unwrap() };
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipeline.html>
+    */
+    pub fn cmd_bind_pipeline<Pl: Pipeline<Device = D, BindPoint = COMPUTE>>(
+        &mut self,
+        pipeline: &'a Pl,
+    ) -> BoundPipeline<'_, Self, Pl> {
+        check_vuids::check_vuids!(CmdBindPipeline);
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_00777: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_COMPUTE, the VkCommandPool that commandBuffer"
+            "was allocated from must support compute operations"
+            }
+            check_vuids::status!(enforced);
+
+            const {
+                assert!(
+                    P::Capability::INCLUDES.contains(vk::QueueFlags::COMPUTE_BIT),
+                    "CommandPool must support compute operations to bind a compute Pipeline"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_00778: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_GRAPHICS, the VkCommandPool that commandBuffer"
+            "was allocated from must support graphics operations"
+            }
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_00779: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_COMPUTE, pipeline must be a compute"
+            "pipeline"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by the COMPUTE BindPoint type of the Pipeline
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_00780: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_GRAPHICS, pipeline must be a graphics"
+            "pipeline"
+            }
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipeline_00781: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the variableMultisampleRate feature is not supported, pipeline is a graphics pipeline,"
+            "the current subpass uses no attachments, and this is not the first call to this function"
+            "with a graphics pipeline after transitioning to the current subpass, then the sample"
+            "count specified by this pipeline must match that set in the previous pipeline"
+            }
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_variableSampleLocations_01525: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkPhysicalDeviceSampleLocationsPropertiesEXT::variableSampleLocations is VK_FALSE,"
+            "and pipeline is a graphics pipeline created with a VkPipelineSampleLocationsStateCreateInfoEXT"
+            "structure having its sampleLocationsEnable member set to VK_TRUE but without VK_DYNAMIC_STATE_SAMPLE_LOCATIONS_EXT"
+            "enabled then the current render pass instance must have been begun by calling vkCmdBeginRenderPass"
+            "with a VkRenderPassSampleLocationsBeginInfoEXT structure whose pPostSubpassSampleLocations"
+            "member contains an element with a subpassIndex matching the current subpass index"
+            "and the sampleLocationsInfo member of that element must match the sampleLocationsInfo"
+            "specified in VkPipelineSampleLocationsStateCreateInfoEXT when the pipeline was created"
+            }
+            check_vuids::requires! {"(VK_EXT_sample_locations)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_None_02323: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "This command must not be recorded when transform feedback is active"
+            }
+            check_vuids::requires! {"(VK_EXT_transform_feedback)"}
+            check_vuids::status!(not_applicable);
+
+            // transform feedback is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_02391: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_RAY_TRACING_KHR, the VkCommandPool"
+            "that commandBuffer was allocated from must support compute operations"
+            }
+            check_vuids::requires! {"(VK_KHR_ray_tracing_pipeline,VK_NV_ray_tracing)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_02392: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_RAY_TRACING_KHR, pipeline must be a"
+            "ray tracing pipeline"
+            }
+            check_vuids::requires! {"(VK_KHR_ray_tracing_pipeline,VK_NV_ray_tracing)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipeline_03382: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pipeline must not have been created with VK_PIPELINE_CREATE_LIBRARY_BIT_KHR set"
+            }
+            check_vuids::requires! {"(VK_KHR_pipeline_library)"}
+            check_vuids::status!(enforced);
+
+            // Pipelines are always created with empty flags
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_commandBuffer_04808: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a secondary command buffer with VkCommandBufferInheritanceViewportScissorInfoNV::viewportScissor2D"
+            "enabled and pipelineBindPoint is VK_PIPELINE_BIND_POINT_GRAPHICS, then the pipeline"
+            "must have been created with VK_DYNAMIC_STATE_VIEWPORT_WITH_COUNT or VK_DYNAMIC_STATE_VIEWPORT,"
+            "and VK_DYNAMIC_STATE_SCISSOR_WITH_COUNT or VK_DYNAMIC_STATE_SCISSOR enabled"
+            }
+            check_vuids::requires! {"(VK_NV_inherited_viewport_scissor)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_commandBuffer_04809: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a secondary command buffer with VkCommandBufferInheritanceViewportScissorInfoNV::viewportScissor2D"
+            "enabled and pipelineBindPoint is VK_PIPELINE_BIND_POINT_GRAPHICS and pipeline was"
+            "created with VkPipelineDiscardRectangleStateCreateInfoEXT structure and its discardRectangleCount"
+            "member is not 0, or the pipeline was created with VK_DYNAMIC_STATE_DISCARD_RECTANGLE_ENABLE_EXT"
+            "enabled, then the pipeline must have been created with VK_DYNAMIC_STATE_DISCARD_RECTANGLE_EXT"
+            "enabled"
+            }
+            check_vuids::requires! {"(VK_NV_inherited_viewport_scissor)+(VK_EXT_discard_rectangles)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_04881: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_GRAPHICS and the provokingVertexModePerPipeline"
+            "limit is VK_FALSE, then pipeline's VkPipelineRasterizationProvokingVertexStateCreateInfoEXT::provokingVertexMode"
+            "must be the same as that of any other pipelines previously bound to this bind point"
+            "within the current render pass instance, including any pipeline already bound when"
+            "beginning the render pass instance"
+            }
+            check_vuids::requires! {"(VK_EXT_provoking_vertex)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_04949: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_SUBPASS_SHADING_HUAWEI, the VkCommandPool"
+            "that commandBuffer was allocated from must support compute operations"
+            }
+            check_vuids::requires! {"(VK_HUAWEI_subpass_shading)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_04950: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineBindPoint is VK_PIPELINE_BIND_POINT_SUBPASS_SHADING_HUAWEI, pipeline must"
+            "be a subpass shading pipeline"
+            }
+            check_vuids::requires! {"(VK_HUAWEI_subpass_shading)"}
+            check_vuids::status!(not_applicable);
+
+            // only compute Pipelines are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineProtectedAccess_07408: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pipelineProtectedAccess feature is enabled, and commandBuffer is a protected"
+            "command buffer, pipeline must have been created without VK_PIPELINE_CREATE_NO_PROTECTED_ACCESS_BIT_EXT"
+            }
+            check_vuids::requires! {"(VK_EXT_pipeline_protected_access)"}
+            check_vuids::status!(not_applicable);
+
+            // protected memory is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineProtectedAccess_07409: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pipelineProtectedAccess feature is enabled, and commandBuffer is not a protected"
+            "command buffer, pipeline must have been created without VK_PIPELINE_CREATE_PROTECTED_ACCESS_ONLY_BIT_EXT"
+            }
+            check_vuids::requires! {"(VK_EXT_pipeline_protected_access)"}
+            check_vuids::status!(enforced);
+
+            // Pipelines are always created with empty flags
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipelineBindPoint_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pipelineBindPoint must be a valid VkPipelineBindPoint value"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by the BindPoint type of the Pipeline
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_pipeline_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pipeline must be a valid VkPipeline handle"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by Pipeline creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_commandBuffer_recording: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by Recording state type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_commandBuffer_cmdpool: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkCommandPool that commandBuffer was allocated from must support graphics, or"
+            "compute operations"
+            }
+            check_vuids::status!(enforced);
+
+            // checked in pipelineBindPoint_00777
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_videocoding: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "This command must only be called outside of a video coding scope"
+            }
+            check_vuids::requires! {"(VK_KHR_video_queue)"}
+            check_vuids::status!(not_applicable);
+
+            // video coding is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdBindPipeline_commonparent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Both of commandBuffer, and pipeline must have been created, allocated, or retrieved"
+            "from the same VkDevice"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by Pipeline<Device = D>
+        }
+
+        unsafe {
+            self.commands().CmdBindPipeline().get_fptr()(
+                self.raw_handle(),
+                vk::PipelineBindPoint::COMPUTE,
+                pipeline.raw_handle(),
+            );
+        }
+
+        BoundPipeline {
+            command_buffer: self,
+            pipeline: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,1057 @@
+use super::_CommandBuffer;
+use super::cmd_bind_pipeline::BoundPipeline;
+use super::command_buffer_state::Recording;
+
+use crate::enumerations::PipelineBindPoint::COMPUTE;
+use crate::error::VulkanError;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::pipeline::Pipeline;
+use crate::handles::{DispatchableHandle, Handle};
+use crate::structs::PhysicalDeviceProperties;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::CommandBufferUsageFlags;
+use vk::has_command::CmdDispatch;
+
+impl<
+        'a,
+        D: Device<Commands: CmdDispatch>,
+        P: CommandPool,
+        L,
+        U: CommandBufferUsageFlags,
+        Pl: Pipeline<BindPoint = COMPUTE>,
+    > BoundPipeline<'_, _CommandBuffer<'a, D, P, L, Recording<U>>, Pl>
+{
+    /**
+    Dispatch compute work items with the bound compute Pipeline
+
+    The group counts are checked against `maxComputeWorkGroupCount` of the PhysicalDevice.
+
+    # Safety
+    Descriptor sets and push constants cannot be bound with vk-safe yet. The shader of the
+    bound Pipeline must not statically use any descriptor sets or push constants, and every
+    shader invocation must terminate.
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdDispatch.html>
+    */
+    pub unsafe fn cmd_dispatch(
+        &mut self,
+        properties: &PhysicalDeviceProperties<D::PhysicalDevice>,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) -> Result<(), VulkanError> {
+        check_vuids::check_vuids!(CmdDispatch);
+
+        let max_group_count = properties.limits.max_compute_work_group_count;
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_magFilter_04553: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkSampler created with magFilter or minFilter equal to VK_FILTER_LINEAR, reductionMode"
+            "equal to VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE, and compareEnable equal to VK_FALSE"
+            "is used to sample a VkImageView as a result of this command, then the image view's"
+            "format features must contain VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_2,VK_EXT_sampler_filter_minmax)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_mipmapMode_04770: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkSampler created with mipmapMode equal to VK_SAMPLER_MIPMAP_MODE_LINEAR, reductionMode"
+            "equal to VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE, and compareEnable equal to VK_FALSE"
+            "is used to sample a VkImageView as a result of this command, then the image view's"
+            "format features must contain VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_2,VK_EXT_sampler_filter_minmax)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_06479: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView is sampled with depth comparison, the image view's format features"
+            "must contain VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_DEPTH_COMPARISON_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_3,VK_KHR_format_feature_flags2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_02691: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView is accessed using atomic operations as a result of this command,"
+            "then the image view's format features must contain VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_07888: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER descriptor is accessed using atomic operations"
+            "as a result of this command, then the storage texel buffer's format features must"
+            "contain VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_02692: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView is sampled with VK_FILTER_CUBIC_EXT as a result of this command,"
+            "then the image view's format features must contain VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_EXT"
+            }
+            check_vuids::requires! {"(VK_IMG_filter_cubic,VK_EXT_filter_cubic)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_02693: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_EXT_filter_cubic extension is not enabled and any VkImageView is sampled"
+            "with VK_FILTER_CUBIC_EXT as a result of this command, it must not have a VkImageViewType"
+            "of VK_IMAGE_VIEW_TYPE_3D, VK_IMAGE_VIEW_TYPE_CUBE, or VK_IMAGE_VIEW_TYPE_CUBE_ARRAY"
+            }
+            check_vuids::requires! {"(VK_IMG_filter_cubic)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_filterCubic_02694: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any VkImageView being sampled with VK_FILTER_CUBIC_EXT as a result of this command"
+            "must have a VkImageViewType and format that supports cubic filtering, as specified"
+            "by VkFilterCubicImageViewImageFormatPropertiesEXT::filterCubic returned by vkGetPhysicalDeviceImageFormatProperties2"
+            }
+            check_vuids::requires! {"(VK_EXT_filter_cubic)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_filterCubicMinmax_02695: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any VkImageView being sampled with VK_FILTER_CUBIC_EXT with a reduction mode of either"
+            "VK_SAMPLER_REDUCTION_MODE_MIN or VK_SAMPLER_REDUCTION_MODE_MAX as a result of this"
+            "command must have a VkImageViewType and format that supports cubic filtering together"
+            "with minmax filtering, as specified by VkFilterCubicImageViewImageFormatPropertiesEXT::filterCubicMinmax"
+            "returned by vkGetPhysicalDeviceImageFormatProperties2"
+            }
+            check_vuids::requires! {"(VK_EXT_filter_cubic)+(VK_VERSION_1_2,VK_EXT_sampler_filter_minmax)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_cubicRangeClamp_09212: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the cubicRangeClamp feature is not enabled, then any VkImageView being sampled"
+            "with VK_FILTER_CUBIC_EXT as a result of this command must not have a VkSamplerReductionModeCreateInfo::reductionMode"
+            "equal to VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE_RANGECLAMP_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_filter_cubic_clamp)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_reductionMode_09213: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any VkImageView being sampled with a VkSamplerReductionModeCreateInfo::reductionMode"
+            "equal to VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE_RANGECLAMP_QCOM as a result of"
+            "this command must sample with VK_FILTER_CUBIC_EXT"
+            }
+            check_vuids::requires! {"(VK_QCOM_filter_cubic_clamp)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_selectableCubicWeights_09214: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the selectableCubicWeights feature is not enabled, then any VkImageView being sampled"
+            "with VK_FILTER_CUBIC_EXT as a result of this command must have VkSamplerCubicWeightsCreateInfoQCOM::cubicWeights"
+            "equal to VK_CUBIC_FILTER_WEIGHTS_CATMULL_ROM_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_filter_cubic_weights)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_flags_02696: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any VkImage created with a VkImageCreateInfo::flags containing VK_IMAGE_CREATE_CORNER_SAMPLED_BIT_NV"
+            "sampled as a result of this command must only be sampled using a VkSamplerAddressMode"
+            "of VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_EDGE"
+            }
+            check_vuids::requires! {"(VK_NV_corner_sampled_image)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpTypeImage_07027: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any VkImageView being written as a storage image where the image format field"
+            "of the OpTypeImage is Unknown, the view's format features must contain VK_FORMAT_FEATURE_2_STORAGE_WRITE_WITHOUT_FORMAT_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_3,VK_KHR_format_feature_flags2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpTypeImage_07028: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any VkImageView being read as a storage image where the image format field of"
+            "the OpTypeImage is Unknown, the view's format features must contain VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_3,VK_KHR_format_feature_flags2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpTypeImage_07029: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any VkBufferView being written as a storage texel buffer where the image format"
+            "field of the OpTypeImage is Unknown, the view's buffer features must contain VK_FORMAT_FEATURE_2_STORAGE_WRITE_WITHOUT_FORMAT_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_3,VK_KHR_format_feature_flags2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpTypeImage_07030: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any VkBufferView being read as a storage texel buffer where the image format field"
+            "of the OpTypeImage is Unknown then the view's buffer features must contain VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_3,VK_KHR_format_feature_flags2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08600: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For each set n that is statically used by a bound shader, a descriptor set must have"
+            "been bound to n at the same pipeline bind point, with a VkPipelineLayout that is compatible"
+            "for set n, with the VkPipelineLayout or VkDescriptorSetLayout array that was used"
+            "to create the current VkPipeline or VkShaderEXT, as described in Pipeline Layout Compatibility"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08601: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For each push constant that is statically used by a bound shader, a push constant"
+            "value must have been set for the same pipeline bind point, with a VkPipelineLayout"
+            "that is compatible for push constants, with the VkPipelineLayout or VkDescriptorSetLayout"
+            "and VkPushConstantRange arrays used to create the current VkPipeline or VkShaderEXT,"
+            "as described in Pipeline Layout Compatibility"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: push constants cannot be set yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_maintenance4_08602: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the maintenance4 feature is not enabled, then for each push constant that is statically"
+            "used by a bound shader, a push constant value must have been set for the same pipeline"
+            "bind point, with a VkPipelineLayout that is compatible for push constants, with the"
+            "VkPipelineLayout or VkDescriptorSetLayout and VkPushConstantRange arrays used to create"
+            "the current VkPipeline or VkShaderEXT, as described in Pipeline Layout Compatibility"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_3,VK_KHR_maintenance4)"}
+            check_vuids::status!(todo);
+
+            // TODO: push constants cannot be set yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08114: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Descriptors in each bound descriptor set, specified via vkCmdBindDescriptorSets, must"
+            "be valid as described by descriptor validity if they are statically used by the VkPipeline"
+            "bound to the pipeline bind point used by this command and the bound VkPipeline was"
+            "not created with VK_PIPELINE_CREATE_DESCRIPTOR_BUFFER_BIT_EXT"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08115: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the descriptors used by the VkPipeline bound to the pipeline bind point were specified"
+            "via vkCmdBindDescriptorSets, the bound VkPipeline must have been created without VK_PIPELINE_CREATE_DESCRIPTOR_BUFFER_BIT_EXT"
+            }
+            check_vuids::requires! {"(VK_EXT_descriptor_buffer)"}
+            check_vuids::status!(not_applicable);
+
+            // descriptor buffers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08116: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Descriptors in bound descriptor buffers, specified via vkCmdBindDescriptorBuffersEXT,"
+            "must be valid if they are dynamically used by the VkPipeline bound to the pipeline"
+            "bind point used by this command and the bound VkPipeline was created with VK_PIPELINE_CREATE_DESCRIPTOR_BUFFER_BIT_EXT"
+            }
+            check_vuids::requires! {"(VK_EXT_descriptor_buffer)"}
+            check_vuids::status!(not_applicable);
+
+            // descriptor buffers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08604: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Descriptors in bound descriptor buffers, specified via vkCmdBindDescriptorBuffersEXT,"
+            "must be valid if they are dynamically used by any VkShaderEXT bound to a stage corresponding"
+            "to the pipeline bind point used by this command"
+            }
+            check_vuids::requires! {"(VK_EXT_descriptor_buffer)+(VK_EXT_shader_object)"}
+            check_vuids::status!(not_applicable);
+
+            // descriptor buffers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08117: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the descriptors used by the VkPipeline bound to the pipeline bind point were specified"
+            "via vkCmdBindDescriptorBuffersEXT, the bound VkPipeline must have been created with"
+            "VK_PIPELINE_CREATE_DESCRIPTOR_BUFFER_BIT_EXT"
+            }
+            check_vuids::requires! {"(VK_EXT_descriptor_buffer)"}
+            check_vuids::status!(not_applicable);
+
+            // descriptor buffers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08119: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a descriptor is dynamically used with a VkPipeline created with VK_PIPELINE_CREATE_DESCRIPTOR_BUFFER_BIT_EXT,"
+            "the descriptor memory must be resident"
+            }
+            check_vuids::requires! {"(VK_EXT_descriptor_buffer)"}
+            check_vuids::status!(not_applicable);
+
+            // descriptor buffers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08605: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a descriptor is dynamically used with a VkShaderEXT created with a VkDescriptorSetLayout"
+            "that was created with VK_DESCRIPTOR_SET_LAYOUT_CREATE_DESCRIPTOR_BUFFER_BIT_EXT, the"
+            "descriptor memory must be resident"
+            }
+            check_vuids::requires! {"(VK_EXT_descriptor_buffer)+(VK_EXT_shader_object)"}
+            check_vuids::status!(not_applicable);
+
+            // descriptor buffers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08606: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the shaderObject feature is not enabled, a valid pipeline must be bound to the"
+            "pipeline bind point used by this command"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by BoundPipeline
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08608: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a pipeline is bound to the pipeline bind point used by this command, there must"
+            "not have been any calls to dynamic state setting commands for any state not specified"
+            "as dynamic in the VkPipeline object bound to the pipeline bind point used by this"
+            "command, since that pipeline was bound"
+            }
+            check_vuids::status!(enforced);
+
+            // compute Pipelines have no dynamic state, and dynamic state commands are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08607: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the shaderObject feature is enabled, either a valid pipeline must be bound to the"
+            "pipeline bind point used by this command or a valid combination of valid and VK_NULL_HANDLE"
+            "shader objects must be bound to every supported shader stage corresponding to the"
+            "pipeline bind point used by this command"
+            }
+            check_vuids::requires! {"(VK_EXT_shader_object)"}
+            check_vuids::status!(enforced);
+
+            // ensured by BoundPipeline
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08609: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VkPipeline object bound to the pipeline bind point used by this command accesses"
+            "a VkSampler object that uses unnormalized coordinates, that sampler must not be used"
+            "to sample from any VkImage with a VkImageView of the type VK_IMAGE_VIEW_TYPE_3D, VK_IMAGE_VIEW_TYPE_CUBE,"
+            "VK_IMAGE_VIEW_TYPE_1D_ARRAY, VK_IMAGE_VIEW_TYPE_2D_ARRAY or VK_IMAGE_VIEW_TYPE_CUBE_ARRAY,"
+            "in any shader stage"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08610: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VkPipeline object bound to the pipeline bind point used by this command accesses"
+            "a VkSampler object that uses unnormalized coordinates, that sampler must not be used"
+            "with any of the SPIR-V OpImageSample* or OpImageSparseSample* instructions with ImplicitLod,"
+            "Dref or Proj in their name, in any shader stage"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08611: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VkPipeline object bound to the pipeline bind point used by this command accesses"
+            "a VkSampler object that uses unnormalized coordinates, that sampler must not be used"
+            "with any of the SPIR-V OpImageSample* or OpImageSparseSample* instructions that includes"
+            "a LOD bias or any offset values, in any shader stage"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_uniformBuffers_06935: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any stage of the VkPipeline object bound to the pipeline bind point used by this"
+            "command accesses a uniform buffer, and that stage was created without enabling either"
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_EXT or VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_2_EXT"
+            "for uniformBuffers, and the robustBufferAccess feature is not enabled, that stage"
+            "must not access values outside of the range of the buffer as specified in the descriptor"
+            "set bound to the same pipeline bind point"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08612: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the robustBufferAccess feature is not enabled, and any VkShaderEXT bound to a stage"
+            "corresponding to the pipeline bind point used by this command accesses a uniform buffer,"
+            "it must not access values outside of the range of the buffer as specified in the descriptor"
+            "set bound to the same pipeline bind point"
+            }
+            check_vuids::requires! {"(VK_EXT_shader_object)"}
+            check_vuids::status!(not_applicable);
+
+            // shader objects are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_storageBuffers_06936: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any stage of the VkPipeline object bound to the pipeline bind point used by this"
+            "command accesses a storage buffer, and that stage was created without enabling either"
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_EXT or VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_2_EXT"
+            "for storageBuffers, and the robustBufferAccess feature is not enabled, that stage"
+            "must not access values outside of the range of the buffer as specified in the descriptor"
+            "set bound to the same pipeline bind point"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_08613: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the robustBufferAccess feature is not enabled, and any VkShaderEXT bound to a stage"
+            "corresponding to the pipeline bind point used by this command accesses a storage buffer,"
+            "it must not access values outside of the range of the buffer as specified in the descriptor"
+            "set bound to the same pipeline bind point"
+            }
+            check_vuids::requires! {"(VK_EXT_shader_object)"}
+            check_vuids::status!(not_applicable);
+
+            // shader objects are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_commandBuffer_02707: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is an unprotected command buffer and protectedNoFault is not supported,"
+            "any resource accessed by bound shaders must not be a protected resource"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_1)"}
+            check_vuids::status!(not_applicable);
+
+            // protected memory is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_viewType_07752: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView is accessed as a result of this command, then the image view's viewType"
+            "must match the Dim operand of the OpTypeImage as described in Instruction/Sampler/Image"
+            "View Validation"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_format_07753: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView is accessed as a result of this command, then the numeric type of"
+            "the image view's format and the Sampled Type operand of the OpTypeImage must match"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWrite_08795: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView created with a format other than VK_FORMAT_A8_UNORM_KHR is accessed"
+            "using OpImageWrite as a result of this command, then the Type of the Texel operand"
+            "of that instruction must have at least as many components as the image view's format"
+            }
+            check_vuids::requires! {"(VK_KHR_maintenance5)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWrite_08796: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView created with the format VK_FORMAT_A8_UNORM_KHR is accessed using"
+            "OpImageWrite as a result of this command, then the Type of the Texel operand of that"
+            "instruction must have four components"
+            }
+            check_vuids::requires! {"(VK_KHR_maintenance5)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWrite_04469: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkBufferView is accessed using OpImageWrite as a result of this command, then"
+            "the Type of the Texel operand of that instruction must have at least as many components"
+            "as the buffer view's format"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_SampledType_04470: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView with a VkFormat that has a 64-bit component width is accessed as"
+            "a result of this command, the SampledType of the OpTypeImage operand of that instruction"
+            "must have a Width of 64"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_SampledType_04471: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkImageView with a VkFormat that has a component width less than 64-bit is accessed"
+            "as a result of this command, the SampledType of the OpTypeImage operand of that instruction"
+            "must have a Width of 32"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_SampledType_04472: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkBufferView with a VkFormat that has a 64-bit component width is accessed as"
+            "a result of this command, the SampledType of the OpTypeImage operand of that instruction"
+            "must have a Width of 64"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_SampledType_04473: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a VkBufferView with a VkFormat that has a component width less than 64-bit is accessed"
+            "as a result of this command, the SampledType of the OpTypeImage operand of that instruction"
+            "must have a Width of 32"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_sparseImageInt64Atomics_04474: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the sparseImageInt64Atomics feature is not enabled, VkImage objects created with"
+            "the VK_IMAGE_CREATE_SPARSE_RESIDENCY_BIT flag must not be accessed by atomic instructions"
+            "through an OpTypeImage with a SampledType with a Width of 64 by this command"
+            }
+            check_vuids::requires! {"(VK_EXT_shader_image_atomic_int64)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_sparseImageInt64Atomics_04475: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the sparseImageInt64Atomics feature is not enabled, VkBuffer objects created with"
+            "the VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT flag must not be accessed by atomic instructions"
+            "through an OpTypeImage with a SampledType with a Width of 64 by this command"
+            }
+            check_vuids::requires! {"(VK_EXT_shader_image_atomic_int64)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWeightedSampleQCOM_06971: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageWeightedSampleQCOM is used to sample a VkImageView as a result of this command,"
+            "then the image view's format features must contain VK_FORMAT_FEATURE_2_WEIGHT_SAMPLED_IMAGE_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWeightedSampleQCOM_06972: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageWeightedSampleQCOM uses a VkImageView as a sample weight image as a result"
+            "of this command, then the image view's format features must contain VK_FORMAT_FEATURE_2_WEIGHT_IMAGE_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBoxFilterQCOM_06973: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageBoxFilterQCOM is used to sample a VkImageView as a result of this command,"
+            "then the image view's format features must contain VK_FORMAT_FEATURE_2_BOX_FILTER_SAMPLED_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBlockMatchSSDQCOM_06974: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageBlockMatchSSDQCOM is used to read from an VkImageView as a result of this"
+            "command, then the image view's format features must contain VK_FORMAT_FEATURE_2_BLOCK_MATCHING_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBlockMatchSADQCOM_06975: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageBlockMatchSADQCOM is used to read from an VkImageView as a result of this"
+            "command, then the image view's format features must contain VK_FORMAT_FEATURE_2_BLOCK_MATCHING_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBlockMatchSADQCOM_06976: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageBlockMatchSADQCOM or OpImageBlockMatchSSDQCOM is used to read from a reference"
+            "image as result of this command, then the specified reference coordinates must not"
+            "fail integer texel coordinate validation"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWeightedSampleQCOM_06977: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If OpImageWeightedSampleQCOM, OpImageBoxFilterQCOM, OpImageBlockMatchSSDQCOM, or OpImageBlockMatchSADQCOM"
+            "uses a VkSampler as a result of this command, then the sampler must have been created"
+            "with VK_SAMPLER_CREATE_IMAGE_PROCESSING_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageWeightedSampleQCOM_06978: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any command other than OpImageWeightedSampleQCOM, OpImageBoxFilterQCOM, OpImageBlockMatchSSDQCOM,"
+            "or OpImageBlockMatchSADQCOM uses a VkSampler as a result of this command, then the"
+            "sampler must not have been created with VK_SAMPLER_CREATE_IMAGE_PROCESSING_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBlockMatchWindow_09215: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a OpImageBlockMatchWindow*QCOM or OpImageBlockMatchGather*QCOM instruction is used"
+            "to read from an VkImageView as a result of this command, then the image view's format"
+            "features must contain VK_FORMAT_FEATURE_2_BLOCK_MATCHING_BIT_QCOM"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBlockMatchWindow_09216: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a OpImageBlockMatchWindow*QCOM or OpImageBlockMatchGather*QCOM instruction is used"
+            "to read from an VkImageView as a result of this command, then the image view's format"
+            "must be a single-component format"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_OpImageBlockMatchWindow_09217: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a OpImageBlockMatchWindow*QCOM or OpImageBlockMatchGather*QCOM read from a reference"
+            "image as result of this command, then the specified reference coordinates must not"
+            "fail integer texel coordinate validation"
+            }
+            check_vuids::requires! {"(VK_QCOM_image_processing2)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_07288: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any shader invocation executed by this command must terminate"
+            }
+            check_vuids::status!(todo);
+
+            // TODO: shaders are not validated; promised by caller
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_None_06550: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VkPipeline object bound to the pipeline bind point used by this command accesses"
+            "a VkSampler or VkImageView object that enables sampler Y′CBCR conversion, that object"
+            "must only be used with OpImageSample* or OpImageSparseSample* instructions"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_1,VK_KHR_sampler_ycbcr_conversion)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_ConstOffset_06551: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VkPipeline object bound to the pipeline bind point used by this command accesses"
+            "a VkSampler or VkImageView object that enables sampler Y′CBCR conversion, that object"
+            "must not use the ConstOffset and Offset operands"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_1,VK_KHR_sampler_ycbcr_conversion)"}
+            check_vuids::status!(todo);
+
+            // TODO: descriptor sets cannot be bound yet; promised by caller that none are used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_commandBuffer_02712: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a protected command buffer and protectedNoFault is not supported,"
+            "any resource written to by the VkPipeline object bound to the pipeline bind point"
+            "used by this command must not be an unprotected resource"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_1)"}
+            check_vuids::status!(not_applicable);
+
+            // protected memory is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_commandBuffer_02713: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a protected command buffer and protectedNoFault is not supported,"
+            "pipeline stages other than the framebuffer-space and compute stages in the VkPipeline"
+            "object bound to the pipeline bind point used by this command must not write to any"
+            "resource"
+            }
+            check_vuids::requires! {"(VK_VERSION_1_1)"}
+            check_vuids::status!(not_applicable);
+
+            // protected memory is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_groupCountX_00386: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "groupCountX must be less than or equal to VkPhysicalDeviceLimits::maxComputeWorkGroupCount[0]"
+            }
+            check_vuids::status!(enforced);
+
+            if group_count_x > max_group_count[0] {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_groupCountY_00387: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "groupCountY must be less than or equal to VkPhysicalDeviceLimits::maxComputeWorkGroupCount[1]"
+            }
+            check_vuids::status!(enforced);
+
+            if group_count_y > max_group_count[1] {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_groupCountZ_00388: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "groupCountZ must be less than or equal to VkPhysicalDeviceLimits::maxComputeWorkGroupCount[2]"
+            }
+            check_vuids::status!(enforced);
+
+            if group_count_z > max_group_count[2] {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_commandBuffer_recording: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+            check_vuids::status!(enforced);
+
+            // ensured by Recording state type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_commandBuffer_cmdpool: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkCommandPool that commandBuffer was allocated from must support compute operations"
+            }
+            check_vuids::status!(enforced);
+
+            const {
+                assert!(
+                    P::Capability::INCLUDES.contains(vk::QueueFlags::COMPUTE_BIT),
+                    "CommandPool must support compute operations"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_renderpass: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "This command must only be called outside of a render pass instance"
+            }
+            check_vuids::status!(not_applicable);
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdDispatch_videocoding: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "This command must only be called outside of a video coding scope"
+            }
+            check_vuids::requires! {"(VK_KHR_video_queue)"}
+            check_vuids::status!(not_applicable);
+
+            // video coding is not supported
+        }
+
+        let command_buffer = &mut *self.command_buffer;
+        unsafe {
+            command_buffer.commands().CmdDispatch().get_fptr()(
+                command_buffer.raw_handle(),
+                group_count_x,
+                group_count_y,
+                group_count_z,
+            );
+        }
+        Ok(())
+    }
+}
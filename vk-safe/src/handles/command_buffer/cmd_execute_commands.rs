@@ -0,0 +1,283 @@
+use super::_CommandBuffer;
+use super::command_buffer_state::{Executable, Recording};
+
+use crate::enumerations::CommandBufferLevel::{PRIMARY, SECONDARY};
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::{DispatchableHandle, Handle};
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::CommandBufferUsageFlags;
+use vk::has_command::CmdExecuteCommands;
+
+impl<'a, D: Device<Commands: CmdExecuteCommands>, P: CommandPool, U: CommandBufferUsageFlags>
+    _CommandBuffer<'a, D, P, PRIMARY, Recording<U>>
+{
+    /**
+    Execute a secondary CommandBuffer from a primary CommandBuffer
+
+    Only available for primary CommandBuffers. The secondary CommandBuffer must
+    be executable, and allocated from a CommandPool for the same queue family.
+
+    The secondary CommandBuffer is consumed, so that it cannot be reset or recorded
+    again while this CommandBuffer refers to it.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: vk::Device<Commands: vk::device::VERSION_1_0>, P: vk::CommandPool, S: vk::CommandPool<QueueFamily = P::QueueFamily>>
+    #   (
+    #       primary: vk::_CommandBuffer<D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Initial>,
+    #       secondary: vk::_CommandBuffer<D, S, vk::CommandBufferLevel::SECONDARY, vk::command_buffer_state::Initial>,
+    #   ) {
+    let inheritance_info = vk::CommandBufferInheritanceInfo::new();
+    let secondary = secondary
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new_secondary((), &inheritance_info))
+        .unwrap()
+        .end_command_buffer()
+        .unwrap();
+
+    let mut primary = primary
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+        .unwrap();
+    primary.cmd_execute_commands(secondary);
+    let primary = primary.end_command_buffer().unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteCommands.html>
+    */
+    pub fn cmd_execute_commands<
+        's: 'a,
+        SP: CommandPool<QueueFamily = P::QueueFamily>,
+        SU: CommandBufferUsageFlags,
+    >(
+        &mut self,
+        secondary: _CommandBuffer<'s, D, SP, SECONDARY, Executable<SU>>,
+    ) {
+        check_vuids::check_vuids!(CmdExecuteCommands);
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00088: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each element of pCommandBuffers must have been allocated with a level of VK_COMMAND_BUFFER_LEVEL_SECONDARY"
+            }
+
+            // ensured by SECONDARY level type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00089: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each element of pCommandBuffers must be in the pending or executable state"
+            }
+
+            // ensured by Executable state type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00091: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any element of pCommandBuffers was not recorded with the VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT"
+            "flag, it must not be in the pending state"
+            }
+
+            // the secondary CommandBuffer is consumed, so it cannot be recorded into any other
+            // primary CommandBuffer which could be pending
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00092: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any element of pCommandBuffers was not recorded with the VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT"
+            "flag, it must not have already been recorded to commandBuffer"
+            }
+
+            // the secondary CommandBuffer is consumed
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00093: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any element of pCommandBuffers was not recorded with the VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT"
+            "flag, it must not appear more than once in pCommandBuffers"
+            }
+
+            // only one secondary CommandBuffer is executed per call
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00094: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each element of pCommandBuffers must have been allocated from a VkCommandPool that"
+            "was created for the same queue family as the VkCommandPool from which commandBuffer"
+            "was allocated"
+            }
+
+            // ensured by CommandPool<QueueFamily = P::QueueFamily>
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00096: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdExecuteCommands is being called within a render pass instance, each element"
+            "of pCommandBuffers must have been recorded with the VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_00100: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdExecuteCommands is not being called within a render pass instance, each element"
+            "of pCommandBuffers must not have been recorded with the VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT"
+            }
+
+            // RENDER_PASS_CONTINUE_BIT is rejected by CommandBufferBeginInfo
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBuffer_00101: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the inheritedQueries feature is not enabled, commandBuffer must not have any queries"
+            "active"
+            }
+
+            // queries are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBuffer_01820: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a protected command buffer and protectedNoFault is not supported,"
+            "each element of pCommandBuffers must be a protected command buffer"
+            }
+
+            // CommandPoolCreateInfo does not allow PROTECTED_BIT
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBuffer_01821: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is an unprotected command buffer and protectedNoFault is not supported,"
+            "each element of pCommandBuffers must be an unprotected command buffer"
+            }
+
+            // CommandPoolCreateInfo does not allow PROTECTED_BIT
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_pCommandBuffers_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pCommandBuffers must be a valid pointer to an array of commandBufferCount valid VkCommandBuffer"
+            "handles"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBuffer_recording: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+
+            // ensured by Recording state type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBuffer_cmdpool: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkCommandPool that commandBuffer was allocated from must support transfer, graphics,"
+            "or compute operations"
+            }
+
+            const {
+                assert!(
+                    P::Capability::INCLUDES.any_of(
+                        vk::QueueFlags::TRANSFER_BIT
+                            .or(vk::QueueFlags::GRAPHICS_BIT)
+                            .or(vk::QueueFlags::COMPUTE_BIT)
+                    ),
+                    "CommandPool must support transfer, graphics, or compute operations"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_videocoding: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "This command must only be called outside of a video coding scope"
+            }
+
+            // video coding is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_bufferlevel: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a primary VkCommandBuffer"
+            }
+
+            // ensured by PRIMARY level type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commandBufferCount_arraylength: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBufferCount must be greater than 0"
+            }
+
+            // always 1
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdExecuteCommands_commonparent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Both of commandBuffer, and the elements of pCommandBuffers must have been created,"
+            "allocated, or retrieved from the same VkDevice"
+            }
+
+            // ensured by the same Device type D
+        }
+
+        let secondary_handle = [secondary.raw_handle()];
+        unsafe {
+            self.commands().CmdExecuteCommands().get_fptr()(
+                self.raw_handle(),
+                1,
+                secondary_handle.as_ptr(),
+            );
+        }
+    }
+}
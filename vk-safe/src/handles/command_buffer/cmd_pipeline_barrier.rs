@@ -0,0 +1,1008 @@
+use super::_CommandBuffer;
+use super::command_buffer_state::Recording;
+
+use crate::features::Features;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::queue::{pipeline_stages_supported, CORE_PIPELINE_STAGES};
+use crate::handles::{DispatchableHandle, Handle};
+
+pub(crate) use private::Barriers;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::{CommandBufferUsageFlags, PipelineStageFlags};
+use vk::has_command::CmdPipelineBarrier;
+
+impl<
+        'a,
+        D: Device<Commands: CmdPipelineBarrier>,
+        P: CommandPool,
+        L,
+        U: CommandBufferUsageFlags,
+    > _CommandBuffer<'a, D, P, L, Recording<U>>
+{
+    /**
+    Insert a memory dependency
+
+    The pipeline stages are provided as types, and the access masks of all barriers are
+    checked at compile time against the stages. i.e. each access bit in the src (dst)
+    access mask of a barrier must be supported by at least one stage in `src_stage`
+    (`dst_stage`). The stages must also be supported by the queue family of the
+    CommandPool.
+
+    Each barrier parameter can be `()` for no barriers, an array of barriers of the same type,
+    or a tuple of barriers of different types. Buffers and Images in the barriers are borrowed
+    for as long as this CommandBuffer.
+
+    Image layout transitions are made with [`ImageMemoryBarrier::transition`](crate::vk::ImageMemoryBarrier::transition).
+    The transitions are consumed when recorded, and the TrackedImages with the new layouts are
+    returned in the same shape as `image_memory_barriers` (e.g. a tuple for a tuple of
+    barriers). The old layout of a transition is only known in the recording which made the
+    previous transition of the Image. A transition of an Image which was last transitioned in a
+    different recording uses `UNDEFINED` as the old layout (see [`TrackedImage`](crate::vk::TrackedImage)).
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<
+    #   'a,
+    #   D: vk::Device<Commands: vk::device::VERSION_1_0>,
+    #   P: vk::CommandPool,
+    #   B: vk::Buffer<Device = D>,
+    #   M: vk::DeviceMemory<Device = D>,
+    #   I: vk::Image<Device = D>,
+    # >(
+    #   mut command_buffer: vk::_CommandBuffer<'a, D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Recording<()>>,
+    #   buffer: &'a vk::BoundBuffer<B, M>,
+    #   image: &'a mut vk::BoundImage<I, M>,
+    # ) {
+    let buffer_barrier = vk::BufferMemoryBarrier::new(
+        buffer,
+        vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+        vk::flags!(AccessFlags + HOST_READ_BIT),
+    );
+    command_buffer.cmd_pipeline_barrier(
+        vk::flags!(PipelineStageFlags + TRANSFER_BIT),
+        vk::flags!(PipelineStageFlags + HOST_BIT),
+        (),
+        [buffer_barrier],
+        (),
+    );
+
+    let transition = vk::ImageMemoryBarrier::transition(
+        image.track_layout(),
+        vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+        (),
+        vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+    );
+    let (image,) = command_buffer.cmd_pipeline_barrier(
+        vk::flags!(PipelineStageFlags + TOP_OF_PIPE_BIT),
+        vk::flags!(PipelineStageFlags + TRANSFER_BIT),
+        (),
+        (),
+        (transition,),
+    );
+    // image is the TrackedImage with the TRANSFER_DST_OPTIMAL layout
+    # let _ = image;
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier.html>
+    */
+    pub fn cmd_pipeline_barrier<
+        S: PipelineStageFlags,
+        T: PipelineStageFlags,
+        MB: Barriers<'a, D, P::QueueFamily, vk::MemoryBarrier>,
+        BB: Barriers<'a, D, P::QueueFamily, vk::BufferMemoryBarrier>,
+        IB: Barriers<'a, D, P::QueueFamily, vk::ImageMemoryBarrier>,
+    >(
+        &mut self,
+        _src_stage: S,
+        _dst_stage: T,
+        memory_barriers: MB,
+        buffer_memory_barriers: BB,
+        image_memory_barriers: IB,
+    ) -> IB::Transitioned {
+        check_vuids::check_vuids!(CmdPipelineBarrier);
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04090: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the geometryShader feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT"
+            }
+
+            // allowed if the feature is enabled on the Device
+            const {
+                assert!(
+                    D::Features::ENABLED.geometry_shader == vk::TRUE
+                        || S::INCLUDES.excludes(vk::PipelineStageFlags::GEOMETRY_SHADER_BIT),
+                    "geometry stage requires the geometryShader feature which is not enabled"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04091: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the tessellationShader feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT"
+            "or VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT"
+            }
+
+            // allowed if the feature is enabled on the Device
+            const {
+                assert!(
+                    D::Features::ENABLED.tessellation_shader == vk::TRUE
+                        || S::INCLUDES.excludes(
+                            vk::PipelineStageFlags::TESSELLATION_CONTROL_SHADER_BIT
+                                .or(vk::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER_BIT)
+                        ),
+                    "tessellation stages require the tessellationShader feature which is not enabled"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04092: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the conditionalRendering feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_CONDITIONAL_RENDERING_BIT_EXT"
+            }
+
+            // checked in srcStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04093: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the fragmentDensityMap feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_FRAGMENT_DENSITY_PROCESS_BIT_EXT"
+            }
+
+            // checked in srcStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04094: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the transformFeedback feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_TRANSFORM_FEEDBACK_BIT_EXT"
+            }
+
+            // checked in srcStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04095: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the meshShader feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_MESH_SHADER_BIT_EXT"
+            }
+
+            // checked in srcStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04096: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the taskShader feature is not enabled, srcStageMask must not contain VK_PIPELINE_STAGE_TASK_SHADER_BIT_EXT"
+            }
+
+            // checked in srcStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_04097: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If neither the shadingRateImage or attachmentFragmentShadingRate are enabled, srcStageMask"
+            "must not contain VK_PIPELINE_STAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR"
+            }
+
+            // checked in srcStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_03937: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the synchronization2 feature is not enabled, srcStageMask must not be 0"
+            }
+
+            const { assert!(S::INCLUDES.is_not_empty(), "src stage must not be empty") }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04090: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the geometryShader feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT"
+            }
+
+            // allowed if the feature is enabled on the Device
+            const {
+                assert!(
+                    D::Features::ENABLED.geometry_shader == vk::TRUE
+                        || T::INCLUDES.excludes(vk::PipelineStageFlags::GEOMETRY_SHADER_BIT),
+                    "geometry stage requires the geometryShader feature which is not enabled"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04091: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the tessellationShader feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT"
+            "or VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT"
+            }
+
+            // allowed if the feature is enabled on the Device
+            const {
+                assert!(
+                    D::Features::ENABLED.tessellation_shader == vk::TRUE
+                        || T::INCLUDES.excludes(
+                            vk::PipelineStageFlags::TESSELLATION_CONTROL_SHADER_BIT
+                                .or(vk::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER_BIT)
+                        ),
+                    "tessellation stages require the tessellationShader feature which is not enabled"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04092: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the conditionalRendering feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_CONDITIONAL_RENDERING_BIT_EXT"
+            }
+
+            // checked in dstStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04093: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the fragmentDensityMap feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_FRAGMENT_DENSITY_PROCESS_BIT_EXT"
+            }
+
+            // checked in dstStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04094: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the transformFeedback feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_TRANSFORM_FEEDBACK_BIT_EXT"
+            }
+
+            // checked in dstStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04095: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the meshShader feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_MESH_SHADER_BIT_EXT"
+            }
+
+            // checked in dstStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04096: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the taskShader feature is not enabled, dstStageMask must not contain VK_PIPELINE_STAGE_TASK_SHADER_BIT_EXT"
+            }
+
+            // checked in dstStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_04097: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If neither the shadingRateImage or attachmentFragmentShadingRate are enabled, dstStageMask"
+            "must not contain VK_PIPELINE_STAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR"
+            }
+
+            // checked in dstStageMask_parameter (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_03937: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the synchronization2 feature is not enabled, dstStageMask must not be 0"
+            }
+
+            const { assert!(T::INCLUDES.is_not_empty(), "dst stage must not be empty") }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcAccessMask_02815: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The srcAccessMask member of each element of pMemoryBarriers must only include access"
+            "flags that are supported by one or more of the pipeline stages in srcStageMask, as"
+            "specified in the table of supported access types"
+            }
+
+            const {
+                assert!(
+                    MB::SRC_ACCESS.subset_of(supported_access(S::INCLUDES))
+                        || MB::SRC_ACCESS.is_empty(),
+                    "src access of memory barriers is not supported by the src stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstAccessMask_02816: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The dstAccessMask member of each element of pMemoryBarriers must only include access"
+            "flags that are supported by one or more of the pipeline stages in dstStageMask, as"
+            "specified in the table of supported access types"
+            }
+
+            const {
+                assert!(
+                    MB::DST_ACCESS.subset_of(supported_access(T::INCLUDES))
+                        || MB::DST_ACCESS.is_empty(),
+                    "dst access of memory barriers is not supported by the dst stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pBufferMemoryBarriers_02817: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any element of pBufferMemoryBarriers, if its srcQueueFamilyIndex and dstQueueFamilyIndex"
+            "members are equal, or if its srcQueueFamilyIndex is the queue family index that was"
+            "used to create the command pool that commandBuffer was allocated from, then its srcAccessMask"
+            "member must only contain access flags that are supported by one or more of the pipeline"
+            "stages in srcStageMask, as specified in the table of supported access types"
+            }
+
+            const {
+                assert!(
+                    BB::SRC_ACCESS.subset_of(supported_access(S::INCLUDES))
+                        || BB::SRC_ACCESS.is_empty(),
+                    "src access of buffer memory barriers is not supported by the src stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pBufferMemoryBarriers_02818: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any element of pBufferMemoryBarriers, if its srcQueueFamilyIndex and dstQueueFamilyIndex"
+            "members are equal, or if its dstQueueFamilyIndex is the queue family index that was"
+            "used to create the command pool that commandBuffer was allocated from, then its dstAccessMask"
+            "member must only contain access flags that are supported by one or more of the pipeline"
+            "stages in dstStageMask, as specified in the table of supported access types"
+            }
+
+            const {
+                assert!(
+                    BB::DST_ACCESS.subset_of(supported_access(T::INCLUDES))
+                        || BB::DST_ACCESS.is_empty(),
+                    "dst access of buffer memory barriers is not supported by the dst stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pImageMemoryBarriers_02819: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any element of pImageMemoryBarriers, if its srcQueueFamilyIndex and dstQueueFamilyIndex"
+            "members are equal, or if its srcQueueFamilyIndex is the queue family index that was"
+            "used to create the command pool that commandBuffer was allocated from, then its srcAccessMask"
+            "member must only contain access flags that are supported by one or more of the pipeline"
+            "stages in srcStageMask, as specified in the table of supported access types"
+            }
+
+            const {
+                assert!(
+                    IB::SRC_ACCESS.subset_of(supported_access(S::INCLUDES))
+                        || IB::SRC_ACCESS.is_empty(),
+                    "src access of image memory barriers is not supported by the src stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pImageMemoryBarriers_02820: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "For any element of pImageMemoryBarriers, if its srcQueueFamilyIndex and dstQueueFamilyIndex"
+            "members are equal, or if its dstQueueFamilyIndex is the queue family index that was"
+            "used to create the command pool that commandBuffer was allocated from, then its dstAccessMask"
+            "member must only contain access flags that are supported by one or more of the pipeline"
+            "stages in dstStageMask, as specified in the table of supported access types"
+            }
+
+            const {
+                assert!(
+                    IB::DST_ACCESS.subset_of(supported_access(T::INCLUDES))
+                        || IB::DST_ACCESS.is_empty(),
+                    "dst access of image memory barriers is not supported by the dst stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_None_07889: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance using a VkRenderPass"
+            "object, the render pass must have been created with at least one subpass dependency"
+            "that expresses a dependency from the current subpass to itself, does not include VK_DEPENDENCY_BY_REGION_BIT"
+            "if this command does not, does not include VK_DEPENDENCY_VIEW_LOCAL_BIT if this command"
+            "does not, and has synchronization scopes and access scopes that are all supersets"
+            "of the scopes defined in this command"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_bufferMemoryBarrierCount_01178: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance using a VkRenderPass"
+            "object, it must not include any buffer memory barriers"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_image_04073: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance using a VkRenderPass"
+            "object, the image member of any image memory barrier included in this command must"
+            "be an attachment used in the current subpass both as an input attachment, and as"
+            "either a color, color resolve, or depth/stencil attachment"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_oldLayout_01181: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance, the oldLayout and"
+            "newLayout members of any image memory barrier included in this command must be equal"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcQueueFamilyIndex_01182: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance, the srcQueueFamilyIndex"
+            "and dstQueueFamilyIndex members of any image memory barrier included in this command"
+            "must be equal"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dependencyFlags_07891: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance, and the source stage"
+            "masks of any memory barriers include framebuffer-space stages, destination stage"
+            "masks of all memory barriers must only include framebuffer-space stages"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dependencyFlags_07892: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance, then the set of"
+            "memory barriers must be equal to or a subset of the memory barriers in the corresponding"
+            "subpass dependency"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dependencyFlags_01186: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called outside of a render pass instance, the dependency"
+            "flags must not include VK_DEPENDENCY_VIEW_LOCAL_BIT"
+            }
+
+            // dependencyFlags is always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_None_07890: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If vkCmdPipelineBarrier is called within a render pass instance, and the source stage"
+            "masks of any memory barriers include framebuffer-space stages, then dependencyFlags"
+            "must include VK_DEPENDENCY_BY_REGION_BIT"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_06461: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any pipeline stage included in srcStageMask must be supported by the capabilities"
+            "of the queue family specified by the queueFamilyIndex member of the VkCommandPoolCreateInfo"
+            "structure that was used to create the VkCommandPool that commandBuffer was allocated"
+            "from, as specified in the table of supported pipeline stages"
+            }
+
+            const {
+                assert!(
+                    pipeline_stages_supported(S::INCLUDES, P::Capability::INCLUDES),
+                    "src stage is not supported by the CommandPool capability"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_06462: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any pipeline stage included in dstStageMask must be supported by the capabilities"
+            "of the queue family specified by the queueFamilyIndex member of the VkCommandPoolCreateInfo"
+            "structure that was used to create the VkCommandPool that commandBuffer was allocated"
+            "from, as specified in the table of supported pipeline stages"
+            }
+
+            const {
+                assert!(
+                    pipeline_stages_supported(T::INCLUDES, P::Capability::INCLUDES),
+                    "dst stage is not supported by the CommandPool capability"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_srcStageMask_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "srcStageMask must be a valid combination of VkPipelineStageFlagBits values"
+            }
+
+            const {
+                assert!(
+                    S::INCLUDES.subset_of(CORE_PIPELINE_STAGES),
+                    "only core Vulkan 1.0 pipeline stages are supported"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dstStageMask_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "dstStageMask must be a valid combination of VkPipelineStageFlagBits values"
+            }
+
+            const {
+                assert!(
+                    T::INCLUDES.subset_of(CORE_PIPELINE_STAGES),
+                    "only core Vulkan 1.0 pipeline stages are supported"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_dependencyFlags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "dependencyFlags must be a valid combination of VkDependencyFlagBits values"
+            }
+
+            // dependencyFlags is always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pMemoryBarriers_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If memoryBarrierCount is not 0, pMemoryBarriers must be a valid pointer to an array"
+            "of memoryBarrierCount valid VkMemoryBarrier structures"
+            }
+
+            // ensured by MemoryBarrier creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pBufferMemoryBarriers_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If bufferMemoryBarrierCount is not 0, pBufferMemoryBarriers must be a valid pointer"
+            "to an array of bufferMemoryBarrierCount valid VkBufferMemoryBarrier structures"
+            }
+
+            // ensured by BufferMemoryBarrier creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_pImageMemoryBarriers_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageMemoryBarrierCount is not 0, pImageMemoryBarriers must be a valid pointer"
+            "to an array of imageMemoryBarrierCount valid VkImageMemoryBarrier structures"
+            }
+
+            // ensured by ImageMemoryBarrier creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_commandBuffer_recording: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+
+            // ensured by Recording state type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkCmdPipelineBarrier_commandBuffer_cmdpool: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkCommandPool that commandBuffer was allocated from must support transfer, graphics,"
+            "or compute operations"
+            }
+
+            const {
+                assert!(
+                    P::Capability::INCLUDES.any_of(
+                        vk::QueueFlags::TRANSFER_BIT
+                            .or(vk::QueueFlags::GRAPHICS_BIT)
+                            .or(vk::QueueFlags::COMPUTE_BIT)
+                    ),
+                    "CommandPool must support transfer, graphics, or compute operations"
+                );
+            }
+        }
+
+        let recording = self.recording();
+        let raw_memory_barriers = memory_barriers.raw_barriers(recording);
+        let raw_buffer_memory_barriers = buffer_memory_barriers.raw_barriers(recording);
+        let raw_image_memory_barriers = image_memory_barriers.raw_barriers(recording);
+        let raw_memory_barriers = raw_memory_barriers.as_ref();
+        let raw_buffer_memory_barriers = raw_buffer_memory_barriers.as_ref();
+        let raw_image_memory_barriers = raw_image_memory_barriers.as_ref();
+
+        unsafe {
+            self.commands().CmdPipelineBarrier().get_fptr()(
+                self.raw_handle(),
+                S::INCLUDES,
+                T::INCLUDES,
+                vk::DependencyFlags::empty(),
+                raw_memory_barriers.len() as u32,
+                raw_memory_barriers.as_ptr(),
+                raw_buffer_memory_barriers.len() as u32,
+                raw_buffer_memory_barriers.as_ptr(),
+                raw_image_memory_barriers.len() as u32,
+                raw_image_memory_barriers.as_ptr(),
+            );
+            // the image layout transitions are now recorded
+            image_memory_barriers.transitioned(recording)
+        }
+    }
+}
+
+/// Access types supported by any of the `stages`
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/html/vkspec.html#synchronization-access-types-supported>
+const fn supported_access(stages: vk::PipelineStageFlags) -> vk::AccessFlags {
+    use vk::AccessFlags as Access;
+    use vk::PipelineStageFlags as Stage;
+
+    let all_graphics = Stage::DRAW_INDIRECT_BIT
+        .or(Stage::VERTEX_INPUT_BIT)
+        .or(Stage::VERTEX_SHADER_BIT)
+        .or(Stage::TESSELLATION_CONTROL_SHADER_BIT)
+        .or(Stage::TESSELLATION_EVALUATION_SHADER_BIT)
+        .or(Stage::GEOMETRY_SHADER_BIT)
+        .or(Stage::FRAGMENT_SHADER_BIT)
+        .or(Stage::EARLY_FRAGMENT_TESTS_BIT)
+        .or(Stage::LATE_FRAGMENT_TESTS_BIT)
+        .or(Stage::COLOR_ATTACHMENT_OUTPUT_BIT);
+
+    let mut stages = stages;
+    if stages.any_of(Stage::ALL_COMMANDS_BIT) {
+        stages = stages
+            .or(all_graphics)
+            .or(Stage::COMPUTE_SHADER_BIT)
+            .or(Stage::TRANSFER_BIT)
+            .or(Stage::HOST_BIT);
+    }
+    if stages.any_of(Stage::ALL_GRAPHICS_BIT) {
+        stages = stages.or(all_graphics);
+    }
+
+    let shader_stages = Stage::VERTEX_SHADER_BIT
+        .or(Stage::TESSELLATION_CONTROL_SHADER_BIT)
+        .or(Stage::TESSELLATION_EVALUATION_SHADER_BIT)
+        .or(Stage::GEOMETRY_SHADER_BIT)
+        .or(Stage::FRAGMENT_SHADER_BIT)
+        .or(Stage::COMPUTE_SHADER_BIT);
+
+    let mut supported = Access::MEMORY_READ_BIT.or(Access::MEMORY_WRITE_BIT);
+
+    if stages.any_of(Stage::DRAW_INDIRECT_BIT) {
+        supported = supported.or(Access::INDIRECT_COMMAND_READ_BIT);
+    }
+    if stages.any_of(Stage::VERTEX_INPUT_BIT) {
+        supported = supported
+            .or(Access::INDEX_READ_BIT)
+            .or(Access::VERTEX_ATTRIBUTE_READ_BIT);
+    }
+    if stages.any_of(shader_stages) {
+        supported = supported
+            .or(Access::UNIFORM_READ_BIT)
+            .or(Access::SHADER_READ_BIT)
+            .or(Access::SHADER_WRITE_BIT);
+    }
+    if stages.any_of(Stage::FRAGMENT_SHADER_BIT) {
+        supported = supported.or(Access::INPUT_ATTACHMENT_READ_BIT);
+    }
+    if stages.any_of(Stage::EARLY_FRAGMENT_TESTS_BIT.or(Stage::LATE_FRAGMENT_TESTS_BIT)) {
+        supported = supported
+            .or(Access::DEPTH_STENCIL_ATTACHMENT_READ_BIT)
+            .or(Access::DEPTH_STENCIL_ATTACHMENT_WRITE_BIT);
+    }
+    if stages.any_of(Stage::COLOR_ATTACHMENT_OUTPUT_BIT) {
+        supported = supported
+            .or(Access::COLOR_ATTACHMENT_READ_BIT)
+            .or(Access::COLOR_ATTACHMENT_WRITE_BIT);
+    }
+    if stages.any_of(Stage::TRANSFER_BIT) {
+        supported = supported
+            .or(Access::TRANSFER_READ_BIT)
+            .or(Access::TRANSFER_WRITE_BIT);
+    }
+    if stages.any_of(Stage::HOST_BIT) {
+        supported = supported
+            .or(Access::HOST_READ_BIT)
+            .or(Access::HOST_WRITE_BIT);
+    }
+
+    supported
+}
+
+mod private {
+    use crate::handles::command_buffer::RecordingId;
+    use crate::handles::device_memory::DeviceMemory;
+    use crate::handles::image::{Image, TrackedImage};
+    use crate::handles::queue::ownership_transfer::{Acquire, Release};
+    use crate::structs::{
+        BufferMemoryBarrier, ImageLayoutTransition, ImageMemoryBarrier, MemoryBarrier,
+    };
+
+    use vk_safe_sys as vk;
+
+    use vk::enum_traits::ImageLayout;
+    use vk::flag_traits::AccessFlags;
+
+    /// Queue family ownership transfers which can be recorded for the queue family `F`
+    pub trait OwnershipTransfer<F> {
+        /// The old layout of an image barrier must be the current layout of the Image
+        ///
+        /// A barrier without a layout transition or ownership transfer does not depend on
+        /// the current layout. An acquire operation must use the same layouts as the matching
+        /// release operation, which must execute before it.
+        const CURRENT_LAYOUT: bool;
+    }
+
+    impl<F> OwnershipTransfer<F> for () {
+        const CURRENT_LAYOUT: bool = false;
+    }
+    impl<F, D> OwnershipTransfer<F> for Release<F, D> {
+        const CURRENT_LAYOUT: bool = true;
+    }
+    impl<S, F> OwnershipTransfer<F> for Acquire<S, F> {
+        const CURRENT_LAYOUT: bool = false;
+    }
+
+    /// A single barrier for [`cmd_pipeline_barrier`](super::_CommandBuffer::cmd_pipeline_barrier)
+    ///
+    /// Buffers and Images must outlive `'a`, and queue family ownership transfers must be
+    /// for the queue family `F`. The barrier is recorded in the CommandBuffer recording
+    /// `recording`.
+    pub trait Barrier<'a, D, F> {
+        const SRC_ACCESS: vk::AccessFlags;
+        const DST_ACCESS: vk::AccessFlags;
+        type Raw: Copy;
+        /// The result of recording the barrier (e.g. a TrackedImage with a new layout)
+        type Transitioned;
+        fn raw_barrier(&self, recording: RecordingId) -> Self::Raw;
+        /// The caller must ensure that the barrier is recorded in `recording`
+        unsafe fn transitioned(self, recording: RecordingId) -> Self::Transitioned;
+    }
+
+    impl<'a, D, F, SA: AccessFlags, DA: AccessFlags> Barrier<'a, D, F> for MemoryBarrier<SA, DA> {
+        const SRC_ACCESS: vk::AccessFlags = SA::INCLUDES;
+        const DST_ACCESS: vk::AccessFlags = DA::INCLUDES;
+        type Raw = vk::MemoryBarrier;
+        type Transitioned = ();
+        fn raw_barrier(&self, _recording: RecordingId) -> Self::Raw {
+            **self
+        }
+        unsafe fn transitioned(self, _recording: RecordingId) -> Self::Transitioned {}
+    }
+
+    impl<'a, 'b: 'a, D, F, SA: AccessFlags, DA: AccessFlags, T: OwnershipTransfer<F>>
+        Barrier<'a, D, F> for BufferMemoryBarrier<'b, D, SA, DA, T>
+    {
+        const SRC_ACCESS: vk::AccessFlags = SA::INCLUDES;
+        const DST_ACCESS: vk::AccessFlags = DA::INCLUDES;
+        type Raw = vk::BufferMemoryBarrier;
+        type Transitioned = ();
+        fn raw_barrier(&self, _recording: RecordingId) -> Self::Raw {
+            **self
+        }
+        unsafe fn transitioned(self, _recording: RecordingId) -> Self::Transitioned {}
+    }
+
+    // layout transitions are only recorded with ImageLayoutTransition, so that the
+    // TrackedImage is updated
+    impl<'a, 'b: 'a, D, F, SA: AccessFlags, DA: AccessFlags, T: OwnershipTransfer<F>, L>
+        Barrier<'a, D, F> for ImageMemoryBarrier<'b, D, SA, DA, T, L, L>
+    {
+        const SRC_ACCESS: vk::AccessFlags = SA::INCLUDES;
+        const DST_ACCESS: vk::AccessFlags = DA::INCLUDES;
+        type Raw = vk::ImageMemoryBarrier;
+        type Transitioned = ();
+        fn raw_barrier(&self, recording: RecordingId) -> Self::Raw {
+            // the layout L is only known in the recording which made the last transition
+            assert!(
+                !T::CURRENT_LAYOUT
+                    || self.recording() == RecordingId::NONE
+                    || self.recording() == recording,
+                "the layout of the Image was transitioned in a different CommandBuffer recording"
+            );
+            **self
+        }
+        unsafe fn transitioned(self, _recording: RecordingId) -> Self::Transitioned {}
+    }
+
+    impl<
+            'a,
+            'b: 'a,
+            'm,
+            D,
+            F,
+            I: Image<Device = D>,
+            M: DeviceMemory<Device = D>,
+            SA: AccessFlags,
+            DA: AccessFlags,
+            Old: ImageLayout,
+            New,
+        > Barrier<'a, D, F> for ImageLayoutTransition<'b, 'm, I, M, SA, DA, Old, New>
+    {
+        const SRC_ACCESS: vk::AccessFlags = SA::INCLUDES;
+        const DST_ACCESS: vk::AccessFlags = DA::INCLUDES;
+        type Raw = vk::ImageMemoryBarrier;
+        type Transitioned = TrackedImage<'b, 'm, I, M, New>;
+        fn raw_barrier(&self, recording: RecordingId) -> Self::Raw {
+            ImageLayoutTransition::raw_barrier(self, recording)
+        }
+        unsafe fn transitioned(self, recording: RecordingId) -> Self::Transitioned {
+            ImageLayoutTransition::transitioned(self, recording)
+        }
+    }
+
+    /// Barriers for [`cmd_pipeline_barrier`](super::_CommandBuffer::cmd_pipeline_barrier)
+    ///
+    /// `()` for no barriers, an array of barriers of the same type, or a tuple of barriers of
+    /// different types. All barriers have the raw type `R`.
+    pub trait Barriers<'a, D, F, R> {
+        /// All src access types of the barriers
+        const SRC_ACCESS: vk::AccessFlags;
+        /// All dst access types of the barriers
+        const DST_ACCESS: vk::AccessFlags;
+        type RawBarriers: AsRef<[R]>;
+        /// The result of recording the barriers
+        type Transitioned;
+        fn raw_barriers(&self, recording: RecordingId) -> Self::RawBarriers;
+        /// The caller must ensure that the barriers are recorded in `recording`
+        unsafe fn transitioned(self, recording: RecordingId) -> Self::Transitioned;
+    }
+
+    impl<'a, D, F, R> Barriers<'a, D, F, R> for () {
+        const SRC_ACCESS: vk::AccessFlags = vk::AccessFlags::empty();
+        const DST_ACCESS: vk::AccessFlags = vk::AccessFlags::empty();
+        type RawBarriers = [R; 0];
+        type Transitioned = ();
+        fn raw_barriers(&self, _recording: RecordingId) -> Self::RawBarriers {
+            []
+        }
+        unsafe fn transitioned(self, _recording: RecordingId) -> Self::Transitioned {}
+    }
+
+    impl<'a, D, F, B: Barrier<'a, D, F>, const N: usize> Barriers<'a, D, F, B::Raw> for [B; N] {
+        const SRC_ACCESS: vk::AccessFlags = B::SRC_ACCESS;
+        const DST_ACCESS: vk::AccessFlags = B::DST_ACCESS;
+        type RawBarriers = [B::Raw; N];
+        type Transitioned = [B::Transitioned; N];
+        fn raw_barriers(&self, recording: RecordingId) -> Self::RawBarriers {
+            self.each_ref().map(|b| b.raw_barrier(recording))
+        }
+        unsafe fn transitioned(self, recording: RecordingId) -> Self::Transitioned {
+            // the caller ensures all barriers are recorded
+            self.map(|b| unsafe { b.transitioned(recording) })
+        }
+    }
+
+    macro_rules! tuple_barriers {
+        ( $( $n:literal : $($B:ident)+ ; )* ) => {
+            $(
+                #[allow(non_snake_case)]
+                impl<'a, D, F, R: Copy, $($B: Barrier<'a, D, F, Raw = R>),+> Barriers<'a, D, F, R>
+                    for ($($B,)+)
+                {
+                    const SRC_ACCESS: vk::AccessFlags =
+                        vk::AccessFlags::empty() $( .or($B::SRC_ACCESS) )+;
+                    const DST_ACCESS: vk::AccessFlags =
+                        vk::AccessFlags::empty() $( .or($B::DST_ACCESS) )+;
+                    type RawBarriers = [R; $n];
+                    type Transitioned = ($($B::Transitioned,)+);
+                    fn raw_barriers(&self, recording: RecordingId) -> Self::RawBarriers {
+                        let ($($B,)+) = self;
+                        [$($B.raw_barrier(recording)),+]
+                    }
+                    unsafe fn transitioned(self, recording: RecordingId) -> Self::Transitioned {
+                        let ($($B,)+) = self;
+                        ($($B.transitioned(recording),)+)
+                    }
+                }
+            )*
+        };
+    }
+
+    tuple_barriers!(
+        1: B1;
+        2: B1 B2;
+        3: B1 B2 B3;
+        4: B1 B2 B3 B4;
+        5: B1 B2 B3 B4 B5;
+        6: B1 B2 B3 B4 B5 B6;
+        7: B1 B2 B3 B4 B5 B6 B7;
+        8: B1 B2 B3 B4 B5 B6 B7 B8;
+    );
+}
@@ -0,0 +1,140 @@
+use super::_CommandBuffer;
+use super::command_buffer_state::{Executable, Recording};
+
+use crate::error::VulkanError;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::{DispatchableHandle, Handle};
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::CommandBufferLevel;
+use vk::flag_traits::CommandBufferUsageFlags;
+use vk::has_command::EndCommandBuffer;
+
+impl<
+        'a,
+        D: Device<Commands: EndCommandBuffer>,
+        P: CommandPool,
+        L: CommandBufferLevel,
+        U: CommandBufferUsageFlags,
+    > _CommandBuffer<'a, D, P, L, Recording<U>>
+{
+    /**
+    Finish recording a CommandBuffer
+
+    The CommandBuffer moves to the [`Executable`] state, keeping the usage flags
+    it was begun with.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: vk::Device<Commands: vk::device::VERSION_1_0>, P: vk::CommandPool>
+    #   (command_buffer: vk::_CommandBuffer<D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Initial>) {
+    let begin_info = vk::CommandBufferBeginInfo::new(());
+    let recording = command_buffer.begin_command_buffer(&begin_info).unwrap();
+    // record commands
+    let executable = recording.end_command_buffer().unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkEndCommandBuffer.html>
+    */
+    pub fn end_command_buffer(
+        self,
+    ) -> Result<_CommandBuffer<'a, D, P, L, Executable<U>>, VulkanError> {
+        check_vuids::check_vuids!(EndCommandBuffer);
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_commandBuffer_00059: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be in the recording state"
+            }
+
+            // only available in the Recording state
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_commandBuffer_00060: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a primary command buffer, there must not be an active render pass"
+            "instance"
+            }
+
+            // render passes are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_commandBuffer_00061: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All queries made active during the recording of commandBuffer must have been made"
+            "inactive"
+            }
+
+            // queries are not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_None_01978: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Conditional rendering must not be active"
+            }
+
+            // conditional rendering is not supported yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_commandBuffer_01815: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a secondary command buffer, there must not be an outstanding vkCmdBeginDebugUtilsLabelEXT"
+            "command recorded to commandBuffer that has not previously been ended by a call to"
+            "vkCmdEndDebugUtilsLabelEXT"
+            }
+
+            // labels can only be begun in primary CommandBuffers
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_commandBuffer_00062: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBuffer is a secondary command buffer, there must not be an outstanding vkCmdDebugMarkerBeginEXT"
+            "command recorded to commandBuffer that has not previously been ended by a call to"
+            "vkCmdDebugMarkerEndEXT"
+            }
+
+            // debug markers are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_None_06991: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "There must be no video session object bound"
+            }
+
+            // video coding is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkEndCommandBuffer_commandBuffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "commandBuffer must be a valid VkCommandBuffer handle"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        unsafe {
+            let res = self.commands().EndCommandBuffer().get_fptr()(self.raw_handle());
+            check_raw_err!(res);
+            Ok(self.transition())
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use super::device::Device;
-use super::Handle;
+use super::{DeviceObject, Handle};
+
+use crate::allocation_callbacks::Allocator;
 
 use std::fmt;
 use std::marker::PhantomData;
 
 use vk_safe_sys as vk;
 
-use vk::flag_traits::CommandPoolCreateFlags;
+use vk::flag_traits::{CommandPoolCreateFlags, QueueFlags};
 use vk::has_command::DestroyCommandPool;
 
 /// A memory object for allocating CommandBuffers
@@ -19,12 +21,17 @@ use vk::has_command::DestroyCommandPool;
 /// synchronization is guaranteed without locking.
 ///
 /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkCommandPool.html>
-pub trait CommandPool: Handle<RawHandle = vk::CommandPool> + Send {
+pub trait CommandPool:
+    Handle<RawHandle = vk::CommandPool> + Send + DeviceObject<Self::Device>
+{
     type Device;
 
     type Flags: CommandPoolCreateFlags;
 
     type QueueFamily;
+
+    /// The capability of the queue family, which CommandBuffers from this CommandPool can use
+    type Capability: QueueFlags;
 }
 
 pub(crate) fn make_command_pool<
@@ -32,27 +39,37 @@ pub(crate) fn make_command_pool<
     D: Device<Commands: DestroyCommandPool>,
     F: CommandPoolCreateFlags,
     Q: Send,
+    C: Send + QueueFlags,
+    A: Allocator,
 >(
     handle: vk::CommandPool,
     device: &'a D,
-) -> impl CommandPool<Device = D, Flags = F, QueueFamily = Q> + use<'a, D, F, Q> {
+    allocator: A,
+) -> impl CommandPool<Device = D, Flags = F, QueueFamily = Q, Capability = C> + use<'a, D, F, Q, C, A>
+{
     _CommandPool {
         handle,
         device,
+        allocator,
         flags: PhantomData,
         queue_family: PhantomData,
+        capability: PhantomData,
     }
 }
 
 /// [`CommandPool`] implementor
-struct _CommandPool<'a, D: Device<Commands: DestroyCommandPool>, F, Q> {
+struct _CommandPool<'a, D: Device<Commands: DestroyCommandPool>, F, Q, C, A: Allocator> {
     handle: vk::CommandPool,
     device: &'a D,
+    allocator: A,
     flags: PhantomData<F>,
     queue_family: PhantomData<Q>,
+    capability: PhantomData<C>,
 }
 
-impl<D: Device<Commands: DestroyCommandPool>, F, Q> fmt::Debug for _CommandPool<'_, D, F, Q> {
+impl<D: Device<Commands: DestroyCommandPool>, F, Q, C, A: Allocator> fmt::Debug
+    for _CommandPool<'_, D, F, Q, C, A>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("_CommandPool")
             .field("handle", &self.handle)
@@ -60,7 +77,9 @@ impl<D: Device<Commands: DestroyCommandPool>, F, Q> fmt::Debug for _CommandPool<
     }
 }
 
-impl<D: Device<Commands: DestroyCommandPool>, F, Q> Handle for _CommandPool<'_, D, F, Q> {
+impl<D: Device<Commands: DestroyCommandPool>, F, Q, C, A: Allocator> Handle
+    for _CommandPool<'_, D, F, Q, C, A>
+{
     type RawHandle = vk::CommandPool;
 
     fn raw_handle(&self) -> Self::RawHandle {
@@ -68,17 +87,31 @@ impl<D: Device<Commands: DestroyCommandPool>, F, Q> Handle for _CommandPool<'_,
     }
 }
 
-impl<D: Sync + Device<Commands: DestroyCommandPool>, F: Send + CommandPoolCreateFlags, Q: Send>
-    CommandPool for _CommandPool<'_, D, F, Q>
+impl<D: Device<Commands: DestroyCommandPool>, F, Q, C, A: Allocator> DeviceObject<D>
+    for _CommandPool<'_, D, F, Q, C, A>
+{
+}
+
+impl<
+        D: Sync + Device<Commands: DestroyCommandPool>,
+        F: Send + CommandPoolCreateFlags,
+        Q: Send,
+        C: Send + QueueFlags,
+        A: Allocator,
+    > CommandPool for _CommandPool<'_, D, F, Q, C, A>
 {
     type Device = D;
 
     type Flags = F;
 
     type QueueFamily = Q;
+
+    type Capability = C;
 }
 
-impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q> Drop for _CommandPool<'a, D, F, Q> {
+impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q, C, A: Allocator> Drop
+    for _CommandPool<'a, D, F, Q, C, A>
+{
     fn drop(&mut self) {
         check_vuids::check_vuids!(DestroyCommandPool);
 
@@ -90,11 +123,8 @@ impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q> Drop for _CommandPool<'a
             "state"
             }
 
-            // **************TODO*****************************
-            // after CommandBuffer submission to Queues is possible
-            // it will be necessary to consider how we ensure that
-            // Queues have finished with the CommandBuffers
-            // before we start destroying everything
+            // pending CommandBuffers borrow the CommandPool (through the CommandBuffer lifetime),
+            // and queue_submit does not return until the submitted work has completed
         }
 
         #[allow(unused_labels)]
@@ -105,8 +135,7 @@ impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q> Drop for _CommandPool<'a
             "set of callbacks must be provided here"
             }
 
-            // TODO
-            // no AllocationCallbacks for now
+            // the same Allocator that was used to create the CommandPool is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -117,9 +146,7 @@ impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q> Drop for _CommandPool<'a
             "must be NULL"
             }
 
-            // TODO
-            // no AllocationCallbacks for now
-            // always null set below
+            // the same Allocator that was used to create the CommandPool is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -150,9 +177,7 @@ impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q> Drop for _CommandPool<'a
             "structure"
             }
 
-            // TODO
-            // no AllocationCallbacks for now
-            // always null set below
+            // ensured by Allocator
         }
 
         #[allow(unused_labels)]
@@ -170,7 +195,7 @@ impl<'a, D: Device<Commands: DestroyCommandPool>, F, Q> Drop for _CommandPool<'a
             self.device.commands().DestroyCommandPool().get_fptr()(
                 self.device.raw_handle(),
                 self.handle,
-                std::ptr::null(),
+                self.allocator.callbacks(),
             );
         }
     }
@@ -0,0 +1,176 @@
+use super::instance::Instance;
+use super::{Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+use crate::debug_utils::DebugUtilsMessengerCallback;
+
+use std::fmt;
+use std::sync::Arc;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::DestroyDebugUtilsMessengerEXT;
+
+/// DebugUtilsMessenger handle trait
+///
+/// Represents a *specific* DebugUtilsMessenger, which reports debug messages to its callback
+/// until it is dropped.
+///
+/// Created with [`create_debug_utils_messenger`](crate::vk::create_debug_utils_messenger).
+pub trait DebugUtilsMessenger:
+    Handle<RawHandle = vk::DebugUtilsMessengerEXT> + ThreadSafeHandle
+{
+    /// The *specific* Instance to which this DebugUtilsMessenger belongs
+    type Instance: Instance;
+}
+
+/// [`DebugUtilsMessenger`] implementor
+struct _DebugUtilsMessenger<'a, I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator>
+{
+    handle: vk::DebugUtilsMessengerEXT,
+    instance: &'a I,
+    allocator: A,
+    // called by Vulkan until the messenger is destroyed
+    _callback: Arc<dyn DebugUtilsMessengerCallback>,
+}
+
+pub(crate) fn make_debug_utils_messenger<
+    'a,
+    I: Instance<Commands: DestroyDebugUtilsMessengerEXT>,
+    A: Allocator,
+>(
+    handle: vk::DebugUtilsMessengerEXT,
+    instance: &'a I,
+    allocator: A,
+    callback: Arc<dyn DebugUtilsMessengerCallback>,
+) -> impl DebugUtilsMessenger<Instance = I> + use<'a, I, A> {
+    _DebugUtilsMessenger {
+        handle,
+        instance,
+        allocator,
+        _callback: callback,
+    }
+}
+
+impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> DebugUtilsMessenger
+    for _DebugUtilsMessenger<'_, I, A>
+{
+    type Instance = I;
+}
+
+unsafe impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> Send
+    for _DebugUtilsMessenger<'_, I, A>
+{
+}
+unsafe impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> Sync
+    for _DebugUtilsMessenger<'_, I, A>
+{
+}
+impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> ThreadSafeHandle
+    for _DebugUtilsMessenger<'_, I, A>
+{
+}
+
+impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> Handle
+    for _DebugUtilsMessenger<'_, I, A>
+{
+    type RawHandle = vk::DebugUtilsMessengerEXT;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> fmt::Debug
+    for _DebugUtilsMessenger<'_, I, A>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugUtilsMessenger")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<I: Instance<Commands: DestroyDebugUtilsMessengerEXT>, A: Allocator> Drop
+    for _DebugUtilsMessenger<'_, I, A>
+{
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyDebugUtilsMessengerEXT);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDebugUtilsMessengerEXT_messenger_01915: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when messenger was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the DebugUtilsMessenger is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDebugUtilsMessengerEXT_messenger_01916: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when messenger was created, pAllocator"
+            "must be NULL"
+            }
+
+            // the same Allocator that was used to create the DebugUtilsMessenger is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDebugUtilsMessengerEXT_instance_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "instance must be a valid VkInstance handle"
+            }
+
+            // ensured by Instance creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDebugUtilsMessengerEXT_messenger_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If messenger is not VK_NULL_HANDLE, messenger must be a valid VkDebugUtilsMessengerEXT"
+            "handle"
+            }
+
+            // ensured by DebugUtilsMessenger creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDebugUtilsMessengerEXT_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDebugUtilsMessengerEXT_messenger_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If messenger is a valid handle, it must have been created, allocated, or retrieved"
+            "from instance"
+            }
+
+            // ensured by DebugUtilsMessenger creation
+        }
+
+        unsafe {
+            self.instance
+                .commands()
+                .DestroyDebugUtilsMessengerEXT()
+                .get_fptr()(
+                self.instance.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
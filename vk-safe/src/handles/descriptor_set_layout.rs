@@ -0,0 +1,274 @@
+use super::device::Device;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::DestroyDescriptorSetLayout;
+
+/// DescriptorSetLayout handle trait
+///
+/// Represents a *specific* DescriptorSetLayout.
+pub trait DescriptorSetLayout:
+    Handle<RawHandle = vk::DescriptorSetLayout> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this DescriptorSetLayout belongs
+    type Device: Device;
+
+    /// Number of descriptors in the DescriptorSetLayout, per descriptor type and shader stage
+    fn descriptor_counts(&self) -> &DescriptorCounts;
+}
+
+/// Core descriptor types, in the order used by [`DescriptorCounts`]
+pub(crate) const CORE_DESCRIPTOR_TYPES: [vk::DescriptorType; 11] = [
+    vk::DescriptorType::SAMPLER,
+    vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+    vk::DescriptorType::SAMPLED_IMAGE,
+    vk::DescriptorType::STORAGE_IMAGE,
+    vk::DescriptorType::UNIFORM_TEXEL_BUFFER,
+    vk::DescriptorType::STORAGE_TEXEL_BUFFER,
+    vk::DescriptorType::UNIFORM_BUFFER,
+    vk::DescriptorType::STORAGE_BUFFER,
+    vk::DescriptorType::UNIFORM_BUFFER_DYNAMIC,
+    vk::DescriptorType::STORAGE_BUFFER_DYNAMIC,
+    vk::DescriptorType::INPUT_ATTACHMENT,
+];
+
+/// Core shader stages, in the order used by [`DescriptorCounts`]
+pub(crate) const CORE_SHADER_STAGES: [vk::ShaderStageFlags; 6] = [
+    vk::ShaderStageFlags::VERTEX_BIT,
+    vk::ShaderStageFlags::TESSELLATION_CONTROL_BIT,
+    vk::ShaderStageFlags::TESSELLATION_EVALUATION_BIT,
+    vk::ShaderStageFlags::GEOMETRY_BIT,
+    vk::ShaderStageFlags::FRAGMENT_BIT,
+    vk::ShaderStageFlags::COMPUTE_BIT,
+];
+
+/// Number of descriptors per descriptor type and shader stage
+///
+/// Used to check the descriptor limits of the PhysicalDevice when creating a
+/// [`PipelineLayout`](crate::vk::PipelineLayout).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DescriptorCounts {
+    per_stage: [[u32; CORE_DESCRIPTOR_TYPES.len()]; CORE_SHADER_STAGES.len()],
+    total: [u32; CORE_DESCRIPTOR_TYPES.len()],
+}
+
+impl DescriptorCounts {
+    /// Count the descriptors of the bindings
+    ///
+    /// All bindings must only use core descriptor types and shader stages,
+    /// as ensured by [`DescriptorSetLayoutBinding`](crate::vk::DescriptorSetLayoutBinding)
+    pub(crate) fn from_bindings(bindings: &[vk::DescriptorSetLayoutBinding]) -> Self {
+        let mut counts = Self::default();
+        for binding in bindings {
+            let ty = CORE_DESCRIPTOR_TYPES
+                .iter()
+                .position(|t| t.is(binding.descriptor_type))
+                .expect("vk-safe internal error: DescriptorSetLayoutBinding with non core descriptor type");
+            counts.total[ty] = counts.total[ty].saturating_add(binding.descriptor_count);
+            for (stage, flag) in CORE_SHADER_STAGES.iter().enumerate() {
+                if binding.stage_flags.contains(*flag) {
+                    counts.per_stage[stage][ty] =
+                        counts.per_stage[stage][ty].saturating_add(binding.descriptor_count);
+                }
+            }
+        }
+        counts
+    }
+
+    /// Add the descriptors of `other`
+    pub(crate) fn add(&mut self, other: &Self) {
+        for (a, b) in self.total.iter_mut().zip(other.total) {
+            *a = a.saturating_add(b);
+        }
+        for (a, b) in self.per_stage.iter_mut().zip(other.per_stage) {
+            for (a, b) in a.iter_mut().zip(b) {
+                *a = a.saturating_add(b);
+            }
+        }
+    }
+
+    /// Total number of descriptors of the given types
+    pub(crate) fn total(&self, types: &[vk::DescriptorType]) -> u32 {
+        Self::sum(&self.total, types)
+    }
+
+    /// Largest number of descriptors of the given types accessible to any single shader stage
+    pub(crate) fn max_per_stage(&self, types: &[vk::DescriptorType]) -> u32 {
+        self.per_stage
+            .iter()
+            .map(|counts| Self::sum(counts, types))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn sum(counts: &[u32; CORE_DESCRIPTOR_TYPES.len()], types: &[vk::DescriptorType]) -> u32 {
+        CORE_DESCRIPTOR_TYPES
+            .iter()
+            .zip(counts)
+            .filter(|(t, _)| types.iter().any(|other| t.is(*other)))
+            .fold(0, |sum, (_, count)| sum.saturating_add(*count))
+    }
+}
+
+/// [`DescriptorSetLayout`] implementor
+struct _DescriptorSetLayout<'a, D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> {
+    handle: vk::DescriptorSetLayout,
+    device: &'a D,
+    allocator: A,
+    counts: DescriptorCounts,
+}
+
+pub(crate) fn make_descriptor_set_layout<
+    'a,
+    D: Device<Commands: DestroyDescriptorSetLayout>,
+    A: Allocator,
+>(
+    handle: vk::DescriptorSetLayout,
+    device: &'a D,
+    allocator: A,
+    counts: DescriptorCounts,
+) -> impl DescriptorSetLayout<Device = D> + use<'a, D, A> {
+    _DescriptorSetLayout {
+        handle,
+        device,
+        allocator,
+        counts,
+    }
+}
+
+impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> DescriptorSetLayout
+    for _DescriptorSetLayout<'_, D, A>
+{
+    type Device = D;
+
+    fn descriptor_counts(&self) -> &DescriptorCounts {
+        &self.counts
+    }
+}
+
+unsafe impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> Send
+    for _DescriptorSetLayout<'_, D, A>
+{
+}
+unsafe impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> Sync
+    for _DescriptorSetLayout<'_, D, A>
+{
+}
+impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> ThreadSafeHandle
+    for _DescriptorSetLayout<'_, D, A>
+{
+}
+
+impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> Handle
+    for _DescriptorSetLayout<'_, D, A>
+{
+    type RawHandle = vk::DescriptorSetLayout;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> DeviceObject<D>
+    for _DescriptorSetLayout<'_, D, A>
+{
+}
+
+impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> fmt::Debug
+    for _DescriptorSetLayout<'_, D, A>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DescriptorSetLayout")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroyDescriptorSetLayout>, A: Allocator> Drop
+    for _DescriptorSetLayout<'_, D, A>
+{
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyDescriptorSetLayout);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDescriptorSetLayout_descriptorSetLayout_00284: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when descriptorSetLayout was created, a compatible"
+            "set of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the DescriptorSetLayout is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDescriptorSetLayout_descriptorSetLayout_00285: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when descriptorSetLayout was created, pAllocator"
+            "must be NULL"
+            }
+
+            // the same Allocator that was used to create the DescriptorSetLayout is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDescriptorSetLayout_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDescriptorSetLayout_descriptorSetLayout_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorSetLayout is not VK_NULL_HANDLE, descriptorSetLayout must be a valid"
+            "VkDescriptorSetLayout handle"
+            }
+
+            // ensured by DescriptorSetLayout creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDescriptorSetLayout_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyDescriptorSetLayout_descriptorSetLayout_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorSetLayout is a valid handle, it must have been created, allocated, or"
+            "retrieved from device"
+            }
+
+            // ensured by DescriptorSetLayout creation
+        }
+
+        unsafe {
+            self.device
+                .commands()
+                .DestroyDescriptorSetLayout()
+                .get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
@@ -1,13 +1,18 @@
+use super::buffer::{self, BoundBuffer};
 use super::command_buffer::_CommandBuffers;
 use super::device_memory::{DeviceMemory, MappedMemory};
+use super::image::{BoundImage, Image};
 use super::physical_device::PhysicalDevice;
-use super::{DispatchableHandle, Handle, ThreadSafeHandle};
+use super::{DeviceObject, DispatchableHandle, Handle, ThreadSafeHandle};
 
+use crate::allocation_callbacks::Allocator;
 use crate::buffer::Buffer;
-use crate::error::Error;
+use crate::error::VulkanError;
+use crate::features::Features;
 use crate::flags::{Excludes, Includes};
 use crate::scope::Tag;
 use crate::structs::*;
+use crate::VkStr;
 use crate::VkVersion;
 
 use std::fmt;
@@ -26,12 +31,30 @@ pub_use_modules!(
     allocate_memory;
     map_memory;
     flush_mapped_memory_ranges;
+    invalidate_mapped_memory_ranges;
     unmap_memory;
     wait_idle;
     get_device_queues;
     create_command_pool;
     allocate_command_buffers;
     create_shader_module;
+    create_buffer;
+    get_buffer_memory_requirements;
+    bind_buffer_memory;
+    create_image;
+    create_image_view;
+    create_sampler;
+    create_fence;
+    create_semaphore;
+    get_image_memory_requirements;
+    bind_image_memory;
+    create_descriptor_set_layout;
+    create_pipeline_layout;
+    create_compute_pipelines;
+};
+#[cfg(VK_EXT_debug_utils)] {
+    set_debug_utils_object_name;
+    set_debug_utils_object_tag;
 };
 );
 
@@ -41,6 +64,11 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     type PhysicalDevice: PhysicalDevice;
     type QueueConfig;
 
+    /// The features which were enabled when the Device was created
+    ///
+    /// See [`DeviceCreateInfo::enable_features`](crate::vk::DeviceCreateInfo::enable_features)
+    type Features: Features;
+
     // ****TODO: if `use<>` becomes available in RPITIT, then this can be uncommented
     // #[cfg(VK_VERSION_1_0)]
     // /// Allocate memory on the Device
@@ -82,6 +110,9 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     #[cfg(VK_VERSION_1_0)]
     /// Map memory for host access
     ///
+    /// Provide a [`MemoryMapRange`] to indicate the range of the memory to map. The range
+    /// is checked against the size of the memory, and must be aligned to `nonCoherentAtomSize`.
+    ///
     /// ```rust
     /// # use vk_safe::vk;
     /// # use vk::MemoryHeapFlags::MULTI_INSTANCE_BIT;
@@ -91,15 +122,15 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     /// #    P: vk::Includes<HOST_VISIBLE_BIT>,
     /// #    H: vk::Excludes<MULTI_INSTANCE_BIT>
     /// # >
-    /// #   (device: D, memory: impl vk::DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H>) {
-    /// let mapped_memory = device.map_memory(memory);
+    /// #   (device: D, memory: impl vk::DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H>,
+    /// #   properties: vk::PhysicalDeviceProperties<D::PhysicalDevice>) {
+    /// let range = vk::MemoryMapRange::whole(&properties);
+    /// let mut mapped_memory = device.map_memory(memory, range).unwrap();
+    /// // SAFETY: the memory was just allocated, so it is not used by the device
+    /// unsafe { mapped_memory.as_mut_slice().fill(0) };
     /// # }
     /// ```
     ///
-    /// ### Note
-    /// *currently this can only be used to map the whole memory range. There may be breaking change in
-    /// future to make the API more inline with the real `vkMapMemory`, which allows mapping sub ranges*
-    ///
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkMapMemory.html>
     fn map_memory<
         M: DeviceMemory<
@@ -110,21 +141,28 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     >(
         &self,
         memory: M,
-    ) -> Result<MappedMemory<M>, Error>
+        range: MemoryMapRange<Self::PhysicalDevice>,
+    ) -> Result<MappedMemory<M>, VulkanError>
     where
         Self::Commands: vk::has_command::MapMemory,
     {
-        map_memory(self, memory)
+        map_memory(self, memory, range)
     }
 
     #[cfg(VK_VERSION_1_0)]
     /// Flush memory to make host writes visible to the device
     ///
+    /// Only memory which is **not** `HOST_COHERENT` needs to be flushed. [`MappedMemoryRange`]
+    /// can only be created for memory which excludes `HOST_COHERENT_BIT`, so trying to flush
+    /// coherent memory is a compile time error. When using memory which is not coherent, host
+    /// writes **must** be flushed before they are used by the device.
+    ///
     /// ```
     /// # use vk_safe::vk;
+    /// # use vk::MemoryPropertyFlags::HOST_COHERENT_BIT;
     /// # fn tst<
     /// #    D: vk::Device<Commands: vk::device::VERSION_1_0>,
-    /// #    M: vk::DeviceMemory<Device = D>
+    /// #    M: vk::DeviceMemory<Device = D, PropertyFlags: vk::Excludes<HOST_COHERENT_BIT>>
     /// # >
     /// #   (device: D, mapped_memory: vk::MappedMemory<M>) {
     /// let ranges = [vk::MappedMemoryRange::whole_range(&mapped_memory)];
@@ -133,13 +171,51 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     /// ```
     ///
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkFlushMappedMemoryRanges.html>
-    fn flush_mapped_memory_ranges(&self, ranges: &[MappedMemoryRange<Self>]) -> Result<(), Error>
+    fn flush_mapped_memory_ranges(
+        &self,
+        ranges: &[MappedMemoryRange<Self>],
+    ) -> Result<(), VulkanError>
     where
         Self::Commands: vk::has_command::FlushMappedMemoryRanges,
     {
         flush_mapped_memory_ranges(self, ranges)
     }
 
+    #[cfg(VK_VERSION_1_0)]
+    /// Invalidate memory to make device writes visible to the host
+    ///
+    /// Only memory which is **not** `HOST_COHERENT` needs to be invalidated. [`MappedMemoryRange`]
+    /// can only be created for memory which excludes `HOST_COHERENT_BIT`, so trying to invalidate
+    /// coherent memory is a compile time error. When using memory which is not coherent, the memory
+    /// **must** be invalidated before reading device writes on the host. This is not tracked, and
+    /// is the responsibility of the caller of the `unsafe` [`MappedMemory::as_slice`] accessors.
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// # use vk::MemoryPropertyFlags::HOST_COHERENT_BIT;
+    /// # fn tst<
+    /// #    D: vk::Device<Commands: vk::device::VERSION_1_0>,
+    /// #    M: vk::DeviceMemory<Device = D, PropertyFlags: vk::Excludes<HOST_COHERENT_BIT>>
+    /// # >
+    /// #   (device: D, mapped_memory: vk::MappedMemory<M>) {
+    /// let ranges = [vk::MappedMemoryRange::whole_range(&mapped_memory)];
+    /// device.invalidate_mapped_memory_ranges(&ranges).unwrap();
+    /// // SAFETY: e.g. after waiting for the submission which wrote to the memory
+    /// let data = unsafe { mapped_memory.as_slice() };
+    /// # }
+    /// ```
+    ///
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkInvalidateMappedMemoryRanges.html>
+    fn invalidate_mapped_memory_ranges(
+        &self,
+        ranges: &[MappedMemoryRange<Self>],
+    ) -> Result<(), VulkanError>
+    where
+        Self::Commands: vk::has_command::InvalidateMappedMemoryRanges,
+    {
+        invalidate_mapped_memory_ranges(self, ranges)
+    }
+
     #[cfg(VK_VERSION_1_0)]
     /// Unmap memory for host access
     ///
@@ -182,7 +258,7 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     /// let result = unsafe { device.wait_idle() };
     /// # }
     /// ```
-    unsafe fn wait_idle(&self) -> Result<(), Error>
+    unsafe fn wait_idle(&self) -> Result<(), VulkanError>
     where
         Self::Commands: vk::has_command::DeviceWaitIdle,
     {
@@ -235,7 +311,7 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     // fn create_command_pool<'a, F, T>(
     //     &'a self,
     //     create_info: &CommandPoolCreateInfo<Self, F, T>,
-    // ) -> Result<_CommandPool<'a, Self, F, T>, Error>
+    // ) -> Result<_CommandPool<'a, Self, F, T>, VulkanError>
     // where
     //     Self::Commands: vk::has_command::CreateCommandPool + vk::has_command::DestroyCommandPool,
     // {
@@ -267,8 +343,8 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
      */
     fn allocate_command_buffers<'a, Pool, Level, B: Buffer<vk::CommandBuffer>>(
         &'a self,
-        info: CommandBufferAllocateInfo<'_, B, Pool, Level>,
-    ) -> Result<_CommandBuffers<'a, Self, Level, B>, Error>
+        info: CommandBufferAllocateInfo<'a, B, Pool, Level>,
+    ) -> Result<_CommandBuffers<'a, Self, Pool, Level, B>, VulkanError>
     where
         Self::Commands: vk::has_command::AllocateCommandBuffers,
     {
@@ -290,12 +366,217 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
     // fn create_shader_module<'a>(
     //     &'a self,
     //     info: &ShaderModuleCreateInfo,
-    // ) -> Result<_ShaderModule<'a, Self>, Error>
+    // ) -> Result<_ShaderModule<'a, Self>, VulkanError>
     // where
     //     Self::Commands: vk::has_command::CreateShaderModule + vk::has_command::DestroyShaderModule,
     // {
     //     create_shader_module(self, info)
     // }
+
+    #[cfg(VK_VERSION_1_0)]
+    /**
+    Get the memory requirements of a Buffer
+
+    The returned [`MemoryRequirements`] can only be used with the *specific* Buffer
+    they were queried for.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: Device<Commands: vk::device::VERSION_1_0>>
+    #   (device: D, buffer: impl vk::Buffer<Device = D>) {
+    let requirements = device.get_buffer_memory_requirements(&buffer);
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements.html>
+     */
+    fn get_buffer_memory_requirements<B: buffer::Buffer<Device = Self>>(
+        &self,
+        buffer: &B,
+    ) -> MemoryRequirements<Self::PhysicalDevice, B>
+    where
+        Self::Commands: vk::has_command::GetBufferMemoryRequirements,
+    {
+        get_buffer_memory_requirements(self, buffer)
+    }
+
+    #[cfg(VK_VERSION_1_0)]
+    /**
+    Bind a Buffer to DeviceMemory
+
+    The Buffer is moved into the returned [`BoundBuffer`], which borrows the
+    memory for as long as the Buffer is bound to it.
+
+    Provide the [`MemoryRequirements`] of the Buffer. Returns an error if
+    the memory type of `memory` is not allowed for the Buffer, if `offset` is
+    not a multiple of the required alignment, or if the Buffer does not fit
+    in `memory` at `offset`.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: Device<Commands: vk::device::VERSION_1_0>>
+    #   (device: D, buffer: impl vk::Buffer<Device = D>, memory: impl vk::DeviceMemory<Device = D>) {
+    let requirements = device.get_buffer_memory_requirements(&buffer);
+    let bound_buffer = device
+        .bind_buffer_memory(buffer, &memory, 0, &requirements)
+        .unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkBindBufferMemory.html>
+     */
+    fn bind_buffer_memory<'m, B: buffer::Buffer<Device = Self>, M: DeviceMemory<Device = Self>>(
+        &self,
+        buffer: B,
+        memory: &'m M,
+        offset: vk::DeviceSize,
+        requirements: &MemoryRequirements<Self::PhysicalDevice, B>,
+    ) -> Result<BoundBuffer<'m, B, M>, VulkanError>
+    where
+        Self::Commands: vk::has_command::BindBufferMemory,
+    {
+        bind_buffer_memory(self, buffer, memory, offset, requirements)
+    }
+
+    #[cfg(VK_VERSION_1_0)]
+    /**
+    Get the memory requirements of an Image
+
+    The returned [`MemoryRequirements`] can only be used with the *specific* Image
+    they were queried for.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: Device<Commands: vk::device::VERSION_1_0>>
+    #   (device: D, image: impl vk::Image<Device = D>) {
+    let requirements = device.get_image_memory_requirements(&image);
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements.html>
+     */
+    fn get_image_memory_requirements<I: Image<Device = Self>>(
+        &self,
+        image: &I,
+    ) -> MemoryRequirements<Self::PhysicalDevice, I>
+    where
+        Self::Commands: vk::has_command::GetImageMemoryRequirements,
+    {
+        get_image_memory_requirements(self, image)
+    }
+
+    #[cfg(VK_VERSION_1_0)]
+    /**
+    Bind an Image to DeviceMemory
+
+    The Image is moved into the returned [`BoundImage`], which borrows the
+    memory for as long as the Image is bound to it.
+
+    Provide the [`MemoryRequirements`] of the Image. Returns an error if
+    the memory type of `memory` is not allowed for the Image, if `offset` is
+    not a multiple of the required alignment, or if the Image does not fit
+    in `memory` at `offset`.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: Device<Commands: vk::device::VERSION_1_0>>
+    #   (device: D, image: impl vk::Image<Device = D>, memory: impl vk::DeviceMemory<Device = D>) {
+    let requirements = device.get_image_memory_requirements(&image);
+    let bound_image = device
+        .bind_image_memory(image, &memory, 0, &requirements)
+        .unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkBindImageMemory.html>
+     */
+    fn bind_image_memory<'m, I: Image<Device = Self>, M: DeviceMemory<Device = Self>>(
+        &self,
+        image: I,
+        memory: &'m M,
+        offset: vk::DeviceSize,
+        requirements: &MemoryRequirements<Self::PhysicalDevice, I>,
+    ) -> Result<BoundImage<'m, I, M>, VulkanError>
+    where
+        Self::Commands: vk::has_command::BindImageMemory,
+    {
+        bind_image_memory(self, image, memory, offset, requirements)
+    }
+
+    #[cfg(VK_EXT_debug_utils)]
+    /**
+    Give an object a name, for debugging
+
+    The name is used by layers and tools to identify the object (e.g. in
+    validation messages reported to a [`DebugUtilsMessenger`](crate::vk::DebugUtilsMessenger)).
+    Any object created, allocated, or retrieved from this Device can be named.
+
+    The object is mutably borrowed, since naming it requires external
+    synchronization of the object.
+
+    Requires `EXT_debug_utils` in the device context, which can only be used
+    with an Instance which also has `EXT_debug_utils` in the instance context.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: Device<Commands: vk::device::VERSION_1_0 + vk::device::EXT_debug_utils>>
+    #   (device: D, mut memory: impl vk::DeviceMemory<Device = D>) {
+    device
+        .set_debug_name(&mut memory, vk_safe::vk_str!("vertex memory"))
+        .unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkSetDebugUtilsObjectNameEXT.html>
+     */
+    fn set_debug_name<H: DeviceObject<Self, RawHandle: vk::ObjectHandle>>(
+        &self,
+        object: &mut H,
+        name: VkStr<'_>,
+    ) -> Result<(), VulkanError>
+    where
+        Self::Commands: vk::has_command::SetDebugUtilsObjectNameEXT,
+    {
+        set_debug_utils_object_name(self, object, name)
+    }
+
+    #[cfg(VK_EXT_debug_utils)]
+    /**
+    Attach arbitrary data to an object, for debugging
+
+    The data is identified by `tag_name`, and is used by layers and tools which
+    understand the tag. Returns an error if `tag` is empty.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<D: Device<Commands: vk::device::VERSION_1_0 + vk::device::EXT_debug_utils>>
+    #   (device: D, mut buffer: impl vk::Buffer<Device = D>) {
+    const MY_TOOL_TAG: u64 = 0x1234;
+    device
+        .set_debug_tag(&mut buffer, MY_TOOL_TAG, b"tool specific data")
+        .unwrap();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkSetDebugUtilsObjectTagEXT.html>
+     */
+    fn set_debug_tag<H: DeviceObject<Self, RawHandle: vk::ObjectHandle>>(
+        &self,
+        object: &mut H,
+        tag_name: u64,
+        tag: &[u8],
+    ) -> Result<(), VulkanError>
+    where
+        Self::Commands: vk::has_command::SetDebugUtilsObjectTagEXT,
+    {
+        set_debug_utils_object_tag(self, object, tag_name, tag)
+    }
 }
 
 // #[allow(unused)]
@@ -314,33 +595,46 @@ pub trait Device: DispatchableHandle<RawHandle = vk::Device> + ThreadSafeHandle
 // }
 
 /// [`Device`] implementor
-struct _Device<C: DestroyDevice, P, Q, T> {
+struct _Device<C: DestroyDevice, A: Allocator, P, Q, F, T> {
     handle: vk::Device,
     commands: C,
+    allocator: A,
     tag: PhantomData<T>,
     physical_device: PhantomData<P>,
     queue_config: PhantomData<Q>,
+    features: PhantomData<F>,
 }
 
-pub(crate) fn make_device<'t, C: DestroyDevice + Version, P: PhysicalDevice, Q>(
+pub(crate) fn make_device<
+    't,
+    C: DestroyDevice + Version,
+    A: Allocator,
+    P: PhysicalDevice,
+    Q,
+    F: Features,
+>(
     handle: vk::Device,
     commands: C,
+    allocator: A,
     _tag: Tag<'t>,
-) -> impl Device<Commands = C, QueueConfig = Q, PhysicalDevice = P> + use<'t, C, P, Q> {
+) -> impl Device<Commands = C, QueueConfig = Q, PhysicalDevice = P, Features = F> + use<'t, C, A, P, Q, F>
+{
     _Device {
         handle,
         commands,
+        allocator,
         tag: PhantomData::<Tag<'t>>,
         physical_device: PhantomData,
         queue_config: PhantomData,
+        features: PhantomData,
     }
 }
 
-unsafe impl<C: DestroyDevice, P, Q, T> Send for _Device<C, P, Q, T> {}
-unsafe impl<C: DestroyDevice, P, Q, T> Sync for _Device<C, P, Q, T> {}
-impl<C: DestroyDevice, P, Q, T> ThreadSafeHandle for _Device<C, P, Q, T> {}
+unsafe impl<C: DestroyDevice, A: Allocator, P, Q, F, T> Send for _Device<C, A, P, Q, F, T> {}
+unsafe impl<C: DestroyDevice, A: Allocator, P, Q, F, T> Sync for _Device<C, A, P, Q, F, T> {}
+impl<C: DestroyDevice, A: Allocator, P, Q, F, T> ThreadSafeHandle for _Device<C, A, P, Q, F, T> {}
 
-impl<C: DestroyDevice, P, Q, T> fmt::Debug for _Device<C, P, Q, T> {
+impl<C: DestroyDevice, A: Allocator, P, Q, F, T> fmt::Debug for _Device<C, A, P, Q, F, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("_Device")
             .field("handle", &self.handle)
@@ -348,7 +642,7 @@ impl<C: DestroyDevice, P, Q, T> fmt::Debug for _Device<C, P, Q, T> {
     }
 }
 
-impl<C: DestroyDevice, P, Q, T> Handle for _Device<C, P, Q, T> {
+impl<C: DestroyDevice, A: Allocator, P, Q, F, T> Handle for _Device<C, A, P, Q, F, T> {
     type RawHandle = vk::Device;
 
     fn raw_handle(&self) -> Self::RawHandle {
@@ -356,7 +650,7 @@ impl<C: DestroyDevice, P, Q, T> Handle for _Device<C, P, Q, T> {
     }
 }
 
-impl<C: DestroyDevice, P, Q, T> DispatchableHandle for _Device<C, P, Q, T> {
+impl<C: DestroyDevice, A: Allocator, P, Q, F, T> DispatchableHandle for _Device<C, A, P, Q, F, T> {
     type Commands = C;
 
     fn commands(&self) -> &Self::Commands {
@@ -364,14 +658,17 @@ impl<C: DestroyDevice, P, Q, T> DispatchableHandle for _Device<C, P, Q, T> {
     }
 }
 
-impl<C: DestroyDevice + Version, P: PhysicalDevice, Q, T> Device for _Device<C, P, Q, T> {
+impl<C: DestroyDevice + Version, A: Allocator, P: PhysicalDevice, Q, F: Features, T> Device
+    for _Device<C, A, P, Q, F, T>
+{
     const VERSION: VkVersion = C::VERSION;
 
     type PhysicalDevice = P;
     type QueueConfig = Q;
+    type Features = F;
 }
 
-impl<C: DestroyDevice, P, Q, T> Drop for _Device<C, P, Q, T> {
+impl<C: DestroyDevice, A: Allocator, P, Q, F, T> Drop for _Device<C, A, P, Q, F, T> {
     fn drop(&mut self) {
         check_vuids::check_vuids!(DestroyDevice);
 
@@ -393,7 +690,7 @@ impl<C: DestroyDevice, P, Q, T> Drop for _Device<C, P, Q, T> {
             "callbacks must be provided here"
             }
 
-            // TODO always null for now
+            // the same Allocator that was used to create the Device is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -404,7 +701,7 @@ impl<C: DestroyDevice, P, Q, T> Drop for _Device<C, P, Q, T> {
             "be NULL"
             }
 
-            // TODO always null for now
+            // the same Allocator that was used to create the Device is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -425,9 +722,9 @@ impl<C: DestroyDevice, P, Q, T> Drop for _Device<C, P, Q, T> {
             "structure"
             }
 
-            // TODO always null for now
+            // ensured by Allocator
         }
 
-        unsafe { self.commands.DestroyDevice().get_fptr()(self.handle, std::ptr::null()) }
+        unsafe { self.commands.DestroyDevice().get_fptr()(self.handle, self.allocator.callbacks()) }
     }
 }
@@ -1,7 +1,7 @@
 use super::Device;
 
 use crate::buffer::Buffer;
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::handles::command_buffer::{_CommandBuffers, make_command_buffers};
 use crate::structs::CommandBufferAllocateInfo;
 
@@ -17,8 +17,8 @@ pub(crate) fn allocate_command_buffers<
     B: Buffer<vk::CommandBuffer>,
 >(
     device: &'a D,
-    alloc_info: CommandBufferAllocateInfo<'_, B, Pool, Level>,
-) -> Result<_CommandBuffers<'a, D, Level, B>, Error> {
+    alloc_info: CommandBufferAllocateInfo<'a, B, Pool, Level>,
+) -> Result<_CommandBuffers<'a, D, Pool, Level, B>, VulkanError> {
     check_vuids::check_vuids!(AllocateCommandBuffers);
 
     #[allow(unused_labels)]
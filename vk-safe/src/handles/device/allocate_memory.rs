@@ -2,6 +2,8 @@ use super::Device;
 
 use std::mem::MaybeUninit;
 
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
 use crate::structs::MemoryAllocateInfo;
 use crate::type_conversions::ConvertWrapper;
 use crate::vk::{make_device_memory, DeviceMemory};
@@ -15,13 +17,15 @@ pub fn allocate_memory<
     D: Device<Commands: AllocateMemory + FreeMemory>,
     P: vk::flag_traits::MemoryPropertyFlags,
     H: vk::flag_traits::MemoryHeapFlags,
+    A: Allocator,
 >(
     device: &'a D,
     info: &MemoryAllocateInfo<D::PhysicalDevice, P, H>,
+    allocator: A,
 ) -> Result<
     // impl DeviceMemory<Device = S, PropertyFlags = P, HeapFlags = H> + Captures<&Self>,
-    impl DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H> + use<'a, D, P, H>,
-    vk::Result,
+    impl DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H> + use<'a, D, P, H, A>,
+    VulkanError,
 > {
     check_vuids::check_vuids!(AllocateMemory);
 
@@ -106,7 +110,7 @@ pub fn allocate_memory<
         "structure"
         }
 
-        // TODO pAllocator not currently supported
+        // ensured by Allocator
     }
 
     #[allow(unused_labels)]
@@ -125,10 +129,16 @@ pub fn allocate_memory<
         let ret = fptr(
             device.raw_handle(),
             info.to_c(),
-            std::ptr::null(),
+            allocator.callbacks(),
             memory.as_mut_ptr(),
         );
         check_raw_err!(ret);
-        Ok(make_device_memory(memory.assume_init(), device))
+        Ok(make_device_memory(
+            memory.assume_init(),
+            device,
+            allocator,
+            info.allocation_size,
+            info.memory_type_index,
+        ))
     }
 }
@@ -0,0 +1,209 @@
+use super::Device;
+
+use crate::error::VulkanError;
+use crate::handles::buffer::{BoundBuffer, Buffer};
+use crate::vk::DeviceMemory;
+use crate::vk::MemoryRequirements;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::BindBufferMemory;
+
+pub(crate) fn bind_buffer_memory<
+    'm,
+    D: Device<Commands: BindBufferMemory>,
+    B: Buffer<Device = D>,
+    M: DeviceMemory<Device = D>,
+>(
+    device: &D,
+    buffer: B,
+    memory: &'m M,
+    offset: vk::DeviceSize,
+    requirements: &MemoryRequirements<D::PhysicalDevice, B>,
+) -> Result<BoundBuffer<'m, B, M>, VulkanError> {
+    check_vuids::check_vuids!(BindBufferMemory);
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_buffer_07459: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "buffer must not have been bound to a memory object"
+        }
+
+        // buffer is moved into BoundBuffer, which cannot be bound again
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_buffer_01030: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "buffer must not have been created with any sparse memory binding flags"
+        }
+
+        // BufferCreateInfo does not support sparse flags
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_memoryOffset_01031: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memoryOffset must be less than the size of memory"
+        }
+
+        if offset >= memory.allocation_size() {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_memory_01035: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memory must have been allocated using one of the memory types allowed in the memoryTypeBits"
+        "member of the VkMemoryRequirements structure returned from a call to vkGetBufferMemoryRequirements"
+        "with buffer"
+        }
+
+        // requirements can only be obtained for this specific buffer
+        if !requirements.supports_memory_type(memory.memory_type_index()) {
+            Err(VulkanError::IncompatibleMemoryType)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_memoryOffset_01036: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memoryOffset must be an integer multiple of the alignment member of the VkMemoryRequirements"
+        "structure returned from a call to vkGetBufferMemoryRequirements with buffer"
+        }
+
+        if offset % requirements.alignment != 0 {
+            Err(VulkanError::MisalignedMemoryOffset)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_size_01037: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "The size member of the VkMemoryRequirements structure returned from a call to vkGetBufferMemoryRequirements"
+        "with buffer must be less than or equal to the size of memory minus memoryOffset"
+        }
+
+        // offset < allocation_size checked above
+        if requirements.size > memory.allocation_size() - offset {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_buffer_01444: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If buffer requires a dedicated allocation (as reported by vkGetBufferMemoryRequirements2"
+        "in VkMemoryDedicatedRequirements::requiresDedicatedAllocation for buffer), memory"
+        "must have been allocated with VkMemoryDedicatedAllocateInfo::buffer equal to buffer"
+        }
+
+        // TODO: dedicated allocations are not supported yet. Buffers only require a dedicated
+        // allocation when created with external memory, which is also not supported yet
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_memory_01508: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the VkMemoryAllocateInfo provided when memory was allocated included a VkMemoryDedicatedAllocateInfo"
+        "structure in its pNext chain, and VkMemoryDedicatedAllocateInfo::buffer was not VK_NULL_HANDLE,"
+        "then buffer must equal VkMemoryDedicatedAllocateInfo::buffer, and memoryOffset must"
+        "be zero"
+        }
+
+        // MemoryAllocateInfo does not support p_next
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_None_01898: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If buffer was created with the VK_BUFFER_CREATE_PROTECTED_BIT bit set, the buffer"
+        "must be bound to a memory object allocated with a memory type that reports VK_MEMORY_PROPERTY_PROTECTED_BIT"
+        }
+
+        // BufferCreateInfo does not support VK_BUFFER_CREATE_PROTECTED_BIT
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_None_01899: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If buffer was created with the VK_BUFFER_CREATE_PROTECTED_BIT bit not set, the buffer"
+        "must not be bound to a memory object allocated with a memory type that reports VK_MEMORY_PROPERTY_PROTECTED_BIT"
+        }
+
+        // memoryTypeBits will not include protected memory types for unprotected buffers
+        // (see VkMemoryRequirements), so this is covered by the memoryTypeBits check
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_buffer_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "buffer must be a valid VkBuffer handle"
+        }
+
+        // ensured by Buffer creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_memory_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memory must be a valid VkDeviceMemory handle"
+        }
+
+        // ensured by DeviceMemory allocation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_buffer_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "buffer must have been created, allocated, or retrieved from device"
+        }
+
+        // ensured by Buffer<Device = D>
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindBufferMemory_memory_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memory must have been created, allocated, or retrieved from device"
+        }
+
+        // ensured by DeviceMemory<Device = D>
+    }
+
+    unsafe {
+        let res = device.commands().BindBufferMemory().get_fptr()(
+            device.raw_handle(),
+            buffer.raw_handle(),
+            memory.raw_handle(),
+            offset,
+        );
+        check_raw_err!(res);
+    }
+    Ok(BoundBuffer::new(buffer, memory, offset))
+}
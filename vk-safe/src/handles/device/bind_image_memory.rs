@@ -0,0 +1,240 @@
+use super::Device;
+
+use crate::error::VulkanError;
+use crate::handles::image::{BoundImage, Image};
+use crate::vk::DeviceMemory;
+use crate::vk::MemoryRequirements;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::BindImageMemory;
+
+pub(crate) fn bind_image_memory<
+    'm,
+    D: Device<Commands: BindImageMemory>,
+    I: Image<Device = D>,
+    M: DeviceMemory<Device = D>,
+>(
+    device: &D,
+    image: I,
+    memory: &'m M,
+    offset: vk::DeviceSize,
+    requirements: &MemoryRequirements<D::PhysicalDevice, I>,
+) -> Result<BoundImage<'m, I, M>, VulkanError> {
+    check_vuids::check_vuids!(BindImageMemory);
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_image_07460: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must not have been bound to a memory object"
+        }
+
+        // image is moved into BoundImage, which cannot be bound again
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_image_01045: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must not have been created with any sparse memory binding flags"
+        }
+
+        // ImageCreateInfo does not support sparse flags
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memoryOffset_01046: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memoryOffset must be less than the size of memory"
+        }
+
+        if offset >= memory.allocation_size() {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_image_01445: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If image requires a dedicated allocation (as reported by vkGetImageMemoryRequirements2"
+        "in VkMemoryDedicatedRequirements::requiresDedicatedAllocation for image), memory must"
+        "have been created with VkMemoryDedicatedAllocateInfo::image equal to image"
+        }
+
+        // TODO: dedicated allocations are not supported yet. Images only require a dedicated
+        // allocation when created with external memory, which is also not supported yet
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memory_02628: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the dedicatedAllocationImageAliasing feature is not enabled, and the VkMemoryAllocateInfo"
+        "provided when memory was allocated included a VkMemoryDedicatedAllocateInfo structure"
+        "in its pNext chain, and VkMemoryDedicatedAllocateInfo::image was not VK_NULL_HANDLE,"
+        "then image must equal VkMemoryDedicatedAllocateInfo::image and memoryOffset must be"
+        "zero"
+        }
+
+        // MemoryAllocateInfo does not support p_next
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memory_02629: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the dedicatedAllocationImageAliasing feature is enabled, and the VkMemoryAllocateInfo"
+        "provided when memory was allocated included a VkMemoryDedicatedAllocateInfo structure"
+        "in its pNext chain, and VkMemoryDedicatedAllocateInfo::image was not VK_NULL_HANDLE,"
+        "then memoryOffset must be zero, and image must be either equal to VkMemoryDedicatedAllocateInfo::image"
+        "or an image that was created using the same parameters in VkImageCreateInfo, with"
+        "the exception that extent and arrayLayers may differ subject to the following restrictions:"
+        "every dimension in the extent parameter of the image being bound must be equal to or"
+        "smaller than the original image for which the allocation was created; and the arrayLayers"
+        "parameter of the image being bound must be equal to or smaller than the original image"
+        "for which the allocation was created"
+        }
+
+        // MemoryAllocateInfo does not support p_next
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_None_01901: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If image was created with the VK_IMAGE_CREATE_PROTECTED_BIT bit set, the image must"
+        "be bound to a memory object allocated with a memory type that reports VK_MEMORY_PROPERTY_PROTECTED_BIT"
+        }
+
+        // ImageCreateInfo does not support VK_IMAGE_CREATE_PROTECTED_BIT
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_None_01902: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If image was created with the VK_IMAGE_CREATE_PROTECTED_BIT bit not set, the image"
+        "must not be bound to a memory object created with a memory type that reports VK_MEMORY_PROPERTY_PROTECTED_BIT"
+        }
+
+        // memoryTypeBits will not include protected memory types for unprotected images
+        // (see VkMemoryRequirements), so this is covered by the memoryTypeBits check
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_image_01608: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must not have been created with the VK_IMAGE_CREATE_DISJOINT_BIT set"
+        }
+
+        // requirements can only be obtained for images without VK_IMAGE_CREATE_DISJOINT_BIT
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memory_01047: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memory must have been allocated using one of the memory types allowed in the memoryTypeBits"
+        "member of the VkMemoryRequirements structure returned from a call to vkGetImageMemoryRequirements"
+        "with image"
+        }
+
+        // requirements can only be obtained for this specific image
+        if !requirements.supports_memory_type(memory.memory_type_index()) {
+            Err(VulkanError::IncompatibleMemoryType)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memoryOffset_01048: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memoryOffset must be an integer multiple of the alignment member of the VkMemoryRequirements"
+        "structure returned from a call to vkGetImageMemoryRequirements with image"
+        }
+
+        if offset % requirements.alignment != 0 {
+            Err(VulkanError::MisalignedMemoryOffset)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_size_01049: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "The difference of the size of memory and memoryOffset must be greater than or equal"
+        "to the size member of the VkMemoryRequirements structure returned from a call to vkGetImageMemoryRequirements"
+        "with the same image"
+        }
+
+        // offset < allocation_size checked above
+        if requirements.size > memory.allocation_size() - offset {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_image_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must be a valid VkImage handle"
+        }
+
+        // ensured by Image creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memory_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memory must be a valid VkDeviceMemory handle"
+        }
+
+        // ensured by DeviceMemory allocation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_image_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must have been created, allocated, or retrieved from device"
+        }
+
+        // ensured by Image<Device = D>
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkBindImageMemory_memory_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "memory must have been created, allocated, or retrieved from device"
+        }
+
+        // ensured by DeviceMemory<Device = D>
+    }
+
+    unsafe {
+        let res = device.commands().BindImageMemory().get_fptr()(
+            device.raw_handle(),
+            image.raw_handle(),
+            memory.raw_handle(),
+            offset,
+        );
+        check_raw_err!(res);
+    }
+    Ok(BoundImage::new(image, memory, offset))
+}
@@ -0,0 +1,135 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::buffer::{make_buffer, Buffer};
+use crate::scope::Tag;
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::BufferCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::BufferUsageFlags;
+use vk::has_command::{CreateBuffer, DestroyBuffer};
+
+/**
+Create a Buffer
+
+The created [`Buffer`] is uniquely identified by the provided [`Tag`], so that
+[`MemoryRequirements`](crate::vk::MemoryRequirements) queried for the Buffer can
+only be used when binding memory to that specific Buffer.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>>(device: D) {
+vk::flags!(Usage: BufferUsageFlags + TRANSFER_SRC_BIT);
+vk::tag!(tag);
+let info = vk::BufferCreateInfo::new(std::num::NonZeroU64::new(1024).unwrap(), Usage);
+let buffer = vk::create_buffer(&device, &info, (), tag).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateBuffer.html>
+*/
+pub fn create_buffer<
+    'a,
+    't,
+    D: Device<Commands: CreateBuffer + DestroyBuffer>,
+    U: BufferUsageFlags,
+    A: Allocator,
+>(
+    device: &'a D,
+    info: &BufferCreateInfo<U>,
+    allocator: A,
+    tag: Tag<'t>,
+) -> Result<impl Buffer<Device = D, Usage = U> + use<'a, 't, D, U, A>, VulkanError> {
+    check_vuids::check_vuids!(CreateBuffer);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateBuffer_flags_00911: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the flags member of pCreateInfo includes VK_BUFFER_CREATE_SPARSE_BINDING_BIT,"
+        "creating this VkBuffer must not cause the total required sparse memory for all currently"
+        "valid sparse resources on the device to exceed VkPhysicalDeviceLimits::sparseAddressSpaceSize"
+        }
+
+        // BufferCreateInfo does not support sparse flags
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateBuffer_pNext_06387: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If using the VkBuffer for an import operation from a VkBufferCollectionFUCHSIA where"
+        "a VkBufferCollectionBufferCreateInfoFUCHSIA has been chained to pNext, pCreateInfo"
+        "must match the VkBufferConstraintsInfoFUCHSIA::createInfo used when setting the constraints"
+        "on the buffer collection with vkSetBufferCollectionBufferConstraintsFUCHSIA"
+        }
+        check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
+
+        // BufferCreateInfo does not support p_next
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateBuffer_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateBuffer_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkBufferCreateInfo structure"
+        }
+
+        // ensured by BufferCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateBuffer_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateBuffer_pBuffer_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pBuffer must be a valid pointer to a VkBuffer handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateBuffer().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_buffer(
+            handle.assume_init(),
+            device,
+            allocator,
+            info.size,
+            tag,
+        ))
+    }
+}
@@ -1,13 +1,14 @@
 use super::Device;
 
-use crate::error::Error;
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
 use crate::handles::command_pool::{make_command_pool, CommandPool};
 use crate::structs::CommandPoolCreateInfo;
 use crate::type_conversions::ConvertWrapper;
 
 use vk_safe_sys as vk;
 
-use vk::flag_traits::CommandPoolCreateFlags;
+use vk::flag_traits::{CommandPoolCreateFlags, QueueFlags};
 use vk::has_command::{CreateCommandPool, DestroyCommandPool};
 
 pub fn create_command_pool<
@@ -15,10 +16,16 @@ pub fn create_command_pool<
     D: Device<Commands: CreateCommandPool + DestroyCommandPool>,
     F: CommandPoolCreateFlags,
     Q: Send,
+    C: Send + QueueFlags,
+    A: Allocator,
 >(
     device: &'a D,
-    create_info: &CommandPoolCreateInfo<D, F, Q>,
-) -> Result<impl CommandPool<Device = D, Flags = F, QueueFamily = Q> + use<'a, D, F, Q>, Error> {
+    create_info: &CommandPoolCreateInfo<D, F, Q, C>,
+    allocator: A,
+) -> Result<
+    impl CommandPool<Device = D, Flags = F, QueueFamily = Q, Capability = C> + use<'a, D, F, Q, C, A>,
+    VulkanError,
+> {
     check_vuids::check_vuids!(CreateCommandPool);
 
     #[allow(unused_labels)]
@@ -60,8 +67,7 @@ pub fn create_command_pool<
         "structure"
         }
 
-        // TODO
-        // always null for now
+        // ensured by Allocator
     }
 
     #[allow(unused_labels)]
@@ -80,12 +86,12 @@ pub fn create_command_pool<
         let res = fptr(
             device.raw_handle(),
             create_info.to_c(),
-            std::ptr::null(),
+            allocator.callbacks(),
             handle.as_mut_ptr(),
         );
         check_raw_err!(res);
         handle.assume_init()
     };
 
-    Ok(make_command_pool(handle, device))
+    Ok(make_command_pool(handle, device, allocator))
 }
@@ -0,0 +1,187 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::enumerations::PipelineBindPoint::COMPUTE;
+use crate::error::VulkanError;
+use crate::handles::pipeline::{make_pipeline, Pipeline};
+use crate::handles::pipeline_layout::PipelineLayout;
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::ComputePipelineCreateInfo;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateComputePipelines, DestroyPipeline};
+
+/**
+Create compute Pipelines
+
+One Pipeline is created for each [`ComputePipelineCreateInfo`]. If creating any of the
+Pipelines fails, the Pipelines which were successfully created are destroyed, and the
+error is returned.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<
+#   D: Device<Commands: vk::device::VERSION_1_0>,
+#   M: vk::ShaderModule<Device = D>,
+#   L: vk::PipelineLayout<Device = D>,
+# >(device: D, module: M, layout: L) {
+// Safety: the shader module has a GLCompute entry point called "main",
+// which is consistent with the layout
+let info = unsafe {
+    let stage = vk::PipelineShaderStageCreateInfo::compute(&module, vk_safe::vk_str!("main"));
+    vk::ComputePipelineCreateInfo::new(stage, &layout)
+};
+let [pipeline] = vk::create_compute_pipelines(&device, &[info], ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateComputePipelines.html>
+*/
+pub fn create_compute_pipelines<
+    'a,
+    D: Device<Commands: CreateComputePipelines + DestroyPipeline>,
+    L: PipelineLayout<Device = D>,
+    const N: usize,
+    A: Allocator,
+>(
+    device: &'a D,
+    infos: &[ComputePipelineCreateInfo<D, L>; N],
+    allocator: A,
+) -> Result<
+    [impl Pipeline<Device = D, Layout = L, BindPoint = COMPUTE> + use<'a, D, L, N, A>; N],
+    VulkanError,
+> {
+    check_vuids::check_vuids!(CreateComputePipelines);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_flags_00695: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the flags member of any element of pCreateInfos contains the VK_PIPELINE_CREATE_DERIVATIVE_BIT"
+        "flag, and the basePipelineIndex member of that same element is not -1, basePipelineIndex"
+        "must be less than the index into pCreateInfos that corresponds to that element"
+        }
+
+        // flags are always empty
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_flags_00696: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the flags member of any element of pCreateInfos contains the VK_PIPELINE_CREATE_DERIVATIVE_BIT"
+        "flag, the base pipeline must have been created with the VK_PIPELINE_CREATE_ALLOW_DERIVATIVES_BIT"
+        "flag set"
+        }
+
+        // flags are always empty
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_pipelineCache_02873: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pipelineCache was created with VK_PIPELINE_CACHE_CREATE_EXTERNALLY_SYNCHRONIZED_BIT,"
+        "host access to pipelineCache must be externally synchronized"
+        }
+
+        // TODO: pipeline caches are not supported yet; always null
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_pipelineCache_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pipelineCache is not VK_NULL_HANDLE, pipelineCache must be a valid VkPipelineCache"
+        "handle"
+        }
+
+        // TODO: pipeline caches are not supported yet; always null
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_pCreateInfos_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfos must be a valid pointer to an array of createInfoCount valid VkComputePipelineCreateInfo"
+        "structures"
+        }
+
+        // ensured by ComputePipelineCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_pPipelines_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pPipelines must be a valid pointer to an array of createInfoCount VkPipeline handles"
+        }
+
+        // array of N handles
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_createInfoCount_arraylength: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "createInfoCount must be greater than 0"
+        }
+
+        const {
+            assert!(N > 0, "must create at least one Pipeline");
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateComputePipelines_pipelineCache_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pipelineCache is a valid handle, it must have been created, allocated, or retrieved"
+        "from device"
+        }
+
+        // TODO: pipeline caches are not supported yet; always null
+    }
+
+    let mut handles = [vk::Pipeline { handle: 0 }; N];
+    unsafe {
+        let res = device.commands().CreateComputePipelines().get_fptr()(
+            device.raw_handle(),
+            vk::PipelineCache { handle: 0 },
+            N.try_into()?,
+            infos.as_slice().to_c(),
+            allocator.callbacks(),
+            handles.as_mut_ptr(),
+        );
+        // Pipelines which failed to be created are VK_NULL_HANDLE, and the rest are valid.
+        // On error, all are dropped here, which destroys the successfully created Pipelines
+        // (destroying VK_NULL_HANDLE does nothing)
+        let pipelines = handles
+            .map(|handle| make_pipeline::<D, L, COMPUTE, A>(handle, device, allocator.clone()));
+        check_raw_err!(res);
+        Ok(pipelines)
+    }
+}
@@ -0,0 +1,107 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::descriptor_set_layout::{
+    make_descriptor_set_layout, DescriptorCounts, DescriptorSetLayout,
+};
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::DescriptorSetLayoutCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateDescriptorSetLayout, DestroyDescriptorSetLayout};
+
+/**
+Create a DescriptorSetLayout
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>>(device: D) {
+let bindings = [vk::DescriptorSetLayoutBinding::new(
+    0,
+    vk::DescriptorType::STORAGE_BUFFER,
+    1,
+    vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+)];
+let info = vk::DescriptorSetLayoutCreateInfo::new(&bindings).unwrap();
+let set_layout = vk::create_descriptor_set_layout(&device, &info, ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorSetLayout.html>
+*/
+pub fn create_descriptor_set_layout<
+    'a,
+    D: Device<Commands: CreateDescriptorSetLayout + DestroyDescriptorSetLayout>,
+    A: Allocator,
+>(
+    device: &'a D,
+    info: &DescriptorSetLayoutCreateInfo,
+    allocator: A,
+) -> Result<impl DescriptorSetLayout<Device = D> + use<'a, D, A>, VulkanError> {
+    check_vuids::check_vuids!(CreateDescriptorSetLayout);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDescriptorSetLayout_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDescriptorSetLayout_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkDescriptorSetLayoutCreateInfo structure"
+        }
+
+        // ensured by DescriptorSetLayoutCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDescriptorSetLayout_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDescriptorSetLayout_pSetLayout_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pSetLayout must be a valid pointer to a VkDescriptorSetLayout handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let counts = DescriptorCounts::from_bindings(info.bindings());
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateDescriptorSetLayout().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_descriptor_set_layout(
+            handle.assume_init(),
+            device,
+            allocator,
+            counts,
+        ))
+    }
+}
@@ -0,0 +1,87 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::fence::{make_fence, Fence};
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::FenceCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateFence, DestroyFence};
+
+/**
+Create a Fence
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>>(device: D) {
+let fence = vk::create_fence(&device, &vk::FenceCreateInfo::new(), ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateFence.html>
+*/
+pub fn create_fence<'a, D: Device<Commands: CreateFence + DestroyFence>, A: Allocator>(
+    device: &'a D,
+    info: &FenceCreateInfo,
+    allocator: A,
+) -> Result<impl Fence<Device = D> + use<'a, D, A>, VulkanError> {
+    check_vuids::check_vuids!(CreateFence);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateFence_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateFence_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkFenceCreateInfo structure"
+        }
+
+        // ensured by FenceCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateFence_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateFence_pFence_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pFence must be a valid pointer to a VkFence handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateFence().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_fence(handle.assume_init(), device, allocator))
+    }
+}
@@ -0,0 +1,134 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::image::{make_image, Image};
+use crate::scope::Tag;
+use crate::structs::ImageParameters::ImageParameters;
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::ImageCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateImage, DestroyImage};
+
+/**
+Create an Image
+
+The created [`Image`] is uniquely identified by the provided [`Tag`], so that
+[`MemoryRequirements`](crate::vk::MemoryRequirements) queried for the Image can
+only be used when binding memory to that specific Image.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>, P: vk::ImageParameters::ImageParameters>
+#   (device: D, info: vk::ImageCreateInfo<D::PhysicalDevice, P>) {
+vk::tag!(tag);
+let image = vk::create_image(&device, &info, (), tag).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateImage.html>
+*/
+pub fn create_image<
+    'a,
+    't,
+    D: Device<Commands: CreateImage + DestroyImage>,
+    P: ImageParameters,
+    A: Allocator,
+>(
+    device: &'a D,
+    info: &ImageCreateInfo<D::PhysicalDevice, P>,
+    allocator: A,
+    tag: Tag<'t>,
+) -> Result<impl Image<Device = D, Params = P> + use<'a, 't, D, P, A>, VulkanError> {
+    check_vuids::check_vuids!(CreateImage);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImage_flags_00939: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the flags member of pCreateInfo includes VK_IMAGE_CREATE_SPARSE_BINDING_BIT, creating"
+        "this VkImage must not cause the total required sparse memory for all currently valid"
+        "sparse resources on the device to exceed VkPhysicalDeviceLimits::sparseAddressSpaceSize"
+        }
+
+        // ImageCreateInfo does not support sparse flags
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImage_pNext_06389: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If using the VkImage for an import operation from a VkBufferCollectionFUCHSIA where"
+        "a VkBufferCollectionImageCreateInfoFUCHSIA has been chained to pNext, pCreateInfo"
+        "must match the VkImageConstraintsInfoFUCHSIA::pFormatConstraints->imageCreateInfo"
+        "used when setting the constraints on the buffer collection with vkSetBufferCollectionImageConstraintsFUCHSIA"
+        }
+        check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
+
+        // ImageCreateInfo does not support p_next
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImage_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImage_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkImageCreateInfo structure"
+        }
+
+        // ensured by ImageCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImage_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImage_pImage_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pImage must be a valid pointer to a VkImage handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateImage().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_image(
+            handle.assume_init(),
+            device,
+            allocator,
+            info,
+            tag,
+        ))
+    }
+}
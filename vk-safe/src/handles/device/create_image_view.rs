@@ -0,0 +1,127 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::image::Image;
+use crate::handles::image_view::{make_image_view, ImageView};
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::ImageViewCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::ImageViewType;
+use vk::flag_traits::ImageAspectFlags;
+use vk::has_command::{CreateImageView, DestroyImageView};
+
+/**
+Create an ImageView
+
+The ImageView borrows the Image for as long as the ImageView exists.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>, I: vk::Image<Device = D>, M: vk::DeviceMemory<Device = D>>
+#   (device: D, image: vk::BoundImage<I, M>) {
+let info = vk::ImageViewCreateInfo::new(
+    &image,
+    vk::ImageViewType::TYPE_2D,
+    vk::flags!(ImageAspectFlags + COLOR_BIT),
+    0..1,
+    0..1,
+)
+.unwrap();
+let image_view = vk::create_image_view(&device, &info, ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateImageView.html>
+*/
+pub fn create_image_view<
+    'a,
+    'i,
+    D: Device<Commands: CreateImageView + DestroyImageView>,
+    I: Image<Device = D>,
+    V: ImageViewType,
+    A: ImageAspectFlags,
+    Al: Allocator,
+>(
+    device: &'a D,
+    info: &ImageViewCreateInfo<'i, I, V, A>,
+    allocator: Al,
+) -> Result<
+    impl ImageView<Device = D, Image = I, ViewType = V, Aspect = A> + use<'a, 'i, D, I, V, A, Al>,
+    VulkanError,
+> {
+    check_vuids::check_vuids!(CreateImageView);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImageView_image_09179: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "VkImageViewCreateInfo::image must have been created from device"
+        }
+
+        // ensured by Image<Device = D>
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImageView_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImageView_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkImageViewCreateInfo structure"
+        }
+
+        // ensured by ImageViewCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImageView_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateImageView_pView_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pView must be a valid pointer to a VkImageView handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateImageView().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_image_view(
+            handle.assume_init(),
+            device,
+            allocator,
+            info,
+        ))
+    }
+}
@@ -0,0 +1,122 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::descriptor_set_layout::DescriptorSetLayout;
+use crate::handles::pipeline_layout::{make_pipeline_layout, PipelineLayout};
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::PipelineLayoutCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreatePipelineLayout, DestroyPipelineLayout};
+
+/**
+Create a PipelineLayout
+
+The [`PipelineLayoutCreateInfo`] must have been checked against the
+properties of the PhysicalDevice the Device was created from.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>, L: vk::DescriptorSetLayout<Device = D>>
+#   (device: D, properties: vk::PhysicalDeviceProperties<D::PhysicalDevice>, set_layout: L) {
+let push_constants = [vk::PushConstantRange::new(
+    &properties,
+    vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+    0..16,
+)
+.unwrap()];
+let info = vk::PipelineLayoutCreateInfo::new(&properties, [&set_layout], &push_constants).unwrap();
+let pipeline_layout = vk::create_pipeline_layout(&device, &info, ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineLayout.html>
+*/
+pub fn create_pipeline_layout<
+    'a,
+    D: Device<Commands: CreatePipelineLayout + DestroyPipelineLayout>,
+    L: DescriptorSetLayout<Device = D>,
+    const N: usize,
+    A: Allocator,
+>(
+    device: &'a D,
+    info: &PipelineLayoutCreateInfo<D::PhysicalDevice, L, N>,
+    allocator: A,
+) -> Result<impl PipelineLayout<Device = D> + use<'a, D, L, N, A>, VulkanError> {
+    check_vuids::check_vuids!(CreatePipelineLayout);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreatePipelineLayout_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreatePipelineLayout_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkPipelineLayoutCreateInfo structure"
+        }
+
+        // ensured by PipelineLayoutCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreatePipelineLayout_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreatePipelineLayout_pPipelineLayout_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pPipelineLayout must be a valid pointer to a VkPipelineLayout handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let set_layouts = info.set_layouts.map(|l| l.raw_handle());
+    let push_constant_ranges = info.push_constant_ranges;
+
+    let raw_info = vk::PipelineLayoutCreateInfo {
+        s_type: vk::StructureType::PIPELINE_LAYOUT_CREATE_INFO,
+        p_next: std::ptr::null(),
+        flags: vk::PipelineLayoutCreateFlags::empty(),
+        set_layout_count: N.try_into()?,
+        p_set_layouts: set_layouts.as_ptr(),
+        push_constant_range_count: push_constant_ranges.len().try_into()?,
+        p_push_constant_ranges: push_constant_ranges.to_c(),
+    };
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreatePipelineLayout().get_fptr()(
+            device.raw_handle(),
+            &raw_info,
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_pipeline_layout(
+            handle.assume_init(),
+            device,
+            allocator,
+        ))
+    }
+}
@@ -0,0 +1,122 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::features::{Features, HasFeatures};
+use crate::handles::sampler::{make_sampler, Sampler};
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::SamplerCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateSampler, DestroySampler};
+
+/**
+Create a Sampler
+
+The [`SamplerCreateInfo`] must have been checked against the
+properties of the PhysicalDevice the Device was created from. Features
+required by the SamplerCreateInfo (e.g. `samplerAnisotropy` for
+[`anisotropy`](SamplerCreateInfo::anisotropy)) must be enabled on the Device,
+or this fails to compile.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>>
+#   (device: D, properties: vk::PhysicalDeviceProperties<D::PhysicalDevice>) {
+let info = vk::SamplerCreateInfo::new(
+    vk::Filter::LINEAR,
+    vk::Filter::LINEAR,
+    vk::SamplerMipmapMode::LINEAR,
+    vk::SamplerAddressMode::REPEAT,
+)
+.lod(&properties, 0.0, 0.0, 4.0)
+.unwrap();
+let sampler = vk::create_sampler(&device, &info, ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateSampler.html>
+*/
+pub fn create_sampler<
+    'a,
+    D: Device<Commands: CreateSampler + DestroySampler>,
+    R: Features,
+    A: Allocator,
+>(
+    device: &'a D,
+    info: &SamplerCreateInfo<D::PhysicalDevice, R>,
+    allocator: A,
+) -> Result<impl Sampler<Device = D> + use<'a, D, R, A>, VulkanError> {
+    // check that the required features are enabled
+    let _ = <D::Features as HasFeatures<R>>::VALID;
+
+    check_vuids::check_vuids!(CreateSampler);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSampler_maxSamplerAllocationCount_04110: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "There must be less than VkPhysicalDeviceLimits::maxSamplerAllocationCount VkSampler"
+        "objects currently created on the device"
+        }
+
+        // TODO: the number of live samplers is not tracked yet
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSampler_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSampler_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkSamplerCreateInfo structure"
+        }
+
+        // ensured by SamplerCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSampler_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSampler_pSampler_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pSampler must be a valid pointer to a VkSampler handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateSampler().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_sampler(handle.assume_init(), device, allocator))
+    }
+}
@@ -0,0 +1,91 @@
+use super::Device;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::semaphore::{make_semaphore, Semaphore};
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::SemaphoreCreateInfo;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateSemaphore, DestroySemaphore};
+
+/**
+Create a Semaphore
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst<D: Device<Commands: vk::device::VERSION_1_0>>(device: D) {
+let semaphore = vk::create_semaphore(&device, &vk::SemaphoreCreateInfo::new(), ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateSemaphore.html>
+*/
+pub fn create_semaphore<
+    'a,
+    D: Device<Commands: CreateSemaphore + DestroySemaphore>,
+    A: Allocator,
+>(
+    device: &'a D,
+    info: &SemaphoreCreateInfo,
+    allocator: A,
+) -> Result<impl Semaphore<Device = D> + use<'a, D, A>, VulkanError> {
+    check_vuids::check_vuids!(CreateSemaphore);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSemaphore_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSemaphore_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkSemaphoreCreateInfo structure"
+        }
+
+        // ensured by SemaphoreCreateInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSemaphore_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateSemaphore_pSemaphore_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pSemaphore must be a valid pointer to a VkSemaphore handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = device.commands().CreateSemaphore().get_fptr()(
+            device.raw_handle(),
+            info.to_c(),
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_semaphore(handle.assume_init(), device, allocator))
+    }
+}
@@ -1,6 +1,7 @@
 use super::Device;
 
-use crate::error::Error;
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
 use crate::handles::shader_module::{make_shader_module, ShaderModule};
 use crate::type_conversions::ConvertWrapper;
 use crate::vk::ShaderModuleCreateInfo;
@@ -11,10 +12,15 @@ use vk_safe_sys as vk;
 
 use vk::has_command::{CreateShaderModule, DestroyShaderModule};
 
-pub fn create_shader_module<'a, D: Device<Commands: CreateShaderModule + DestroyShaderModule>>(
+pub fn create_shader_module<
+    'a,
+    D: Device<Commands: CreateShaderModule + DestroyShaderModule>,
+    A: Allocator,
+>(
     device: &'a D,
     info: &ShaderModuleCreateInfo,
-) -> Result<impl ShaderModule<Device = D> + use<'a, D>, Error> {
+    allocator: A,
+) -> Result<impl ShaderModule<Device = D> + use<'a, D, A>, VulkanError> {
     check_vuids::check_vuids!(CreateShaderModule);
 
     #[allow(unused_labels)]
@@ -56,7 +62,7 @@ pub fn create_shader_module<'a, D: Device<Commands: CreateShaderModule + Destroy
         "structure"
         }
 
-        // TODO always null for now
+        // ensured by Allocator
     }
 
     #[allow(unused_labels)]
@@ -74,10 +80,10 @@ pub fn create_shader_module<'a, D: Device<Commands: CreateShaderModule + Destroy
         let res = device.commands().CreateShaderModule().get_fptr()(
             device.raw_handle(),
             info.to_c(),
-            std::ptr::null(),
+            allocator.callbacks(),
             handle.as_mut_ptr(),
         );
         check_raw_err!(res);
-        Ok(make_shader_module(device, handle.assume_init()))
+        Ok(make_shader_module(device, allocator, handle.assume_init()))
     }
 }
@@ -1,6 +1,6 @@
 use super::Device;
 
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::structs::MappedMemoryRange;
 use crate::type_conversions::ConvertWrapper;
 
@@ -11,7 +11,7 @@ use vk::has_command::FlushMappedMemoryRanges;
 pub(crate) fn flush_mapped_memory_ranges<D: Device<Commands: FlushMappedMemoryRanges>>(
     device: &D,
     ranges: &[MappedMemoryRange<D>],
-) -> Result<(), Error> {
+) -> Result<(), VulkanError> {
     check_vuids::check_vuids!(FlushMappedMemoryRanges);
 
     #[allow(unused_labels)]
@@ -0,0 +1,71 @@
+use super::Device;
+
+use crate::handles::buffer::Buffer;
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::MemoryRequirements;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetBufferMemoryRequirements;
+
+pub(crate) fn get_buffer_memory_requirements<
+    D: Device<Commands: GetBufferMemoryRequirements>,
+    B: Buffer<Device = D>,
+>(
+    device: &D,
+    buffer: &B,
+) -> MemoryRequirements<D::PhysicalDevice, B> {
+    check_vuids::check_vuids!(GetBufferMemoryRequirements);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetBufferMemoryRequirements_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetBufferMemoryRequirements_buffer_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "buffer must be a valid VkBuffer handle"
+        }
+
+        // ensured by Buffer creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetBufferMemoryRequirements_pMemoryRequirements_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pMemoryRequirements must be a valid pointer to a VkMemoryRequirements structure"
+        }
+
+        // MaybeUninit
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetBufferMemoryRequirements_buffer_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "buffer must have been created, allocated, or retrieved from device"
+        }
+
+        // ensured by Buffer<Device = D>
+    }
+
+    let mut requirements = MaybeUninit::uninit();
+    unsafe {
+        device.commands().GetBufferMemoryRequirements().get_fptr()(
+            device.raw_handle(),
+            buffer.raw_handle(),
+            requirements.as_mut_ptr(),
+        );
+        MemoryRequirements::from_c(requirements.assume_init())
+    }
+}
@@ -33,7 +33,7 @@ pub fn get_device_queues<'a, 't, D: Device<Commands: GetDeviceQueue>, Q: QueueFl
     tag: Tag<'t>,
 ) -> Result<
     (
-        QueueFamilyMarker<Tag<'t>>,
+        QueueFamilyMarker<Tag<'t>, Q>,
         impl Iterator<Item: Queue<Device = D, Capability = Q, Family = Tag<'t>> + Captures<&'a D>>,
     ),
     UnsupportedCapability,
@@ -0,0 +1,72 @@
+use super::Device;
+
+use crate::handles::image::Image;
+use crate::structs::ImageParameters::ImageParameters;
+use crate::type_conversions::ConvertWrapper;
+use crate::vk::MemoryRequirements;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetImageMemoryRequirements;
+
+pub(crate) fn get_image_memory_requirements<
+    D: Device<Commands: GetImageMemoryRequirements>,
+    I: Image<Device = D>,
+>(
+    device: &D,
+    image: &I,
+) -> MemoryRequirements<D::PhysicalDevice, I> {
+    check_vuids::check_vuids!(GetImageMemoryRequirements);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetImageMemoryRequirements_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetImageMemoryRequirements_image_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must be a valid VkImage handle"
+        }
+
+        // ensured by Image creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetImageMemoryRequirements_pMemoryRequirements_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pMemoryRequirements must be a valid pointer to a VkMemoryRequirements structure"
+        }
+
+        // MaybeUninit
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetImageMemoryRequirements_image_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "image must have been created, allocated, or retrieved from device"
+        }
+
+        // ensured by Image<Device = D>
+    }
+
+    let mut requirements = MaybeUninit::uninit();
+    unsafe {
+        device.commands().GetImageMemoryRequirements().get_fptr()(
+            device.raw_handle(),
+            image.raw_handle(),
+            requirements.as_mut_ptr(),
+        );
+        MemoryRequirements::from_c(requirements.assume_init())
+    }
+}
@@ -1,7 +1,8 @@
 use super::Device;
 
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::flags::{Excludes, Includes};
+use crate::structs::MemoryMapRange;
 use crate::vk::DeviceMemory;
 use crate::vk::MappedMemory;
 
@@ -22,7 +23,8 @@ pub(crate) fn map_memory<
 >(
     device: &D,
     memory: M,
-) -> Result<MappedMemory<M>, Error> {
+    range: MemoryMapRange<D::PhysicalDevice>,
+) -> Result<MappedMemory<M>, VulkanError> {
     check_vuids::check_vuids!(MapMemory);
 
     #[allow(unused_labels)]
@@ -32,7 +34,10 @@ pub(crate) fn map_memory<
         "memory must not be currently host mapped"
         }
 
-        // memory is moved into MappedMemory, which does not allow mapping again
+        // memory is moved into MappedMemory, which cannot be mapped again
+        const {
+            assert!(!M::MAPPED, "memory is already host mapped");
+        }
     }
 
     #[allow(unused_labels)]
@@ -42,7 +47,7 @@ pub(crate) fn map_memory<
         "offset must be less than the size of memory"
         }
 
-        // currently always set to zero
+        // checked by MemoryMapRange::checked_size
     }
 
     #[allow(unused_labels)]
@@ -52,7 +57,7 @@ pub(crate) fn map_memory<
         "If size is not equal to VK_WHOLE_SIZE, size must be greater than 0"
         }
 
-        // currently always set to VK_WHOLE_SIZE
+        // MemoryMapRange uses NonZeroU64 for size
     }
 
     #[allow(unused_labels)]
@@ -63,7 +68,7 @@ pub(crate) fn map_memory<
         "of the memory minus offset"
         }
 
-        // currently always set to VK_WHOLE_SIZE
+        // checked by MemoryMapRange::checked_size
     }
 
     #[allow(unused_labels)]
@@ -144,6 +149,11 @@ pub(crate) fn map_memory<
         // ensured by Scope
     }
 
+    // in addition to the above, MemoryMapRange ensures the range is aligned to nonCoherentAtomSize
+    // so that the mapped range can be used for MappedMemoryRange (see VUID_VkMappedMemoryRange_size_01389)
+    let size = range.checked_size(memory.allocation_size())?;
+    let mapped_size: usize = size.try_into()?;
+
     let mut ptr = std::mem::MaybeUninit::uninit();
 
     let fptr = device.commands().MapMemory().get_fptr();
@@ -151,13 +161,19 @@ pub(crate) fn map_memory<
         let res = fptr(
             device.raw_handle(),
             memory.raw_handle(),
-            0,
-            vk::WHOLE_SIZE,
+            range.offset,
+            range.size,
             vk::MemoryMapFlags::empty(),
             ptr.as_mut_ptr(),
         );
         check_raw_err!(res);
 
-        Ok(MappedMemory::new(memory, ptr.assume_init()))
+        Ok(MappedMemory::new(
+            memory,
+            ptr.assume_init(),
+            range.offset,
+            mapped_size,
+            range.non_coherent_atom_size,
+        ))
     }
 }
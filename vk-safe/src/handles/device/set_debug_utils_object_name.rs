@@ -0,0 +1,175 @@
+use super::Device;
+
+use crate::error::VulkanError;
+use crate::handles::DeviceObject;
+use crate::VkStr;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::SetDebugUtilsObjectNameEXT;
+use vk::ObjectHandle;
+
+pub(crate) fn set_debug_utils_object_name<
+    D: Device<Commands: SetDebugUtilsObjectNameEXT>,
+    H: DeviceObject<D, RawHandle: ObjectHandle>,
+>(
+    device: &D,
+    object: &mut H,
+    name: VkStr<'_>,
+) -> Result<(), VulkanError> {
+    check_vuids::check_vuids!(SetDebugUtilsObjectNameEXT);
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_pNameInfo_02587: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pNameInfo->objectType must not be VK_OBJECT_TYPE_UNKNOWN"
+        }
+
+        // ensured by ObjectHandle::OBJECT_TYPE of the raw handle type
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_pNameInfo_02588: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pNameInfo->objectHandle must not be VK_NULL_HANDLE"
+        }
+
+        // a Handle is always a valid handle
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_pNameInfo_07872: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pNameInfo->objectHandle is the valid handle of an instance-level object, the VkDevice"
+        "identified by device must be a descendent of the same VkInstance as the object identified"
+        "by pNameInfo->objectHandle"
+        }
+
+        // only device-level objects can be named
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_pNameInfo_07873: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pNameInfo->objectHandle is the valid handle of a physical-device-level object, the"
+        "VkDevice identified by device must be a descendant of the same VkPhysicalDevice as the"
+        "object identified by pNameInfo->objectHandle"
+        }
+
+        // only device-level objects can be named
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_pNameInfo_07874: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pNameInfo->objectHandle is the valid handle of a device-level object, that object must"
+        "be a descendent of the VkDevice identified by device"
+        }
+
+        // ensured by DeviceObject<D>
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectNameEXT_pNameInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pNameInfo must be a valid pointer to a valid VkDebugUtilsObjectNameInfoEXT structure"
+        }
+
+        // created below
+    }
+
+    check_vuids::check_vuids!(DebugUtilsObjectNameInfoEXT);
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectNameInfoEXT_objectType_02589: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If objectType is VK_OBJECT_TYPE_UNKNOWN, objectHandle must not be VK_NULL_HANDLE"
+        }
+
+        // objectType is never VK_OBJECT_TYPE_UNKNOWN
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectNameInfoEXT_objectType_02590: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If objectType is not VK_OBJECT_TYPE_UNKNOWN, objectHandle must be VK_NULL_HANDLE or a"
+        "valid Vulkan handle of the type associated with objectType as defined in the VkObjectType"
+        "and Vulkan Handle Relationship table"
+        }
+
+        // ensured by ObjectHandle, which is generated from the objtypeenum of each handle type
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectNameInfoEXT_sType_sType: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "sType must be VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT"
+        }
+
+        // set below
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectNameInfoEXT_pNext_pNext: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pNext must be NULL"
+        }
+
+        // set below
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectNameInfoEXT_objectType_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "objectType must be a valid VkObjectType value"
+        }
+
+        // ensured by ObjectHandle::OBJECT_TYPE
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectNameInfoEXT_pObjectName_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pObjectName is not NULL, pObjectName must be a null-terminated UTF-8 string"
+        }
+
+        // ensured by VkStr
+    }
+
+    let info = vk::DebugUtilsObjectNameInfoEXT {
+        s_type: vk::StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+        p_next: std::ptr::null(),
+        object_type: <H::RawHandle as ObjectHandle>::OBJECT_TYPE,
+        object_handle: object.raw_handle().object_handle(),
+        p_object_name: name.as_ptr(),
+    };
+
+    unsafe {
+        let res =
+            device.commands().SetDebugUtilsObjectNameEXT().get_fptr()(device.raw_handle(), &info);
+        check_raw_err!(res);
+    }
+    Ok(())
+}
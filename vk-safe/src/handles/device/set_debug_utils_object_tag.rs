@@ -0,0 +1,168 @@
+use super::Device;
+
+use crate::error::VulkanError;
+use crate::handles::DeviceObject;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::SetDebugUtilsObjectTagEXT;
+use vk::ObjectHandle;
+
+pub(crate) fn set_debug_utils_object_tag<
+    D: Device<Commands: SetDebugUtilsObjectTagEXT>,
+    H: DeviceObject<D, RawHandle: ObjectHandle>,
+>(
+    device: &D,
+    object: &mut H,
+    tag_name: u64,
+    tag: &[u8],
+) -> Result<(), VulkanError> {
+    check_vuids::check_vuids!(SetDebugUtilsObjectTagEXT);
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectTagEXT_pNameInfo_07875: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pNameInfo->objectHandle is the valid handle of an instance-level object, the VkDevice"
+        "identified by device must be a descendent of the same VkInstance as the object identified"
+        "by pNameInfo->objectHandle"
+        }
+
+        // only device-level objects can be tagged
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectTagEXT_pNameInfo_07876: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pNameInfo->objectHandle is the valid handle of a physical-device-level object, the"
+        "VkDevice identified by device must be a descendant of the same VkPhysicalDevice as the"
+        "object identified by pNameInfo->objectHandle"
+        }
+
+        // only device-level objects can be tagged
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectTagEXT_pNameInfo_07877: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pNameInfo->objectHandle is the valid handle of a device-level object, that object must"
+        "be a descendent of the VkDevice identified by device"
+        }
+
+        // ensured by DeviceObject<D>
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectTagEXT_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkSetDebugUtilsObjectTagEXT_pTagInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pTagInfo must be a valid pointer to a valid VkDebugUtilsObjectTagInfoEXT structure"
+        }
+
+        // created below
+    }
+
+    check_vuids::check_vuids!(DebugUtilsObjectTagInfoEXT);
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_objectType_01908: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "objectType must not be VK_OBJECT_TYPE_UNKNOWN"
+        }
+
+        // ensured by ObjectHandle::OBJECT_TYPE of the raw handle type
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_objectHandle_01910: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "objectHandle must be a valid Vulkan handle of the type associated with objectType as"
+        "defined in the VkObjectType and Vulkan Handle Relationship table"
+        }
+
+        // ensured by ObjectHandle, which is generated from the objtypeenum of each handle type
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_sType_sType: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "sType must be VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_TAG_INFO_EXT"
+        }
+
+        // set below
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_pNext_pNext: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pNext must be NULL"
+        }
+
+        // set below
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_objectType_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "objectType must be a valid VkObjectType value"
+        }
+
+        // ensured by ObjectHandle::OBJECT_TYPE
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_pTag_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pTag must be a valid pointer to an array of tagSize bytes"
+        }
+
+        // ensured by the tag slice
+    }
+
+    #[allow(unused_labels)]
+    'VUID_VkDebugUtilsObjectTagInfoEXT_tagSize_arraylength: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "tagSize must be greater than 0"
+        }
+
+        if tag.is_empty() {
+            Err(VulkanError::EmptyDebugTag)?
+        }
+    }
+
+    let info = vk::DebugUtilsObjectTagInfoEXT {
+        s_type: vk::StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT,
+        p_next: std::ptr::null(),
+        object_type: <H::RawHandle as ObjectHandle>::OBJECT_TYPE,
+        object_handle: object.raw_handle().object_handle(),
+        tag_name,
+        tag_size: tag.len(),
+        p_tag: tag.as_ptr().cast(),
+    };
+
+    unsafe {
+        let res =
+            device.commands().SetDebugUtilsObjectTagEXT().get_fptr()(device.raw_handle(), &info);
+        check_raw_err!(res);
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use super::Device;
 
-use crate::error::Error;
+use crate::error::VulkanError;
 
 use vk_safe_sys as vk;
 
@@ -8,7 +8,7 @@ use vk::has_command::DeviceWaitIdle;
 
 pub(crate) unsafe fn wait_idle(
     device: &impl Device<Commands: DeviceWaitIdle>,
-) -> Result<(), Error> {
+) -> Result<(), VulkanError> {
     let fptr = device.commands().DeviceWaitIdle().get_fptr();
 
     check_vuids::check_vuids!(DeviceWaitIdle);
@@ -1,28 +1,45 @@
-use super::{Handle, ThreadSafeHandle};
+use super::{DeviceObject, Handle, ThreadSafeHandle};
 
 use std::fmt;
 use std::marker::PhantomData;
 
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
 use crate::handles::device::Device;
+use crate::pod::Pod;
 
 use vk_safe_sys as vk;
 
 use vk::flag_traits::{MemoryHeapFlags, MemoryPropertyFlags};
 use vk::has_command::FreeMemory;
 
-pub trait DeviceMemory: Handle<RawHandle = vk::DeviceMemory> + ThreadSafeHandle {
+pub trait DeviceMemory:
+    Handle<RawHandle = vk::DeviceMemory> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
     /// The *specific* Device to which this DeviceMemory belongs
     type Device;
     /// Properties of the memory type this DeviceMemory was allocated with
     type PropertyFlags: MemoryPropertyFlags;
     /// Properties of the memory heap from which this DeviceMemory was allocated
     type HeapFlags: MemoryHeapFlags;
+
+    /// Size of the memory allocation in bytes
+    fn allocation_size(&self) -> vk::DeviceSize;
+
+    /// Index of the memory type this DeviceMemory was allocated with
+    fn memory_type_index(&self) -> u32;
+
+    /// If the memory is currently host mapped (i.e. it is a [`MappedMemory`])
+    const MAPPED: bool;
 }
 
 /// [`DeviceMemory`] implementor
-struct _DeviceMemory<'a, D: Device<Commands: FreeMemory>, P, H> {
+struct _DeviceMemory<'a, D: Device<Commands: FreeMemory>, P, H, A: Allocator> {
     handle: vk::DeviceMemory,
     device: &'a D,
+    allocator: A,
+    size: vk::DeviceSize,
+    memory_type_index: u32,
     property_flags: PhantomData<P>,
     heap_flags: PhantomData<H>,
 }
@@ -32,32 +49,52 @@ pub(crate) fn make_device_memory<
     D: Device<Commands: FreeMemory>,
     P: MemoryPropertyFlags,
     H: MemoryHeapFlags,
+    A: Allocator,
 >(
     handle: vk::DeviceMemory,
     device: &'a D,
-) -> impl DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H> + use<'a, D, P, H> {
+    allocator: A,
+    size: vk::DeviceSize,
+    memory_type_index: u32,
+) -> impl DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H> + use<'a, D, P, H, A> {
     _DeviceMemory {
         handle,
         device,
+        allocator,
+        size,
+        memory_type_index,
         property_flags: PhantomData,
         heap_flags: PhantomData,
     }
 }
 
-unsafe impl<D: Device<Commands: FreeMemory>, P, H> Send for _DeviceMemory<'_, D, P, H> {}
-unsafe impl<D: Device<Commands: FreeMemory>, P, H> Sync for _DeviceMemory<'_, D, P, H> {}
-impl<D: Device<Commands: FreeMemory>, P, H> ThreadSafeHandle for _DeviceMemory<'_, D, P, H> {}
+unsafe impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> Send
+    for _DeviceMemory<'_, D, P, H, A>
+{
+}
+unsafe impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> Sync
+    for _DeviceMemory<'_, D, P, H, A>
+{
+}
+impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> ThreadSafeHandle
+    for _DeviceMemory<'_, D, P, H, A>
+{
+}
 
-impl<D: Device<Commands: FreeMemory>, P, H> fmt::Debug for _DeviceMemory<'_, D, P, H> {
+impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> fmt::Debug
+    for _DeviceMemory<'_, D, P, H, A>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DeviceMemory")
             .field("handle", &self.handle)
             // .field("device", &self.device)
+            .field("size", &self.size)
+            .field("memory_type_index", &self.memory_type_index)
             .finish()
     }
 }
 
-impl<D: Device<Commands: FreeMemory>, P, H> Handle for _DeviceMemory<'_, D, P, H> {
+impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> Handle for _DeviceMemory<'_, D, P, H, A> {
     type RawHandle = vk::DeviceMemory;
 
     fn raw_handle(&self) -> Self::RawHandle {
@@ -65,15 +102,30 @@ impl<D: Device<Commands: FreeMemory>, P, H> Handle for _DeviceMemory<'_, D, P, H
     }
 }
 
-impl<D: Device<Commands: FreeMemory>, P: MemoryPropertyFlags, H: MemoryHeapFlags> DeviceMemory
-    for _DeviceMemory<'_, D, P, H>
+impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> DeviceObject<D>
+    for _DeviceMemory<'_, D, P, H, A>
+{
+}
+
+impl<D: Device<Commands: FreeMemory>, P: MemoryPropertyFlags, H: MemoryHeapFlags, A: Allocator>
+    DeviceMemory for _DeviceMemory<'_, D, P, H, A>
 {
     type Device = D;
     type PropertyFlags = P;
     type HeapFlags = H;
+
+    fn allocation_size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+
+    const MAPPED: bool = false;
 }
 
-impl<D: Device<Commands: FreeMemory>, P, H> Drop for _DeviceMemory<'_, D, P, H> {
+impl<D: Device<Commands: FreeMemory>, P, H, A: Allocator> Drop for _DeviceMemory<'_, D, P, H, A> {
     fn drop(&mut self) {
         check_vuids::check_vuids!(FreeMemory);
 
@@ -116,7 +168,7 @@ impl<D: Device<Commands: FreeMemory>, P, H> Drop for _DeviceMemory<'_, D, P, H>
             "structure"
             }
 
-            // TODO always null for now
+            // ensured by Allocator
         }
 
         #[allow(unused_labels)]
@@ -134,17 +186,60 @@ impl<D: Device<Commands: FreeMemory>, P, H> Drop for _DeviceMemory<'_, D, P, H>
             self.device.commands().FreeMemory().get_fptr()(
                 self.device.raw_handle(),
                 self.raw_handle(),
-                std::ptr::null(),
+                self.allocator.callbacks(),
             )
         }
     }
 }
 
 /// DeviceMemory which has been mapped for host access
+///
+/// Provides access to the mapped range as bytes with [`as_slice`](MappedMemory::as_slice) /
+/// [`as_mut_slice`](MappedMemory::as_mut_slice), or as a slice of some [`Pod`] type with
+/// [`as_slice_of`](MappedMemory::as_slice_of) / [`as_mut_slice_of`](MappedMemory::as_mut_slice_of).
+/// Index 0 of each slice corresponds to the offset that was mapped.
+///
+/// If the memory is not `HOST_COHERENT`, host writes must be made visible to the device with
+/// [`flush_mapped_memory_ranges`](crate::vk::Device::flush_mapped_memory_ranges), and device writes
+/// must be made visible to the host with [`invalidate_mapped_memory_ranges`](crate::vk::Device::invalidate_mapped_memory_ranges).
+/// vk-safe does not track this, so invalidating before a read is part of the safety contract of
+/// the `unsafe` slice accessors.
+///
+/// MappedMemory is also [`DeviceMemory`], so Buffers and Images can be bound to it (e.g. with
+/// [`bind_buffer_memory`](crate::vk::Device::bind_buffer_memory)) while it is mapped. The memory
+/// cannot then be unmapped until they are dropped.
+///
+/// ```
+/// # use vk_safe::vk;
+/// # use vk::traits::*;
+/// # use vk::MemoryHeapFlags::MULTI_INSTANCE_BIT;
+/// # use vk::MemoryPropertyFlags::HOST_VISIBLE_BIT;
+/// # fn tst<
+/// #    D: vk::Device<Commands: vk::device::VERSION_1_0>,
+/// #    P: vk::Includes<HOST_VISIBLE_BIT>,
+/// #    H: vk::Excludes<MULTI_INSTANCE_BIT>
+/// # >
+/// #   (device: D, memory: impl vk::DeviceMemory<Device = D, PropertyFlags = P, HeapFlags = H>,
+/// #   buffer: impl vk::Buffer<Device = D>, properties: vk::PhysicalDeviceProperties<D::PhysicalDevice>) {
+/// let mapped_memory = device
+///     .map_memory(memory, vk::MemoryMapRange::whole(&properties))
+///     .unwrap();
+/// let requirements = device.get_buffer_memory_requirements(&buffer);
+/// let bound_buffer = device
+///     .bind_buffer_memory(buffer, &mapped_memory, 0, &requirements)
+///     .unwrap();
+/// # }
+/// ```
+///
+/// ⚠️ Currently, vk-safe does not track use of the memory by the device, so the slice accessors
+/// are `unsafe`. See the `# Safety` section of each accessor.
 #[derive(Debug)]
 pub struct MappedMemory<M> {
     memory: M,
-    ptr: *const std::ffi::c_void,
+    ptr: *mut std::ffi::c_void,
+    offset: vk::DeviceSize,
+    size: usize,
+    non_coherent_atom_size: vk::DeviceSize,
 }
 
 impl<M: DeviceMemory> MappedMemory<M> {
@@ -156,10 +251,151 @@ impl<M: DeviceMemory> MappedMemory<M> {
     pub(crate) unsafe fn take(self) -> M {
         self.memory
     }
+
+    /// Offset of the mapping from the beginning of the memory object
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    /// Size of the mapping in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Check that a sub range of the mapping can be used in a [`MappedMemoryRange`](crate::vk::MappedMemoryRange)
+    ///
+    /// `offset` is relative to the beginning of the mapping.
+    /// returns the offset relative to the beginning of the memory object
+    pub(crate) fn check_sub_range(
+        &self,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+    ) -> Result<vk::DeviceSize, VulkanError> {
+        let mapped_size: vk::DeviceSize = self.size.try_into()?;
+        if offset >= mapped_size || size > mapped_size - offset {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        }
+        let memory_offset = self.offset + offset;
+        let reaches_end = memory_offset + size == self.memory.allocation_size();
+        if offset % self.non_coherent_atom_size != 0
+            || (size % self.non_coherent_atom_size != 0 && !reaches_end)
+        {
+            Err(VulkanError::MisalignedMemoryRange)?
+        }
+        Ok(memory_offset)
+    }
+
+    /// View the mapped memory as bytes
+    ///
+    /// # Safety
+    /// The device must not write to the mapped range while the returned slice is in use
+    /// (i.e. no submitted commands which write to the memory may be pending execution).
+    ///
+    /// If the memory is not `HOST_COHERENT`, the caller must also have invalidated the mapped
+    /// range with [`invalidate_mapped_memory_ranges`](crate::vk::Device::invalidate_mapped_memory_ranges)
+    /// after any device writes completed, before reading through the returned slice.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        // SAFETY: ptr is a host mapping of size bytes, which lives as long as self
+        unsafe { std::slice::from_raw_parts(self.ptr.cast(), self.size) }
+    }
+
+    /// View the mapped memory as mutable bytes
+    ///
+    /// # Safety
+    /// The device must not access the mapped range while the returned slice is in use
+    /// (i.e. no submitted commands which use the memory may be pending execution).
+    ///
+    /// The same invalidation requirement as [`as_slice`](MappedMemory::as_slice) applies to
+    /// reading through the returned slice. If the memory is not `HOST_COHERENT`, host writes are
+    /// only visible to the device after they are flushed with
+    /// [`flush_mapped_memory_ranges`](crate::vk::Device::flush_mapped_memory_ranges).
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: ptr is a host mapping of size bytes, which lives as long as self
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.cast(), self.size) }
+    }
+
+    /// View the mapped memory as a slice of `T`
+    ///
+    /// Any trailing bytes which do not fit a whole `T` are excluded.
+    /// Returns `None` if the mapping is not aligned for `T`, or `T` is zero sized.
+    ///
+    /// # Safety
+    /// Same as [`as_slice`](MappedMemory::as_slice)
+    pub unsafe fn as_slice_of<T: Pod>(&self) -> Option<&[T]> {
+        let len = self.pod_len::<T>()?;
+        // SAFETY: checked alignment and size, and T: Pod can be any bit pattern
+        unsafe { Some(std::slice::from_raw_parts(self.ptr.cast(), len)) }
+    }
+
+    /// View the mapped memory as a mutable slice of `T`
+    ///
+    /// Any trailing bytes which do not fit a whole `T` are excluded.
+    /// Returns `None` if the mapping is not aligned for `T`, or `T` is zero sized.
+    ///
+    /// # Safety
+    /// Same as [`as_mut_slice`](MappedMemory::as_mut_slice)
+    pub unsafe fn as_mut_slice_of<T: Pod>(&mut self) -> Option<&mut [T]> {
+        let len = self.pod_len::<T>()?;
+        // SAFETY: checked alignment and size, and T: Pod can be any bit pattern
+        unsafe { Some(std::slice::from_raw_parts_mut(self.ptr.cast(), len)) }
+    }
+
+    fn pod_len<T: Pod>(&self) -> Option<usize> {
+        let t_size = std::mem::size_of::<T>();
+        if t_size == 0 || !self.ptr.cast::<T>().is_aligned() {
+            None
+        } else {
+            Some(self.size / t_size)
+        }
+    }
+}
+
+// SAFETY: the mapped pointer is only dereferenced through the slice accessors,
+// which follow the borrow rules of &self and &mut self
+unsafe impl<M: Send> Send for MappedMemory<M> {}
+unsafe impl<M: Sync> Sync for MappedMemory<M> {}
+impl<M: DeviceMemory> ThreadSafeHandle for MappedMemory<M> {}
+
+impl<M: DeviceMemory> Handle for MappedMemory<M> {
+    type RawHandle = vk::DeviceMemory;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.memory.raw_handle()
+    }
+}
+
+impl<M: DeviceMemory> DeviceObject<M::Device> for MappedMemory<M> {}
+
+impl<M: DeviceMemory> DeviceMemory for MappedMemory<M> {
+    type Device = M::Device;
+    type PropertyFlags = M::PropertyFlags;
+    type HeapFlags = M::HeapFlags;
+
+    fn allocation_size(&self) -> vk::DeviceSize {
+        self.memory.allocation_size()
+    }
+
+    fn memory_type_index(&self) -> u32 {
+        self.memory.memory_type_index()
+    }
+
+    const MAPPED: bool = true;
 }
 
 impl<M> MappedMemory<M> {
-    pub(crate) fn new(memory: M, ptr: *const std::ffi::c_void) -> Self {
-        Self { memory, ptr }
+    pub(crate) fn new(
+        memory: M,
+        ptr: *mut std::ffi::c_void,
+        offset: vk::DeviceSize,
+        size: usize,
+        non_coherent_atom_size: vk::DeviceSize,
+    ) -> Self {
+        Self {
+            memory,
+            ptr,
+            offset,
+            size,
+            non_coherent_atom_size,
+        }
     }
 }
@@ -0,0 +1,162 @@
+use super::device::Device;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::DestroyFence;
+
+/// Fence handle trait
+///
+/// Represents a *specific* Fence. A Fence is used with [`queue_submit`](crate::vk::Queue::queue_submit)
+/// to know when the submitted work has completed. While submitted work is pending, the
+/// Fence is borrowed by the [`Submission`](crate::vk::Submission).
+pub trait Fence:
+    Handle<RawHandle = vk::Fence> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this Fence belongs
+    type Device: Device;
+
+    /// The Device to which this Fence belongs
+    fn device(&self) -> &Self::Device;
+}
+
+/// [`Fence`] implementor
+struct _Fence<'a, D: Device<Commands: DestroyFence>, A: Allocator> {
+    handle: vk::Fence,
+    device: &'a D,
+    allocator: A,
+}
+
+pub(crate) fn make_fence<'a, D: Device<Commands: DestroyFence>, A: Allocator>(
+    handle: vk::Fence,
+    device: &'a D,
+    allocator: A,
+) -> impl Fence<Device = D> + use<'a, D, A> {
+    _Fence {
+        handle,
+        device,
+        allocator,
+    }
+}
+
+impl<D: Device<Commands: DestroyFence>, A: Allocator> Fence for _Fence<'_, D, A> {
+    type Device = D;
+
+    fn device(&self) -> &Self::Device {
+        self.device
+    }
+}
+
+unsafe impl<D: Device<Commands: DestroyFence>, A: Allocator> Send for _Fence<'_, D, A> {}
+unsafe impl<D: Device<Commands: DestroyFence>, A: Allocator> Sync for _Fence<'_, D, A> {}
+impl<D: Device<Commands: DestroyFence>, A: Allocator> ThreadSafeHandle for _Fence<'_, D, A> {}
+
+impl<D: Device<Commands: DestroyFence>, A: Allocator> Handle for _Fence<'_, D, A> {
+    type RawHandle = vk::Fence;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroyFence>, A: Allocator> DeviceObject<D> for _Fence<'_, D, A> {}
+
+impl<D: Device<Commands: DestroyFence>, A: Allocator> fmt::Debug for _Fence<'_, D, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fence")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroyFence>, A: Allocator> Drop for _Fence<'_, D, A> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyFence);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_fence_01120: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All queue submission commands that refer to fence must have completed execution"
+            }
+
+            // queue_submit borrows the Fence until the submitted work has completed
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_fence_01121: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when fence was created, a compatible set of"
+            "callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the Fence is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_fence_01122: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when fence was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the Fence is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_fence_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If fence is not VK_NULL_HANDLE, fence must be a valid VkFence handle"
+            }
+
+            // ensured by Fence creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyFence_fence_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If fence is a valid handle, it must have been created, allocated, or retrieved from"
+            "device"
+            }
+
+            // ensured by Fence creation
+        }
+
+        unsafe {
+            self.device.commands().DestroyFence().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
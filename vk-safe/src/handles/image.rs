@@ -0,0 +1,375 @@
+use super::command_buffer::RecordingId;
+use super::device::Device;
+use super::device_memory::DeviceMemory;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::allocation_callbacks::Allocator;
+use crate::structs::ImageParameters::ImageParameters;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::ImageLayout;
+use vk::has_command::DestroyImage;
+
+/// Image handle trait
+///
+/// Represents a *specific* Image which has not yet been bound to memory.
+/// Use [`bind_image_memory`](Device::bind_image_memory) to bind the Image
+/// to [`DeviceMemory`], which results in a [`BoundImage`].
+pub trait Image:
+    Handle<RawHandle = vk::Image> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this Image belongs
+    type Device: Device;
+    /// The verified parameters that this Image was created with
+    type Params: ImageParameters;
+
+    /// Extent of the Image
+    fn extent(&self) -> vk::Extent3D;
+
+    /// Number of mip levels of the Image
+    fn mip_levels(&self) -> u32;
+
+    /// Number of array layers of the Image
+    fn array_layers(&self) -> u32;
+
+    /// Sample count of the Image
+    fn samples(&self) -> vk::SampleCountFlags;
+}
+
+/// [`Image`] implementor
+struct _Image<'a, D: Device<Commands: DestroyImage>, P, T, A: Allocator> {
+    handle: vk::Image,
+    device: &'a D,
+    allocator: A,
+    extent: vk::Extent3D,
+    mip_levels: u32,
+    array_layers: u32,
+    samples: vk::SampleCountFlags,
+    params: PhantomData<P>,
+    tag: PhantomData<T>,
+}
+
+pub(crate) fn make_image<
+    'a,
+    D: Device<Commands: DestroyImage>,
+    P: ImageParameters,
+    T,
+    A: Allocator,
+>(
+    handle: vk::Image,
+    device: &'a D,
+    allocator: A,
+    info: &vk::ImageCreateInfo,
+    _tag: T,
+) -> impl Image<Device = D, Params = P> + use<'a, D, P, T, A> {
+    _Image {
+        handle,
+        device,
+        allocator,
+        extent: info.extent,
+        mip_levels: info.mip_levels,
+        array_layers: info.array_layers,
+        samples: info.samples,
+        params: PhantomData,
+        tag: PhantomData::<T>,
+    }
+}
+
+impl<D: Device<Commands: DestroyImage>, P: ImageParameters, T, A: Allocator> Image
+    for _Image<'_, D, P, T, A>
+{
+    type Device = D;
+    type Params = P;
+
+    fn extent(&self) -> vk::Extent3D {
+        self.extent
+    }
+
+    fn mip_levels(&self) -> u32 {
+        self.mip_levels
+    }
+
+    fn array_layers(&self) -> u32 {
+        self.array_layers
+    }
+
+    fn samples(&self) -> vk::SampleCountFlags {
+        self.samples
+    }
+}
+
+unsafe impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> Send for _Image<'_, D, P, T, A> {}
+unsafe impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> Sync for _Image<'_, D, P, T, A> {}
+impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> ThreadSafeHandle
+    for _Image<'_, D, P, T, A>
+{
+}
+
+impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> Handle for _Image<'_, D, P, T, A> {
+    type RawHandle = vk::Image;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> DeviceObject<D>
+    for _Image<'_, D, P, T, A>
+{
+}
+
+impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> fmt::Debug for _Image<'_, D, P, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Image")
+            .field("handle", &self.handle)
+            .field("extent", &self.extent)
+            .field("mip_levels", &self.mip_levels)
+            .field("array_layers", &self.array_layers)
+            .field("samples", &self.samples)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroyImage>, P, T, A: Allocator> Drop for _Image<'_, D, P, T, A> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyImage);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_image_01932: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All submitted commands that refer to image, either directly or via a VkImageView,"
+            "must have completed execution"
+            }
+
+            // CommandBuffers borrow the (bound) Image for as long as they exist, and queue_submit
+            // does not return until the submitted CommandBuffers complete execution
+            // TODO: image views cannot be used in submitted commands yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_image_01933: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when image was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the Image is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_image_01934: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when image was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the Image is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_image_04882: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "image must not have been acquired from vkGetSwapchainImagesKHR"
+            }
+
+            // _Image is only created with vkCreateImage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_image_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is not VK_NULL_HANDLE, image must be a valid VkImage handle"
+            }
+
+            // ensured by Image creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImage_image_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is a valid handle, it must have been created, allocated, or retrieved from"
+            "device"
+            }
+
+            // ensured by Image creation
+        }
+
+        unsafe {
+            self.device.commands().DestroyImage().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
+
+/// An [`Image`] which is bound to [`DeviceMemory`]
+///
+/// Created with [`bind_image_memory`](Device::bind_image_memory). The
+/// memory is borrowed for as long as the Image is bound to it.
+pub struct BoundImage<'m, I, M> {
+    image: I,
+    memory: &'m M,
+    offset: vk::DeviceSize,
+}
+
+impl<'m, I: Image, M: DeviceMemory<Device = I::Device>> BoundImage<'m, I, M> {
+    pub(crate) fn new(image: I, memory: &'m M, offset: vk::DeviceSize) -> Self {
+        Self {
+            image,
+            memory,
+            offset,
+        }
+    }
+
+    /// The Image which is bound
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+
+    /// The DeviceMemory the Image is bound to
+    pub fn memory(&self) -> &'m M {
+        self.memory
+    }
+
+    /// Offset in bytes into the DeviceMemory where the Image is bound
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    /// Start tracking the layout of the Image
+    ///
+    /// The tracked layout always starts as `UNDEFINED`, which is a valid old layout for any
+    /// layout transition, but does not preserve the contents of the Image.
+    ///
+    /// The BoundImage is borrowed exclusively for as long as the TrackedImage (or anything
+    /// which uses it, such as a CommandBuffer) exists, so that only one layout can be tracked
+    /// for the Image at a time. Use [`TrackedImage::image`] to access the BoundImage while
+    /// the layout is tracked (e.g. to create an ImageView).
+    pub fn track_layout(
+        &mut self,
+    ) -> TrackedImage<'_, 'm, I, M, crate::enumerations::ImageLayout::UNDEFINED> {
+        TrackedImage {
+            image: self,
+            layout: PhantomData,
+            recording: RecordingId::NONE,
+        }
+    }
+}
+
+impl<I: Image, M: DeviceMemory> ThreadSafeHandle for BoundImage<'_, I, M> {}
+
+impl<I: Image, M: DeviceMemory> Handle for BoundImage<'_, I, M> {
+    type RawHandle = vk::Image;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.image.raw_handle()
+    }
+}
+
+impl<I: Image, M: DeviceMemory> DeviceObject<I::Device> for BoundImage<'_, I, M> {}
+
+impl<I: Image, M: DeviceMemory> fmt::Debug for BoundImage<'_, I, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundImage")
+            .field("image", &self.image)
+            .field("memory", self.memory)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// A [`BoundImage`] with a tracked layout `L`
+///
+/// Created with [`track_layout`](BoundImage::track_layout). Layout transitions are made with
+/// [`ImageMemoryBarrier::transition`](crate::vk::ImageMemoryBarrier::transition), which
+/// consumes the TrackedImage. The TrackedImage with the new layout is returned by
+/// [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier) when the
+/// transition is recorded.
+///
+/// The tracked layout is only known within the recording of the CommandBuffer which made the
+/// last layout transition, since the order in which different CommandBuffers execute is not
+/// known when recording. A layout transition which is recorded into a different CommandBuffer
+/// (or a later recording of the same CommandBuffer) uses `UNDEFINED` as the old layout, which
+/// does not preserve the contents of the Image. The layout of the Image is not tracked across
+/// different TrackedImages for the same Image.
+pub struct TrackedImage<'i, 'm, I, M, L> {
+    image: &'i BoundImage<'m, I, M>,
+    layout: PhantomData<L>,
+    recording: RecordingId,
+}
+
+impl<'i, 'm, I: Image, M: DeviceMemory<Device = I::Device>, L: ImageLayout>
+    TrackedImage<'i, 'm, I, M, L>
+{
+    /// The Image which is tracked
+    pub fn image(&self) -> &'i BoundImage<'m, I, M> {
+        self.image
+    }
+
+    /// The current layout of the Image
+    pub fn layout(&self) -> vk::ImageLayout {
+        L::VALUE
+    }
+
+    /// The recording of the CommandBuffer which made the last layout transition
+    ///
+    /// [`RecordingId::NONE`] if the layout has not been transitioned.
+    pub(crate) fn recording(&self) -> RecordingId {
+        self.recording
+    }
+
+    /// The caller must ensure that a transition of the layout of the Image to `N` is recorded
+    /// in `recording`
+    pub(crate) unsafe fn transition<N>(
+        self,
+        recording: RecordingId,
+    ) -> TrackedImage<'i, 'm, I, M, N> {
+        TrackedImage {
+            image: self.image,
+            layout: PhantomData,
+            recording,
+        }
+    }
+}
+
+impl<I: Image, M: DeviceMemory, L: ImageLayout> fmt::Debug for TrackedImage<'_, '_, I, M, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrackedImage")
+            .field("image", self.image)
+            .field("layout", &L::VALUE)
+            .finish()
+    }
+}
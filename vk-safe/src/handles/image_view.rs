@@ -0,0 +1,208 @@
+use super::device::Device;
+use super::image::Image;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+use crate::structs::ImageViewCreateInfo;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::ImageViewType;
+use vk::flag_traits::ImageAspectFlags;
+use vk::has_command::DestroyImageView;
+
+/// ImageView handle trait
+///
+/// Represents a *specific* ImageView of an [`Image`]. The view type
+/// and aspect mask are part of the type.
+pub trait ImageView:
+    Handle<RawHandle = vk::ImageView> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this ImageView belongs
+    type Device: Device;
+    /// The *specific* Image this ImageView is a view of
+    type Image: Image<Device = Self::Device>;
+    /// The view type of this ImageView
+    type ViewType: ImageViewType;
+    /// The aspects of the Image which are included in this ImageView
+    type Aspect: ImageAspectFlags;
+}
+
+/// [`ImageView`] implementor
+struct _ImageView<'a, 'i, D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> {
+    handle: vk::ImageView,
+    device: &'a D,
+    allocator: Al,
+    image: PhantomData<&'i I>,
+    view_type: PhantomData<V>,
+    aspect: PhantomData<A>,
+}
+
+pub(crate) fn make_image_view<
+    'a,
+    'i,
+    D: Device<Commands: DestroyImageView>,
+    I: Image<Device = D>,
+    V: ImageViewType,
+    A: ImageAspectFlags,
+    Al: Allocator,
+>(
+    handle: vk::ImageView,
+    device: &'a D,
+    allocator: Al,
+    _info: &ImageViewCreateInfo<'i, I, V, A>,
+) -> impl ImageView<Device = D, Image = I, ViewType = V, Aspect = A> + use<'a, 'i, D, I, V, A, Al> {
+    _ImageView {
+        handle,
+        device,
+        allocator,
+        image: PhantomData::<&'i I>,
+        view_type: PhantomData,
+        aspect: PhantomData,
+    }
+}
+
+impl<
+        D: Device<Commands: DestroyImageView>,
+        I: Image<Device = D>,
+        V: ImageViewType,
+        A: ImageAspectFlags,
+        Al: Allocator,
+    > ImageView for _ImageView<'_, '_, D, I, V, A, Al>
+{
+    type Device = D;
+    type Image = I;
+    type ViewType = V;
+    type Aspect = A;
+}
+
+unsafe impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> Send
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+}
+unsafe impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> Sync
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+}
+impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> ThreadSafeHandle
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+}
+
+impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> Handle
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+    type RawHandle = vk::ImageView;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> DeviceObject<D>
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+}
+
+impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> fmt::Debug
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageView")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroyImageView>, I, V, A, Al: Allocator> Drop
+    for _ImageView<'_, '_, D, I, V, A, Al>
+{
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyImageView);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_imageView_01026: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All submitted commands that refer to imageView must have completed execution"
+            }
+
+            // TODO: image views cannot be used in submitted commands yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_imageView_01027: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when imageView was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the ImageView is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_imageView_01028: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when imageView was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the ImageView is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_imageView_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageView is not VK_NULL_HANDLE, imageView must be a valid VkImageView handle"
+            }
+
+            // ensured by ImageView creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyImageView_imageView_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageView is a valid handle, it must have been created, allocated, or retrieved"
+            "from device"
+            }
+
+            // ensured by ImageView creation
+        }
+
+        unsafe {
+            self.device.commands().DestroyImageView().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
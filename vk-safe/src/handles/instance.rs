@@ -1,12 +1,16 @@
 use super::physical_device::PhysicalDeviceHandle;
 use super::{DispatchableHandle, Handle, ThreadSafeHandle};
 
+use crate::allocation_callbacks::Allocator;
+use crate::entry::Entry;
 use crate::enumerator::Enumerator;
 use crate::scope::{Captures, Tag};
 use crate::VkVersion;
 
+use std::any::Any;
 use std::fmt;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use vk_safe_sys as vk;
 
@@ -17,6 +21,9 @@ pub_use_modules!(
 #[cfg(VK_VERSION_1_0)] {
     enumerate_physical_devices;
 };
+#[cfg(VK_EXT_debug_utils)] {
+    create_debug_utils_messenger;
+};
 );
 
 /// Main Vulkan object
@@ -30,6 +37,9 @@ pub_use_modules!(
 pub trait Instance: DispatchableHandle<RawHandle = vk::Instance> + ThreadSafeHandle {
     const VERSION: VkVersion;
 
+    /// The [`Entry`] that was used to create the Instance
+    fn entry(&self) -> &Entry;
+
     #[cfg(VK_VERSION_1_0)]
     /// Enumerate PhysicalDevices on the system
     ///
@@ -66,17 +76,21 @@ pub trait Instance: DispatchableHandle<RawHandle = vk::Instance> + ThreadSafeHan
 }
 
 // Hidden type which implements [Instance]
-struct _Instance<C: DestroyInstance, T> {
+struct _Instance<C: DestroyInstance, A: Allocator, T> {
     handle: vk::Instance,
     commands: C,
+    entry: Entry,
+    allocator: A,
+    // used by Vulkan until the Instance is destroyed (e.g. debug messenger callbacks from the InstanceCreateInfo)
+    _keep_alive: Vec<Arc<dyn Any + Send + Sync>>,
     tag: PhantomData<T>,
 }
 
-unsafe impl<C: DestroyInstance, T> Send for _Instance<C, T> {}
-unsafe impl<C: DestroyInstance, T> Sync for _Instance<C, T> {}
-impl<C: DestroyInstance, T> ThreadSafeHandle for _Instance<C, T> {}
+unsafe impl<C: DestroyInstance, A: Allocator, T> Send for _Instance<C, A, T> {}
+unsafe impl<C: DestroyInstance, A: Allocator, T> Sync for _Instance<C, A, T> {}
+impl<C: DestroyInstance, A: Allocator, T> ThreadSafeHandle for _Instance<C, A, T> {}
 
-impl<C: DestroyInstance, T> fmt::Debug for _Instance<C, T> {
+impl<C: DestroyInstance, A: Allocator, T> fmt::Debug for _Instance<C, A, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Instance")
             .field("handle", &self.handle)
@@ -85,17 +99,27 @@ impl<C: DestroyInstance, T> fmt::Debug for _Instance<C, T> {
     }
 }
 
-impl<C: DestroyInstance, T> _Instance<C, T> {
-    fn new(handle: vk::Instance, commands: C, _tag: T) -> Self {
+impl<C: DestroyInstance, A: Allocator, T> _Instance<C, A, T> {
+    fn new(
+        handle: vk::Instance,
+        commands: C,
+        entry: Entry,
+        allocator: A,
+        keep_alive: Vec<Arc<dyn Any + Send + Sync>>,
+        _tag: T,
+    ) -> Self {
         Self {
             handle,
             commands,
+            entry,
+            allocator,
+            _keep_alive: keep_alive,
             tag: PhantomData,
         }
     }
 }
 
-impl<C: DestroyInstance, T> Handle for _Instance<C, T> {
+impl<C: DestroyInstance, A: Allocator, T> Handle for _Instance<C, A, T> {
     type RawHandle = vk::Instance;
 
     fn raw_handle(&self) -> Self::RawHandle {
@@ -103,7 +127,7 @@ impl<C: DestroyInstance, T> Handle for _Instance<C, T> {
     }
 }
 
-impl<C: DestroyInstance, T> DispatchableHandle for _Instance<C, T> {
+impl<C: DestroyInstance, A: Allocator, T> DispatchableHandle for _Instance<C, A, T> {
     type Commands = C;
 
     fn commands(&self) -> &Self::Commands {
@@ -111,20 +135,27 @@ impl<C: DestroyInstance, T> DispatchableHandle for _Instance<C, T> {
     }
 }
 
-impl<C: DestroyInstance + Version, T> Instance for _Instance<C, T> {
+impl<C: DestroyInstance + Version, A: Allocator, T> Instance for _Instance<C, A, T> {
     const VERSION: VkVersion = C::VERSION;
+
+    fn entry(&self) -> &Entry {
+        &self.entry
+    }
 }
 
-pub(crate) fn make_instance<C: DestroyInstance + Version>(
+pub(crate) fn make_instance<C: DestroyInstance + Version, A: Allocator>(
     handle: vk::Instance,
     commands: C,
+    entry: Entry,
+    allocator: A,
+    keep_alive: Vec<Arc<dyn Any + Send + Sync>>,
     tag: Tag,
 ) -> impl Instance<Commands = C> + Captures<Tag> {
-    _Instance::new(handle, commands, tag)
+    _Instance::new(handle, commands, entry, allocator, keep_alive, tag)
 }
 
 /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkDestroyInstance.html>
-impl<C: DestroyInstance, T> Drop for _Instance<C, T> {
+impl<C: DestroyInstance, A: Allocator, T> Drop for _Instance<C, A, T> {
     fn drop(&mut self) {
         check_vuids::check_vuids!(DestroyInstance);
 
@@ -150,7 +181,7 @@ impl<C: DestroyInstance, T> Drop for _Instance<C, T> {
             "of callbacks must be provided here"
             }
 
-            // TODO: VkAllocationCallbacks not currently supported
+            // the same Allocator that was used to create the Instance is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -161,7 +192,7 @@ impl<C: DestroyInstance, T> Drop for _Instance<C, T> {
             "be NULL"
             }
 
-            // TODO: VkAllocationCallbacks not currently supported
+            // the same Allocator that was used to create the Instance is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -182,9 +213,11 @@ impl<C: DestroyInstance, T> Drop for _Instance<C, T> {
             "structure"
             }
 
-            // TODO: VkAllocationCallbacks not currently supported
+            // ensured by Allocator
         }
 
-        unsafe { self.commands.DestroyInstance().get_fptr()(self.handle, std::ptr::null()) }
+        unsafe {
+            self.commands.DestroyInstance().get_fptr()(self.handle, self.allocator.callbacks())
+        }
     }
 }
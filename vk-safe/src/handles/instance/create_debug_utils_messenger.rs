@@ -0,0 +1,123 @@
+use super::Instance;
+
+use crate::allocation_callbacks::Allocator;
+use crate::error::VulkanError;
+use crate::handles::debug_utils_messenger::{make_debug_utils_messenger, DebugUtilsMessenger};
+use crate::vk::DebugUtilsMessengerCreateInfoEXT;
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{CreateDebugUtilsMessengerEXT, DestroyDebugUtilsMessengerEXT};
+
+/**
+Create a DebugUtilsMessenger
+
+Requires `EXT_debug_utils` in the instance context. The messenger reports the messages
+selected by the [`DebugUtilsMessengerCreateInfoEXT`] to its callback until it is dropped,
+and keeps the callback alive until then.
+
+```
+# use vk_safe::vk;
+# use vk::traits::*;
+# fn tst(instance: impl Instance<Commands: vk::instance::EXT_debug_utils>) {
+let info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+    vk::flags!(
+        DebugUtilsMessageSeverityFlagsEXT
+            + DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+            + DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT
+    ),
+    vk::flags!(
+        DebugUtilsMessageTypeFlagsEXT
+            + DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT
+            + DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT
+            + DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT
+    ),
+    |message: &vk::DebugUtilsMessage| eprintln!("{message:?}"),
+);
+let messenger = vk::create_debug_utils_messenger(&instance, &info, ()).unwrap();
+# }
+```
+
+<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html>
+*/
+pub fn create_debug_utils_messenger<
+    'a,
+    I: Instance<Commands: CreateDebugUtilsMessengerEXT + DestroyDebugUtilsMessengerEXT>,
+    A: Allocator,
+>(
+    instance: &'a I,
+    info: &DebugUtilsMessengerCreateInfoEXT,
+    allocator: A,
+) -> Result<impl DebugUtilsMessenger<Instance = I> + use<'a, I, A>, VulkanError> {
+    check_vuids::check_vuids!(CreateDebugUtilsMessengerEXT);
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDebugUtilsMessengerEXT_instance_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "instance must be a valid VkInstance handle"
+        }
+
+        // ensured by Instance creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDebugUtilsMessengerEXT_pCreateInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pCreateInfo must be a valid pointer to a valid VkDebugUtilsMessengerCreateInfoEXT"
+        "structure"
+        }
+
+        // rust reference; DebugUtilsMessengerCreateInfoEXT self validated
+        // (and p_next is reset below, in case the info was in a pNext chain)
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDebugUtilsMessengerEXT_pAllocator_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+        "structure"
+        }
+
+        // ensured by Allocator
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkCreateDebugUtilsMessengerEXT_pMessenger_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pMessenger must be a valid pointer to a VkDebugUtilsMessengerEXT handle"
+        }
+
+        // MaybeUninit
+    }
+
+    let create_info = vk::DebugUtilsMessengerCreateInfoEXT {
+        p_next: std::ptr::null(),
+        ..info.inner
+    };
+
+    let mut handle = MaybeUninit::uninit();
+    unsafe {
+        let res = instance
+            .commands()
+            .CreateDebugUtilsMessengerEXT()
+            .get_fptr()(
+            instance.raw_handle(),
+            &create_info,
+            allocator.callbacks(),
+            handle.as_mut_ptr(),
+        );
+        check_raw_err!(res);
+        Ok(make_debug_utils_messenger(
+            handle.assume_init(),
+            instance,
+            allocator,
+            info.callback.clone(),
+        ))
+    }
+}
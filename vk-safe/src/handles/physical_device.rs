@@ -2,7 +2,7 @@ use super::instance::Instance;
 use super::{DispatchableHandle, Handle, ThreadSafeHandle};
 
 use crate::enumerator::Enumerator;
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::scope::{Captures, Tag};
 use crate::structs::*;
 use crate::vk_str::VkStr;
@@ -30,6 +30,13 @@ pub_use_modules!(
     get_physical_device_memory_properties;
     create_device;
 };
+#[cfg(any(VK_VERSION_1_1, VK_KHR_get_physical_device_properties2))] {
+    get_physical_device_features2;
+    get_physical_device_properties2;
+    get_physical_device_memory_properties2;
+    get_physical_device_format_properties2;
+    get_physical_device_queue_family_properties2;
+};
 
 );
 
@@ -43,6 +50,9 @@ pub trait PhysicalDevice:
 {
     type Instance: Instance;
 
+    /// The [`Instance`] that the PhysicalDevice belongs to
+    fn instance(&self) -> &Self::Instance;
+
     #[cfg(VK_VERSION_1_0)]
     /// Query the properties of the PhysicalDevice
     ///
@@ -178,7 +188,7 @@ pub trait PhysicalDevice:
     fn get_physical_device_image_format_properties<Params: ImageParameters::ImageParameters>(
         &self,
         params: Params,
-    ) -> Result<ImageFormatProperties<Self, Params>, Error>
+    ) -> Result<ImageFormatProperties<Self, Params>, VulkanError>
     where
         Self::Commands: vk::has_command::GetPhysicalDeviceImageFormatProperties,
     {
@@ -221,7 +231,7 @@ pub trait PhysicalDevice:
         &self,
         samples: SampleCount,
         image_format_properties: ImageFormatProperties<Self, Params>,
-    ) -> Result<impl Enumerator<SparseImageFormatProperties<Self>>, Error>
+    ) -> Result<impl Enumerator<SparseImageFormatProperties<Self>>, VulkanError>
     where
         Self::Commands: vk::has_command::GetPhysicalDeviceSparseImageFormatProperties,
     {
@@ -271,6 +281,182 @@ pub trait PhysicalDevice:
         get_physical_device_memory_properties(self)
     }
 
+    #[cfg(any(VK_VERSION_1_1, VK_KHR_get_physical_device_properties2))]
+    /// Query the features of the PhysicalDevice, including the features of extension structures
+    ///
+    /// Each structure in `chain` is written with the features which are supported by the
+    /// PhysicalDevice, and can be read after the query. The core features are returned, as
+    /// with [`get_physical_device_features`](PhysicalDevice::get_physical_device_features).
+    ///
+    /// Available with `VERSION_1_1` or `KHR_get_physical_device_properties2`.
+    ///
+    /// ```rust
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # fn tst<P: PhysicalDevice<Commands: vk::instance::VERSION_1_1>>
+    /// #   (physical_device: P) {
+    /// // sType and pNext are set when pushed to the chain, and the rest is written by the query
+    /// let mut vulkan_12_features: vk_safe::raw::PhysicalDeviceVulkan12Features =
+    ///     unsafe { std::mem::zeroed() };
+    /// let features = physical_device.get_physical_device_features2(
+    ///     vk::OutputChain::new().push_next(&mut vulkan_12_features),
+    /// );
+    /// let timeline_semaphore = vulkan_12_features.timeline_semaphore;
+    /// # }
+    /// ```
+    ///
+    /// Vulkan docs:
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html>
+    fn get_physical_device_features2<L>(
+        &self,
+        chain: OutputChain<vk::PhysicalDeviceFeatures2, L>,
+    ) -> PhysicalDeviceFeatures<Self>
+    where
+        Self::Commands: vk::has_command::GetPhysicalDeviceFeatures2,
+    {
+        get_physical_device_features2(self, chain)
+    }
+
+    #[cfg(any(VK_VERSION_1_1, VK_KHR_get_physical_device_properties2))]
+    /// Query the properties of the PhysicalDevice, including the properties of extension structures
+    ///
+    /// Each structure in `chain` is written with the properties of the PhysicalDevice, and can
+    /// be read after the query. The core properties are returned, as with
+    /// [`get_physical_device_properties`](PhysicalDevice::get_physical_device_properties).
+    ///
+    /// Available with `VERSION_1_1` or `KHR_get_physical_device_properties2`.
+    ///
+    /// ```rust
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # fn tst<P: PhysicalDevice<Commands: vk::instance::VERSION_1_1>>
+    /// #   (physical_device: P) {
+    /// let mut driver_properties: vk_safe::raw::PhysicalDeviceDriverProperties =
+    ///     unsafe { std::mem::zeroed() };
+    /// let properties = physical_device.get_physical_device_properties2(
+    ///     vk::OutputChain::new().push_next(&mut driver_properties),
+    /// );
+    /// let driver_id = driver_properties.driver_id;
+    /// # }
+    /// ```
+    ///
+    /// Vulkan docs:
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>
+    fn get_physical_device_properties2<L>(
+        &self,
+        chain: OutputChain<vk::PhysicalDeviceProperties2, L>,
+    ) -> PhysicalDeviceProperties<Self>
+    where
+        Self::Commands: vk::has_command::GetPhysicalDeviceProperties2,
+    {
+        get_physical_device_properties2(self, chain)
+    }
+
+    #[cfg(any(VK_VERSION_1_1, VK_KHR_get_physical_device_properties2))]
+    /// Query the memory properties of the PhysicalDevice, including the properties of extension structures
+    ///
+    /// Each structure in `chain` is written by the query, and can be read after the query. The
+    /// core memory properties are returned, as with
+    /// [`get_physical_device_memory_properties`](PhysicalDevice::get_physical_device_memory_properties).
+    ///
+    /// Available with `VERSION_1_1` or `KHR_get_physical_device_properties2`.
+    ///
+    /// ```rust
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # fn tst<P: PhysicalDevice<Commands: vk::instance::VERSION_1_1>>
+    /// #   (physical_device: P) {
+    /// let memory_properties =
+    ///     physical_device.get_physical_device_memory_properties2(vk::OutputChain::new());
+    /// # }
+    /// ```
+    ///
+    /// Vulkan docs:
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html>
+    fn get_physical_device_memory_properties2<L>(
+        &self,
+        chain: OutputChain<vk::PhysicalDeviceMemoryProperties2, L>,
+    ) -> PhysicalDeviceMemoryProperties<Self>
+    where
+        Self::Commands: vk::has_command::GetPhysicalDeviceMemoryProperties2,
+    {
+        get_physical_device_memory_properties2(self, chain)
+    }
+
+    #[cfg(any(VK_VERSION_1_1, VK_KHR_get_physical_device_properties2))]
+    /// Query the format properties of the PhysicalDevice, including the properties of extension structures
+    ///
+    /// Each structure in `chain` is written by the query, and can be read after the query. The
+    /// core format properties are returned, as with
+    /// [`get_physical_device_format_properties`](PhysicalDevice::get_physical_device_format_properties).
+    ///
+    /// Available with `VERSION_1_1` or `KHR_get_physical_device_properties2`.
+    ///
+    /// ```rust
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # fn tst<P: PhysicalDevice<Commands: vk::instance::VERSION_1_1>>
+    /// #   (physical_device: P) {
+    /// let format_properties = physical_device.get_physical_device_format_properties2(
+    ///     vk::Format::R8G8B8A8_SRGB,
+    ///     vk::OutputChain::new(),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// Vulkan docs:
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>
+    fn get_physical_device_format_properties2<F: vk::enum_traits::Format, L>(
+        &self,
+        format: F,
+        chain: OutputChain<vk::FormatProperties2, L>,
+    ) -> FormatProperties<Self, F>
+    where
+        Self::Commands: vk::has_command::GetPhysicalDeviceFormatProperties2,
+    {
+        get_physical_device_format_properties2(self, format, chain)
+    }
+
+    #[cfg(any(VK_VERSION_1_1, VK_KHR_get_physical_device_properties2))]
+    /// Query the queue family properties of the PhysicalDevice, including the properties of extension structures
+    ///
+    /// `chains` has one [`OutputChain`] per queue family, in order of the family index. Each
+    /// structure in the chain of a family is written with the properties of that family, and can
+    /// be read after the query. Families after the end of `chains` are queried without a chain,
+    /// and extra chains are not used.
+    ///
+    /// The number of queue families is queried first, and all families are returned, as with
+    /// [`get_physical_device_queue_family_properties`](PhysicalDevice::get_physical_device_queue_family_properties).
+    ///
+    /// Available with `VERSION_1_1` or `KHR_get_physical_device_properties2`.
+    ///
+    /// ```rust
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # fn tst<P: PhysicalDevice<Commands: vk::instance::VERSION_1_1>>
+    /// #   (physical_device: P) {
+    /// let mut global_priorities: [vk_safe::raw::QueueFamilyGlobalPriorityPropertiesKHR; 4] =
+    ///     unsafe { std::mem::zeroed() };
+    /// let queue_family_properties = physical_device.get_physical_device_queue_family_properties2(
+    ///     global_priorities
+    ///         .iter_mut()
+    ///         .map(|p| vk::OutputChain::new().push_next(p)),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// Vulkan docs:
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html>
+    fn get_physical_device_queue_family_properties2<'a, L: 'a>(
+        &self,
+        chains: impl IntoIterator<Item = OutputChain<'a, vk::QueueFamilyProperties2, L>>,
+    ) -> QueueFamilies<Self, Vec<vk::QueueFamilyProperties>>
+    where
+        Self::Commands: vk::has_command::GetPhysicalDeviceQueueFamilyProperties2,
+    {
+        get_physical_device_queue_family_properties2(self, chains)
+    }
+
     // ****TODO: if `use<>` becomes available in RPITIT, then this can be uncommented
     // /// Create a device from the PhysicalDevice
     // ///
@@ -285,19 +471,20 @@ pub trait PhysicalDevice:
     // /// # fn tst<P: vk::PhysicalDevice<Commands: vk::instance::VERSION_1_0>, T>
     // /// #   (physical_device: P, create_info: &vk::DeviceCreateInfo<D, (P, T)>, queue_properties: &vk::QueueFamiliesRef<P>) {
     // /// vk::tag!(tag);
-    // /// let device = physical_device.create_device(create_info, tag).unwrap();
+    // /// let device = physical_device.create_device(create_info, (), tag).unwrap();
     // /// # }
     // /// ```
     // ///
     // /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCreateDevice.html>
-    // fn create_device<'t, C, O, Z: HasScope<Self>>(
+    // fn create_device<'t, C, O, Z: HasScope<Self>, A: Allocator>(
     //     &self,
     //     create_info: &DeviceCreateInfo<C, Z>,
+    //     allocator: A,
     //     tag: Tag<'t>,
     // ) -> Result<
     //     // impl Device<Context = D::Commands, PhysicalDevice = S, QueueConfig = Z> + Captures<Tag<'t>>,
-    //     _Device<C::Commands, Self, Z, Tag<'t>>,
-    //     Error,
+    //     _Device<C::Commands, A, Self, Z, Tag<'t>>,
+    //     VulkanError,
     // >
     // where
     //     Self::Commands:
@@ -306,7 +493,7 @@ pub trait PhysicalDevice:
     //     C::Commands:
     //         DestroyDevice + LoadCommands + Version + VersionCheck<Self::Commands> + Send + Sync,
     // {
-    //     create_device(self, create_info, tag)
+    //     create_device(self, create_info, allocator, tag)
     // }
 }
 
@@ -416,4 +603,8 @@ impl<I: Instance, T> DispatchableHandle for _PhysicalDevice<'_, I, T> {
 
 impl<I: Instance, T> PhysicalDevice for _PhysicalDevice<'_, I, T> {
     type Instance = I;
+
+    fn instance(&self) -> &Self::Instance {
+        self.instance
+    }
 }
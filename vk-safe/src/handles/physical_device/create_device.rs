@@ -2,8 +2,10 @@ use super::PhysicalDevice;
 
 use std::mem::MaybeUninit;
 
+use crate::allocation_callbacks::Allocator;
 use crate::enumerator::Enumerator;
-use crate::error::Error;
+use crate::error::VulkanError;
+use crate::features::Features;
 use crate::handles::device::{make_device, Device};
 use crate::scope::{Captures, HasScope, Tag};
 use crate::structs::DeviceCreateInfo;
@@ -39,14 +41,18 @@ pub fn create_device<
     C,
     O,
     Z: HasScope<P>,
+    N,
+    F: Features,
+    A: Allocator,
 >(
     physical_device: &P,
-    create_info: &DeviceCreateInfo<C, Z>,
+    create_info: &DeviceCreateInfo<C, Z, N, F>,
+    allocator: A,
     tag: Tag<'t>,
 ) -> Result<
-    impl Device<Commands = C::Commands, PhysicalDevice = P, QueueConfig = Z>
-        + Captures<(Tag<'t>, P, C, Z)>,
-    Error,
+    impl Device<Commands = C::Commands, PhysicalDevice = P, QueueConfig = Z, Features = F>
+        + Captures<(Tag<'t>, P, C, Z, F)>,
+    VulkanError,
 >
 where
     C: Context + InstanceDependencies<P::Commands, O> + Send + Sync,
@@ -96,7 +102,7 @@ where
         "structure"
         }
 
-        // AllocationCallbacks not supported
+        // ensured by Allocator
     }
 
     #[allow(unused_labels)]
@@ -125,17 +131,27 @@ where
     }
     // *********************************************
 
+    let instance = physical_device.instance();
+    let get_device_proc_addr = instance
+        .entry()
+        .get_device_proc_addr(instance.raw_handle())?;
+
     let device;
     unsafe {
         let res = physical_device.commands().CreateDevice().get_fptr()(
             physical_device.raw_handle(),
             create_info.to_c(),
-            std::ptr::null(),
+            allocator.callbacks(),
             handle.as_mut_ptr(),
         );
         check_raw_err!(res);
         device = handle.assume_init();
     }
-    let loader = |command_name| unsafe { vk::GetDeviceProcAddr(device, command_name) };
-    Ok(make_device(device, C::Commands::load(loader)?, tag))
+    let loader = |command_name| unsafe { get_device_proc_addr(device, command_name) };
+    Ok(make_device(
+        device,
+        C::Commands::load(loader)?,
+        allocator,
+        tag,
+    ))
 }
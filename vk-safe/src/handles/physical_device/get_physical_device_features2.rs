@@ -0,0 +1,47 @@
+use super::PhysicalDevice;
+
+use crate::structs::{OutputChain, PhysicalDeviceFeatures};
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetPhysicalDeviceFeatures2;
+
+pub(crate) fn get_physical_device_features2<
+    P: PhysicalDevice<Commands: GetPhysicalDeviceFeatures2>,
+    L,
+>(
+    physical_device: &P,
+    chain: OutputChain<vk::PhysicalDeviceFeatures2, L>,
+) -> PhysicalDeviceFeatures<P> {
+    check_vuids::check_vuids!(GetPhysicalDeviceFeatures2);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceFeatures2_physicalDevice_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "physicalDevice must be a valid VkPhysicalDevice handle"
+        }
+
+        // valid from creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceFeatures2_pFeatures_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pFeatures must be a valid pointer to a VkPhysicalDeviceFeatures2 structure"
+        }
+
+        // OutputChain sets sType, and the pNext chain of structures which extend VkPhysicalDeviceFeatures2
+    }
+
+    let mut features = chain.uninit_base();
+    unsafe {
+        physical_device
+            .commands()
+            .GetPhysicalDeviceFeatures2()
+            .get_fptr()(physical_device.raw_handle(), features.as_mut_ptr());
+        PhysicalDeviceFeatures::from_c(features.assume_init().features)
+    }
+}
@@ -0,0 +1,63 @@
+use super::PhysicalDevice;
+
+use crate::structs::{FormatProperties, OutputChain};
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetPhysicalDeviceFormatProperties2;
+
+pub(crate) fn get_physical_device_format_properties2<
+    P: PhysicalDevice<Commands: GetPhysicalDeviceFormatProperties2>,
+    F: vk::enum_traits::Format,
+    L,
+>(
+    physical_device: &P,
+    _format: F,
+    chain: OutputChain<vk::FormatProperties2, L>,
+) -> FormatProperties<P, F> {
+    check_vuids::check_vuids!(GetPhysicalDeviceFormatProperties2);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceFormatProperties2_physicalDevice_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "physicalDevice must be a valid VkPhysicalDevice handle"
+        }
+
+        // valid from creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceFormatProperties2_format_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "format must be a valid VkFormat value"
+        }
+
+        // vk::Format
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceFormatProperties2_pFormatProperties_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pFormatProperties must be a valid pointer to a VkFormatProperties2 structure"
+        }
+
+        // OutputChain sets sType, and the pNext chain of unique structures which extend VkFormatProperties2
+    }
+
+    let mut properties = chain.uninit_base();
+    unsafe {
+        physical_device
+            .commands()
+            .GetPhysicalDeviceFormatProperties2()
+            .get_fptr()(
+            physical_device.raw_handle(),
+            F::VALUE,
+            properties.as_mut_ptr(),
+        );
+        FormatProperties::from_c(properties.assume_init().format_properties)
+    }
+}
@@ -1,6 +1,6 @@
 use super::PhysicalDevice;
 
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::structs::{ImageFormatProperties, ImageParameters::ImageParameters};
 use crate::type_conversions::ConvertWrapper;
 
@@ -16,7 +16,7 @@ pub(crate) fn get_physical_device_image_format_properties<
 >(
     physical_device: &P,
     _params: Params,
-) -> Result<ImageFormatProperties<P, Params>, Error> {
+) -> Result<ImageFormatProperties<P, Params>, VulkanError> {
     // *************Regarding VUID checks**************
     // please see the checks for [ImageParameters]
 
@@ -0,0 +1,47 @@
+use super::PhysicalDevice;
+
+use crate::structs::{OutputChain, PhysicalDeviceMemoryProperties};
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetPhysicalDeviceMemoryProperties2;
+
+pub(crate) fn get_physical_device_memory_properties2<
+    P: PhysicalDevice<Commands: GetPhysicalDeviceMemoryProperties2>,
+    L,
+>(
+    physical_device: &P,
+    chain: OutputChain<vk::PhysicalDeviceMemoryProperties2, L>,
+) -> PhysicalDeviceMemoryProperties<P> {
+    check_vuids::check_vuids!(GetPhysicalDeviceMemoryProperties2);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceMemoryProperties2_physicalDevice_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "physicalDevice must be a valid VkPhysicalDevice handle"
+        }
+
+        // valid from creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceMemoryProperties2_pMemoryProperties_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pMemoryProperties must be a valid pointer to a VkPhysicalDeviceMemoryProperties2 structure"
+        }
+
+        // OutputChain sets sType, and the pNext chain of unique structures which extend VkPhysicalDeviceMemoryProperties2
+    }
+
+    let mut properties = chain.uninit_base();
+    unsafe {
+        physical_device
+            .commands()
+            .GetPhysicalDeviceMemoryProperties2()
+            .get_fptr()(physical_device.raw_handle(), properties.as_mut_ptr());
+        PhysicalDeviceMemoryProperties::from_c(properties.assume_init().memory_properties)
+    }
+}
@@ -0,0 +1,47 @@
+use super::PhysicalDevice;
+
+use crate::structs::{OutputChain, PhysicalDeviceProperties};
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetPhysicalDeviceProperties2;
+
+pub(crate) fn get_physical_device_properties2<
+    P: PhysicalDevice<Commands: GetPhysicalDeviceProperties2>,
+    L,
+>(
+    physical_device: &P,
+    chain: OutputChain<vk::PhysicalDeviceProperties2, L>,
+) -> PhysicalDeviceProperties<P> {
+    check_vuids::check_vuids!(GetPhysicalDeviceProperties2);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceProperties2_physicalDevice_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "physicalDevice must be a valid VkPhysicalDevice handle"
+        }
+
+        // valid from creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceProperties2_pProperties_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pProperties must be a valid pointer to a VkPhysicalDeviceProperties2 structure"
+        }
+
+        // OutputChain sets sType, and the pNext chain of unique structures which extend VkPhysicalDeviceProperties2
+    }
+
+    let mut properties = chain.uninit_base();
+    unsafe {
+        physical_device
+            .commands()
+            .GetPhysicalDeviceProperties2()
+            .get_fptr()(physical_device.raw_handle(), properties.as_mut_ptr());
+        PhysicalDeviceProperties::from_c(properties.assume_init().properties)
+    }
+}
@@ -0,0 +1,91 @@
+use super::PhysicalDevice;
+
+use crate::structs::{OutputChain, QueueFamilies};
+
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::GetPhysicalDeviceQueueFamilyProperties2;
+
+pub(crate) fn get_physical_device_queue_family_properties2<
+    'a,
+    P: PhysicalDevice<Commands: GetPhysicalDeviceQueueFamilyProperties2>,
+    L: 'a,
+>(
+    physical_device: &P,
+    chains: impl IntoIterator<Item = OutputChain<'a, vk::QueueFamilyProperties2, L>>,
+) -> QueueFamilies<P, Vec<vk::QueueFamilyProperties>> {
+    check_vuids::check_vuids!(GetPhysicalDeviceQueueFamilyProperties2);
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceQueueFamilyProperties2_physicalDevice_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "physicalDevice must be a valid VkPhysicalDevice handle"
+        }
+
+        // valid from creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceQueueFamilyProperties2_pQueueFamilyPropertyCount_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pQueueFamilyPropertyCount must be a valid pointer to a uint32_t value"
+        }
+
+        // &mut count
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkGetPhysicalDeviceQueueFamilyProperties2_pQueueFamilyProperties_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If the value referenced by pQueueFamilyPropertyCount is not 0, and pQueueFamilyProperties"
+        "is not NULL, pQueueFamilyProperties must be a valid pointer to an array of pQueueFamilyPropertyCount"
+        "VkQueueFamilyProperties2 structures"
+        }
+
+        // Vec with capacity for count structures, and each has sType and pNext set from an OutputChain
+    }
+
+    let fptr = physical_device
+        .commands()
+        .GetPhysicalDeviceQueueFamilyProperties2()
+        .get_fptr();
+
+    let mut count = 0;
+    unsafe {
+        fptr(
+            physical_device.raw_handle(),
+            &mut count,
+            std::ptr::null_mut(),
+        )
+    };
+
+    // each family has its own pNext chain, and families without a chain get an empty chain
+    let mut chains = chains.into_iter();
+    let mut properties: Vec<MaybeUninit<vk::QueueFamilyProperties2>> = (0..count)
+        .map(|_| match chains.next() {
+            Some(chain) => chain.uninit_base(),
+            None => OutputChain::<vk::QueueFamilyProperties2, ()>::new().uninit_base(),
+        })
+        .collect();
+
+    unsafe {
+        fptr(
+            physical_device.raw_handle(),
+            &mut count,
+            properties.as_mut_ptr().cast(),
+        )
+    };
+    // the number of queue families does not change, but count is the number actually written
+    properties.truncate(count as usize);
+
+    let families = properties
+        .into_iter()
+        .map(|p| unsafe { p.assume_init().queue_family_properties })
+        .collect();
+    QueueFamilies::new(families)
+}
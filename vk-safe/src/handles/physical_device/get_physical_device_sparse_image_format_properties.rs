@@ -1,7 +1,7 @@
 use super::PhysicalDevice;
 
 use crate::enumerator::Enumerator;
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::scope::Captures;
 use crate::structs::{
     ImageFormatProperties, ImageParameters::ImageParameters, SparseImageFormatProperties,
@@ -11,9 +11,6 @@ use vk_safe_sys as vk;
 
 use vk::has_command::GetPhysicalDeviceSparseImageFormatProperties;
 
-unit_error!(OnlyOneSampleCountAllowed);
-unit_error!(UnsupportedSampleCount);
-
 pub(crate) fn get_physical_device_sparse_image_format_properties<
     P: PhysicalDevice<Commands: GetPhysicalDeviceSparseImageFormatProperties>,
     Params: ImageParameters,
@@ -22,7 +19,7 @@ pub(crate) fn get_physical_device_sparse_image_format_properties<
     physical_device: &P,
     _samples: SampleCount,
     image_format_properties: ImageFormatProperties<P, Params>,
-) -> Result<impl Enumerator<SparseImageFormatProperties<P>> + Captures<&P>, Error> {
+) -> Result<impl Enumerator<SparseImageFormatProperties<P>> + Captures<&P>, VulkanError> {
     check_vuids::check_vuids!(GetPhysicalDeviceSparseImageFormatProperties);
 
     #[allow(unused_labels)]
@@ -37,12 +34,12 @@ pub(crate) fn get_physical_device_sparse_image_format_properties<
 
         // I interpret this VUID to mean there should be exactly one bit set which is supported for the given image format, type, tiling, and usage
         if SampleCount::INCLUDES.count_bits() != 1 {
-            Err(OnlyOneSampleCountAllowed)?
+            Err(VulkanError::OnlyOneSampleCountAllowed)?
         } else if !image_format_properties
             .sample_counts
             .contains(SampleCount::INCLUDES)
         {
-            Err(UnsupportedSampleCount)?
+            Err(VulkanError::UnsupportedSampleCount)?
         }
         // since we keep the parameters used for the given ImageFormatProperties, we ensure to use the the same format, type, tiling, and usage
     }
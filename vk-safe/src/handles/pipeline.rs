@@ -0,0 +1,234 @@
+use super::device::Device;
+use super::pipeline_layout::PipelineLayout;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::PipelineBindPoint;
+use vk::has_command::DestroyPipeline;
+
+/// Pipeline handle trait
+///
+/// Represents a *specific* Pipeline.
+///
+/// The PipelineLayout is not borrowed by the Pipeline, but is tracked as a type so
+/// that compatible descriptor sets and push constants can be used with the Pipeline.
+pub trait Pipeline:
+    Handle<RawHandle = vk::Pipeline> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this Pipeline belongs
+    type Device: Device;
+    /// The PipelineLayout the Pipeline was created with
+    type Layout: PipelineLayout<Device = Self::Device>;
+    /// The type of Pipeline (e.g. compute)
+    type BindPoint: PipelineBindPoint;
+}
+
+/// [`Pipeline`] implementor
+struct _Pipeline<
+    'a,
+    D: Device<Commands: DestroyPipeline>,
+    L: PipelineLayout<Device = D>,
+    B: PipelineBindPoint,
+    A: Allocator,
+> {
+    handle: vk::Pipeline,
+    device: &'a D,
+    allocator: A,
+    layout: PhantomData<L>,
+    bind_point: PhantomData<B>,
+}
+
+pub(crate) fn make_pipeline<
+    'a,
+    D: Device<Commands: DestroyPipeline>,
+    L: PipelineLayout<Device = D>,
+    B: PipelineBindPoint,
+    A: Allocator,
+>(
+    handle: vk::Pipeline,
+    device: &'a D,
+    allocator: A,
+) -> impl Pipeline<Device = D, Layout = L, BindPoint = B> + use<'a, D, L, B, A> {
+    _Pipeline {
+        handle,
+        device,
+        allocator,
+        layout: PhantomData,
+        bind_point: PhantomData,
+    }
+}
+
+impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > Pipeline for _Pipeline<'_, D, L, B, A>
+{
+    type Device = D;
+    type Layout = L;
+    type BindPoint = B;
+}
+
+unsafe impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > Send for _Pipeline<'_, D, L, B, A>
+{
+}
+unsafe impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > Sync for _Pipeline<'_, D, L, B, A>
+{
+}
+impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > ThreadSafeHandle for _Pipeline<'_, D, L, B, A>
+{
+}
+
+impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > Handle for _Pipeline<'_, D, L, B, A>
+{
+    type RawHandle = vk::Pipeline;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > DeviceObject<D> for _Pipeline<'_, D, L, B, A>
+{
+}
+
+impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > fmt::Debug for _Pipeline<'_, D, L, B, A>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<
+        D: Device<Commands: DestroyPipeline>,
+        L: PipelineLayout<Device = D>,
+        B: PipelineBindPoint,
+        A: Allocator,
+    > Drop for _Pipeline<'_, D, L, B, A>
+{
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyPipeline);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_pipeline_00765: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All submitted commands that refer to pipeline must have completed execution"
+            }
+
+            // CommandBuffers borrow the Pipeline for as long as they exist, and queue_submit
+            // does not return until the submitted CommandBuffers complete execution
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_pipeline_00766: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when pipeline was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the Pipeline is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_pipeline_00767: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when pipeline was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the Pipeline is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_pipeline_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipeline is not VK_NULL_HANDLE, pipeline must be a valid VkPipeline handle"
+            }
+
+            // ensured by Pipeline creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipeline_pipeline_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipeline is a valid handle, it must have been created, allocated, or retrieved from"
+            "device"
+            }
+
+            // ensured by Pipeline creation
+        }
+
+        unsafe {
+            self.device.commands().DestroyPipeline().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use super::device::Device;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::DestroyPipelineLayout;
+
+/// PipelineLayout handle trait
+///
+/// Represents a *specific* PipelineLayout.
+pub trait PipelineLayout:
+    Handle<RawHandle = vk::PipelineLayout> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this PipelineLayout belongs
+    type Device: Device;
+}
+
+/// [`PipelineLayout`] implementor
+struct _PipelineLayout<'a, D: Device<Commands: DestroyPipelineLayout>, A: Allocator> {
+    handle: vk::PipelineLayout,
+    device: &'a D,
+    allocator: A,
+}
+
+pub(crate) fn make_pipeline_layout<'a, D: Device<Commands: DestroyPipelineLayout>, A: Allocator>(
+    handle: vk::PipelineLayout,
+    device: &'a D,
+    allocator: A,
+) -> impl PipelineLayout<Device = D> + use<'a, D, A> {
+    _PipelineLayout {
+        handle,
+        device,
+        allocator,
+    }
+}
+
+impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> PipelineLayout
+    for _PipelineLayout<'_, D, A>
+{
+    type Device = D;
+}
+
+unsafe impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> Send
+    for _PipelineLayout<'_, D, A>
+{
+}
+unsafe impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> Sync
+    for _PipelineLayout<'_, D, A>
+{
+}
+impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> ThreadSafeHandle
+    for _PipelineLayout<'_, D, A>
+{
+}
+
+impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> Handle
+    for _PipelineLayout<'_, D, A>
+{
+    type RawHandle = vk::PipelineLayout;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> DeviceObject<D>
+    for _PipelineLayout<'_, D, A>
+{
+}
+
+impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> fmt::Debug
+    for _PipelineLayout<'_, D, A>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipelineLayout")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroyPipelineLayout>, A: Allocator> Drop for _PipelineLayout<'_, D, A> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroyPipelineLayout);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_pipelineLayout_00299: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when pipelineLayout was created, a compatible"
+            "set of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the PipelineLayout is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_pipelineLayout_00300: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when pipelineLayout was created, pAllocator"
+            "must be NULL"
+            }
+
+            // the same Allocator that was used to create the PipelineLayout is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_pipelineLayout_02004: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pipelineLayout must not have been passed to any vkCmd* command for any command buffers"
+            "that are still in the recording state when vkDestroyPipelineLayout is called"
+            }
+
+            // TODO: PipelineLayouts cannot be passed to vkCmd* commands yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_pipelineLayout_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineLayout is not VK_NULL_HANDLE, pipelineLayout must be a valid VkPipelineLayout"
+            "handle"
+            }
+
+            // ensured by PipelineLayout creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroyPipelineLayout_pipelineLayout_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pipelineLayout is a valid handle, it must have been created, allocated, or retrieved"
+            "from device"
+            }
+
+            // ensured by PipelineLayout creation
+        }
+
+        unsafe {
+            self.device.commands().DestroyPipelineLayout().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
@@ -1,7 +1,14 @@
 use super::device::Device;
-use super::{DispatchableHandle, Handle, ThreadSafeHandle};
+use super::{DeviceObject, DispatchableHandle, Handle, ThreadSafeHandle};
 
+use super::command_pool::CommandPool;
+use super::fence::Fence;
+
+use crate::error::VulkanError;
 use crate::scope::{Captures, Tag};
+#[cfg(VK_EXT_debug_utils)]
+use crate::structs::DebugUtilsLabelEXT;
+use crate::structs::{SignalSemaphores, SubmitCommandBuffers, SubmitInfo, WaitSemaphores};
 
 use std::fmt;
 use std::marker::PhantomData;
@@ -10,10 +17,111 @@ use vk_safe_sys as vk;
 
 use vk::flag_traits::QueueFlags;
 
-pub trait Queue: DispatchableHandle<RawHandle = vk::Queue> + ThreadSafeHandle {
-    type Device;
+pub_use_modules!(
+#[cfg(VK_VERSION_1_0)] {
+    queue_submit;
+};
+#[cfg(VK_EXT_debug_utils)] {
+    queue_begin_debug_utils_label;
+};
+);
+
+pub trait Queue:
+    DispatchableHandle<RawHandle = vk::Queue> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    type Device: Device;
     type Capability: QueueFlags;
     type Family;
+
+    #[cfg(VK_VERSION_1_0)]
+    /**
+    Submit CommandBuffers to the Queue
+
+    The CommandBuffers must be executable, and allocated from a CommandPool for
+    the same queue family as this Queue. The Fence is reset and then signaled
+    when the submitted work completes.
+
+    The submitted work is represented by a [`Submission`] which is given to `scope`,
+    along with the Queue (e.g. to submit more work while this work is pending). The
+    Submission holds the CommandBuffers and Semaphores from the [`SubmitInfo`], and
+    borrows the Fence, until the submitted work has completed. Thus, pending
+    CommandBuffers can not be reset, freed, or submitted again.
+
+    The Submission cannot escape `scope`, and this function does not return until
+    the submitted work has completed, even if the Submission is leaked or `scope`
+    panics. Returns the result of `scope`.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<
+    #   D: vk::Device<Commands: vk::device::VERSION_1_0>,
+    #   Q: vk::Queue<Device = D, Commands = D::Commands>,
+    #   P: vk::CommandPool<QueueFamily = Q::Family>,
+    # >(
+    #   mut queue: Q,
+    #   fence: &mut impl vk::Fence<Device = D>,
+    #   command_buffer: vk::_CommandBuffer<D, P, vk::CommandBufferLevel::PRIMARY, vk::command_buffer_state::Executable<()>>,
+    # ) {
+    let submit_info = vk::SubmitInfo::new([command_buffer]);
+    let completed = queue
+        .queue_submit(submit_info, fence, |_queue, submission| {
+            submission.wait(u64::MAX).unwrap().unwrap()
+        })
+        .unwrap();
+    let [command_buffer] = completed.command_buffers;
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkQueueSubmit.html>
+    */
+    fn queue_submit<C, W, S, F, R>(
+        &mut self,
+        info: SubmitInfo<C, W, S>,
+        fence: &mut F,
+        scope: impl for<'s> FnOnce(&'s mut Self, Submission<'s, F, C, W, S>) -> R,
+    ) -> Result<R, VulkanError>
+    where
+        Self::Commands: vk::has_command::QueueSubmit,
+        Self::Device:
+            Device<Commands: vk::has_command::ResetFences + vk::has_command::WaitForFences>,
+        C: SubmitCommandBuffers<Self::Device, Pool: CommandPool<QueueFamily = Self::Family>>,
+        W: WaitSemaphores<Self::Device>,
+        S: SignalSemaphores<Self::Device>,
+        F: Fence<Device = Self::Device>,
+    {
+        queue_submit(self, info, fence, scope)
+    }
+
+    #[cfg(VK_EXT_debug_utils)]
+    /**
+    Begin a labeled region of Queue operations, for debugging
+
+    Operations on the Queue (e.g. submissions) made through the returned
+    [`QueueLabelRegion`] are in the labeled region. The label is ended when
+    the region is dropped. Regions can be nested.
+
+    ```
+    # use vk_safe::vk;
+    # use vk::traits::*;
+    # fn tst<Q: vk::Queue<Commands: vk::device::VERSION_1_0 + vk::device::EXT_debug_utils>>
+    #   (mut queue: Q) {
+    let label = vk::DebugUtilsLabelEXT::new(vk_safe::vk_str!("frame"));
+    let mut frame = queue.begin_label(&label);
+    // submit work with frame.queue_submit(...)
+    frame.end_label();
+    # }
+    ```
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkQueueBeginDebugUtilsLabelEXT.html>
+    */
+    fn begin_label(&mut self, label: &DebugUtilsLabelEXT) -> QueueLabelRegion<'_, Self>
+    where
+        Self::Commands: vk::has_command::QueueBeginDebugUtilsLabelEXT
+            + vk::has_command::QueueEndDebugUtilsLabelEXT,
+    {
+        queue_begin_debug_utils_label(self, label)
+    }
 }
 
 pub(crate) unsafe fn make_queue<'a, 't, D: Device, C: QueueFlags>(
@@ -60,11 +168,13 @@ impl<'a, D, C, T> Handle for _Queue<'a, D, C, T> {
     }
 }
 
+impl<'a, D, C, T> DeviceObject<D> for _Queue<'a, D, C, T> {}
+
 impl<'a, D: Device, C, T> DispatchableHandle for _Queue<'a, D, C, T> {
     type Commands = D::Commands;
 
     fn commands(&self) -> &Self::Commands {
-        todo!()
+        self.device.commands()
     }
 }
 
@@ -74,13 +184,20 @@ impl<'a, D: Device, C: QueueFlags, T> Queue for _Queue<'a, D, C, T> {
     type Family = T;
 }
 
+/// Marker for a configured queue family
+///
+/// Returned from [`get_device_queues`](crate::vk::get_device_queues) together with the
+/// Queues of the family. The tag `T` is shared with the Queues, and the capability `C`
+/// is the same as the Queues. Used to create CommandPools for the family, and to
+/// refer to the family in queue family ownership transfers.
 #[derive(Clone, Copy)]
-pub struct QueueFamilyMarker<T> {
+pub struct QueueFamilyMarker<T, C> {
     queue_family_index: u32,
     tag: PhantomData<T>,
+    capability: PhantomData<C>,
 }
 
-impl<T> fmt::Debug for QueueFamilyMarker<T> {
+impl<T, C> fmt::Debug for QueueFamilyMarker<T, C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("QueueFamilyMarker")
             .field("queue_family_index", &self.queue_family_index)
@@ -88,22 +205,112 @@ impl<T> fmt::Debug for QueueFamilyMarker<T> {
     }
 }
 
-impl<'t> QueueFamilyMarker<Tag<'t>> {
+impl<'t, C: QueueFlags> QueueFamilyMarker<Tag<'t>, C> {
     /// create a queue family marker
     ///
     /// The caller must ensure that the index is a correct index for a family
-    /// of [`Queue`] created with the same tag
+    /// of [`Queue`] created with the same tag, and which supports the capability `C`
     pub(crate) unsafe fn new(queue_family_index: u32, _tag: &Tag<'t>) -> Self {
         Self {
             queue_family_index,
             tag: PhantomData,
+            capability: PhantomData,
         }
     }
 }
 
-impl<T> QueueFamilyMarker<T> {
+impl<T, C> QueueFamilyMarker<T, C> {
     /// get the index for this family for certain Vulkan Commands
     pub(crate) fn family_index(&self) -> u32 {
         self.queue_family_index
     }
 }
+
+/// Type level representation of queue family ownership transfers
+///
+/// Used with [`BufferMemoryBarrier`](crate::vk::BufferMemoryBarrier) and
+/// [`ImageMemoryBarrier`](crate::vk::ImageMemoryBarrier) to define a release or acquire
+/// operation between two queue families, which are identified by the tags of their
+/// [`QueueFamilyMarker`]s. A barrier with an ownership transfer can only be recorded into a
+/// CommandBuffer allocated from a CommandPool for the queue family which performs the operation
+/// (i.e. the source family for a release, and the destination family for an acquire).
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/html/vkspec.html#synchronization-queue-transfers>
+pub mod ownership_transfer {
+    use std::marker::PhantomData;
+
+    /// Release ownership from the queue family `S` to the queue family `D`
+    #[derive(Debug)]
+    pub struct Release<S, D>(PhantomData<(S, D)>);
+
+    /// Acquire ownership from the queue family `S` by the queue family `D`
+    #[derive(Debug)]
+    pub struct Acquire<S, D>(PhantomData<(S, D)>);
+}
+
+/// Pipeline stages from core Vulkan 1.0
+pub(crate) const CORE_PIPELINE_STAGES: vk::PipelineStageFlags =
+    vk::PipelineStageFlags::TOP_OF_PIPE_BIT
+        .or(vk::PipelineStageFlags::DRAW_INDIRECT_BIT)
+        .or(vk::PipelineStageFlags::VERTEX_INPUT_BIT)
+        .or(vk::PipelineStageFlags::VERTEX_SHADER_BIT)
+        .or(vk::PipelineStageFlags::TESSELLATION_CONTROL_SHADER_BIT)
+        .or(vk::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER_BIT)
+        .or(vk::PipelineStageFlags::GEOMETRY_SHADER_BIT)
+        .or(vk::PipelineStageFlags::FRAGMENT_SHADER_BIT)
+        .or(vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS_BIT)
+        .or(vk::PipelineStageFlags::LATE_FRAGMENT_TESTS_BIT)
+        .or(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT_BIT)
+        .or(vk::PipelineStageFlags::COMPUTE_SHADER_BIT)
+        .or(vk::PipelineStageFlags::TRANSFER_BIT)
+        .or(vk::PipelineStageFlags::BOTTOM_OF_PIPE_BIT)
+        .or(vk::PipelineStageFlags::HOST_BIT)
+        .or(vk::PipelineStageFlags::ALL_GRAPHICS_BIT)
+        .or(vk::PipelineStageFlags::ALL_COMMANDS_BIT);
+
+/// Check if all `stages` are supported by a queue with the `capability` flags
+///
+/// Empty `stages` are trivially supported.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/html/vkspec.html#synchronization-pipeline-stages-supported>
+pub(crate) const fn pipeline_stages_supported(
+    stages: vk::PipelineStageFlags,
+    capability: vk::QueueFlags,
+) -> bool {
+    use vk::PipelineStageFlags as Stage;
+    use vk::QueueFlags as Capability;
+
+    let mut supported = Stage::TOP_OF_PIPE_BIT
+        .or(Stage::BOTTOM_OF_PIPE_BIT)
+        .or(Stage::HOST_BIT)
+        .or(Stage::ALL_COMMANDS_BIT);
+
+    if capability.any_of(Capability::GRAPHICS_BIT) {
+        supported = supported
+            .or(Stage::DRAW_INDIRECT_BIT)
+            .or(Stage::VERTEX_INPUT_BIT)
+            .or(Stage::VERTEX_SHADER_BIT)
+            .or(Stage::TESSELLATION_CONTROL_SHADER_BIT)
+            .or(Stage::TESSELLATION_EVALUATION_SHADER_BIT)
+            .or(Stage::GEOMETRY_SHADER_BIT)
+            .or(Stage::FRAGMENT_SHADER_BIT)
+            .or(Stage::EARLY_FRAGMENT_TESTS_BIT)
+            .or(Stage::LATE_FRAGMENT_TESTS_BIT)
+            .or(Stage::COLOR_ATTACHMENT_OUTPUT_BIT)
+            .or(Stage::ALL_GRAPHICS_BIT)
+            .or(Stage::TRANSFER_BIT);
+    }
+
+    if capability.any_of(Capability::COMPUTE_BIT) {
+        supported = supported
+            .or(Stage::DRAW_INDIRECT_BIT)
+            .or(Stage::COMPUTE_SHADER_BIT)
+            .or(Stage::TRANSFER_BIT);
+    }
+
+    if capability.any_of(Capability::TRANSFER_BIT) {
+        supported = supported.or(Stage::TRANSFER_BIT);
+    }
+
+    stages.is_empty() || stages.subset_of(supported)
+}
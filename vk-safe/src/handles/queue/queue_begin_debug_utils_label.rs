@@ -0,0 +1,126 @@
+use super::Queue;
+
+use crate::structs::DebugUtilsLabelEXT;
+use crate::type_conversions::ConvertWrapper;
+
+use std::ops::{Deref, DerefMut};
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{QueueBeginDebugUtilsLabelEXT, QueueEndDebugUtilsLabelEXT};
+
+/// A labeled region of Queue operations
+///
+/// Returned by [`begin_label`](crate::vk::Queue::begin_label). The Queue is used through
+/// this type (e.g. to submit CommandBuffers) while the label is open, and the label is
+/// ended with [`end_label`](Self::end_label) or when dropped.
+///
+/// If the region is leaked (e.g. with `std::mem::forget`), or the Queue is replaced (e.g. with
+/// `std::mem::swap`), the label is never ended.
+#[derive(Debug)]
+pub struct QueueLabelRegion<'q, Q: Queue<Commands: QueueEndDebugUtilsLabelEXT>> {
+    queue: &'q mut Q,
+    handle: vk::Queue,
+}
+
+pub(crate) fn queue_begin_debug_utils_label<
+    'q,
+    Q: Queue<Commands: QueueBeginDebugUtilsLabelEXT + QueueEndDebugUtilsLabelEXT>,
+>(
+    queue: &'q mut Q,
+    label: &DebugUtilsLabelEXT,
+) -> QueueLabelRegion<'q, Q> {
+    check_vuids::check_vuids!(QueueBeginDebugUtilsLabelEXT);
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueBeginDebugUtilsLabelEXT_queue_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "queue must be a valid VkQueue handle"
+        }
+
+        // ensured by Queue creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueBeginDebugUtilsLabelEXT_pLabelInfo_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pLabelInfo must be a valid pointer to a valid VkDebugUtilsLabelEXT structure"
+        }
+
+        // ensured by DebugUtilsLabelEXT creation
+    }
+
+    unsafe {
+        queue.commands().QueueBeginDebugUtilsLabelEXT().get_fptr()(
+            queue.raw_handle(),
+            label.to_c(),
+        );
+    }
+
+    let handle = queue.raw_handle();
+    QueueLabelRegion { queue, handle }
+}
+
+impl<Q: Queue<Commands: QueueEndDebugUtilsLabelEXT>> QueueLabelRegion<'_, Q> {
+    /// End the label
+    ///
+    /// The same as dropping the region, but more explicit.
+    ///
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkQueueEndDebugUtilsLabelEXT.html>
+    pub fn end_label(self) {}
+}
+
+impl<Q: Queue<Commands: QueueEndDebugUtilsLabelEXT>> Drop for QueueLabelRegion<'_, Q> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(QueueEndDebugUtilsLabelEXT);
+
+        #[allow(unused_labels)]
+        'VUID_vkQueueEndDebugUtilsLabelEXT_None_01911: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "There must be an outstanding vkQueueBeginDebugUtilsLabelEXT command prior to the"
+            "vkQueueEndDebugUtilsLabelEXT on the queue"
+            }
+
+            // QueueLabelRegion is only created by beginning a label
+            if self.queue.raw_handle().handle != self.handle.handle {
+                // the Queue was replaced through DerefMut, and the label was not begun on this
+                // Queue, so leave the label open as if the region was leaked
+                return;
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkQueueEndDebugUtilsLabelEXT_queue_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "queue must be a valid VkQueue handle"
+            }
+
+            // ensured by Queue creation
+        }
+
+        unsafe {
+            self.queue
+                .commands()
+                .QueueEndDebugUtilsLabelEXT()
+                .get_fptr()(self.queue.raw_handle());
+        }
+    }
+}
+
+impl<Q: Queue<Commands: QueueEndDebugUtilsLabelEXT>> Deref for QueueLabelRegion<'_, Q> {
+    type Target = Q;
+
+    fn deref(&self) -> &Self::Target {
+        self.queue
+    }
+}
+
+impl<Q: Queue<Commands: QueueEndDebugUtilsLabelEXT>> DerefMut for QueueLabelRegion<'_, Q> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.queue
+    }
+}
@@ -0,0 +1,543 @@
+use super::{pipeline_stages_supported, Queue};
+
+use crate::error::VulkanError;
+use crate::handles::command_pool::CommandPool;
+use crate::handles::device::Device;
+use crate::handles::fence::Fence;
+use crate::handles::{DispatchableHandle, Handle};
+use crate::structs::{SignalSemaphores, SubmitCommandBuffers, SubmitInfo, WaitSemaphores};
+
+use std::fmt;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::{QueueSubmit, ResetFences, WaitForFences};
+
+pub(crate) fn queue_submit<Q, C, W, S, F, R>(
+    queue: &mut Q,
+    info: SubmitInfo<C, W, S>,
+    fence: &mut F,
+    scope: impl for<'s> FnOnce(&'s mut Q, Submission<'s, F, C, W, S>) -> R,
+) -> Result<R, VulkanError>
+where
+    Q: Queue<Commands: QueueSubmit, Device: Device<Commands: ResetFences + WaitForFences>>,
+    C: SubmitCommandBuffers<Q::Device, Pool: CommandPool<QueueFamily = Q::Family>>,
+    W: WaitSemaphores<Q::Device>,
+    S: SignalSemaphores<Q::Device>,
+    F: Fence<Device = Q::Device>,
+{
+    check_vuids::check_vuids!(QueueSubmit);
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_fence_00063: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If fence is not VK_NULL_HANDLE, fence must be unsignaled"
+        }
+
+        // the fence is reset below
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_fence_00064: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If fence is not VK_NULL_HANDLE, fence must not be associated with any other queue command"
+        "that has not yet completed execution on that queue"
+        }
+
+        // the fence is mutably borrowed by queue_submit until the submitted work has completed
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pCommandBuffers_00065: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Any calls to vkCmdSetEvent, vkCmdResetEvent or vkCmdWaitEvents that have been recorded"
+        "into any of the command buffer elements of the pCommandBuffers member of any element"
+        "of pSubmits, must not reference any VkEvent that is referenced by any of those commands"
+        "in a command buffer that has been submitted to another queue and is still in the pending"
+        "state"
+        }
+
+        // events are not supported at this time
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pWaitDstStageMask_00066: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Any stage flag included in any element of the pWaitDstStageMask member of any element"
+        "of pSubmits must be a pipeline stage supported by one of the capabilities of queue,"
+        "as specified in the table of supported pipeline stages"
+        }
+
+        const {
+            assert!(
+                pipeline_stages_supported(W::STAGES, Q::Capability::INCLUDES),
+                "wait stage is not supported by the Queue capability"
+            )
+        }
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pSignalSemaphores_00067: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Each binary semaphore element of the pSignalSemaphores member of any element of pSubmits"
+        "must be unsignaled when the semaphore signal operation it defines is executed on the"
+        "device"
+        }
+
+        // signal Semaphores are unsignaled, and SignaledSemaphore is only made after the signal completes
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pWaitSemaphores_00068: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "When a semaphore wait operation referring to a binary semaphore defined by any element"
+        "of the pWaitSemaphores member of any element of pSubmits executes on queue, there"
+        "must be no other queues waiting on the same semaphore"
+        }
+
+        // SignaledSemaphore is moved into the SubmitInfo, so it can only be waited on once
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pWaitSemaphores_03238: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "All elements of the pWaitSemaphores member of all elements of pSubmits created with"
+        "a VkSemaphoreType of VK_SEMAPHORE_TYPE_BINARY must reference a semaphore signal operation"
+        "that has been submitted for execution and any semaphore signal operations on which"
+        "it depends must have also been submitted for execution"
+        }
+
+        // SignaledSemaphore is only made after the signal operation has completed
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pCommandBuffers_00070: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Each element of the pCommandBuffers member of each element of pSubmits must be in"
+        "the pending or executable state"
+        }
+
+        // SubmitInfo only accepts Executable CommandBuffers
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pCommandBuffers_00071: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If any element of the pCommandBuffers member of any element of pSubmits was not recorded"
+        "with the VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT, it must not be in the pending"
+        "state"
+        }
+
+        // CommandBuffers are held by the Submission while pending (or leaked with it), so they
+        // can not be submitted again
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pCommandBuffers_00072: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Any secondary command buffers recorded into any element of the pCommandBuffers member"
+        "of any element of pSubmits must be in the pending or executable state"
+        }
+
+        // secondary CommandBuffers are consumed by cmd_execute_commands
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pCommandBuffers_00073: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If any secondary command buffers recorded into any element of the pCommandBuffers"
+        "member of any element of pSubmits was not recorded with the VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT,"
+        "it must not be in the pending state"
+        }
+
+        // secondary CommandBuffers are consumed by cmd_execute_commands, and so are only
+        // pending with the primary CommandBuffer they are recorded into
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pCommandBuffers_00074: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Each element of the pCommandBuffers member of each element of pSubmits must have been"
+        "allocated from a VkCommandPool that was created for the same queue family queue belongs"
+        "to"
+        }
+
+        // ensured by the QueueFamily of the CommandPool matching the Family of the Queue
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pSubmits_02207: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If any element of pSubmits-&gt;pCommandBuffers includes a Queue Family Transfer Acquire"
+        "Operation, there must exist a previously submitted Queue Family Transfer Release Operation"
+        "on queue family srcQueueFamilyIndex that matches it"
+        }
+
+        // queue family ownership transfers are not supported at this time
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pSubmits_02808: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Any resource created with VK_SHARING_MODE_EXCLUSIVE that is read by an operation specified"
+        "by pSubmits must not be owned by any queue family other than the one which queue belongs"
+        "to, at the time it is executed"
+        }
+        check_vuids::status!(todo)
+
+        // TODO: the queue family which owns a resource is not tracked, so a resource which is
+        // used by CommandBuffers from different queue families is not checked
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pSubmits_04626: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Any resource created with VK_SHARING_MODE_CONCURRENT that is accessed by an operation"
+        "specified by pSubmits must have included the queue family of queue at resource creation"
+        "time"
+        }
+
+        // Buffers and Images are always created with VK_SHARING_MODE_EXCLUSIVE
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_queue_06448: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If queue was not created with VK_DEVICE_QUEUE_CREATE_PROTECTED_BIT, there must be"
+        "no element of pSubmits that includes a VkProtectedSubmitInfo structure in its pNext"
+        "chain with protectedSubmit equal to VK_TRUE"
+        }
+
+        // p_next not currently supported
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_queue_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "queue must be a valid VkQueue handle"
+        }
+
+        // ensured by Queue creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_pSubmits_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If submitCount is not 0, pSubmits must be a valid pointer to an array of submitCount"
+        "valid VkSubmitInfo structures"
+        }
+
+        // ensured by SubmitInfo creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_fence_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "If fence is not VK_NULL_HANDLE, fence must be a valid VkFence handle"
+        }
+
+        // ensured by Fence creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkQueueSubmit_commonparent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Both of fence, and queue that are valid handles of non-ignored parameters must have"
+        "been created, allocated, or retrieved from the same VkDevice"
+        }
+
+        // the Fence must have the same Device as the Queue
+    }
+
+    reset_fence(fence)?;
+
+    let command_buffers = info.command_buffers.raw_handles();
+    let command_buffers = command_buffers.as_ref();
+    let wait_semaphores = info.wait_semaphores.raw_handles();
+    let wait_semaphores = wait_semaphores.as_ref();
+    let wait_stages = info.wait_semaphores.raw_stages();
+    let wait_stages = wait_stages.as_ref();
+    let signal_semaphores = info.signal_semaphores.raw_handles();
+    let signal_semaphores = signal_semaphores.as_ref();
+
+    let submit_info = vk::SubmitInfo {
+        s_type: vk::StructureType::SUBMIT_INFO,
+        p_next: std::ptr::null(),
+        wait_semaphore_count: wait_semaphores.len().try_into()?,
+        p_wait_semaphores: wait_semaphores.as_ptr(),
+        p_wait_dst_stage_mask: wait_stages.as_ptr(),
+        command_buffer_count: command_buffers.len().try_into()?,
+        p_command_buffers: command_buffers.as_ptr(),
+        signal_semaphore_count: signal_semaphores.len().try_into()?,
+        p_signal_semaphores: signal_semaphores.as_ptr(),
+    };
+
+    unsafe {
+        let res = queue.commands().QueueSubmit().get_fptr()(
+            queue.raw_handle(),
+            1,
+            &submit_info,
+            fence.raw_handle(),
+        );
+        check_raw_err!(res);
+    }
+
+    // The Submission cannot escape the scope, but it can be leaked (e.g. with std::mem::forget),
+    // or the scope can panic. In either case, the borrows of the Fence and of everything used by
+    // the submitted CommandBuffers end when this function returns, so always wait here as well
+    let pending = PendingFence(fence);
+    let submission = Submission {
+        fence: &mut *pending.0,
+        inner: Some((
+            info.command_buffers,
+            info.wait_semaphores,
+            info.signal_semaphores,
+        )),
+    };
+    Ok(scope(queue, submission))
+}
+
+/// Waits for the Fence of a submission when dropped
+struct PendingFence<'f, F: Fence<Device: Device<Commands: WaitForFences>>>(&'f mut F);
+
+impl<F: Fence<Device: Device<Commands: WaitForFences>>> Drop for PendingFence<'_, F> {
+    fn drop(&mut self) {
+        // nothing can be done if waiting fails
+        // the work is considered complete if the Device is lost
+        let _ = wait_for_fence(self.0, u64::MAX);
+    }
+}
+
+fn reset_fence<F: Fence<Device: Device<Commands: ResetFences>>>(
+    fence: &mut F,
+) -> Result<(), VulkanError> {
+    check_vuids::check_vuids!(ResetFences);
+
+    #[allow(unused_labels)]
+    'VUID_vkResetFences_pFences_01123: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Each element of pFences must not be currently associated with any queue command that"
+        "has not yet completed execution on that queue"
+        }
+
+        // the Fence is mutably borrowed by queue_submit until the submitted work has completed
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkResetFences_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkResetFences_pFences_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pFences must be a valid pointer to an array of fenceCount valid VkFence handles"
+        }
+
+        // ensured by Fence creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkResetFences_fenceCount_arraylength: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "fenceCount must be greater than 0"
+        }
+
+        // always 1
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkResetFences_pFences_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Each element of pFences must have been created, allocated, or retrieved from device"
+        }
+
+        // the Device of the Fence is used
+    }
+
+    let device = fence.device();
+    let handle = fence.raw_handle();
+    unsafe {
+        let res = device.commands().ResetFences().get_fptr()(device.raw_handle(), 1, &handle);
+        check_raw_err!(res);
+    }
+    Ok(())
+}
+
+fn wait_for_fence<F: Fence<Device: Device<Commands: WaitForFences>>>(
+    fence: &F,
+    timeout: u64,
+) -> vk::Result {
+    check_vuids::check_vuids!(WaitForFences);
+
+    #[allow(unused_labels)]
+    'VUID_vkWaitForFences_device_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "device must be a valid VkDevice handle"
+        }
+
+        // ensured by Device creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkWaitForFences_pFences_parameter: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "pFences must be a valid pointer to an array of fenceCount valid VkFence handles"
+        }
+
+        // ensured by Fence creation
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkWaitForFences_fenceCount_arraylength: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "fenceCount must be greater than 0"
+        }
+
+        // always 1
+    }
+
+    #[allow(unused_labels)]
+    'VUID_vkWaitForFences_pFences_parent: {
+        check_vuids::version! {"1.3.268"}
+        check_vuids::description! {
+        "Each element of pFences must have been created, allocated, or retrieved from device"
+        }
+
+        // the Device of the Fence is used
+    }
+
+    let device = fence.device();
+    let handle = fence.raw_handle();
+    unsafe {
+        device.commands().WaitForFences().get_fptr()(
+            device.raw_handle(),
+            1,
+            &handle,
+            vk::TRUE,
+            timeout,
+        )
+    }
+}
+
+/// Work submitted to a Queue, which may still be pending
+///
+/// Given to the scope of [`queue_submit`](crate::vk::Queue::queue_submit). Holds the
+/// CommandBuffers and Semaphores that were submitted, and borrows the Fence, until the
+/// submitted work has completed. Use [`wait`](Self::wait) to get them back.
+///
+/// A Submission cannot outlive the scope. If a Submission is dropped, it blocks until the
+/// submitted work has completed. If it is leaked (e.g. with [`std::mem::forget`]), the
+/// CommandBuffers and Semaphores are leaked, and `queue_submit` blocks until the submitted work
+/// has completed before returning.
+pub struct Submission<'f, F: Fence<Device: Device<Commands: WaitForFences>>, C, W, S> {
+    fence: &'f mut F,
+    inner: Option<(C, W, S)>,
+}
+
+/// The CommandBuffers and Semaphores of a completed [`Submission`]
+#[derive(Debug)]
+pub struct CompletedSubmission<C, W, S> {
+    /// The submitted CommandBuffers
+    pub command_buffers: C,
+    /// The Semaphores which were waited on, which are now unsignaled
+    pub wait_semaphores: W,
+    /// The Semaphores which are now signaled
+    pub signal_semaphores: S,
+}
+
+impl<'f, F, C, W, S> Submission<'f, F, C, W, S>
+where
+    F: Fence<Device: Device<Commands: WaitForFences>>,
+    C: SubmitCommandBuffers<F::Device>,
+    W: WaitSemaphores<F::Device>,
+    S: SignalSemaphores<F::Device>,
+{
+    /**
+    Wait for the submitted work to complete
+
+    Blocks for up to `timeout` nanoseconds. Returns [`CompletedSubmission`] if the work completed,
+    or gives back the Submission if the timeout expired first.
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkWaitForFences.html>
+    */
+    pub fn wait(
+        mut self,
+        timeout: u64,
+    ) -> Result<
+        Result<CompletedSubmission<C::Completed, W::Completed, S::Completed>, Self>,
+        VulkanError,
+    > {
+        let res = wait_for_fence(self.fence, timeout);
+        if res == vk::Result::TIMEOUT {
+            return Ok(Err(self));
+        }
+        check_raw_err!(res);
+
+        let (command_buffers, wait_semaphores, signal_semaphores) = self.inner.take().expect(
+            "vk-safe internal error: Submission should hold the submitted items until completed",
+        );
+        unsafe {
+            Ok(Ok(CompletedSubmission {
+                command_buffers: command_buffers.complete(),
+                wait_semaphores: wait_semaphores.complete(),
+                signal_semaphores: signal_semaphores.complete(),
+            }))
+        }
+    }
+}
+
+impl<F: Fence<Device: Device<Commands: WaitForFences>>, C, W, S> fmt::Debug
+    for Submission<'_, F, C, W, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Submission")
+            .field("fence", &self.fence)
+            .field("completed", &self.inner.is_none())
+            .finish()
+    }
+}
+
+impl<F: Fence<Device: Device<Commands: WaitForFences>>, C, W, S> Drop
+    for Submission<'_, F, C, W, S>
+{
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            // nothing can be done if waiting fails
+            // the work is considered complete if the Device is lost
+            let _ = wait_for_fence(self.fence, u64::MAX);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use super::device::Device;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::DestroySampler;
+
+/// Sampler handle trait
+///
+/// Represents a *specific* Sampler.
+pub trait Sampler:
+    Handle<RawHandle = vk::Sampler> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this Sampler belongs
+    type Device: Device;
+}
+
+/// [`Sampler`] implementor
+struct _Sampler<'a, D: Device<Commands: DestroySampler>, A: Allocator> {
+    handle: vk::Sampler,
+    device: &'a D,
+    allocator: A,
+}
+
+pub(crate) fn make_sampler<'a, D: Device<Commands: DestroySampler>, A: Allocator>(
+    handle: vk::Sampler,
+    device: &'a D,
+    allocator: A,
+) -> impl Sampler<Device = D> + use<'a, D, A> {
+    _Sampler {
+        handle,
+        device,
+        allocator,
+    }
+}
+
+impl<D: Device<Commands: DestroySampler>, A: Allocator> Sampler for _Sampler<'_, D, A> {
+    type Device = D;
+}
+
+unsafe impl<D: Device<Commands: DestroySampler>, A: Allocator> Send for _Sampler<'_, D, A> {}
+unsafe impl<D: Device<Commands: DestroySampler>, A: Allocator> Sync for _Sampler<'_, D, A> {}
+impl<D: Device<Commands: DestroySampler>, A: Allocator> ThreadSafeHandle for _Sampler<'_, D, A> {}
+
+impl<D: Device<Commands: DestroySampler>, A: Allocator> Handle for _Sampler<'_, D, A> {
+    type RawHandle = vk::Sampler;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroySampler>, A: Allocator> DeviceObject<D> for _Sampler<'_, D, A> {}
+
+impl<D: Device<Commands: DestroySampler>, A: Allocator> fmt::Debug for _Sampler<'_, D, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sampler")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroySampler>, A: Allocator> Drop for _Sampler<'_, D, A> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroySampler);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_sampler_01082: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All submitted commands that refer to sampler must have completed execution"
+            }
+
+            // TODO: samplers cannot be used in submitted commands yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_sampler_01083: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when sampler was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the Sampler is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_sampler_01084: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when sampler was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the Sampler is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_sampler_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sampler is not VK_NULL_HANDLE, sampler must be a valid VkSampler handle"
+            }
+
+            // ensured by Sampler creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySampler_sampler_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sampler is a valid handle, it must have been created, allocated, or retrieved from"
+            "device"
+            }
+
+            // ensured by Sampler creation
+        }
+
+        unsafe {
+            self.device.commands().DestroySampler().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
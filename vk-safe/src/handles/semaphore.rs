@@ -0,0 +1,203 @@
+use super::device::Device;
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
+
+use std::fmt;
+
+use vk_safe_sys as vk;
+
+use vk::has_command::DestroySemaphore;
+
+/// Semaphore handle trait
+///
+/// Represents a *specific* (binary) Semaphore, which is **unsignaled** and has no pending
+/// semaphore operations.
+///
+/// A Semaphore can be signaled by [`queue_submit`](crate::vk::Queue::queue_submit). Once the
+/// submission completes, the Semaphore is returned as a [`SignaledSemaphore`], which can
+/// then be waited on by another submission.
+pub trait Semaphore:
+    Handle<RawHandle = vk::Semaphore> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
+    /// The *specific* Device to which this Semaphore belongs
+    type Device: Device;
+}
+
+/// [`Semaphore`] implementor
+struct _Semaphore<'a, D: Device<Commands: DestroySemaphore>, A: Allocator> {
+    handle: vk::Semaphore,
+    device: &'a D,
+    allocator: A,
+}
+
+pub(crate) fn make_semaphore<'a, D: Device<Commands: DestroySemaphore>, A: Allocator>(
+    handle: vk::Semaphore,
+    device: &'a D,
+    allocator: A,
+) -> impl Semaphore<Device = D> + use<'a, D, A> {
+    _Semaphore {
+        handle,
+        device,
+        allocator,
+    }
+}
+
+impl<D: Device<Commands: DestroySemaphore>, A: Allocator> Semaphore for _Semaphore<'_, D, A> {
+    type Device = D;
+}
+
+unsafe impl<D: Device<Commands: DestroySemaphore>, A: Allocator> Send for _Semaphore<'_, D, A> {}
+unsafe impl<D: Device<Commands: DestroySemaphore>, A: Allocator> Sync for _Semaphore<'_, D, A> {}
+impl<D: Device<Commands: DestroySemaphore>, A: Allocator> ThreadSafeHandle
+    for _Semaphore<'_, D, A>
+{
+}
+
+impl<D: Device<Commands: DestroySemaphore>, A: Allocator> Handle for _Semaphore<'_, D, A> {
+    type RawHandle = vk::Semaphore;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.handle
+    }
+}
+
+impl<D: Device<Commands: DestroySemaphore>, A: Allocator> DeviceObject<D> for _Semaphore<'_, D, A> {}
+
+impl<D: Device<Commands: DestroySemaphore>, A: Allocator> fmt::Debug for _Semaphore<'_, D, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semaphore")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<D: Device<Commands: DestroySemaphore>, A: Allocator> Drop for _Semaphore<'_, D, A> {
+    fn drop(&mut self) {
+        check_vuids::check_vuids!(DestroySemaphore);
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_semaphore_01137: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "All submitted batches that refer to semaphore must have completed execution"
+            }
+
+            // a Submission owns the Semaphores until the submitted work has completed, and a leaked
+            // Submission leaks the Semaphores
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_semaphore_01138: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If VkAllocationCallbacks were provided when semaphore was created, a compatible set"
+            "of callbacks must be provided here"
+            }
+
+            // the same Allocator that was used to create the Semaphore is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_semaphore_01139: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If no VkAllocationCallbacks were provided when semaphore was created, pAllocator must"
+            "be NULL"
+            }
+
+            // the same Allocator that was used to create the Semaphore is kept and used here
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_device_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "device must be a valid VkDevice handle"
+            }
+
+            // ensured by Device creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_semaphore_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If semaphore is not VK_NULL_HANDLE, semaphore must be a valid VkSemaphore handle"
+            }
+
+            // ensured by Semaphore creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_pAllocator_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pAllocator is not NULL, pAllocator must be a valid pointer to a valid VkAllocationCallbacks"
+            "structure"
+            }
+
+            // ensured by Allocator
+        }
+
+        #[allow(unused_labels)]
+        'VUID_vkDestroySemaphore_semaphore_parent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If semaphore is a valid handle, it must have been created, allocated, or retrieved"
+            "from device"
+            }
+
+            // ensured by Semaphore creation
+        }
+
+        unsafe {
+            self.device.commands().DestroySemaphore().get_fptr()(
+                self.device.raw_handle(),
+                self.handle,
+                self.allocator.callbacks(),
+            )
+        }
+    }
+}
+
+/// A [`Semaphore`] which is signaled
+///
+/// Returned from a completed [`Submission`](crate::vk::Submission) which signaled the
+/// Semaphore. Can be waited on by another submission, after which the Semaphore is
+/// returned unsignaled.
+pub struct SignaledSemaphore<S> {
+    semaphore: S,
+}
+
+impl<S: Semaphore> SignaledSemaphore<S> {
+    /// The caller must ensure that the Semaphore is signaled
+    pub(crate) unsafe fn new(semaphore: S) -> Self {
+        Self { semaphore }
+    }
+
+    /// The caller must ensure that the Semaphore will be unsignaled
+    pub(crate) unsafe fn into_inner(self) -> S {
+        self.semaphore
+    }
+}
+
+impl<S: Semaphore> Handle for SignaledSemaphore<S> {
+    type RawHandle = vk::Semaphore;
+
+    fn raw_handle(&self) -> Self::RawHandle {
+        self.semaphore.raw_handle()
+    }
+}
+
+impl<S: Semaphore> DeviceObject<S::Device> for SignaledSemaphore<S> {}
+
+impl<S: Semaphore> ThreadSafeHandle for SignaledSemaphore<S> {}
+
+impl<S: Semaphore> fmt::Debug for SignaledSemaphore<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignaledSemaphore")
+            .field("semaphore", &self.semaphore)
+            .finish()
+    }
+}
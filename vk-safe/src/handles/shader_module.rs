@@ -1,35 +1,58 @@
 use super::device::Device;
-use super::{Handle, ThreadSafeHandle};
+use super::{DeviceObject, Handle, ThreadSafeHandle};
+
+use crate::allocation_callbacks::Allocator;
 
 use vk::has_command::DestroyShaderModule;
 use vk_safe_sys as vk;
 
-pub trait ShaderModule: Handle<RawHandle = vk::ShaderModule> + ThreadSafeHandle {
+pub trait ShaderModule:
+    Handle<RawHandle = vk::ShaderModule> + ThreadSafeHandle + DeviceObject<Self::Device>
+{
     type Device: Device;
 }
 
 /// [`ShaderModule`] implementor
-struct _ShaderModule<'a, D: Device<Commands: DestroyShaderModule>> {
+struct _ShaderModule<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> {
     handle: vk::ShaderModule,
     device: &'a D,
+    allocator: A,
 }
 
-pub(crate) fn make_shader_module<'a, D: Device<Commands: DestroyShaderModule>>(
+pub(crate) fn make_shader_module<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator>(
     device: &'a D,
+    allocator: A,
     handle: vk::ShaderModule,
-) -> impl ShaderModule<Device = D> + use<'a, D> {
-    _ShaderModule { handle, device }
+) -> impl ShaderModule<Device = D> + use<'a, D, A> {
+    _ShaderModule {
+        handle,
+        device,
+        allocator,
+    }
 }
 
-impl<'a, D: Device<Commands: DestroyShaderModule>> ShaderModule for _ShaderModule<'a, D> {
+impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> ShaderModule
+    for _ShaderModule<'a, D, A>
+{
     type Device = D;
 }
 
-unsafe impl<'a, D: Device<Commands: DestroyShaderModule>> Send for _ShaderModule<'a, D> {}
-unsafe impl<'a, D: Device<Commands: DestroyShaderModule>> Sync for _ShaderModule<'a, D> {}
-impl<'a, D: Device<Commands: DestroyShaderModule>> ThreadSafeHandle for _ShaderModule<'a, D> {}
+unsafe impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> Send
+    for _ShaderModule<'a, D, A>
+{
+}
+unsafe impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> Sync
+    for _ShaderModule<'a, D, A>
+{
+}
+impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> ThreadSafeHandle
+    for _ShaderModule<'a, D, A>
+{
+}
 
-impl<'a, D: Device<Commands: DestroyShaderModule>> Handle for _ShaderModule<'a, D> {
+impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> Handle
+    for _ShaderModule<'a, D, A>
+{
     type RawHandle = vk::ShaderModule;
 
     fn raw_handle(&self) -> Self::RawHandle {
@@ -37,7 +60,14 @@ impl<'a, D: Device<Commands: DestroyShaderModule>> Handle for _ShaderModule<'a,
     }
 }
 
-impl<'a, D: Device<Commands: DestroyShaderModule>> std::fmt::Debug for _ShaderModule<'a, D> {
+impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> DeviceObject<D>
+    for _ShaderModule<'a, D, A>
+{
+}
+
+impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> std::fmt::Debug
+    for _ShaderModule<'a, D, A>
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ShaderModule")
             .field("handle", &self.handle)
@@ -45,7 +75,7 @@ impl<'a, D: Device<Commands: DestroyShaderModule>> std::fmt::Debug for _ShaderMo
     }
 }
 
-impl<'a, D: Device<Commands: DestroyShaderModule>> Drop for _ShaderModule<'a, D> {
+impl<'a, D: Device<Commands: DestroyShaderModule>, A: Allocator> Drop for _ShaderModule<'a, D, A> {
     fn drop(&mut self) {
         check_vuids::check_vuids!(DestroyShaderModule);
 
@@ -57,7 +87,7 @@ impl<'a, D: Device<Commands: DestroyShaderModule>> Drop for _ShaderModule<'a, D>
             "set of callbacks must be provided here"
             }
 
-            // always null for now
+            // the same Allocator that was used to create the ShaderModule is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -68,7 +98,7 @@ impl<'a, D: Device<Commands: DestroyShaderModule>> Drop for _ShaderModule<'a, D>
             "must be NULL"
             }
 
-            // always null for now
+            // the same Allocator that was used to create the ShaderModule is kept and used here
         }
 
         #[allow(unused_labels)]
@@ -100,7 +130,7 @@ impl<'a, D: Device<Commands: DestroyShaderModule>> Drop for _ShaderModule<'a, D>
             "structure"
             }
 
-            // always null for now
+            // ensured by Allocator
         }
 
         #[allow(unused_labels)]
@@ -118,7 +148,7 @@ impl<'a, D: Device<Commands: DestroyShaderModule>> Drop for _ShaderModule<'a, D>
             self.device.commands().DestroyShaderModule().get_fptr()(
                 self.device.raw_handle(),
                 self.handle,
-                std::ptr::null(),
+                self.allocator.callbacks(),
             )
         }
     }
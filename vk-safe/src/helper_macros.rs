@@ -177,7 +177,7 @@ macro_rules! pub_export_modules {
         )*
 
         pub(crate) mod export {
-            $( #[allow(unused_imports)] pub use super::$name::*; )*
+            $( $(#[$($attributes)*])* #[allow(unused_imports)] pub use super::$name::*; )*
         }
     };
 }
@@ -185,13 +185,13 @@ macro_rules! pub_export_modules {
 /// Include a module, and publicly use the modules contents
 macro_rules! pub_use_modules {
     (
-        $( #[cfg($feature:ident)] $block:tt );* $(;)?
+        $( #[cfg($feature:meta)] $block:tt );* $(;)?
     ) => {
-        $( pub_use_modules!(@INNER $feature $block); )*
+        $( pub_use_modules!(@INNER ($feature) $block); )*
     };
     (
         @INNER
-        $feature:ident
+        ($feature:meta)
         {
             $(
                 $(#[$($attributes:tt)*])*
@@ -0,0 +1,103 @@
+//! Instance layers
+//!
+//! Layers are enabled for an Instance with [`InstanceCreateInfo::enable_layers`](crate::vk::InstanceCreateInfo::enable_layers),
+//! which takes the layers from [`enumerate_instance_layer_properties`](crate::vk::Entry::enumerate_instance_layer_properties)
+//! as proof that the layers are available.
+//!
+//! Use the [`layers!`] macro to create a type that correctly implements [`Layers`]. `()` represents
+//! no enabled layers.
+//!
+//! The enabled layers are not kept in the type of the Instance, since layers do not change which
+//! Vulkan APIs can be used. This makes it easy to only enable layers in some builds (e.g. debug builds).
+//!
+//! ```
+//! # use vk_safe::vk;
+//! # use vk::traits::*;
+//! # vk::instance_context!(InstanceContext: VERSION_1_0);
+//! # fn tst(entry: vk::Entry) -> Result<(), vk::VulkanError> {
+//! vk::layers!(ValidationLayers: "VK_LAYER_KHRONOS_validation");
+//!
+//! let available = entry.enumerate_instance_layer_properties().auto_get_enumerate()?;
+//!
+//! let app_info = vk::ApplicationInfo::new(InstanceContext);
+//! let mut instance_info = vk::InstanceCreateInfo::new(&app_info);
+//! if cfg!(debug_assertions) {
+//!     instance_info = instance_info.enable_layers(&available, ValidationLayers)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::c_char;
+
+use crate::error::VulkanError;
+use crate::structs::LayerProperties;
+use crate::VkStr;
+
+/// A set of instance layers
+///
+/// Use the [`layers!`](crate::layers!()) macro to create a type which implements this trait.
+/// It is not recommended to manually implement this trait.
+pub unsafe trait Layers {
+    /// The names of the layers
+    const NAMES: &'static [VkStr<'static>];
+    /// The same names as [`Layers::NAMES`], as an array of c strings for Vulkan
+    #[doc(hidden)]
+    const RAW_NAMES: &'static [*const c_char];
+}
+
+unsafe impl Layers for () {
+    const NAMES: &'static [VkStr<'static>] = &[];
+    const RAW_NAMES: &'static [*const c_char] = &[];
+}
+
+/// Check that every layer in `L` is in `available`
+pub(crate) fn check_available<L: Layers>(
+    available: &[LayerProperties<()>],
+) -> Result<(), VulkanError> {
+    for name in L::NAMES {
+        if !available.iter().any(|l| l.layer_name() == name.as_str()) {
+            Err(VulkanError::LayerNotPresent)?
+        }
+    }
+    Ok(())
+}
+
+/// Create a type that represents a set of instance layers
+///
+/// This will create a type with your provided name, and properly implement [`Layers`]. The
+/// layers are the names of the layers (e.g. `"VK_LAYER_KHRONOS_validation"`), and at least one
+/// layer must be provided. The layers are enabled in the same order as provided.
+///
+/// ## Example
+/// ```
+/// use vk_safe as vk;
+///
+/// vk::layers!(MyLayers: "VK_LAYER_KHRONOS_validation" + "VK_LAYER_LUNARG_api_dump");
+///
+/// // also make a value of an unnamed type
+/// let layers = vk::layers!("VK_LAYER_KHRONOS_validation");
+/// ```
+#[macro_export]
+macro_rules! layers {
+    ( $(#[$($attributes:tt)*])* $vis:vis $name:ident : $first:literal $( + $layer:literal )* ) => {
+        $(#[$($attributes)*])*
+        #[derive(Copy, Clone)]
+        $vis struct $name;
+
+        unsafe impl $crate::layers::Layers for $name {
+            const NAMES: &'static [$crate::VkStr<'static>] =
+                &[$crate::vk_str!($first) $( , $crate::vk_str!($layer) )*];
+            const RAW_NAMES: &'static [*const ::std::ffi::c_char] =
+                &[$crate::vk_str!($first).as_ptr() $( , $crate::vk_str!($layer).as_ptr() )*];
+        }
+    };
+
+    ( $first:literal $( + $layer:literal )* ) => {
+        {
+            $crate::layers!(PrivateLayersImpl: $first $( + $layer )*);
+            PrivateLayersImpl
+        }
+    };
+}
+pub use layers;
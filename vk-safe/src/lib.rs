@@ -30,7 +30,7 @@ vk::tag!(instance_tag);
 // configure and create instance
 let app_info = vk::ApplicationInfo::new(InstanceContext);
 let instance_info = vk::InstanceCreateInfo::new(&app_info);
-let instance = vk::create_instance(&instance_info, instance_tag).unwrap();
+let instance = vk::create_instance(&instance_info, (), instance_tag).unwrap();
 
 // get physical devices
 let physical_devices = instance
@@ -64,7 +64,7 @@ for physical_device in physical_devices.iter() {
     vk::tag!(device_tag);
     // configure and create device
     let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
-    let device = vk::create_device(&physical_device, &device_create_info, device_tag)
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag)
         .unwrap();
 }
 ```
@@ -86,9 +86,10 @@ remaining name in-line with
 ...
 
 ### Returning Result
-All Vulkan commands that can fail will return a Result. There Err variant is currently
-a placeholder dyn Error type. This should be changed in future to an Error type that enables
-handling specific Vulkan errors more easily.
+All Vulkan commands that can fail will return a Result. The Err variant is
+[`VulkanError`](error::VulkanError), which is either an [`ErrorCode`](error::ErrorCode) returned
+by Vulkan, or a failure detected by vk-safe itself. Errors can be matched on directly, and are
+classified as recoverable or fatal. See the [`error`] module for more details.
 
 ### Structs are read-only by default
 Most structs have a thin wrapper with a Deref implementation to provide read-only access.
@@ -102,13 +103,53 @@ Vulkan has many "Enumerate" or "Get" commands which take a pointer / length for
 to which return data will be written. Said commands can also be used to query length of
 data to be returned by passing a null pointer. In vk-safe, "Enumerate" or "Get" commands
 return an [`Enumerator`](crate::enumerator::Enumerator) which can be used to control allocation.
-
-### 🚧 AllocationCallbacks
-Vulkan supports AllocationCallbacks mostly for debugging purposes. These are not currently
-supported in vk-safe. Adding them will *most likely* be a breaking change, assuming that
-parameters will be added to the respective `create_*` commands.
-(e.g. [`create_instance()`](vk::create_instance) will likely have an allocation_callbacks
-parameter added).
+When a provided buffer is too small, Vulkan reports `VK_INCOMPLETE`, which vk-safe returns as an
+[`Enumerated::Partial`](crate::enumerator::Enumerated::Partial) result.
+
+### AllocationCallbacks
+Vulkan supports AllocationCallbacks, which let the application provide the host memory allocator
+used by the implementation (e.g. to track host memory usage). Every command which creates an object
+takes an [`Allocator`](vk::Allocator), which is either `()` for the default allocator, or
+[`AllocationCallbacks`](vk::AllocationCallbacks) made from a [`HostAllocator`](vk::HostAllocator).
+The created handle keeps the Allocator in its type, so that the same callbacks are always used
+to destroy the object.
+
+### pNext extension chains
+Vulkan structs can be extended by adding other structs to their pNext chain. Structs which support
+a pNext chain have a `push_next` method (e.g. [`DeviceCreateInfo::push_next`](vk::DeviceCreateInfo::push_next)),
+which accepts the structs that extend it and are [`SafeExtends`](vk::SafeExtends) (i.e. structs which
+cannot have invalid pointers or handles). Any other struct which extends it can be added with the unsafe
+`push_next_unchecked`. The structs in the chain are tracked at the type level with an
+[`ExtensionList`](vk::ExtensionList), so that rules about what is in the chain (e.g. each struct being
+unique) are checked at compile time. Queries which return extendable structs take an
+[`OutputChain`](vk::OutputChain) of the structs to be written to (e.g.
+[`get_physical_device_features2`](vk::PhysicalDevice::get_physical_device_features2), which is
+available with `VERSION_1_1` or `KHR_get_physical_device_properties2`).
+
+### Device features
+Features are enabled with [`DeviceCreateInfo::enable_features`](vk::DeviceCreateInfo::enable_features),
+which takes the features queried from the PhysicalDevice as proof that the features are supported.
+The enabled features are a type made with the [`features!`](vk::features!) macro, which the created
+Device keeps as [`Device::Features`](vk::Device::Features). APIs which require a feature check at
+compile time that it is enabled (e.g. [`create_sampler`](vk::create_sampler) with anisotropic filtering).
+See the [`features`](mod@features) module for more details.
+
+### Instance layers
+Layers (e.g. `VK_LAYER_KHRONOS_validation`) are enabled with [`InstanceCreateInfo::enable_layers`](vk::InstanceCreateInfo::enable_layers),
+which takes the layers from [`enumerate_instance_layer_properties`](vk::Entry::enumerate_instance_layer_properties) as proof that
+the layers are available. The enabled layers are a type made with the [`layers!`](vk::layers!) macro. The validation layer can be
+configured with [`ValidationFeaturesEXT`](vk::ValidationFeaturesEXT) and [`LayerSettingsCreateInfoEXT`](vk::LayerSettingsCreateInfoEXT)
+in the pNext chain of the InstanceCreateInfo. See the [`layers`](mod@layers) module for more details.
+
+### Debug messages
+With `EXT_debug_utils` in the instance context, messages from the implementation and layers (e.g. validation errors)
+are reported to a Rust closure (or trait object) by a [`DebugUtilsMessenger`](vk::DebugUtilsMessenger), created with
+[`create_debug_utils_messenger`](vk::create_debug_utils_messenger). The messages are decoded into a
+[`DebugUtilsMessage`](vk::DebugUtilsMessage), with the message id, VUID, and the objects involved. A debug messenger
+can also be added to the [`InstanceCreateInfo`](vk::InstanceCreateInfo) to report messages from creating and
+destroying the Instance. Objects of a Device can be named for the messages with [`Device::set_debug_name`](vk::Device::set_debug_name),
+and Queue operations and CommandBuffer commands can be grouped into labeled regions with [`Queue::begin_label`](vk::Queue::begin_label)
+and [`_CommandBuffer::begin_label`](vk::_CommandBuffer::begin_label). See the [`debug_utils`](mod@debug_utils) module for more details.
 
 ## VUIDs (implementation detail)
 All Vulkan APIs have valid usage rules that must be followed. Each valid usage rule has a VUID (Valid Usage Identifier). For all v-safe APIs,
@@ -149,7 +190,7 @@ I am not sure this can be done with Vulkano since validation is built in as runt
 */
 
 #[macro_use]
-mod error;
+pub mod error;
 
 #[macro_use]
 mod helper_macros;
@@ -168,10 +209,16 @@ pub use vk_str::VkStr;
 
 pub mod buffer;
 
+pub mod pod;
+
 pub mod scope;
 
 pub mod flags;
 
+pub mod features;
+
+pub mod layers;
+
 // pub mod dispatchable_handles;
 // pub mod non_dispatchable_handles;
 
@@ -181,6 +228,11 @@ pub mod structs;
 
 pub mod enumerator;
 
+pub mod allocation_callbacks;
+
+#[cfg(VK_EXT_debug_utils)]
+pub mod debug_utils;
+
 /// All raw vulkan types exported by vk-safe-sys
 pub mod raw {
     #[doc(hidden)]
@@ -261,7 +313,11 @@ pub mod vk {
     pub use super::enumerations::*;
     pub use super::flags::*;
 
+    pub use super::features::{features, Features};
+    pub use super::layers::{layers, Layers};
+
     pub use super::structs::*;
+    pub use vk_safe_sys::{DeviceSize, Extent3D};
 
     /// Module that exports all of the handles traits
     ///
@@ -279,6 +335,15 @@ pub mod vk {
 
     pub use super::entry::*;
 
+    pub use super::allocation_callbacks::{AllocationCallbacks, Allocator, HostAllocator};
+
+    #[cfg(VK_EXT_debug_utils)]
+    pub use super::debug_utils::{
+        DebugUtilsMessage, DebugUtilsMessengerCallback, DebugUtilsObject,
+    };
+
+    pub use super::error::{ErrorCode, VulkanError};
+
     pub use super::scope::tag;
 
     pub use super::context::device;
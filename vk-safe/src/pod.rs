@@ -0,0 +1,30 @@
+/*!
+Plain old data
+
+Types which can be safely viewed as raw bytes, and created from arbitrary raw bytes.
+This is used to provide typed access to host mapped memory (see [`MappedMemory`](crate::vk::MappedMemory)).
+
+This is the same idea as `bytemuck::Pod`. vk-safe does not depend on `bytemuck` in order to keep
+dependencies at a minimum, but a user can implement [`Pod`] for any type which implements `bytemuck::Pod`.
+*/
+
+/// Plain old data
+///
+/// # Safety
+/// The implementor must:
+/// - be inhabited
+/// - allow any bit pattern
+/// - have no padding bytes
+/// - have no pointers or interior mutability
+/// - be `#[repr(C)]` or `#[repr(transparent)]` (if it is a struct)
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ( $($ty:ty),* $(,)? ) => {
+        $( unsafe impl Pod for $ty {} )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
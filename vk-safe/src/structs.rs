@@ -16,9 +16,40 @@ pub_use_modules!(
         device_queue_create_info;
         device_create_info;
         memory_allocate_info;
+        memory_map_range;
         mapped_memory_range;
         command_pool_create_info;
         command_buffer_alloc_info;
+        command_buffer_inheritance_info;
+        command_buffer_begin_info;
         shader_module_create_info;
+        buffer_create_info;
+        memory_requirements;
+        image_create_info;
+        image_view_create_info;
+        sampler_create_info;
+        fence_create_info;
+        semaphore_create_info;
+        submit_info;
+        memory_barrier;
+        buffer_memory_barrier;
+        image_memory_barrier;
+        descriptor_set_layout_binding;
+        descriptor_set_layout_create_info;
+        push_constant_range;
+        pipeline_layout_create_info;
+        pipeline_shader_stage_create_info;
+        compute_pipeline_create_info;
+        extension_chain;
+        validation_features;
+    };
+    #[cfg(VK_EXT_layer_settings)]
+    {
+        layer_settings;
+    };
+    #[cfg(VK_EXT_debug_utils)]
+    {
+        debug_utils_messenger_create_info;
+        debug_utils_label;
     };
 );
@@ -0,0 +1,224 @@
+use crate::type_conversions::ConvertWrapper;
+
+use std::num::NonZeroU64;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::BufferUsageFlags;
+
+struct_wrapper!(
+/// Info for creating a Buffer
+///
+/// used with [`create_buffer`](crate::vk::create_buffer)
+///
+/// Must use the [`flags!`](crate::flags!) macro to declare the usage
+/// flags of the Buffer. The usage is part of the type of the created Buffer.
+///
+/// The Buffer is always created with `VK_SHARING_MODE_EXCLUSIVE` for now.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkBufferCreateInfo.html>
+BufferCreateInfo<Usage,>
+impl Deref, Debug
+);
+
+impl<U: BufferUsageFlags> BufferCreateInfo<U> {
+    pub fn new(size: NonZeroU64, usage: U) -> Self {
+        check_vuids::check_vuids!(BufferCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_size_00912: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "size must be greater than 0"
+            }
+
+            // using NonZeroU64
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_sharingMode_00913: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sharingMode is VK_SHARING_MODE_CONCURRENT, pQueueFamilyIndices must be a valid"
+            "pointer to an array of queueFamilyIndexCount uint32_t values"
+            }
+
+            // TODO: only VK_SHARING_MODE_EXCLUSIVE for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_sharingMode_00914: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sharingMode is VK_SHARING_MODE_CONCURRENT, queueFamilyIndexCount must be greater"
+            "than 1"
+            }
+
+            // TODO: only VK_SHARING_MODE_EXCLUSIVE for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_sharingMode_01419: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sharingMode is VK_SHARING_MODE_CONCURRENT, each element of pQueueFamilyIndices"
+            "must be unique and must be less than pQueueFamilyPropertyCount returned by either"
+            "vkGetPhysicalDeviceQueueFamilyProperties or vkGetPhysicalDeviceQueueFamilyProperties2"
+            "for the physicalDevice that was used to create device"
+            }
+
+            // TODO: only VK_SHARING_MODE_EXCLUSIVE for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_flags_00915: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the sparseBinding feature is not enabled, flags must not contain VK_BUFFER_CREATE_SPARSE_BINDING_BIT"
+            }
+
+            // TODO: flags are always empty for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_flags_00916: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the sparseResidencyBuffer feature is not enabled, flags must not contain VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT"
+            }
+
+            // TODO: flags are always empty for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_flags_00917: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the sparseResidencyAliased feature is not enabled, flags must not contain VK_BUFFER_CREATE_SPARSE_ALIASED_BIT"
+            }
+
+            // TODO: flags are always empty for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_flags_00918: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT or VK_BUFFER_CREATE_SPARSE_ALIASED_BIT,"
+            "it must also contain VK_BUFFER_CREATE_SPARSE_BINDING_BIT"
+            }
+
+            // TODO: flags are always empty for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_flags_01887: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the protectedMemory feature is not enabled, flags must not contain VK_BUFFER_CREATE_PROTECTED_BIT"
+            }
+
+            // TODO: flags are always empty for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_None_01888: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any of the bits VK_BUFFER_CREATE_SPARSE_BINDING_BIT, VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT,"
+            "or VK_BUFFER_CREATE_SPARSE_ALIASED_BIT are set, VK_BUFFER_CREATE_PROTECTED_BIT must"
+            "not also be set"
+            }
+
+            // TODO: flags are always empty for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_size_06409: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "size must be less than or equal to VkPhysicalDeviceMaintenance4Properties::maxBufferSize"
+            }
+
+            // TODO: VK_VERSION_1_3 and VK_KHR_maintenance4 properties are not checked yet
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkBufferCreateFlagBits values"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_sharingMode_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sharingMode must be a valid VkSharingMode value"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_usage_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "usage must be a valid combination of VkBufferUsageFlagBits values"
+            }
+
+            // ensured by BufferUsageFlags type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferCreateInfo_usage_requiredbitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "usage must not be 0"
+            }
+
+            const {
+                if U::INCLUDES.is_empty() {
+                    panic!("Buffer usage must include at least one BufferUsageFlags bit")
+                }
+            }
+        }
+
+        let _ = usage; // just used for the type
+        unsafe {
+            Self::from_c(vk::BufferCreateInfo {
+                s_type: vk::StructureType::BUFFER_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::BufferCreateFlags::empty(),
+                size: size.get(),
+                usage: U::INCLUDES,
+                sharing_mode: vk::SharingMode::EXCLUSIVE,
+                queue_family_index_count: 0,
+                p_queue_family_indices: std::ptr::null(),
+            })
+        }
+    }
+}
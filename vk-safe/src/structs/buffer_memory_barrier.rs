@@ -0,0 +1,287 @@
+use crate::error::VulkanError;
+use crate::handles::buffer::{BoundBuffer, Buffer};
+use crate::handles::queue::ownership_transfer::{Acquire, Release};
+use crate::handles::queue::QueueFamilyMarker;
+use crate::handles::{device_memory::DeviceMemory, Handle};
+use crate::type_conversions::ConvertWrapper;
+
+use std::ops::Range;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::AccessFlags;
+
+struct_wrapper!(
+/// A memory barrier for a range of a Buffer
+///
+/// used with [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier)
+///
+/// The access masks are provided as types, so that they can be checked at compile time
+/// against the pipeline stages of the barrier. The Buffer must be bound to memory, and is
+/// borrowed for as long as the CommandBuffer the barrier is recorded into.
+///
+/// By default, the barrier does not transfer queue family ownership. Use
+/// [`release`](Self::release) or [`acquire`](Self::acquire) to define a queue family
+/// ownership transfer between two queue families, identified by their
+/// [`QueueFamilyMarker`](crate::vk::QueueFamilyMarker)s.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkBufferMemoryBarrier.html>
+BufferMemoryBarrier<'a, Device, SrcAccess, DstAccess, Transfer,>
+impl Deref, Debug
+);
+
+impl<'a, D, SA: AccessFlags, DA: AccessFlags> BufferMemoryBarrier<'a, D, SA, DA, ()> {
+    /// Barrier for the whole Buffer
+    pub fn new<B: Buffer<Device = D>, M: DeviceMemory<Device = D>>(
+        buffer: &'a BoundBuffer<'_, B, M>,
+        _src_access: SA,
+        _dst_access: DA,
+    ) -> Self {
+        // VUIDs are checked in make_barrier
+        unsafe { Self::make_barrier(buffer, 0, vk::WHOLE_SIZE) }
+    }
+
+    /// Barrier for part of the Buffer
+    ///
+    /// `range` is in bytes from the beginning of the Buffer, and must be non-empty and
+    /// within the Buffer.
+    pub fn sub_range<B: Buffer<Device = D>, M: DeviceMemory<Device = D>>(
+        buffer: &'a BoundBuffer<'_, B, M>,
+        range: Range<vk::DeviceSize>,
+        _src_access: SA,
+        _dst_access: DA,
+    ) -> Result<Self, VulkanError> {
+        if range.is_empty() || range.end > buffer.size() {
+            Err(VulkanError::BufferRangeOutOfBounds)?
+        }
+        // VUIDs are checked in make_barrier
+        unsafe {
+            Ok(Self::make_barrier(
+                buffer,
+                range.start,
+                range.end - range.start,
+            ))
+        }
+    }
+
+    /// caller must ensure that the offset and size are valid for the Buffer
+    /// (i.e. either WHOLE_SIZE from the beginning of the Buffer, or checked by sub_range)
+    unsafe fn make_barrier<B: Buffer<Device = D>, M: DeviceMemory<Device = D>>(
+        buffer: &'a BoundBuffer<'_, B, M>,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+    ) -> Self {
+        check_vuids::check_vuids!(BufferMemoryBarrier);
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_offset_01187: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "offset must be less than the size of buffer"
+            }
+
+            // new uses offset 0, which is less than the (non-zero) size of the Buffer
+            // sub_range checks that the range is non-empty and within the Buffer
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_size_01188: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If size is not equal to VK_WHOLE_SIZE, size must be greater than 0"
+            }
+
+            // checked by sub_range
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_size_01189: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If size is not equal to VK_WHOLE_SIZE, size must be less than or equal to than the"
+            "size of buffer minus offset"
+            }
+
+            // checked by sub_range
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_buffer_01931: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If buffer is non-sparse then it must be bound completely and contiguously to a single"
+            "VkDeviceMemory object"
+            }
+
+            // using BoundBuffer
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_buffer_09095: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If buffer was created with a sharing mode of VK_SHARING_MODE_EXCLUSIVE, and srcQueueFamilyIndex"
+            "and dstQueueFamilyIndex are not equal, srcQueueFamilyIndex must be VK_QUEUE_FAMILY_EXTERNAL,"
+            "VK_QUEUE_FAMILY_FOREIGN_EXT, or a valid queue family"
+            }
+
+            // QUEUE_FAMILY_IGNORED for both by default
+            // release and acquire only use indices from QueueFamilyMarker
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_buffer_09096: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If buffer was created with a sharing mode of VK_SHARING_MODE_EXCLUSIVE, and srcQueueFamilyIndex"
+            "and dstQueueFamilyIndex are not equal, dstQueueFamilyIndex must be VK_QUEUE_FAMILY_EXTERNAL,"
+            "VK_QUEUE_FAMILY_FOREIGN_EXT, or a valid queue family"
+            }
+
+            // QUEUE_FAMILY_IGNORED for both by default
+            // release and acquire only use indices from QueueFamilyMarker
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_srcQueueFamilyIndex_04087: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex is not equal to dstQueueFamilyIndex, at least one of srcQueueFamilyIndex"
+            "or dstQueueFamilyIndex must not be VK_QUEUE_FAMILY_EXTERNAL or VK_QUEUE_FAMILY_FOREIGN_EXT"
+            }
+
+            // external and foreign queue families are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_None_09097: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_KHR_external_memory extension is not enabled, and the value of VkApplicationInfo::apiVersion"
+            "used to create the VkInstance is not greater than or equal to Version 1.1, srcQueueFamilyIndex"
+            "must not be VK_QUEUE_FAMILY_EXTERNAL"
+            }
+
+            // external queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_None_09098: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_EXT_queue_family_foreign extension is not enabled, srcQueueFamilyIndex must"
+            "not be VK_QUEUE_FAMILY_FOREIGN_EXT"
+            }
+
+            // foreign queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_None_09099: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_KHR_external_memory extension is not enabled, and the value of VkApplicationInfo::apiVersion"
+            "used to create the VkInstance is not greater than or equal to Version 1.1, dstQueueFamilyIndex"
+            "must not be VK_QUEUE_FAMILY_EXTERNAL"
+            }
+
+            // external queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_None_09100: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_EXT_queue_family_foreign extension is not enabled, dstQueueFamilyIndex must"
+            "not be VK_QUEUE_FAMILY_FOREIGN_EXT"
+            }
+
+            // foreign queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pNext must be NULL or a pointer to a valid instance of VkExternalMemoryAcquireUnmodifiedEXT"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkBufferMemoryBarrier_buffer_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "buffer must be a valid VkBuffer handle"
+            }
+
+            // ensured by Buffer creation
+        }
+
+        unsafe {
+            Self::from_c(vk::BufferMemoryBarrier {
+                s_type: vk::StructureType::BUFFER_MEMORY_BARRIER,
+                p_next: std::ptr::null(),
+                src_access_mask: SA::INCLUDES,
+                dst_access_mask: DA::INCLUDES,
+                src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                buffer: buffer.raw_handle(),
+                offset,
+                size,
+            })
+        }
+    }
+
+    /// Release ownership of the Buffer range from the queue family `S` to the queue family `T`
+    ///
+    /// Must be recorded into a CommandBuffer for the queue family `S`. The dst access mask
+    /// is ignored for a release operation.
+    pub fn release<S, T, SC, TC>(
+        self,
+        src_family: &QueueFamilyMarker<S, SC>,
+        dst_family: &QueueFamilyMarker<T, TC>,
+    ) -> BufferMemoryBarrier<'a, D, SA, DA, Release<S, T>> {
+        self.transfer(src_family.family_index(), dst_family.family_index())
+    }
+
+    /// Acquire ownership of the Buffer range from the queue family `S` by the queue family `T`
+    ///
+    /// Must be recorded into a CommandBuffer for the queue family `T`. The src access mask
+    /// is ignored for an acquire operation.
+    pub fn acquire<S, T, SC, TC>(
+        self,
+        src_family: &QueueFamilyMarker<S, SC>,
+        dst_family: &QueueFamilyMarker<T, TC>,
+    ) -> BufferMemoryBarrier<'a, D, SA, DA, Acquire<S, T>> {
+        self.transfer(src_family.family_index(), dst_family.family_index())
+    }
+
+    fn transfer<X>(self, src: u32, dst: u32) -> BufferMemoryBarrier<'a, D, SA, DA, X> {
+        let mut inner = self.inner;
+        inner.src_queue_family_index = src;
+        inner.dst_queue_family_index = dst;
+        unsafe { BufferMemoryBarrier::from_c(inner) }
+    }
+}
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 use std::ops::Deref;
 
 use crate::buffer::Buffer;
-use crate::error::Error;
+use crate::error::VulkanError;
 use crate::vk::CommandPool;
 
 use vk_safe_sys as vk;
@@ -28,8 +28,6 @@ impl<'a, B, P, L> Deref for CommandBufferAllocateInfo<'a, B, P, L> {
     }
 }
 
-unit_error!(ZeroSizedBuffer);
-
 impl<'a, B: Buffer<vk::CommandBuffer>, P: CommandPool, L: vk::enum_traits::CommandBufferLevel>
     CommandBufferAllocateInfo<'a, B, P, L>
 {
@@ -38,7 +36,7 @@ impl<'a, B: Buffer<vk::CommandBuffer>, P: CommandPool, L: vk::enum_traits::Comma
     /// The CommandBufferAllocateInfo will contain information
     /// for allocating `buffer.capacity()` number of [`CommandBuffer`](crate::vk::CommandBuffer),
     /// for the indicated `level`.
-    pub fn new(command_pool: &'a P, level: L, buffer: B) -> Result<Self, Error> {
+    pub fn new(command_pool: &'a P, level: L, buffer: B) -> Result<Self, VulkanError> {
         check_vuids::check_vuids!(CommandBufferAllocateInfo);
 
         #[allow(unused_labels)]
@@ -85,7 +83,7 @@ impl<'a, B: Buffer<vk::CommandBuffer>, P: CommandPool, L: vk::enum_traits::Comma
         // commandBufferCount is set based on the buffer
         // we check that the buffer capacity is > 0
         if buffer.capacity() == 0 {
-            Err(ZeroSizedBuffer)?
+            Err(VulkanError::ZeroSizedBuffer)?
         }
 
         let _ = level;
@@ -0,0 +1,163 @@
+use super::CommandBufferInheritanceInfo;
+
+use crate::enumerations::CommandBufferLevel::{PRIMARY, SECONDARY};
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::CommandBufferUsageFlags;
+
+struct_wrapper!(
+/// Info for beginning a CommandBuffer
+///
+/// used with [`begin_command_buffer`](crate::vk::_CommandBuffer::begin_command_buffer)
+///
+/// Must use the [`flags!`](crate::flags!) macro to declare the usage flags (or `()` for no flags).
+/// The usage flags are carried in the state of the CommandBuffer after it begins.
+///
+/// Use [`new`](Self::new) for primary CommandBuffers, and [`new_secondary`](Self::new_secondary)
+/// for secondary CommandBuffers, which also require [`CommandBufferInheritanceInfo`].
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkCommandBufferBeginInfo.html>
+CommandBufferBeginInfo<'a, Level, Usage,>
+impl Deref, Debug
+);
+
+impl<U: CommandBufferUsageFlags> CommandBufferBeginInfo<'static, PRIMARY, U> {
+    /// Begin info for a primary CommandBuffer
+    pub fn new(usage: U) -> Self {
+        let _ = usage;
+
+        // see VUID_vkBeginCommandBuffer_commandBuffer_02840
+        const {
+            assert!(
+                !U::INCLUDES.contains(
+                    vk::CommandBufferUsageFlags::SIMULTANEOUS_USE_BIT
+                        .or(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT_BIT)
+                ),
+                "primary CommandBuffer cannot use both SIMULTANEOUS_USE_BIT and ONE_TIME_SUBMIT_BIT"
+            );
+        }
+
+        Self::new_inner(std::ptr::null())
+    }
+}
+
+impl<'a, U: CommandBufferUsageFlags> CommandBufferBeginInfo<'a, SECONDARY, U> {
+    /// Begin info for a secondary CommandBuffer
+    pub fn new_secondary(usage: U, inheritance_info: &'a CommandBufferInheritanceInfo) -> Self {
+        let _ = usage;
+
+        Self::new_inner(inheritance_info.to_c())
+    }
+}
+
+impl<'a, L, U: CommandBufferUsageFlags> CommandBufferBeginInfo<'a, L, U> {
+    fn new_inner(inheritance_info: *const vk::CommandBufferInheritanceInfo) -> Self {
+        check_vuids::check_vuids!(CommandBufferBeginInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_flags_09123: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT and the renderPass"
+            "member of pInheritanceInfo is not VK_NULL_HANDLE, the renderPass member of pInheritanceInfo"
+            "must be a valid VkRenderPass"
+            }
+
+            // render passes are not supported yet, so RENDER_PASS_CONTINUE_BIT is rejected
+            const {
+                assert!(
+                    !U::INCLUDES.contains(vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE_BIT),
+                    "RENDER_PASS_CONTINUE_BIT is not supported by vk-safe at this time"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_flags_00055: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT, the framebuffer"
+            "member of pInheritanceInfo must be either VK_NULL_HANDLE, or a valid VkFramebuffer"
+            "that is compatible with the renderPass member of pInheritanceInfo"
+            }
+
+            // RENDER_PASS_CONTINUE_BIT is rejected above
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_flags_06000: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT and the renderPass"
+            "member of pInheritanceInfo is VK_NULL_HANDLE, the pNext chain of pInheritanceInfo"
+            "must include a VkCommandBufferInheritanceRenderingInfo structure"
+            }
+
+            // RENDER_PASS_CONTINUE_BIT is rejected above
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_flags_06002: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT, the renderPass"
+            "member of pInheritanceInfo is VK_NULL_HANDLE, and the pNext chain of pInheritanceInfo"
+            "includes a VkAttachmentSampleCountInfoAMD or VkAttachmentSampleCountInfoNV structure,"
+            "the colorAttachmentCount member of that structure must be equal to the value of VkCommandBufferInheritanceRenderingInfo::colorAttachmentCount"
+            }
+
+            // RENDER_PASS_CONTINUE_BIT is rejected above
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_COMMAND_BUFFER_BEGIN_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pNext must be NULL or a pointer to a valid instance of VkDeviceGroupCommandBufferBeginInfo"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferBeginInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkCommandBufferUsageFlagBits values"
+            }
+
+            // ensured by CommandBufferUsageFlags type
+        }
+
+        unsafe {
+            Self::from_c(vk::CommandBufferBeginInfo {
+                s_type: vk::StructureType::COMMAND_BUFFER_BEGIN_INFO,
+                p_next: std::ptr::null(),
+                flags: U::INCLUDES,
+                p_inheritance_info: inheritance_info,
+            })
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+struct_wrapper!(
+/// Info for beginning a secondary CommandBuffer
+///
+/// used with [`CommandBufferBeginInfo::new_secondary`](crate::vk::CommandBufferBeginInfo::new_secondary)
+///
+/// **⚠️ Render passes and queries are not supported at this time**. Thus, this
+/// currently does not inherit any state from the primary CommandBuffer.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkCommandBufferInheritanceInfo.html>
+CommandBufferInheritanceInfo
+impl Deref, Debug
+);
+
+impl CommandBufferInheritanceInfo {
+    pub fn new() -> Self {
+        check_vuids::check_vuids!(CommandBufferInheritanceInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_occlusionQueryEnable_00056: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the inheritedQueries feature is not enabled, occlusionQueryEnable must be VK_FALSE"
+            }
+
+            // set to VK_FALSE below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_queryFlags_00057: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the inheritedQueries feature is enabled, queryFlags must be a valid combination"
+            "of VkQueryControlFlagBits values"
+            }
+
+            // set to empty below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_queryFlags_02788: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the inheritedQueries feature is not enabled, queryFlags must be 0"
+            }
+
+            // set to empty below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_pipelineStatistics_02789: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pipelineStatisticsQuery feature is enabled, pipelineStatistics must be a valid"
+            "combination of VkQueryPipelineStatisticFlagBits values"
+            }
+
+            // set to empty below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_pipelineStatistics_00058: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pipelineStatisticsQuery feature is not enabled, pipelineStatistics must be"
+            "0"
+            }
+
+            // set to empty below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkCommandBufferInheritanceInfo_commonparent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Both of framebuffer, and renderPass that are valid handles of non-ignored parameters"
+            "must have been created, allocated, or retrieved from the same VkDevice"
+            }
+
+            // both are VK_NULL_HANDLE
+        }
+
+        unsafe {
+            Self::from_c(vk::CommandBufferInheritanceInfo {
+                s_type: vk::StructureType::COMMAND_BUFFER_INHERITANCE_INFO,
+                p_next: std::ptr::null(),
+                render_pass: vk::RenderPass { handle: 0 },
+                subpass: 0,
+                framebuffer: vk::Framebuffer { handle: 0 },
+                occlusion_query_enable: vk::FALSE,
+                query_flags: vk::QueryControlFlags::empty(),
+                pipeline_statistics: vk::QueryPipelineStatisticFlags::empty(),
+            })
+        }
+    }
+}
+
+impl Default for CommandBufferInheritanceInfo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
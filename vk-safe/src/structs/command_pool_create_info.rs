@@ -14,15 +14,16 @@ struct_wrapper!(
 /// that will be used with the CommandPool.
 ///
 /// CommandBuffers form the CommandPool will be usable with
-/// Queues from the provided [`QueueFamily`].
+/// Queues from the provided [`QueueFamilyMarker`], and the CommandPool
+/// has the same capability as the Queues.
 ///
 /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkCommandPoolCreateInfo.html>
-CommandPoolCreateInfo<Device, Flags, Tag,>
+CommandPoolCreateInfo<Device, Flags, Tag, Capability,>
 impl Deref, Debug
 );
 
-impl<D, F: CommandPoolCreateFlags, T> CommandPoolCreateInfo<D, F, T> {
-    pub fn new<'a>(flags: F, queue_family_marker: &QueueFamilyMarker<T>) -> Self {
+impl<D, F: CommandPoolCreateFlags, T, C> CommandPoolCreateInfo<D, F, T, C> {
+    pub fn new<'a>(flags: F, queue_family_marker: &QueueFamilyMarker<T, C>) -> Self {
         check_vuids::check_vuids!(CommandPoolCreateInfo);
 
         #[allow(unused_labels)]
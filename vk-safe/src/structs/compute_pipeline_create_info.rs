@@ -0,0 +1,205 @@
+use super::PipelineShaderStageCreateInfo;
+
+use crate::handles::pipeline_layout::PipelineLayout;
+use crate::handles::Handle;
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::flag_types::ShaderStageFlags::COMPUTE_BIT;
+
+struct_wrapper!(
+/// Info for creating a compute [`Pipeline`](crate::vk::Pipeline)
+///
+/// used with [`create_compute_pipelines`](crate::vk::create_compute_pipelines)
+///
+/// The ShaderModule of the stage, and the PipelineLayout, are borrowed until the Pipeline is created.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkComputePipelineCreateInfo.html>
+ComputePipelineCreateInfo<'a, Device, Layout,>
+impl Deref, Debug
+);
+
+impl<'a, D, L: PipelineLayout<Device = D>> ComputePipelineCreateInfo<'a, D, L> {
+    /**
+    Create info for a compute Pipeline
+
+    # Safety
+    The descriptor sets and push constants used by the shader must be consistent with
+    `layout`. i.e. each resource used by the shader must be declared in the
+    DescriptorSetLayout of the respective set, with a matching descriptor type and
+    accessible to the compute stage, and each push constant block used by the shader
+    must be within a push constant range accessible to the compute stage.
+
+    <https://registry.khronos.org/vulkan/specs/1.3-extensions/html/vkspec.html#interfaces-resources-layout>
+    */
+    pub unsafe fn new(
+        stage: PipelineShaderStageCreateInfo<'a, D, COMPUTE_BIT>,
+        layout: &'a L,
+    ) -> Self {
+        check_vuids::check_vuids!(ComputePipelineCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_flags_00697: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains the VK_PIPELINE_CREATE_DERIVATIVE_BIT flag, and basePipelineIndex"
+            "is -1, basePipelineHandle must be a valid handle to a compute VkPipeline"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_flags_00698: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains the VK_PIPELINE_CREATE_DERIVATIVE_BIT flag, and basePipelineHandle"
+            "is VK_NULL_HANDLE, basePipelineIndex must be a valid index into the calling command's"
+            "pCreateInfos parameter"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_flags_00699: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains the VK_PIPELINE_CREATE_DERIVATIVE_BIT flag, and basePipelineIndex"
+            "is not -1, basePipelineHandle must be VK_NULL_HANDLE"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_flags_00700: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains the VK_PIPELINE_CREATE_DERIVATIVE_BIT flag, and basePipelineHandle"
+            "is not VK_NULL_HANDLE, basePipelineIndex must be -1"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_stage_00701: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The stage member of stage must be VK_SHADER_STAGE_COMPUTE_BIT"
+            }
+
+            // ensured by the COMPUTE_BIT stage type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_stage_00702: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The shader code for the entry point identified by stage and the rest of the state"
+            "identified by this structure must adhere to the pipeline linking rules described in"
+            "the Shader Interfaces chapter"
+            }
+
+            // promised by caller
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_layout_00703: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "layout must be consistent with the layout of the compute shader specified in stage"
+            }
+
+            // promised by caller
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_layout_01687: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The number of resources in layout accessible to the compute shader stage must be less"
+            "than or equal to VkPhysicalDeviceLimits::maxPerStageResources"
+            }
+
+            // checked by PipelineLayoutCreateInfo creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_pipelineCreationCacheControl_02875: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pipelineCreationCacheControl feature is not enabled, flags must not include"
+            "VK_PIPELINE_CREATE_FAIL_ON_PIPELINE_COMPILE_REQUIRED_BIT or VK_PIPELINE_CREATE_EARLY_RETURN_ON_FAILURE_BIT"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_stage_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "stage must be a valid VkPipelineShaderStageCreateInfo structure"
+            }
+
+            // ensured by PipelineShaderStageCreateInfo creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_layout_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "layout must be a valid VkPipelineLayout handle"
+            }
+
+            // ensured by PipelineLayout creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkComputePipelineCreateInfo_commonparent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Both of basePipelineHandle, and layout that are valid handles of non-ignored parameters"
+            "must have been created, allocated, or retrieved from the same VkDevice"
+            }
+
+            // layout is for the same Device as the stage (D)
+            // basePipelineHandle is always null
+        }
+
+        unsafe {
+            Self::from_c(vk::ComputePipelineCreateInfo {
+                s_type: vk::StructureType::COMPUTE_PIPELINE_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::PipelineCreateFlags::empty(),
+                stage: stage.to_c(),
+                layout: layout.raw_handle(),
+                base_pipeline_handle: vk::Pipeline { handle: 0 },
+                base_pipeline_index: -1,
+            })
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::type_conversions::ConvertWrapper;
+use crate::VkStr;
+
+use vk_safe_sys as vk;
+
+struct_wrapper!(
+/// A label for a region of Queue operations or CommandBuffer commands
+///
+/// Used with [`Queue::begin_label`](crate::vk::Queue::begin_label) and
+/// [`_CommandBuffer::begin_label`](crate::vk::_CommandBuffer::begin_label). Layers and tools
+/// (e.g. frame debuggers) show the label name and color for the region.
+///
+/// ```
+/// # use vk_safe::vk;
+/// let label = vk::DebugUtilsLabelEXT::new(vk_safe::vk_str!("shadow pass")).color([1.0, 0.0, 0.0, 1.0]);
+/// ```
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsLabelEXT.html>
+DebugUtilsLabelEXT<'a,>
+impl Clone, Copy, Deref, Debug
+);
+
+impl<'a> DebugUtilsLabelEXT<'a> {
+    /// Create a label with `name`, and no color
+    pub fn new(name: VkStr<'a>) -> Self {
+        check_vuids::check_vuids!(DebugUtilsLabelEXT);
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsLabelEXT_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsLabelEXT_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pNext must be NULL"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsLabelEXT_pLabelName_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pLabelName must be a null-terminated UTF-8 string"
+            }
+
+            // ensured by VkStr
+        }
+
+        unsafe {
+            Self::from_c(vk::DebugUtilsLabelEXT {
+                s_type: vk::StructureType::DEBUG_UTILS_LABEL_EXT,
+                p_next: std::ptr::null(),
+                p_label_name: name.as_ptr(),
+                color: [0.0; 4],
+            })
+        }
+    }
+
+    /// Set the RGBA color of the label, with each component in the range 0.0 to 1.0
+    ///
+    /// If all components are 0.0 (the default), the color is ignored
+    pub const fn color(mut self, color: [f32; 4]) -> Self {
+        self.inner.color = color;
+        self
+    }
+}
@@ -0,0 +1,205 @@
+use crate::debug_utils::{debug_utils_messenger_callback, DebugUtilsMessengerCallback};
+
+use std::fmt;
+use std::sync::Arc;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::{DebugUtilsMessageSeverityFlagsEXT, DebugUtilsMessageTypeFlagsEXT};
+use vk::Stype;
+
+/// Info for creating a debug messenger
+///
+/// Messages with any of the `severities` and any of the `types` are reported to the callback.
+/// Use with [`create_debug_utils_messenger`](crate::vk::create_debug_utils_messenger), or add
+/// to the pNext chain of [`InstanceCreateInfo`](crate::vk::InstanceCreateInfo) with
+/// [`push_debug_utils_messenger`](crate::vk::InstanceCreateInfo::push_debug_utils_messenger)
+/// to report messages from creating and destroying the Instance.
+///
+/// The callback is kept alive (in an Arc) by the DebugUtilsMessengerCreateInfoEXT, and by
+/// everything which is created with it. See the [`debug_utils`](mod@crate::debug_utils) module
+/// for more details.
+///
+/// ```
+/// # use vk_safe::vk;
+/// use vk::DebugUtilsMessageSeverityFlagsEXT::*;
+/// use vk::DebugUtilsMessageTypeFlagsEXT::*;
+///
+/// let info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+///     DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+///     vk::flags!(
+///         DebugUtilsMessageTypeFlagsEXT
+///             + DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT
+///             + DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT
+///             + DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT
+///     ),
+///     |message: &vk::DebugUtilsMessage| panic!("{}", message.message),
+/// );
+///
+/// // a trait object can also be used as the callback
+/// let callback: Box<dyn vk::DebugUtilsMessengerCallback> =
+///     Box::new(|message: &vk::DebugUtilsMessage| eprintln!("{}", message.message));
+/// let info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+///     vk::flags!(
+///         DebugUtilsMessageSeverityFlagsEXT
+///             + DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+///             + DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT
+///     ),
+///     DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+///     callback,
+/// );
+/// ```
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessengerCreateInfoEXT.html>
+#[repr(C)]
+pub struct DebugUtilsMessengerCreateInfoEXT {
+    pub(crate) inner: vk::DebugUtilsMessengerCreateInfoEXT,
+    pub(crate) callback: Arc<dyn DebugUtilsMessengerCallback>,
+}
+
+impl DebugUtilsMessengerCreateInfoEXT {
+    /// Report messages with any of `severities` and any of `types` to `callback`
+    pub fn new<
+        S: DebugUtilsMessageSeverityFlagsEXT,
+        T: DebugUtilsMessageTypeFlagsEXT,
+        F: DebugUtilsMessengerCallback,
+    >(
+        _severities: S,
+        _types: T,
+        callback: F,
+    ) -> Self {
+        check_vuids::check_vuids!(DebugUtilsMessengerCreateInfoEXT);
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_pfnUserCallback_01914: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pfnUserCallback must be a valid PFN_vkDebugUtilsMessengerCallbackEXT"
+            }
+
+            // set below to debug_utils_messenger_callback
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_flags_zerobitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be 0"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_messageSeverity_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "messageSeverity must be a valid combination of VkDebugUtilsMessageSeverityFlagBitsEXT"
+            "values"
+            }
+
+            // ensured by DebugUtilsMessageSeverityFlagsEXT type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_messageSeverity_requiredbitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "messageSeverity must not be 0"
+            }
+
+            const {
+                assert!(
+                    S::INCLUDES.is_not_empty(),
+                    "at least one message severity must be reported"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_messageType_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "messageType must be a valid combination of VkDebugUtilsMessageTypeFlagBitsEXT values"
+            }
+
+            // ensured by DebugUtilsMessageTypeFlagsEXT type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_messageType_requiredbitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "messageType must not be 0"
+            }
+
+            const {
+                assert!(
+                    T::INCLUDES.is_not_empty(),
+                    "at least one message type must be reported"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDebugUtilsMessengerCreateInfoEXT_pfnUserCallback_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pfnUserCallback must be a valid PFN_vkDebugUtilsMessengerCallbackEXT value"
+            }
+
+            // set below to debug_utils_messenger_callback
+        }
+
+        let callback = Arc::new(callback);
+        // SAFETY: debug_utils_messenger_callback has the signature of PFN_vkDebugUtilsMessengerCallbackEXT
+        let pfn_user_callback = unsafe {
+            vk::PFN_vkDebugUtilsMessengerCallbackEXT::new(std::mem::transmute::<
+                vk::FptrTyPFN_vkDebugUtilsMessengerCallbackEXT,
+                vk::PFN_vkVoidFunction,
+            >(
+                debug_utils_messenger_callback::<F>
+            ))
+        };
+        Self {
+            inner: vk::DebugUtilsMessengerCreateInfoEXT {
+                s_type: vk::DebugUtilsMessengerCreateInfoEXT::S_TYPE,
+                p_next: std::ptr::null(),
+                flags: vk::DebugUtilsMessengerCreateFlagsEXT::empty(),
+                message_severity: S::INCLUDES,
+                message_type: T::INCLUDES,
+                pfn_user_callback,
+                // the callback in the Arc, which is not moved by coercing the Arc to dyn
+                p_user_data: Arc::as_ptr(&callback).cast_mut().cast(),
+            },
+            callback,
+        }
+    }
+}
+
+impl fmt::Debug for DebugUtilsMessengerCreateInfoEXT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugUtilsMessengerCreateInfoEXT")
+            .field("message_severity", &self.inner.message_severity)
+            .field("message_type", &self.inner.message_type)
+            .finish()
+    }
+}
+
+// SAFETY: DebugUtilsMessengerCreateInfoEXT is repr(C), and starts with vk::DebugUtilsMessengerCreateInfoEXT
+//
+// StructExtends<vk::InstanceCreateInfo> is not implemented, because the Instance must keep the
+// callback alive until it is destroyed. InstanceCreateInfo::push_debug_utils_messenger is used instead.
+unsafe impl Stype for DebugUtilsMessengerCreateInfoEXT {
+    const S_TYPE: vk::StructureType = vk::DebugUtilsMessengerCreateInfoEXT::S_TYPE;
+}
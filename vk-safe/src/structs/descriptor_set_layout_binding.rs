@@ -0,0 +1,169 @@
+use crate::handles::descriptor_set_layout::CORE_DESCRIPTOR_TYPES;
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::DescriptorType;
+use vk::flag_traits::ShaderStageFlags;
+
+struct_wrapper!(
+/// A single binding in a [`DescriptorSetLayout`](crate::vk::DescriptorSetLayout)
+///
+/// used with [`DescriptorSetLayoutCreateInfo`](crate::vk::DescriptorSetLayoutCreateInfo)
+///
+/// The descriptor type and shader stages are provided as types, so that they can be checked
+/// at compile time. Only the core Vulkan 1.0 descriptor types and shader stages are supported.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDescriptorSetLayoutBinding.html>
+DescriptorSetLayoutBinding
+impl Clone, Copy, Deref, Debug
+);
+
+impl DescriptorSetLayoutBinding {
+    /// A binding of `count` descriptors, accessible to the shader `stages`
+    pub fn new<T: DescriptorType, S: ShaderStageFlags>(
+        binding: u32,
+        _descriptor_type: T,
+        count: u32,
+        _stages: S,
+    ) -> Self {
+        check_vuids::check_vuids!(DescriptorSetLayoutBinding);
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_00282: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorType is VK_DESCRIPTOR_TYPE_SAMPLER or VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER,"
+            "and descriptorCount is not 0 and pImmutableSamplers is not NULL, pImmutableSamplers"
+            "must be a valid pointer to an array of descriptorCount valid VkSampler handles"
+            }
+
+            // TODO: immutable samplers are not supported yet; always NULL
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorCount_00283: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorCount is not 0, stageFlags must be VK_SHADER_STAGE_ALL or a valid combination"
+            "of other VkShaderStageFlagBits values"
+            }
+
+            // only core 1.0 stages are allowed
+            const {
+                let core_stages = vk::ShaderStageFlags::VERTEX_BIT
+                    .or(vk::ShaderStageFlags::TESSELLATION_CONTROL_BIT)
+                    .or(vk::ShaderStageFlags::TESSELLATION_EVALUATION_BIT)
+                    .or(vk::ShaderStageFlags::GEOMETRY_BIT)
+                    .or(vk::ShaderStageFlags::FRAGMENT_BIT)
+                    .or(vk::ShaderStageFlags::COMPUTE_BIT);
+                assert!(
+                    S::INCLUDES.is_empty() || S::INCLUDES.subset_of(core_stages),
+                    "only core Vulkan 1.0 shader stages are supported"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_01510: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorType is VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT and descriptorCount is not"
+            "0, then stageFlags must be 0 or VK_SHADER_STAGE_FRAGMENT_BIT"
+            }
+
+            const {
+                assert!(
+                    !T::VALUE.is(vk::DescriptorType::INPUT_ATTACHMENT)
+                        || S::INCLUDES.is_empty()
+                        || S::INCLUDES.eq(vk::ShaderStageFlags::FRAGMENT_BIT),
+                    "input attachments can only be accessed from the fragment stage"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_pImmutableSamplers_04009: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sampler objects indicated by pImmutableSamplers must not have a borderColor with"
+            "one of the values VK_BORDER_COLOR_FLOAT_CUSTOM_EXT or VK_BORDER_COLOR_INT_CUSTOM_EXT"
+            }
+
+            // TODO: immutable samplers are not supported yet; always NULL
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_04604: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the inlineUniformBlock feature is not enabled, descriptorType must not be VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK"
+            }
+
+            // checked in descriptorType_parameter (only core 1.0 descriptor types are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_02209: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorType is VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK then descriptorCount"
+            "must be a multiple of 4"
+            }
+
+            // checked in descriptorType_parameter (only core 1.0 descriptor types are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_08765: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorType is VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK then descriptorCount"
+            "must be less than or equal to VkPhysicalDeviceInlineUniformBlockProperties::maxInlineUniformBlockSize"
+            }
+
+            // checked in descriptorType_parameter (only core 1.0 descriptor types are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_04605: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If descriptorType is VK_DESCRIPTOR_TYPE_MUTABLE_EXT, then pImmutableSamplers must be"
+            "NULL"
+            }
+
+            // checked in descriptorType_parameter (only core 1.0 descriptor types are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutBinding_descriptorType_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "descriptorType must be a valid VkDescriptorType value"
+            }
+
+            // only core 1.0 descriptor types are allowed
+            // (DescriptorCounts relies on this)
+            const {
+                let mut i = 0;
+                let mut core = false;
+                while i < CORE_DESCRIPTOR_TYPES.len() {
+                    core |= T::VALUE.is(CORE_DESCRIPTOR_TYPES[i]);
+                    i += 1;
+                }
+                assert!(core, "only core Vulkan 1.0 descriptor types are supported");
+            }
+        }
+
+        unsafe {
+            Self::from_c(vk::DescriptorSetLayoutBinding {
+                binding,
+                descriptor_type: T::VALUE,
+                descriptor_count: count,
+                stage_flags: S::INCLUDES,
+                p_immutable_samplers: std::ptr::null(),
+            })
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use super::DescriptorSetLayoutBinding;
+
+use crate::error::VulkanError;
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+struct_wrapper!(
+/// Info for creating a [`DescriptorSetLayout`](crate::vk::DescriptorSetLayout)
+///
+/// used with [`create_descriptor_set_layout`](crate::vk::create_descriptor_set_layout)
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDescriptorSetLayoutCreateInfo.html>
+DescriptorSetLayoutCreateInfo<'a,>
+impl Deref, Debug
+);
+
+impl<'a> DescriptorSetLayoutCreateInfo<'a> {
+    /// Create info for a layout with the provided bindings
+    ///
+    /// Each binding must have a unique binding number.
+    pub fn new(bindings: &'a [DescriptorSetLayoutBinding]) -> Result<Self, VulkanError> {
+        check_vuids::check_vuids!(DescriptorSetLayoutCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_binding_00279: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The VkDescriptorSetLayoutBinding::binding members of the elements of the pBindings"
+            "array must each have different values"
+            }
+
+            for (i, a) in bindings.iter().enumerate() {
+                if bindings[i + 1..].iter().any(|b| a.binding == b.binding) {
+                    Err(VulkanError::DuplicateDescriptorBinding)?
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_00280: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR, then all"
+            "elements of pBindings must not have a descriptorType of VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC"
+            "or VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_02208: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR, then all"
+            "elements of pBindings must not have a descriptorType of VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_00281: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR, then the"
+            "total number of elements of all bindings must be less than or equal to VkPhysicalDevicePushDescriptorPropertiesKHR::maxPushDescriptors"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_04590: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR, flags must"
+            "not contain VK_DESCRIPTOR_SET_LAYOUT_CREATE_HOST_ONLY_POOL_BIT_EXT"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_04591: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR, pBindings"
+            "must not have a descriptorType of VK_DESCRIPTOR_TYPE_MUTABLE_EXT"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_03000: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any binding has the VK_DESCRIPTOR_BINDING_UPDATE_AFTER_BIND_BIT bit set, flags"
+            "must include VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            }
+
+            // TODO: p_next not currently supported (no binding flags)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_descriptorType_03001: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any binding has the VK_DESCRIPTOR_BINDING_UPDATE_AFTER_BIND_BIT bit set, then all"
+            "bindings must not have descriptorType of VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC"
+            "or VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC"
+            }
+
+            // TODO: p_next not currently supported (no binding flags)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_04592: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags contains VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT, flags"
+            "must not contain VK_DESCRIPTOR_SET_LAYOUT_CREATE_HOST_ONLY_POOL_BIT_EXT"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_descriptorType_04593: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any binding has a descriptorType of VK_DESCRIPTOR_TYPE_MUTABLE_EXT, then a VkMutableDescriptorTypeCreateInfoEXT"
+            "must be present in the pNext chain"
+            }
+
+            // only core 1.0 descriptor types are allowed by DescriptorSetLayoutBinding
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_04594: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If a binding has a descriptorType value of VK_DESCRIPTOR_TYPE_MUTABLE_EXT, then pImmutableSamplers"
+            "must be NULL"
+            }
+
+            // only core 1.0 descriptor types are allowed by DescriptorSetLayoutBinding
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each pNext member of any structure (including this one) in the pNext chain must be"
+            "either NULL or a pointer to a valid instance of VkDescriptorSetLayoutBindingFlagsCreateInfo"
+            "or VkMutableDescriptorTypeCreateInfoEXT"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkDescriptorSetLayoutCreateFlagBits values"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkDescriptorSetLayoutCreateInfo_pBindings_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If bindingCount is not 0, pBindings must be a valid pointer to an array of bindingCount"
+            "valid VkDescriptorSetLayoutBinding structures"
+            }
+
+            // ensured by DescriptorSetLayoutBinding creation
+        }
+
+        unsafe {
+            Ok(Self::from_c(vk::DescriptorSetLayoutCreateInfo {
+                s_type: vk::StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::DescriptorSetLayoutCreateFlags::empty(),
+                binding_count: bindings.len().try_into()?,
+                p_bindings: bindings.to_c(),
+            }))
+        }
+    }
+
+    /// The bindings of the layout
+    pub(crate) fn bindings(&self) -> &'a [vk::DescriptorSetLayoutBinding] {
+        if self.inner.binding_count == 0 {
+            return &[];
+        }
+        unsafe {
+            std::slice::from_raw_parts(self.inner.p_bindings, self.inner.binding_count as usize)
+        }
+    }
+}
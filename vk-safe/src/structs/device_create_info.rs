@@ -1,11 +1,18 @@
+use super::extension_chain::{self, includes, includes_any, is_any, ExtensionList, SafeExtends};
 use super::DeviceQueueCreateInfo;
 
+use std::marker::PhantomData;
+
+use crate::error::VulkanError;
+use crate::features::{self, Features};
+use crate::scope::HasScope;
+use crate::structs::PhysicalDeviceFeatures;
 use crate::type_conversions::ConvertWrapper;
 
 use vk_safe_sys as vk;
 
 use vk::context::{Context, Extensions};
-use vk::Version;
+use vk::{StructExtends, Stype, Version};
 
 struct_wrapper!(
 /// info for creating a Device
@@ -13,11 +20,11 @@ struct_wrapper!(
 /// To be used with [`create_device`](crate::vk::create_device)
 ///
 /// see <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDeviceCreateInfo.html>
-DeviceCreateInfo<'a, Config, QueueScope,>
+DeviceCreateInfo<'a, Config, QueueScope, Next, Features,>
 impl Clone, Copy, Deref, Debug
 );
 
-impl<'a> DeviceCreateInfo<'a, (), ()> {
+impl<'a> DeviceCreateInfo<'a, (), (), (), ()> {
     /// create DeviceCreateInfo
     ///
     /// Requires context from [`vk::device_context!`] (which expresses the core version and
@@ -26,7 +33,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
     pub fn new<C: Extensions + Context, Z>(
         context: C,
         queue_create_info: &'a [DeviceQueueCreateInfo<Z>],
-    ) -> DeviceCreateInfo<'a, C, Z>
+    ) -> DeviceCreateInfo<'a, C, Z, (), ()>
     where
         C::Commands: Version,
     {
@@ -82,7 +89,9 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "must be NULL"
             }
 
-            // TODO: p_next not supported
+            // pEnabledFeatures is only set by Self::enable_features, which fails to compile if the
+            // pNext chain includes VkPhysicalDeviceFeatures2, and push_next fails to compile if
+            // VkPhysicalDeviceFeatures2 is added after features are enabled
         }
 
         #[allow(unused_labels)]
@@ -164,7 +173,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "is VK_TRUE, ppEnabledExtensionNames must not contain VK_EXT_buffer_device_address"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -178,7 +187,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "structure"
             }
 
-            // TODO: p_next not supported
+            // checked at compile time in push_next
         }
 
         #[allow(unused_labels)]
@@ -195,7 +204,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "or VkPhysicalDeviceVulkanMemoryModelFeatures structure"
             }
 
-            // TODO: p_next not supported
+            // checked at compile time in push_next
         }
 
         #[allow(unused_labels)]
@@ -207,7 +216,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "must be VK_TRUE"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -219,7 +228,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "must be VK_TRUE"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -231,7 +240,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "must be VK_TRUE"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -243,7 +252,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "must be VK_TRUE"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -255,7 +264,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "must be VK_TRUE"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -267,7 +276,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "and VkPhysicalDeviceVulkan12Features::shaderOutputLayer must both be VK_TRUE"
             }
 
-            // TODO: p_next and extensions not supported
+            // TODO: features in the pNext chain are not checked against extensions
         }
 
         #[allow(unused_labels)]
@@ -284,7 +293,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "or VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures structure"
             }
 
-            // TODO: p_next not supported
+            // checked at compile time in push_next
         }
 
         #[allow(unused_labels)]
@@ -522,7 +531,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "or VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures"
             }
 
-            // TODO: p_next not supported
+            // ensured by StructExtends<vk::DeviceCreateInfo> in push_next
         }
 
         #[allow(unused_labels)]
@@ -533,7 +542,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "of structures of type VkDeviceDeviceMemoryReportCreateInfoEXT or VkDevicePrivateDataCreateInfo"
             }
 
-            // TODO: p_next not supported
+            // checked at compile time in push_next
         }
 
         #[allow(unused_labels)]
@@ -587,7 +596,7 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
             "VkPhysicalDeviceFeatures structure"
             }
 
-            // TODO: features not supported
+            // NULL, or set by Self::enable_features to a valid VkPhysicalDeviceFeatures
         }
 
         #[allow(unused_labels)]
@@ -622,3 +631,209 @@ impl<'a> DeviceCreateInfo<'a, (), ()> {
         }
     }
 }
+
+impl<'a, C, Z, N: ExtensionList, F: Features> DeviceCreateInfo<'a, C, Z, N, F> {
+    /// Add an extension structure to the pNext chain
+    ///
+    /// `next` can be any structure which extends VkDeviceCreateInfo and is
+    /// [`SafeExtends`](crate::vk::SafeExtends) (i.e. feature structures), and is borrowed for
+    /// as long as the DeviceCreateInfo is used. Adding a structure which is already in the
+    /// chain, or which cannot be in the same chain as another structure, fails to compile.
+    /// Other structures can be added with
+    /// [`push_next_unchecked`](DeviceCreateInfo::push_next_unchecked).
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// # vk::device_context!(DeviceContext: VERSION_1_1);
+    /// # fn tst<Z>(queue_configs: &[vk::DeviceQueueCreateInfo<Z>]) {
+    /// let mut protected_memory = vk_safe::raw::PhysicalDeviceProtectedMemoryFeatures {
+    ///     s_type: vk_safe::raw::StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+    ///     p_next: std::ptr::null_mut(),
+    ///     protected_memory: vk_safe::raw::TRUE,
+    /// };
+    /// let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, queue_configs)
+    ///     .push_next(&mut protected_memory);
+    /// # }
+    /// ```
+    pub fn push_next<E: SafeExtends<vk::DeviceCreateInfo>>(
+        self,
+        next: &'a mut E,
+    ) -> DeviceCreateInfo<'a, C, Z, (E, N), F> {
+        // SAFETY: SafeExtends structures have no unchecked pointers or handles
+        unsafe { self.push_next_unchecked(next) }
+    }
+
+    /// Add any extension structure to the pNext chain
+    ///
+    /// The same as [`push_next`](DeviceCreateInfo::push_next), but for any structure which
+    /// extends VkDeviceCreateInfo, including raw structures with pointers and handles.
+    ///
+    /// # Safety
+    /// Any pointers and handles in `next` (other than `p_next`) must be valid for
+    /// vkCreateDevice, and pointers must stay valid for as long as the DeviceCreateInfo is used.
+    pub unsafe fn push_next_unchecked<E: StructExtends<vk::DeviceCreateInfo> + Stype>(
+        self,
+        next: &'a mut E,
+    ) -> DeviceCreateInfo<'a, C, Z, (E, N), F> {
+        let _ = <N as CheckNext<E, F>>::VALID;
+        let mut inner = self.inner;
+        inner.p_next =
+            extension_chain::push_next::<vk::DeviceCreateInfo, E>(inner.p_next, next).cast_const();
+        DeviceCreateInfo {
+            inner,
+            lifetimes: PhantomData,
+            types: PhantomData,
+        }
+    }
+}
+
+impl<'a, C, Z, N: ExtensionList> DeviceCreateInfo<'a, C, Z, N, ()> {
+    /// Enable features of VkPhysicalDeviceFeatures
+    ///
+    /// `supported` must be the features queried from the same PhysicalDevice that the Device
+    /// will be created from (i.e. the PhysicalDevice of the queue configurations). Returns
+    /// [`VulkanError::UnsupportedFeature`] if any feature in `features` is not supported.
+    ///
+    /// The created [`Device`](crate::vk::Device) has `features` as its
+    /// [`Features`](crate::vk::Device::Features) type, which is used to check at compile time
+    /// that a feature is enabled when an API requires it.
+    ///
+    /// Enabling features when the pNext chain includes VkPhysicalDeviceFeatures2 fails to compile.
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # vk::device_context!(DeviceContext: VERSION_1_0);
+    /// # fn tst<P: PhysicalDevice<Commands: vk::instance::VERSION_1_0>, Z: vk_safe::scope::HasScope<P>>
+    /// #   (physical_device: P, queue_configs: &[vk::DeviceQueueCreateInfo<Z>]) {
+    /// vk::features!(MyFeatures: sampler_anisotropy);
+    ///
+    /// let supported = physical_device.get_physical_device_features();
+    /// let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, queue_configs)
+    ///     .enable_features(&supported, MyFeatures)
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn enable_features<S, F: Features>(
+        self,
+        supported: &PhysicalDeviceFeatures<S>,
+        features: F,
+    ) -> Result<DeviceCreateInfo<'a, C, Z, N, F>, VulkanError>
+    where
+        Z: HasScope<S>,
+    {
+        let _ = features;
+
+        const {
+            assert!(
+                features::is_empty(F::ENABLED)
+                    || !includes::<N>(vk::PhysicalDeviceFeatures2::S_TYPE),
+                "violated VUID_VkDeviceCreateInfo_pNext_00373"
+            )
+        }
+
+        if !features::includes(supported, F::ENABLED) {
+            Err(VulkanError::UnsupportedFeature)?
+        }
+
+        let mut inner = self.inner;
+        if !features::is_empty(F::ENABLED) {
+            inner.p_enabled_features = F::ENABLED;
+        }
+        Ok(DeviceCreateInfo {
+            inner,
+            lifetimes: PhantomData,
+            types: PhantomData,
+        })
+    }
+}
+
+/// Structures which can be in the pNext chain more than once
+/// (VUID_VkDeviceCreateInfo_sType_unique)
+const MULTIPLE_ALLOWED: [vk::StructureType; 2] = [
+    vk::DeviceDeviceMemoryReportCreateInfoEXT::S_TYPE,
+    vk::DevicePrivateDataCreateInfo::S_TYPE,
+];
+
+/// Structures which cannot be in the same pNext chain as VkPhysicalDeviceVulkan11Features
+/// (VUID_VkDeviceCreateInfo_pNext_02829)
+const INCLUDED_IN_VULKAN_11_FEATURES: [vk::StructureType; 6] = [
+    vk::PhysicalDevice16BitStorageFeatures::S_TYPE,
+    vk::PhysicalDeviceMultiviewFeatures::S_TYPE,
+    vk::PhysicalDeviceVariablePointersFeatures::S_TYPE,
+    vk::PhysicalDeviceProtectedMemoryFeatures::S_TYPE,
+    vk::PhysicalDeviceSamplerYcbcrConversionFeatures::S_TYPE,
+    vk::PhysicalDeviceShaderDrawParametersFeatures::S_TYPE,
+];
+
+/// Structures which cannot be in the same pNext chain as VkPhysicalDeviceVulkan12Features
+/// (VUID_VkDeviceCreateInfo_pNext_02830)
+const INCLUDED_IN_VULKAN_12_FEATURES: [vk::StructureType; 13] = [
+    vk::PhysicalDevice8BitStorageFeatures::S_TYPE,
+    vk::PhysicalDeviceShaderAtomicInt64Features::S_TYPE,
+    vk::PhysicalDeviceShaderFloat16Int8Features::S_TYPE,
+    vk::PhysicalDeviceDescriptorIndexingFeatures::S_TYPE,
+    vk::PhysicalDeviceScalarBlockLayoutFeatures::S_TYPE,
+    vk::PhysicalDeviceImagelessFramebufferFeatures::S_TYPE,
+    vk::PhysicalDeviceUniformBufferStandardLayoutFeatures::S_TYPE,
+    vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures::S_TYPE,
+    vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures::S_TYPE,
+    vk::PhysicalDeviceHostQueryResetFeatures::S_TYPE,
+    vk::PhysicalDeviceTimelineSemaphoreFeatures::S_TYPE,
+    vk::PhysicalDeviceBufferDeviceAddressFeatures::S_TYPE,
+    vk::PhysicalDeviceVulkanMemoryModelFeatures::S_TYPE,
+];
+
+/// Structures which cannot be in the same pNext chain as VkPhysicalDeviceVulkan13Features
+/// (VUID_VkDeviceCreateInfo_pNext_06532)
+const INCLUDED_IN_VULKAN_13_FEATURES: [vk::StructureType; 13] = [
+    vk::PhysicalDeviceDynamicRenderingFeatures::S_TYPE,
+    vk::PhysicalDeviceImageRobustnessFeatures::S_TYPE,
+    vk::PhysicalDeviceInlineUniformBlockFeatures::S_TYPE,
+    vk::PhysicalDeviceMaintenance4Features::S_TYPE,
+    vk::PhysicalDevicePipelineCreationCacheControlFeatures::S_TYPE,
+    vk::PhysicalDevicePrivateDataFeatures::S_TYPE,
+    vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures::S_TYPE,
+    vk::PhysicalDeviceShaderIntegerDotProductFeatures::S_TYPE,
+    vk::PhysicalDeviceShaderTerminateInvocationFeatures::S_TYPE,
+    vk::PhysicalDeviceSubgroupSizeControlFeatures::S_TYPE,
+    vk::PhysicalDeviceSynchronization2Features::S_TYPE,
+    vk::PhysicalDeviceTextureCompressionASTCHDRFeatures::S_TYPE,
+    vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures::S_TYPE,
+];
+
+/// Check the VUIDs which depend on the structures in the pNext chain `N`, when adding `E`
+/// with enabled features `F`
+trait CheckNext<E, F> {
+    const VALID: ();
+}
+
+impl<N: ExtensionList, E: Stype, F: Features> CheckNext<E, F> for N {
+    const VALID: () = {
+        if E::S_TYPE.is(vk::PhysicalDeviceFeatures2::S_TYPE) && !features::is_empty(F::ENABLED) {
+            panic!("violated VUID_VkDeviceCreateInfo_pNext_00373")
+        }
+
+        if includes::<N>(E::S_TYPE) && !is_any(E::S_TYPE, &MULTIPLE_ALLOWED) {
+            panic!("violated VUID_VkDeviceCreateInfo_sType_unique")
+        }
+
+        if includes::<(E, N)>(vk::PhysicalDeviceVulkan11Features::S_TYPE)
+            && includes_any::<(E, N)>(&INCLUDED_IN_VULKAN_11_FEATURES)
+        {
+            panic!("violated VUID_VkDeviceCreateInfo_pNext_02829")
+        }
+
+        if includes::<(E, N)>(vk::PhysicalDeviceVulkan12Features::S_TYPE)
+            && includes_any::<(E, N)>(&INCLUDED_IN_VULKAN_12_FEATURES)
+        {
+            panic!("violated VUID_VkDeviceCreateInfo_pNext_02830")
+        }
+
+        if includes::<(E, N)>(vk::PhysicalDeviceVulkan13Features::S_TYPE)
+            && includes_any::<(E, N)>(&INCLUDED_IN_VULKAN_13_FEATURES)
+        {
+            panic!("violated VUID_VkDeviceCreateInfo_pNext_06532")
+        }
+    };
+}
@@ -0,0 +1,294 @@
+//! pNext extension chains
+//!
+//! Many Vulkan structures can be extended by adding other structures to their pNext chain.
+//! A structure which can extend a base structure `B` implements
+//! [`StructExtends<B>`](vk_safe_sys::StructExtends), which is generated from vk.xml.
+//!
+//! Structures in vk-safe which support a pNext chain have a `push_next` method, which adds an
+//! extension structure to the front of the chain. The structures in the chain are tracked at
+//! the type level with an [`ExtensionList`], so that rules which depend on what is in the chain
+//! (such as each structure being unique) are checked at compile time.
+//!
+//! `push_next` sets the `s_type` and `p_next` members of the extension structure, so they can
+//! be set to anything when making the structure.
+//!
+//! vk-safe cannot check the pointers and handles in a raw extension structure, so `push_next`
+//! only takes structures which implement [`SafeExtends`]. Those are the structures which are
+//! known to be valid whatever their members are set to, and the vk-safe wrappers of extension
+//! structures, which borrow anything that they point to. Any other structure can be added with
+//! the unsafe `push_next_unchecked`.
+//!
+//! An extension structure is borrowed mutably while it is in a chain. Queries which return
+//! structures with a pNext chain take an [`OutputChain`], and Vulkan writes the results to
+//! the extension structures, which can be read after the chain is no longer used.
+
+use std::ffi::c_void;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use vk_safe_sys as vk;
+
+use vk::{BaseOutStructure, StructExtends, Stype};
+
+/// Type level list of the structures in a pNext chain
+///
+/// `()` is the empty list, and `(E, L)` is the list `L` with `E` added to the front.
+pub unsafe trait ExtensionList {
+    #[doc(hidden)]
+    const HEAD: Option<vk::StructureType>;
+    #[doc(hidden)]
+    type Tail: ExtensionList;
+}
+
+unsafe impl ExtensionList for () {
+    const HEAD: Option<vk::StructureType> = None;
+    type Tail = ();
+}
+
+unsafe impl<E: Stype, L: ExtensionList> ExtensionList for (E, L) {
+    const HEAD: Option<vk::StructureType> = Some(E::S_TYPE);
+    type Tail = L;
+}
+
+/// Check if a structure with `s_type` is in `L`
+pub(crate) const fn includes<L: ExtensionList>(s_type: vk::StructureType) -> bool {
+    match L::HEAD {
+        None => false,
+        Some(head) => head.is(s_type) || includes::<L::Tail>(s_type),
+    }
+}
+
+/// Check if a structure with any of `s_types` is in `L`
+pub(crate) const fn includes_any<L: ExtensionList>(s_types: &[vk::StructureType]) -> bool {
+    match L::HEAD {
+        None => false,
+        Some(head) => is_any(head, s_types) || includes_any::<L::Tail>(s_types),
+    }
+}
+
+/// Check if `s_type` is any of `s_types`
+pub(crate) const fn is_any(s_type: vk::StructureType, s_types: &[vk::StructureType]) -> bool {
+    let mut i = 0;
+    while i < s_types.len() {
+        if s_type.is(s_types[i]) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Check that `E` is not already in the list
+///
+/// Use as `let _ = <L as UniqueNext<E>>::VALID;` before adding `E` to `L`, which
+/// fails to compile if `E` is already in `L`.
+pub(crate) trait UniqueNext<E> {
+    const VALID: ();
+}
+
+impl<L: ExtensionList, E: Stype> UniqueNext<E> for L {
+    const VALID: () = {
+        if includes::<L>(E::S_TYPE) {
+            panic!("the same structure cannot be added to a pNext chain more than once")
+        }
+    };
+}
+
+/// A structure which can be safely added to the pNext chain of `B`
+///
+/// This trait is sealed, and is implemented for:
+/// - feature structures (structures which extend VkPhysicalDeviceFeatures2), which only have
+///   VkBool32 members, for VkPhysicalDeviceFeatures2 and VkDeviceCreateInfo
+/// - core output structures (e.g. `PhysicalDeviceDriverProperties`), which only have plain data
+///   members that are written by the query, for VkPhysicalDeviceProperties2,
+///   VkQueueFamilyProperties2, and VkFormatProperties2
+/// - vk-safe wrappers of extension structures (e.g. [`ValidationFeaturesEXT`](crate::vk::ValidationFeaturesEXT)),
+///   which borrow anything that they point to
+pub trait SafeExtends<B>: private::SafeExtends<B> + StructExtends<B> + Stype {}
+
+impl<B, E: private::SafeExtends<B> + StructExtends<B> + Stype> SafeExtends<B> for E {}
+
+pub(crate) mod private {
+    use super::*;
+
+    /// # Safety
+    /// Adding the implementor to the pNext chain of `B` must be valid for any value of the
+    /// implementor (other than `s_type` and `p_next`, which are set by `push_next`), and
+    /// anything the implementor points to must be borrowed for the lifetime of the implementor
+    pub unsafe trait SafeExtends<B> {}
+
+    // SAFETY: feature structures only have VkBool32 members
+    unsafe impl<E: StructExtends<vk::PhysicalDeviceFeatures2>>
+        SafeExtends<vk::PhysicalDeviceFeatures2> for E
+    {
+    }
+
+    // SAFETY: feature structures only have VkBool32 members
+    unsafe impl<E: StructExtends<vk::PhysicalDeviceFeatures2> + StructExtends<vk::DeviceCreateInfo>>
+        SafeExtends<vk::DeviceCreateInfo> for E
+    {
+    }
+
+    macro_rules! output_structures {
+        ( $( $base:ident : $($ext:ident)+ ; )* ) => {
+            $( $(
+                unsafe impl SafeExtends<vk::$base> for vk::$ext {}
+            )+ )*
+        };
+    }
+
+    // SAFETY: these structures do not have any pointers (other than pNext) or handles, and
+    // all their members are only written by the query
+    //
+    // Not all output structures are like this (e.g. VkDrmFormatModifierPropertiesListEXT has an
+    // array which is provided by the caller), so they are listed individually.
+    output_structures!(
+        PhysicalDeviceProperties2:
+            PhysicalDeviceIDProperties
+            PhysicalDeviceMaintenance3Properties
+            PhysicalDeviceMultiviewProperties
+            PhysicalDevicePointClippingProperties
+            PhysicalDeviceProtectedMemoryProperties
+            PhysicalDeviceSubgroupProperties
+            PhysicalDeviceVulkan11Properties
+            PhysicalDeviceDepthStencilResolveProperties
+            PhysicalDeviceDescriptorIndexingProperties
+            PhysicalDeviceDriverProperties
+            PhysicalDeviceFloatControlsProperties
+            PhysicalDeviceSamplerFilterMinmaxProperties
+            PhysicalDeviceTimelineSemaphoreProperties
+            PhysicalDeviceVulkan12Properties
+            PhysicalDeviceInlineUniformBlockProperties
+            PhysicalDeviceMaintenance4Properties
+            PhysicalDeviceShaderIntegerDotProductProperties
+            PhysicalDeviceSubgroupSizeControlProperties
+            PhysicalDeviceTexelBufferAlignmentProperties
+            PhysicalDeviceVulkan13Properties;
+        QueueFamilyProperties2:
+            QueueFamilyGlobalPriorityPropertiesKHR;
+        FormatProperties2:
+            FormatProperties3;
+    );
+}
+
+/// Add `next` to the front of the pNext chain of a base structure `B`
+///
+/// `p_next` is the current start of the chain. Returns the new start of the chain, to be set
+/// as the p_next of the base structure.
+pub(crate) fn push_next<B, E: StructExtends<B> + Stype>(
+    p_next: *const c_void,
+    next: &mut E,
+) -> *mut c_void {
+    let base: *mut BaseOutStructure = (next as *mut E).cast();
+    // SAFETY: a structure which extends another structure starts with sType and pNext
+    // members, the same as BaseOutStructure
+    unsafe {
+        (*base).s_type = E::S_TYPE;
+        (*base).p_next = p_next.cast_mut().cast();
+    }
+    base.cast()
+}
+
+/// pNext chain of output structures, for a query which returns the base structure `B`
+///
+/// `B` is the raw base structure (e.g. `vk_safe::raw::PhysicalDeviceFeatures2`), and `L`
+/// is the [`ExtensionList`] of the structures in the chain.
+pub struct OutputChain<'a, B, L> {
+    head: *mut c_void,
+    _borrow: PhantomData<(&'a mut (), B, L)>,
+}
+
+impl<B> OutputChain<'_, B, ()> {
+    /// Make an empty OutputChain
+    pub fn new() -> Self {
+        Self {
+            head: std::ptr::null_mut(),
+            _borrow: PhantomData,
+        }
+    }
+}
+
+impl<B> Default for OutputChain<'_, B, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, B, L: ExtensionList> OutputChain<'a, B, L> {
+    /// Add a structure to the front of the chain
+    ///
+    /// Adding a structure which is already in the chain fails to compile. Structures which
+    /// do not implement [`SafeExtends`] (such as structures with an array provided by the
+    /// caller) can be added with [`push_next_unchecked`](Self::push_next_unchecked).
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// let mut driver_properties: vk_safe::raw::PhysicalDeviceDriverProperties =
+    ///     unsafe { std::mem::zeroed() };
+    /// let chain = vk::OutputChain::<vk_safe::raw::PhysicalDeviceProperties2, _>::new()
+    ///     .push_next(&mut driver_properties);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use vk_safe::vk;
+    /// let mut modifier_properties: vk_safe::raw::DrmFormatModifierPropertiesListEXT =
+    ///     unsafe { std::mem::zeroed() };
+    /// let chain = vk::OutputChain::<vk_safe::raw::FormatProperties2, _>::new()
+    ///     .push_next(&mut modifier_properties);
+    /// ```
+    pub fn push_next<E: SafeExtends<B>>(self, next: &'a mut E) -> OutputChain<'a, B, (E, L)> {
+        // SAFETY: E is valid in the chain of B with any values
+        unsafe { self.push_next_unchecked(next) }
+    }
+
+    /// Add any structure which extends `B` to the front of the chain
+    ///
+    /// Adding a structure which is already in the chain fails to compile.
+    ///
+    /// # Safety
+    /// Any pointers and handles in `next` (other than `p_next`) must be valid for the query,
+    /// as described in the Vulkan documentation of the structure.
+    pub unsafe fn push_next_unchecked<E: StructExtends<B> + Stype>(
+        self,
+        next: &'a mut E,
+    ) -> OutputChain<'a, B, (E, L)> {
+        let _ = <L as UniqueNext<E>>::VALID;
+        OutputChain {
+            head: push_next::<B, E>(self.head, next),
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Pointer to the first structure in the chain (or null if the chain is empty)
+    ///
+    /// This can be used as the p_next of the raw base structure.
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.head
+    }
+}
+
+impl<B: Stype, L> OutputChain<'_, B, L> {
+    /// Uninitialized base structure, with the sType of `B` and the pNext of this chain
+    ///
+    /// The rest of the base structure is to be written by Vulkan.
+    pub(crate) fn uninit_base(&self) -> MaybeUninit<B> {
+        let mut base = MaybeUninit::<B>::uninit();
+        let header: *mut BaseOutStructure = base.as_mut_ptr().cast();
+        // SAFETY: a structure with an sType starts with sType and pNext members, the same
+        // as BaseOutStructure
+        unsafe {
+            std::ptr::addr_of_mut!((*header).s_type).write(B::S_TYPE);
+            std::ptr::addr_of_mut!((*header).p_next).write(self.head.cast());
+        }
+        base
+    }
+}
+
+impl<B, L> fmt::Debug for OutputChain<'_, B, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputChain")
+            .field("head", &self.head)
+            .finish()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+struct_wrapper!(
+/// Info for creating a [`Fence`](crate::vk::Fence)
+///
+/// Fences are always created unsignaled. A Fence is reset by vk-safe before
+/// each use with [`queue_submit`](crate::vk::Queue::queue_submit).
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkFenceCreateInfo.html>
+FenceCreateInfo
+impl Deref, Debug
+);
+
+impl FenceCreateInfo {
+    pub fn new() -> Self {
+        check_vuids::check_vuids!(FenceCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkFenceCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_FENCE_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkFenceCreateInfo_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each pNext member of any structure (including this one) in the pNext chain must be"
+            "either NULL or a pointer to a valid instance of VkExportFenceCreateInfo or VkExportFenceWin32HandleInfoKHR"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkFenceCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkFenceCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkFenceCreateFlagBits values"
+            }
+
+            // set to empty below
+        }
+
+        unsafe {
+            Self::from_c(vk::FenceCreateInfo {
+                s_type: vk::StructureType::FENCE_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::FenceCreateFlags::empty(),
+            })
+        }
+    }
+}
+
+impl Default for FenceCreateInfo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
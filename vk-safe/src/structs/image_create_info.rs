@@ -0,0 +1,546 @@
+use super::{ImageFormatProperties, ImageParameters::ImageParameters, PhysicalDeviceProperties};
+
+use crate::error::VulkanError;
+use crate::type_conversions::ConvertWrapper;
+
+use std::num::NonZeroU32;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::SampleCountFlags;
+
+struct_wrapper!(
+/// Info for creating an Image
+///
+/// used with [`create_image`](crate::vk::create_image)
+///
+/// The format, type, tiling, usage and create flags of the Image are taken from the
+/// verified [`ImageParameters`](crate::vk::ImageParameters). The extent, mip levels,
+/// array layers and sample count are checked against the [`ImageFormatProperties`] that
+/// were queried with the same parameters for the PhysicalDevice `S`.
+///
+/// The Image is always created with `VK_SHARING_MODE_EXCLUSIVE` and `VK_IMAGE_LAYOUT_UNDEFINED`
+/// for now.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkImageCreateInfo.html>
+ImageCreateInfo<S, Params,>
+impl Deref, Debug
+);
+
+impl<S, P: ImageParameters> ImageCreateInfo<S, P> {
+    pub fn new<SampleCount: SampleCountFlags>(
+        image_format_properties: &ImageFormatProperties<S, P>,
+        physical_device_properties: &PhysicalDeviceProperties<S>,
+        extent: vk::Extent3D,
+        mip_levels: NonZeroU32,
+        array_layers: NonZeroU32,
+        _samples: SampleCount,
+    ) -> Result<Self, VulkanError> {
+        use vk::image_create_flag_bits::*;
+        use vk::image_tiling::LINEAR;
+        use vk::image_type::*;
+        use vk::image_usage_flag_bits::*;
+        use vk::sample_count_flag_bits::TYPE_1_BIT;
+
+        let image_type = P::image_type();
+        let create_flags = P::image_create_flags();
+        let usage_flags = P::image_usage_flags();
+        let samples = SampleCount::INCLUDES;
+
+        check_vuids::check_vuids!(ImageCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_imageCreateMaxMipLevels_02251: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each of the following values (as described in Image Creation Limits) must not be"
+            "undefined : imageCreateMaxMipLevels, imageCreateMaxArrayLayers, imageCreateMaxExtent,"
+            "and imageCreateSampleCounts"
+            }
+
+            // ImageFormatProperties can only be obtained for supported parameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_sharingMode_00941: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sharingMode is VK_SHARING_MODE_CONCURRENT, pQueueFamilyIndices must be a valid"
+            "pointer to an array of queueFamilyIndexCount uint32_t values"
+            }
+
+            // TODO: only VK_SHARING_MODE_EXCLUSIVE for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_sharingMode_00942: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sharingMode is VK_SHARING_MODE_CONCURRENT, queueFamilyIndexCount must be greater"
+            "than 1"
+            }
+
+            // TODO: only VK_SHARING_MODE_EXCLUSIVE for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_sharingMode_01420: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sharingMode is VK_SHARING_MODE_CONCURRENT, each element of pQueueFamilyIndices"
+            "must be unique and must be less than pQueueFamilyPropertyCount returned by either"
+            "vkGetPhysicalDeviceQueueFamilyProperties or vkGetPhysicalDeviceQueueFamilyProperties2"
+            "for the physicalDevice that was used to create device"
+            }
+
+            // TODO: only VK_SHARING_MODE_EXCLUSIVE for now
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_extent_00944: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "extent.width must be greater than 0"
+            }
+
+            if extent.width == 0 {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_extent_00945: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "extent.height must be greater than 0"
+            }
+
+            if extent.height == 0 {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_extent_00946: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "extent.depth must be greater than 0"
+            }
+
+            if extent.depth == 0 {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_mipLevels_00947: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "mipLevels must be greater than 0"
+            }
+
+            // using NonZeroU32
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_arrayLayers_00948: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "arrayLayers must be greater than 0"
+            }
+
+            // using NonZeroU32
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_extent_02252: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "extent.width must be less than or equal to imageCreateMaxExtent.width (as defined"
+            "in Image Creation Limits)"
+            }
+
+            if extent.width > image_format_properties.max_extent.width {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_extent_02253: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "extent.height must be less than or equal to imageCreateMaxExtent.height (as defined"
+            "in Image Creation Limits)"
+            }
+
+            if extent.height > image_format_properties.max_extent.height {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_extent_02254: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "extent.depth must be less than or equal to imageCreateMaxExtent.depth (as defined"
+            "in Image Creation Limits)"
+            }
+
+            if extent.depth > image_format_properties.max_extent.depth {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_imageType_00954: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageType is VK_IMAGE_TYPE_2D and flags contains VK_IMAGE_CREATE_CUBE_COMPATIBLE_BIT,"
+            "extent.width and extent.height must be equal and arrayLayers must be greater than"
+            "or equal to 6"
+            }
+
+            if image_type.is(TYPE_2D) && create_flags.contains(CUBE_COMPATIBLE_BIT) {
+                if extent.width != extent.height {
+                    Err(VulkanError::InvalidImageExtent)?
+                }
+                if array_layers.get() < 6 {
+                    Err(VulkanError::InvalidArrayLayers)?
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_imageType_00956: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageType is VK_IMAGE_TYPE_1D, both extent.height and extent.depth must be 1"
+            }
+
+            if image_type.is(TYPE_1D) && (extent.height != 1 || extent.depth != 1) {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_imageType_00957: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageType is VK_IMAGE_TYPE_2D, extent.depth must be 1"
+            }
+
+            if image_type.is(TYPE_2D) && extent.depth != 1 {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_mipLevels_00958: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "mipLevels must be less than or equal to the number of levels in the complete mipmap"
+            "chain based on extent.width, extent.height, and extent.depth"
+            }
+
+            // extent is non zero, checked above
+            let max_dimension = extent.width.max(extent.height).max(extent.depth);
+            let complete_mip_chain = u32::BITS - max_dimension.leading_zeros();
+            if mip_levels.get() > complete_mip_chain {
+                Err(VulkanError::InvalidMipLevels)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_mipLevels_02255: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "mipLevels must be less than or equal to imageCreateMaxMipLevels (as defined in Image"
+            "Creation Limits)"
+            }
+
+            if mip_levels.get() > image_format_properties.max_mip_levels {
+                Err(VulkanError::InvalidMipLevels)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_arrayLayers_02256: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "arrayLayers must be less than or equal to imageCreateMaxArrayLayers (as defined in"
+            "Image Creation Limits)"
+            }
+
+            if array_layers.get() > image_format_properties.max_array_layers {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_imageType_00961: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If imageType is VK_IMAGE_TYPE_3D, arrayLayers must be 1"
+            }
+
+            if image_type.is(TYPE_3D) && array_layers.get() != 1 {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_samples_02257: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If samples is not VK_SAMPLE_COUNT_1_BIT, then imageType must be VK_IMAGE_TYPE_2D,"
+            "flags must not contain VK_IMAGE_CREATE_CUBE_COMPATIBLE_BIT, mipLevels must be equal"
+            "to 1, and imageCreateMaybeLinear (as defined in Image Creation Limits) must be VK_FALSE,"
+            }
+
+            if samples != TYPE_1_BIT {
+                if !image_type.is(TYPE_2D)
+                    || create_flags.contains(CUBE_COMPATIBLE_BIT)
+                    || P::image_tiling().is(LINEAR)
+                {
+                    Err(VulkanError::UnsupportedSampleCount)?
+                }
+                if mip_levels.get() != 1 {
+                    Err(VulkanError::InvalidMipLevels)?
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_usage_00964: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage includes VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT, VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT,"
+            "VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT, or VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT,"
+            "extent.width must be less than or equal to VkPhysicalDeviceLimits::maxFramebufferWidth"
+            }
+
+            let attachment_usage = COLOR_ATTACHMENT_BIT
+                .or(DEPTH_STENCIL_ATTACHMENT_BIT)
+                .or(INPUT_ATTACHMENT_BIT)
+                .or(TRANSIENT_ATTACHMENT_BIT);
+            if usage_flags.any_of(attachment_usage)
+                && extent.width > physical_device_properties.limits.max_framebuffer_width
+            {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_usage_00965: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage includes VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT, VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT,"
+            "VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT, or VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT,"
+            "extent.height must be less than or equal to VkPhysicalDeviceLimits::maxFramebufferHeight"
+            }
+
+            let attachment_usage = COLOR_ATTACHMENT_BIT
+                .or(DEPTH_STENCIL_ATTACHMENT_BIT)
+                .or(INPUT_ATTACHMENT_BIT)
+                .or(TRANSIENT_ATTACHMENT_BIT);
+            if usage_flags.any_of(attachment_usage)
+                && extent.height > physical_device_properties.limits.max_framebuffer_height
+            {
+                Err(VulkanError::InvalidImageExtent)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_samples_02258: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "samples must be a bit value that is set in imageCreateSampleCounts (as defined in"
+            "Image Creation Limits)"
+            }
+
+            if samples.count_bits() != 1 {
+                Err(VulkanError::OnlyOneSampleCountAllowed)?
+            } else if !image_format_properties.sample_counts.contains(samples) {
+                Err(VulkanError::UnsupportedSampleCount)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_usage_00968: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the shaderStorageImageMultisample feature is not enabled, and usage contains"
+            "VK_IMAGE_USAGE_STORAGE_BIT, samples must be VK_SAMPLE_COUNT_1_BIT"
+            }
+
+            // ImageCreateInfo is scoped to the PhysicalDevice rather than a Device with enabled
+            // features, so assume the feature is not enabled
+            if usage_flags.contains(STORAGE_BIT) && samples != TYPE_1_BIT {
+                Err(VulkanError::UnsupportedSampleCount)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_flags_00969: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the sparseBinding feature is not enabled, flags must not contain VK_IMAGE_CREATE_SPARSE_BINDING_BIT"
+            }
+
+            // TODO: sparse resources are not supported yet
+            const {
+                let sparse_flags = SPARSE_BINDING_BIT
+                    .or(SPARSE_RESIDENCY_BIT)
+                    .or(SPARSE_ALIASED_BIT);
+                if P::ImageCreateFlags::INCLUDES.any_of(sparse_flags) {
+                    panic!("sparse images are not supported by vk-safe at this time")
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_flags_01890: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the protectedMemory feature is not enabled, flags must not contain VK_IMAGE_CREATE_PROTECTED_BIT"
+            }
+
+            // TODO: protected memory is not supported yet
+            const {
+                if P::ImageCreateFlags::INCLUDES.contains(PROTECTED_BIT) {
+                    panic!("PROTECTED_BIT not supported by vk-safe at this time")
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_initialLayout_00993: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "initialLayout must be VK_IMAGE_LAYOUT_UNDEFINED or VK_IMAGE_LAYOUT_PREINITIALIZED"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkImageCreateFlagBits values"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_imageType_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "imageType must be a valid VkImageType value"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_format_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "format must be a valid VkFormat value"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_samples_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "samples must be a valid VkSampleCountFlagBits value"
+            }
+
+            // ensured by SampleCountFlags type, and checked for a single bit above
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_tiling_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "tiling must be a valid VkImageTiling value"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_usage_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "usage must be a valid combination of VkImageUsageFlagBits values"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_usage_requiredbitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "usage must not be 0"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_sharingMode_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sharingMode must be a valid VkSharingMode value"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageCreateInfo_initialLayout_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "initialLayout must be a valid VkImageLayout value"
+            }
+
+            // set below
+        }
+
+        unsafe {
+            Ok(Self::from_c(vk::ImageCreateInfo {
+                s_type: vk::StructureType::IMAGE_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: create_flags,
+                image_type,
+                format: P::format(),
+                extent,
+                mip_levels: mip_levels.get(),
+                array_layers: array_layers.get(),
+                samples,
+                tiling: P::image_tiling(),
+                usage: usage_flags,
+                sharing_mode: vk::SharingMode::EXCLUSIVE,
+                queue_family_index_count: 0,
+                p_queue_family_indices: std::ptr::null(),
+                initial_layout: vk::ImageLayout::UNDEFINED,
+            }))
+        }
+    }
+}
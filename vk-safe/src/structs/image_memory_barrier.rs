@@ -0,0 +1,744 @@
+use super::ImageParameters::ImageParameters;
+
+use crate::handles::command_buffer::RecordingId;
+use crate::handles::image::{BoundImage, Image, TrackedImage};
+use crate::handles::queue::ownership_transfer::{Acquire, Release};
+use crate::handles::queue::QueueFamilyMarker;
+use crate::handles::{device_memory::DeviceMemory, Handle};
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::ImageLayout;
+use vk::flag_traits::AccessFlags;
+
+/// A memory barrier for an Image, which can also transition the layout of the Image
+///
+/// used with [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier)
+///
+/// The access masks are provided as types, so that they can be checked at compile time
+/// against the pipeline stages of the barrier. The barrier always applies to the whole Image
+/// (all aspects of the format, all mip levels, and all array layers).
+///
+/// The Image must be a [`TrackedImage`](crate::vk::TrackedImage). Use
+/// [`transition`](Self::transition) to transition the layout of the Image, which consumes the
+/// TrackedImage. [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier)
+/// returns the TrackedImage with the new layout when the transition is recorded. Only layouts
+/// from core Vulkan 1.0 are supported, and the layouts are checked at compile time against the
+/// usage of the Image.
+///
+/// Use [`release`](Self::release) or [`acquire`](Self::acquire) on a barrier without a layout
+/// transition to define a queue family ownership transfer.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkImageMemoryBarrier.html>
+pub struct ImageMemoryBarrier<'a, D, SA, DA, T, Old, New> {
+    inner: vk::ImageMemoryBarrier,
+    // the recording in which the layout of the TrackedImage was last transitioned
+    recording: RecordingId,
+    _refs: PhantomData<&'a ()>,
+    _types: PhantomData<(D, SA, DA, T, Old, New)>,
+}
+
+impl<D, SA, DA, T, Old, New> std::ops::Deref for ImageMemoryBarrier<'_, D, SA, DA, T, Old, New> {
+    type Target = vk::ImageMemoryBarrier;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<D, SA, DA, T, Old, New> fmt::Debug for ImageMemoryBarrier<'_, D, SA, DA, T, Old, New> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<D, SA, DA, T, Old, New> ImageMemoryBarrier<'_, D, SA, DA, T, Old, New> {
+    /// The recording in which the layout of the Image was last transitioned
+    pub(crate) fn recording(&self) -> RecordingId {
+        self.recording
+    }
+}
+
+impl<'a, D, SA: AccessFlags, DA: AccessFlags, L: ImageLayout>
+    ImageMemoryBarrier<'a, D, SA, DA, (), L, L>
+{
+    /// Barrier for an Image without a layout transition
+    pub fn new<'m, I: Image<Device = D>, M: DeviceMemory<Device = D>>(
+        image: &TrackedImage<'a, 'm, I, M, L>,
+        _src_access: SA,
+        _dst_access: DA,
+    ) -> Self {
+        // VUIDs are checked in make_barrier
+        Self::make_barrier(image.image(), image.recording())
+    }
+}
+
+impl<'a, D, SA: AccessFlags, DA: AccessFlags, Old: ImageLayout, New: ImageLayout>
+    ImageMemoryBarrier<'a, D, SA, DA, (), Old, New>
+{
+    /// Barrier for an Image which transitions the layout from `Old` to `New`
+    ///
+    /// The TrackedImage is held by the returned [`ImageLayoutTransition`], and is returned
+    /// with the `New` layout by [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier).
+    pub fn transition<'m, I: Image<Device = D>, M: DeviceMemory<Device = D>>(
+        image: TrackedImage<'a, 'm, I, M, Old>,
+        _new_layout: New,
+        _src_access: SA,
+        _dst_access: DA,
+    ) -> ImageLayoutTransition<'a, 'm, I, M, SA, DA, Old, New> {
+        // VUIDs are checked in make_barrier
+        ImageLayoutTransition {
+            barrier: Self::make_barrier(image.image(), image.recording()),
+            image,
+        }
+    }
+
+    fn make_barrier<'m, I: Image<Device = D>, M: DeviceMemory<Device = D>>(
+        image: &'a BoundImage<'m, I, M>,
+        recording: RecordingId,
+    ) -> Self {
+        check_vuids::check_vuids!(ImageMemoryBarrier);
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_subresourceRange_01486: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "subresourceRange.baseMipLevel must be less than the mipLevels specified in VkImageCreateInfo"
+            "when image was created"
+            }
+
+            // always 0, and an Image has at least one mip level
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_subresourceRange_01724: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If subresourceRange.levelCount is not VK_REMAINING_MIP_LEVELS, subresourceRange.baseMipLevel"
+            "+ subresourceRange.levelCount must be less than or equal to the mipLevels specified"
+            "in VkImageCreateInfo when image was created"
+            }
+
+            // always VK_REMAINING_MIP_LEVELS
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_subresourceRange_01488: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is not a 3D image or was created without VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT"
+            "set, subresourceRange.baseArrayLayer must be less than the arrayLayers specified in"
+            "VkImageCreateInfo when image was created"
+            }
+
+            // always 0, and an Image has at least one array layer
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_subresourceRange_01725: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is not a 3D image or was created without VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT"
+            "set, and subresourceRange.layerCount is not VK_REMAINING_ARRAY_LAYERS, subresourceRange.baseArrayLayer"
+            "+ subresourceRange.layerCount must be less than or equal to the arrayLayers specified"
+            "in VkImageCreateInfo when image was created"
+            }
+
+            // always VK_REMAINING_ARRAY_LAYERS
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_01932: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is non-sparse then it must be bound completely and contiguously to a single"
+            "VkDeviceMemory object"
+            }
+
+            // using BoundImage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_09117: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was created with a sharing mode of VK_SHARING_MODE_EXCLUSIVE, and srcQueueFamilyIndex"
+            "and dstQueueFamilyIndex are not equal, srcQueueFamilyIndex must be VK_QUEUE_FAMILY_EXTERNAL,"
+            "VK_QUEUE_FAMILY_FOREIGN_EXT, or a valid queue family"
+            }
+
+            // QUEUE_FAMILY_IGNORED for both by default
+            // release and acquire only use indices from QueueFamilyMarker
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_09118: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was created with a sharing mode of VK_SHARING_MODE_EXCLUSIVE, and srcQueueFamilyIndex"
+            "and dstQueueFamilyIndex are not equal, dstQueueFamilyIndex must be VK_QUEUE_FAMILY_EXTERNAL,"
+            "VK_QUEUE_FAMILY_FOREIGN_EXT, or a valid queue family"
+            }
+
+            // QUEUE_FAMILY_IGNORED for both by default
+            // release and acquire only use indices from QueueFamilyMarker
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_srcQueueFamilyIndex_04070: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex is not equal to dstQueueFamilyIndex, at least one of srcQueueFamilyIndex"
+            "or dstQueueFamilyIndex must not be VK_QUEUE_FAMILY_EXTERNAL or VK_QUEUE_FAMILY_FOREIGN_EXT"
+            }
+
+            // external and foreign queue families are not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_None_09119: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_KHR_external_memory extension is not enabled, and the value of VkApplicationInfo::apiVersion"
+            "used to create the VkInstance is not greater than or equal to Version 1.1, srcQueueFamilyIndex"
+            "must not be VK_QUEUE_FAMILY_EXTERNAL"
+            }
+
+            // external queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_None_09120: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_EXT_queue_family_foreign extension is not enabled, srcQueueFamilyIndex must"
+            "not be VK_QUEUE_FAMILY_FOREIGN_EXT"
+            }
+
+            // foreign queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_None_09121: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_KHR_external_memory extension is not enabled, and the value of VkApplicationInfo::apiVersion"
+            "used to create the VkInstance is not greater than or equal to Version 1.1, dstQueueFamilyIndex"
+            "must not be VK_QUEUE_FAMILY_EXTERNAL"
+            }
+
+            // external queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_None_09122: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the VK_EXT_queue_family_foreign extension is not enabled, dstQueueFamilyIndex must"
+            "not be VK_QUEUE_FAMILY_FOREIGN_EXT"
+            }
+
+            // foreign queue family is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01197: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, oldLayout must be VK_IMAGE_LAYOUT_UNDEFINED"
+            "or the current layout of the image subresources affected by the barrier"
+            }
+
+            // ensured by the layout of the TrackedImage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_newLayout_01198: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, newLayout must not be"
+            "VK_IMAGE_LAYOUT_UNDEFINED or VK_IMAGE_LAYOUT_PREINITIALIZED"
+            }
+
+            // ownership transfers are checked in release and acquire
+            const {
+                use vk::image_layout::*;
+                if !Old::VALUE.is(New::VALUE) {
+                    assert!(
+                        !New::VALUE.is(UNDEFINED) && !New::VALUE.is(PREINITIALIZED),
+                        "cannot transition an Image to the UNDEFINED or PREINITIALIZED layout"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01208: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL then image must have been created with"
+            "VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT"
+            }
+
+            const {
+                use vk::image_layout::COLOR_ATTACHMENT_OPTIMAL;
+                if Old::VALUE.is(COLOR_ATTACHMENT_OPTIMAL)
+                    || New::VALUE.is(COLOR_ATTACHMENT_OPTIMAL)
+                {
+                    assert!(
+                        <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES
+                            .contains(vk::ImageUsageFlags::COLOR_ATTACHMENT_BIT),
+                        "COLOR_ATTACHMENT_OPTIMAL layout requires an image with COLOR_ATTACHMENT_BIT usage"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01209: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_DEPTH_STENCIL_ATTACHMENT_OPTIMAL then image must have been created"
+            "with VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT"
+            }
+
+            const {
+                use vk::image_layout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL;
+                if Old::VALUE.is(DEPTH_STENCIL_ATTACHMENT_OPTIMAL)
+                    || New::VALUE.is(DEPTH_STENCIL_ATTACHMENT_OPTIMAL)
+                {
+                    assert!(
+                        <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES
+                            .contains(vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT_BIT),
+                        "DEPTH_STENCIL_ATTACHMENT_OPTIMAL layout requires an image with DEPTH_STENCIL_ATTACHMENT_BIT usage"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01210: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_DEPTH_STENCIL_READ_ONLY_OPTIMAL then image must have been created"
+            "with VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT"
+            }
+
+            const {
+                use vk::image_layout::DEPTH_STENCIL_READ_ONLY_OPTIMAL;
+                if Old::VALUE.is(DEPTH_STENCIL_READ_ONLY_OPTIMAL)
+                    || New::VALUE.is(DEPTH_STENCIL_READ_ONLY_OPTIMAL)
+                {
+                    assert!(
+                        <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES
+                            .contains(vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT_BIT),
+                        "DEPTH_STENCIL_READ_ONLY_OPTIMAL layout requires an image with DEPTH_STENCIL_ATTACHMENT_BIT usage"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01211: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL then image must have been created with"
+            "VK_IMAGE_USAGE_SAMPLED_BIT or VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT"
+            }
+
+            const {
+                use vk::image_layout::SHADER_READ_ONLY_OPTIMAL;
+                if Old::VALUE.is(SHADER_READ_ONLY_OPTIMAL)
+                    || New::VALUE.is(SHADER_READ_ONLY_OPTIMAL)
+                {
+                    assert!(
+                        <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES.any_of(
+                            vk::ImageUsageFlags::SAMPLED_BIT
+                                .or(vk::ImageUsageFlags::INPUT_ATTACHMENT_BIT)
+                        ),
+                        "SHADER_READ_ONLY_OPTIMAL layout requires an image with SAMPLED_BIT or INPUT_ATTACHMENT_BIT usage"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01212: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL then image must have been created with VK_IMAGE_USAGE_TRANSFER_SRC_BIT"
+            }
+
+            const {
+                use vk::image_layout::TRANSFER_SRC_OPTIMAL;
+                if Old::VALUE.is(TRANSFER_SRC_OPTIMAL) || New::VALUE.is(TRANSFER_SRC_OPTIMAL) {
+                    assert!(
+                        <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES
+                            .contains(vk::ImageUsageFlags::TRANSFER_SRC_BIT),
+                        "TRANSFER_SRC_OPTIMAL layout requires an image with TRANSFER_SRC_BIT usage"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01213: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL then image must have been created with VK_IMAGE_USAGE_TRANSFER_DST_BIT"
+            }
+
+            const {
+                use vk::image_layout::TRANSFER_DST_OPTIMAL;
+                if Old::VALUE.is(TRANSFER_DST_OPTIMAL) || New::VALUE.is(TRANSFER_DST_OPTIMAL) {
+                    assert!(
+                        <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES
+                            .contains(vk::ImageUsageFlags::TRANSFER_DST_BIT),
+                        "TRANSFER_DST_OPTIMAL layout requires an image with TRANSFER_DST_BIT usage"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01658: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL then image must have"
+            "been created with VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT"
+            }
+
+            // only layouts from core Vulkan 1.0 are supported (see oldLayout_parameter)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_01659: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If srcQueueFamilyIndex and dstQueueFamilyIndex define a queue family ownership transfer"
+            "or oldLayout and newLayout define an image layout transition, and oldLayout or newLayout"
+            "is VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL then image must have"
+            "been created with VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT"
+            }
+
+            // only layouts from core Vulkan 1.0 are supported (see oldLayout_parameter)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_01671: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image has a single-plane color format or is not disjoint, then the aspectMask member"
+            "of subresourceRange must be VK_IMAGE_ASPECT_COLOR_BIT"
+            }
+
+            // aspect is determined from the format below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_01672: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image has a multi-planar format and the image is disjoint, then the aspectMask member"
+            "of subresourceRange must include at least one multi-planar aspect mask bit or VK_IMAGE_ASPECT_COLOR_BIT"
+            }
+
+            // multi-planar formats are rejected below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_03319: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image has a depth/stencil format with both depth and stencil and the separateDepthStencilLayouts"
+            "feature is enabled, then the aspectMask member of subresourceRange must include either"
+            "or both VK_IMAGE_ASPECT_DEPTH_BIT and VK_IMAGE_ASPECT_STENCIL_BIT"
+            }
+
+            // both aspects are always included below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_03320: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image has a depth/stencil format with both depth and stencil and the separateDepthStencilLayouts"
+            "feature is not enabled, then the aspectMask member of subresourceRange must include"
+            "both VK_IMAGE_ASPECT_DEPTH_BIT and VK_IMAGE_ASPECT_STENCIL_BIT"
+            }
+
+            // both aspects are always included below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each pNext member of any structure (including this one) in the pNext chain must be"
+            "either NULL or a pointer to a valid instance of VkExternalMemoryAcquireUnmodifiedEXT"
+            "or VkSampleLocationsInfoEXT"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_oldLayout_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "oldLayout must be a valid VkImageLayout value"
+            }
+
+            const {
+                assert!(
+                    core_layout(Old::VALUE),
+                    "only image layouts from core Vulkan 1.0 are supported by vk-safe at this time"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_newLayout_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "newLayout must be a valid VkImageLayout value"
+            }
+
+            const {
+                assert!(
+                    core_layout(New::VALUE),
+                    "only image layouts from core Vulkan 1.0 are supported by vk-safe at this time"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_image_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "image must be a valid VkImage handle"
+            }
+
+            // ensured by Image creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageMemoryBarrier_subresourceRange_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "subresourceRange must be a valid VkImageSubresourceRange structure"
+            }
+
+            // whole image with the aspect determined below
+        }
+
+        let aspect_mask = const {
+            use vk::image_aspect_flag_bits::*;
+            let format = <I::Params as ImageParameters>::Format::VALUE;
+
+            // TODO: multi-planar formats require sampler Y′CBCR conversion, which is not supported yet
+            assert!(
+                !format.is_multi_planar_format(),
+                "multi-planar formats are not supported by vk-safe at this time"
+            );
+
+            if format.has_depth_component() && format.has_stencil_component() {
+                DEPTH_BIT.or(STENCIL_BIT)
+            } else if format.has_depth_component() {
+                DEPTH_BIT
+            } else if format.has_stencil_component() {
+                STENCIL_BIT
+            } else {
+                COLOR_BIT
+            }
+        };
+
+        Self {
+            inner: vk::ImageMemoryBarrier {
+                s_type: vk::StructureType::IMAGE_MEMORY_BARRIER,
+                p_next: std::ptr::null(),
+                src_access_mask: SA::INCLUDES,
+                dst_access_mask: DA::INCLUDES,
+                old_layout: Old::VALUE,
+                new_layout: New::VALUE,
+                src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                image: image.raw_handle(),
+                subresource_range: vk::ImageSubresourceRange {
+                    aspect_mask,
+                    base_mip_level: 0,
+                    level_count: vk::REMAINING_MIP_LEVELS,
+                    base_array_layer: 0,
+                    layer_count: vk::REMAINING_ARRAY_LAYERS,
+                },
+            },
+            recording,
+            _refs: PhantomData,
+            _types: PhantomData,
+        }
+    }
+
+    /// Release ownership of the Image from the queue family `S` to the queue family `T`
+    ///
+    /// Must be recorded into a CommandBuffer for the queue family `S`. The dst access mask
+    /// is ignored for a release operation.
+    ///
+    /// The layout of the Image must be current, so the release must be recorded in the same
+    /// CommandBuffer recording as the last layout transition of the Image. Recording the
+    /// release in any other recording panics.
+    ///
+    /// Layout transitions are not supported with queue family ownership transfers, since
+    /// the release and acquire operations must define the same transition.
+    pub fn release<S, T, SC, TC>(
+        self,
+        src_family: &QueueFamilyMarker<S, SC>,
+        dst_family: &QueueFamilyMarker<T, TC>,
+    ) -> ImageMemoryBarrier<'a, D, SA, DA, Release<S, T>, Old, New> {
+        self.transfer(src_family.family_index(), dst_family.family_index())
+    }
+
+    /// Acquire ownership of the Image from the queue family `S` by the queue family `T`
+    ///
+    /// Must be recorded into a CommandBuffer for the queue family `T`. The src access mask
+    /// is ignored for an acquire operation.
+    ///
+    /// Layout transitions are not supported with queue family ownership transfers, since
+    /// the release and acquire operations must define the same transition.
+    pub fn acquire<S, T, SC, TC>(
+        self,
+        src_family: &QueueFamilyMarker<S, SC>,
+        dst_family: &QueueFamilyMarker<T, TC>,
+    ) -> ImageMemoryBarrier<'a, D, SA, DA, Acquire<S, T>, Old, New> {
+        self.transfer(src_family.family_index(), dst_family.family_index())
+    }
+
+    fn transfer<X>(self, src: u32, dst: u32) -> ImageMemoryBarrier<'a, D, SA, DA, X, Old, New> {
+        const {
+            use vk::image_layout::*;
+            assert!(
+                Old::VALUE.is(New::VALUE),
+                "layout transitions are not supported with queue family ownership transfers"
+            );
+            // VUID_VkImageMemoryBarrier_newLayout_01198
+            assert!(
+                !New::VALUE.is(UNDEFINED) && !New::VALUE.is(PREINITIALIZED),
+                "cannot transfer ownership of an Image in the UNDEFINED or PREINITIALIZED layout"
+            );
+        }
+
+        let mut inner = self.inner;
+        inner.src_queue_family_index = src;
+        inner.dst_queue_family_index = dst;
+        ImageMemoryBarrier {
+            inner,
+            recording: self.recording,
+            _refs: PhantomData,
+            _types: PhantomData,
+        }
+    }
+}
+
+/// An [`ImageMemoryBarrier`] which transitions the layout of a [`TrackedImage`]
+///
+/// Created with [`ImageMemoryBarrier::transition`]. The TrackedImage with the `New` layout is
+/// returned by [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier) when
+/// the transition is recorded. Use [`cancel`](Self::cancel) to get back the TrackedImage
+/// with the `Old` layout without recording the transition.
+pub struct ImageLayoutTransition<'a, 'm, I: Image, M, SA, DA, Old, New> {
+    barrier: ImageMemoryBarrier<'a, I::Device, SA, DA, (), Old, New>,
+    image: TrackedImage<'a, 'm, I, M, Old>,
+}
+
+impl<'a, 'm, I: Image, M: DeviceMemory<Device = I::Device>, SA, DA, Old: ImageLayout, New>
+    ImageLayoutTransition<'a, 'm, I, M, SA, DA, Old, New>
+{
+    /// Discard the transition, and return the TrackedImage with the `Old` layout
+    pub fn cancel(self) -> TrackedImage<'a, 'm, I, M, Old> {
+        self.image
+    }
+
+    /// The raw barrier to record in `recording`
+    ///
+    /// The `Old` layout is only known in the recording which made the last transition, since
+    /// the order of execution of different recordings is not known. In any other recording,
+    /// `UNDEFINED` is used as the old layout, which is always valid but does not preserve the
+    /// contents of the Image.
+    pub(crate) fn raw_barrier(&self, recording: RecordingId) -> vk::ImageMemoryBarrier {
+        let mut barrier = *self.barrier;
+        if self.barrier.recording() != RecordingId::NONE && self.barrier.recording() != recording {
+            barrier.old_layout = vk::ImageLayout::UNDEFINED;
+        }
+        barrier
+    }
+
+    /// The caller must ensure that the transition is recorded in `recording`
+    pub(crate) unsafe fn transitioned(
+        self,
+        recording: RecordingId,
+    ) -> TrackedImage<'a, 'm, I, M, New> {
+        self.image.transition(recording)
+    }
+}
+
+impl<I: Image, M: DeviceMemory, SA, DA, Old: ImageLayout, New> fmt::Debug
+    for ImageLayoutTransition<'_, '_, I, M, SA, DA, Old, New>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageLayoutTransition")
+            .field("barrier", &self.barrier)
+            .field("image", &self.image)
+            .finish()
+    }
+}
+
+/// Image layouts from core Vulkan 1.0
+const fn core_layout(layout: vk::ImageLayout) -> bool {
+    use vk::image_layout::*;
+    layout.is(UNDEFINED)
+        || layout.is(GENERAL)
+        || layout.is(COLOR_ATTACHMENT_OPTIMAL)
+        || layout.is(DEPTH_STENCIL_ATTACHMENT_OPTIMAL)
+        || layout.is(DEPTH_STENCIL_READ_ONLY_OPTIMAL)
+        || layout.is(SHADER_READ_ONLY_OPTIMAL)
+        || layout.is(TRANSFER_SRC_OPTIMAL)
+        || layout.is(TRANSFER_DST_OPTIMAL)
+        || layout.is(PREINITIALIZED)
+}
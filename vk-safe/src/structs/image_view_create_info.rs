@@ -0,0 +1,629 @@
+use super::ImageParameters::ImageParameters;
+
+use crate::error::VulkanError;
+use crate::features::Features;
+use crate::handles::device::Device;
+use crate::handles::image::{BoundImage, Image};
+use crate::handles::{device_memory::DeviceMemory, Handle};
+use crate::type_conversions::ConvertWrapper;
+
+use std::ops::Range;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::ImageViewType;
+use vk::flag_traits::ImageAspectFlags;
+
+struct_wrapper!(
+/// Info for creating an ImageView
+///
+/// used with [`create_image_view`](crate::vk::create_image_view)
+///
+/// The view always has the same format as the Image, with the identity component mapping.
+/// The view type and aspect mask are checked at compile time against the
+/// [`ImageParameters`](crate::vk::ImageParameters) of the Image. For example, the
+/// `DEPTH_BIT` aspect can only be used with a format which has a depth component.
+///
+/// The Image must be bound to memory.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkImageViewCreateInfo.html>
+ImageViewCreateInfo<'a, Image, ViewType, Aspect,>
+impl Deref, Debug
+);
+
+impl<'a, I: Image, V: ImageViewType, A: ImageAspectFlags> ImageViewCreateInfo<'a, I, V, A> {
+    pub fn new<M: DeviceMemory<Device = I::Device>>(
+        image: &'a BoundImage<'_, I, M>,
+        _view_type: V,
+        _aspect: A,
+        mip_levels: Range<u32>,
+        array_layers: Range<u32>,
+    ) -> Result<Self, VulkanError> {
+        check_vuids::check_vuids!(ImageViewCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_01003: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was not created with VK_IMAGE_CREATE_CUBE_COMPATIBLE_BIT then viewType must"
+            "not be VK_IMAGE_VIEW_TYPE_CUBE or VK_IMAGE_VIEW_TYPE_CUBE_ARRAY"
+            }
+
+            const {
+                use vk::image_view_type::*;
+                let view_type = V::VALUE;
+                let create_flags = <I::Params as ImageParameters>::ImageCreateFlags::INCLUDES;
+                if view_type.is(CUBE) || view_type.is(CUBE_ARRAY) {
+                    assert!(
+                        create_flags.contains(vk::ImageCreateFlags::CUBE_COMPATIBLE_BIT),
+                        "CUBE view types require an image created with CUBE_COMPATIBLE_BIT"
+                    );
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_viewType_01004: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the imageCubeArray feature is not enabled, viewType must not be VK_IMAGE_VIEW_TYPE_CUBE_ARRAY"
+            }
+
+            // allowed if the feature is enabled on the Device of the Image
+            const {
+                assert!(
+                    <I::Device as Device>::Features::ENABLED.image_cube_array == vk::TRUE
+                        || !V::VALUE.is(vk::image_view_type::CUBE_ARRAY),
+                    "CUBE_ARRAY view type requires the imageCubeArray feature, which is not enabled"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_01005: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was created with VK_IMAGE_TYPE_3D but without VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT"
+            "set then viewType must not be VK_IMAGE_VIEW_TYPE_2D_ARRAY"
+            }
+
+            // checked with the view type compatibility table below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_04970: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was created with VK_IMAGE_TYPE_3D and viewType is VK_IMAGE_VIEW_TYPE_2D"
+            "or VK_IMAGE_VIEW_TYPE_2D_ARRAY then subresourceRange.levelCount must be 1"
+            }
+
+            // 2D views of 3D images are not supported (see view type compatibility table below)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_04971: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was created with VK_IMAGE_TYPE_3D and viewType is VK_IMAGE_VIEW_TYPE_2D"
+            "or VK_IMAGE_VIEW_TYPE_2D_ARRAY then VkImageCreateInfo::flags must not contain any"
+            "of VK_IMAGE_CREATE_SPARSE_BINDING_BIT, VK_IMAGE_CREATE_SPARSE_RESIDENCY_BIT, and"
+            "VK_IMAGE_CREATE_SPARSE_ALIASED_BIT"
+            }
+
+            // 2D views of 3D images are not supported (see view type compatibility table below)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_04972: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was created with a samples value not equal to VK_SAMPLE_COUNT_1_BIT then"
+            "viewType must be either VK_IMAGE_VIEW_TYPE_2D or VK_IMAGE_VIEW_TYPE_2D_ARRAY"
+            }
+
+            let view_type = V::VALUE;
+            if image.image().samples() != vk::SampleCountFlags::TYPE_1_BIT
+                && !view_type.is(vk::image_view_type::TYPE_2D)
+                && !view_type.is(vk::image_view_type::TYPE_2D_ARRAY)
+            {
+                Err(VulkanError::IncompatibleImageViewType)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_04441: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "image must have been created with a usage value containing at least one of the usages"
+            "defined in the valid image usage list for image views"
+            }
+
+            const {
+                use vk::image_usage_flag_bits::*;
+                let view_usages = SAMPLED_BIT
+                    .or(STORAGE_BIT)
+                    .or(COLOR_ATTACHMENT_BIT)
+                    .or(DEPTH_STENCIL_ATTACHMENT_BIT)
+                    .or(INPUT_ATTACHMENT_BIT)
+                    .or(TRANSIENT_ATTACHMENT_BIT);
+                assert!(
+                    <I::Params as ImageParameters>::ImageUsageFlags::INCLUDES.any_of(view_usages),
+                    "image usage does not allow creating image views"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_None_02273: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The format features of the resultant image view must contain at least one bit"
+            }
+
+            // The image was created with ImageFormatProperties, which can only be obtained if the
+            // format features support the usage of the image. Thus, the format features of the view
+            // (which has the same format and usage as the image) include the features for the usage.
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_usage_02274: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage contains VK_IMAGE_USAGE_SAMPLED_BIT, then the format features of the resultant"
+            "image view must contain VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT"
+            }
+
+            // see VUID_VkImageViewCreateInfo_None_02273
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_usage_02275: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage contains VK_IMAGE_USAGE_STORAGE_BIT, then the image view&#8217;s format features"
+            "must contain VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT"
+            }
+
+            // see VUID_VkImageViewCreateInfo_None_02273
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_usage_02276: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage contains VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT, then the image view&#8217;s"
+            "format features must contain VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT"
+            }
+
+            // see VUID_VkImageViewCreateInfo_None_02273
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_usage_02277: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage contains VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT, then the image view&#8217;s"
+            "format features must contain VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT"
+            }
+
+            // see VUID_VkImageViewCreateInfo_None_02273
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_usage_08932: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If usage contains VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT, then the image view&#8217;s"
+            "format features must contain at least one of VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT"
+            "or VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT"
+            }
+
+            // see VUID_VkImageViewCreateInfo_None_02273
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_subresourceRange_01478: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "subresourceRange.baseMipLevel must be less than the mipLevels specified in VkImageCreateInfo"
+            "when image was created"
+            }
+
+            if mip_levels.start >= image.image().mip_levels() {
+                Err(VulkanError::InvalidMipLevels)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_subresourceRange_01718: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If subresourceRange.levelCount is not VK_REMAINING_MIP_LEVELS, subresourceRange.baseMipLevel"
+            "+ subresourceRange.levelCount must be less than or equal to the mipLevels specified"
+            "in VkImageCreateInfo when image was created"
+            }
+
+            if mip_levels.is_empty() || mip_levels.end > image.image().mip_levels() {
+                Err(VulkanError::InvalidMipLevels)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_01482: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is not a 3D image created with VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT set,"
+            "or viewType is not VK_IMAGE_VIEW_TYPE_2D or VK_IMAGE_VIEW_TYPE_2D_ARRAY, subresourceRange.baseArrayLayer"
+            "must be less than the arrayLayers specified in VkImageCreateInfo when image was created"
+            }
+
+            if array_layers.start >= image.image().array_layers() {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_subresourceRange_01483: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If subresourceRange.layerCount is not VK_REMAINING_ARRAY_LAYERS, image is not a 3D"
+            "image created with VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT set, or viewType is not"
+            "VK_IMAGE_VIEW_TYPE_2D or VK_IMAGE_VIEW_TYPE_2D_ARRAY, subresourceRange.layerCount"
+            "must be non-zero and subresourceRange.baseArrayLayer + subresourceRange.layerCount"
+            "must be less than or equal to the arrayLayers specified in VkImageCreateInfo when"
+            "image was created"
+            }
+
+            if array_layers.is_empty() || array_layers.end > image.image().array_layers() {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_01762: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image was not created with the VK_IMAGE_CREATE_MUTABLE_FORMAT_BIT flag, or if the"
+            "format of the image is a multi-planar format and if subresourceRange.aspectMask is"
+            "VK_IMAGE_ASPECT_COLOR_BIT, format must be identical to the format used to create image"
+            }
+
+            // the view always uses the format of the image
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_01020: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If image is non-sparse then it must be bound completely and contiguously to a single"
+            "VkDeviceMemory object"
+            }
+
+            // using BoundImage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_subResourceRange_01021: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "viewType must be compatible with the type of image as shown in the view type compatibility"
+            "table"
+            }
+
+            const {
+                use vk::image_type::*;
+                use vk::image_view_type::*;
+                let image_type = <I::Params as ImageParameters>::ImageType::VALUE;
+                let view_type = V::VALUE;
+                let compatible = if image_type.is(TYPE_1D) {
+                    view_type.is(TYPE_1D) || view_type.is(TYPE_1D_ARRAY)
+                } else if image_type.is(TYPE_2D) {
+                    view_type.is(TYPE_2D)
+                        || view_type.is(TYPE_2D_ARRAY)
+                        || view_type.is(CUBE)
+                        || view_type.is(CUBE_ARRAY)
+                } else if image_type.is(TYPE_3D) {
+                    // TODO: 2D views of 3D images (VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT) are not supported yet
+                    view_type.is(TYPE_3D)
+                } else {
+                    false
+                };
+                assert!(
+                    compatible,
+                    "image view type is not compatible with the image type"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_viewType_02960: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If viewType is VK_IMAGE_VIEW_TYPE_CUBE and subresourceRange.layerCount is not VK_REMAINING_ARRAY_LAYERS,"
+            "subresourceRange.layerCount must be 6"
+            }
+
+            if V::VALUE.is(vk::image_view_type::CUBE) && array_layers.len() != 6 {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_viewType_02961: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If viewType is VK_IMAGE_VIEW_TYPE_CUBE_ARRAY and subresourceRange.layerCount is not"
+            "VK_REMAINING_ARRAY_LAYERS, subresourceRange.layerCount must be a multiple of 6"
+            }
+
+            if V::VALUE.is(vk::image_view_type::CUBE_ARRAY) && array_layers.len() % 6 != 0 {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_viewType_02962: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If viewType is VK_IMAGE_VIEW_TYPE_CUBE and subresourceRange.layerCount is VK_REMAINING_ARRAY_LAYERS,"
+            "the remaining number of layers must be 6"
+            }
+
+            // VK_REMAINING_ARRAY_LAYERS is not used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_viewType_02963: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If viewType is VK_IMAGE_VIEW_TYPE_CUBE_ARRAY and subresourceRange.layerCount is VK_REMAINING_ARRAY_LAYERS,"
+            "the remaining number of layers must be a multiple of 6"
+            }
+
+            // VK_REMAINING_ARRAY_LAYERS is not used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_imageViewType_04973: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If viewType is VK_IMAGE_VIEW_TYPE_1D, VK_IMAGE_VIEW_TYPE_2D, or VK_IMAGE_VIEW_TYPE_3D;"
+            "and subresourceRange.layerCount is not VK_REMAINING_ARRAY_LAYERS, then subresourceRange.layerCount"
+            "must be 1"
+            }
+
+            let view_type = V::VALUE;
+            if (view_type.is(vk::image_view_type::TYPE_1D)
+                || view_type.is(vk::image_view_type::TYPE_2D)
+                || view_type.is(vk::image_view_type::TYPE_3D))
+                && array_layers.len() != 1
+            {
+                Err(VulkanError::InvalidArrayLayers)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_imageViewType_04974: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If viewType is VK_IMAGE_VIEW_TYPE_1D, VK_IMAGE_VIEW_TYPE_2D, or VK_IMAGE_VIEW_TYPE_3D;"
+            "and subresourceRange.layerCount is VK_REMAINING_ARRAY_LAYERS, then the remaining number"
+            "of layers must be 1"
+            }
+
+            // VK_REMAINING_ARRAY_LAYERS is not used
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkImageViewCreateFlagBits values"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_image_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "image must be a valid VkImage handle"
+            }
+
+            // ensured by Image creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_viewType_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "viewType must be a valid VkImageViewType value"
+            }
+
+            // ensured by ImageViewType type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_format_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "format must be a valid VkFormat value"
+            }
+
+            // ensured by ImageParameters
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_components_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "components must be a valid VkComponentMapping structure"
+            }
+
+            // identity mapping set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageViewCreateInfo_subresourceRange_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "subresourceRange must be a valid VkImageSubresourceRange structure"
+            }
+
+            // see the VkImageSubresourceRange VUIDs below
+        }
+
+        check_vuids::check_vuids!(ImageSubresourceRange);
+
+        #[allow(unused_labels)]
+        'VUID_VkImageSubresourceRange_levelCount_01720: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If levelCount is not VK_REMAINING_MIP_LEVELS, it must be greater than 0"
+            }
+
+            // checked by VUID_VkImageViewCreateInfo_subresourceRange_01718
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageSubresourceRange_layerCount_01721: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If layerCount is not VK_REMAINING_ARRAY_LAYERS, it must be greater than 0"
+            }
+
+            // checked by VUID_VkImageViewCreateInfo_subresourceRange_01483
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageSubresourceRange_aspectMask_01670: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If aspectMask includes VK_IMAGE_ASPECT_COLOR_BIT, then it must not include any of"
+            "VK_IMAGE_ASPECT_PLANE_0_BIT, VK_IMAGE_ASPECT_PLANE_1_BIT, or VK_IMAGE_ASPECT_PLANE_2_BIT"
+            }
+
+            // PLANE aspects are only used with multi-planar formats, which are not supported
+            const {
+                use vk::image_aspect_flag_bits::*;
+                assert!(
+                    A::INCLUDES.subset_of(COLOR_BIT.or(DEPTH_BIT).or(STENCIL_BIT)),
+                    "only COLOR_BIT, DEPTH_BIT and STENCIL_BIT aspects are supported for image views"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageSubresourceRange_aspectMask_02278: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "aspectMask must not include VK_IMAGE_ASPECT_MEMORY_PLANE_i_BIT_EXT for any index i"
+            }
+
+            // see VUID_VkImageSubresourceRange_aspectMask_01670
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageSubresourceRange_aspectMask_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "aspectMask must be a valid combination of VkImageAspectFlagBits values"
+            }
+
+            // ensured by ImageAspectFlags type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkImageSubresourceRange_aspectMask_requiredbitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "aspectMask must not be 0"
+            }
+
+            const {
+                assert!(!A::INCLUDES.is_empty(), "aspectMask must not be empty");
+            }
+        }
+
+        // The aspects must also be present in the format of the Image. This has no VUID in
+        // 1.3.268 (it is VUID-VkImageViewCreateInfo-subresourceRange-09594 in later versions)
+        const {
+            use vk::image_aspect_flag_bits::*;
+            let format = <I::Params as ImageParameters>::Format::VALUE;
+            let aspect = A::INCLUDES;
+
+            // TODO: multi-planar formats require sampler Y′CBCR conversion, which is not supported yet
+            assert!(
+                !format.is_multi_planar_format(),
+                "multi-planar formats are not supported by vk-safe at this time"
+            );
+
+            if aspect.contains(COLOR_BIT) {
+                assert!(
+                    !format.has_depth_component() && !format.has_stencil_component(),
+                    "COLOR_BIT aspect requires a color format"
+                );
+            }
+
+            if aspect.contains(DEPTH_BIT) {
+                assert!(
+                    format.has_depth_component(),
+                    "DEPTH_BIT aspect requires a format with a depth component"
+                );
+            }
+
+            if aspect.contains(STENCIL_BIT) {
+                assert!(
+                    format.has_stencil_component(),
+                    "STENCIL_BIT aspect requires a format with a stencil component"
+                );
+            }
+        }
+
+        unsafe {
+            Ok(Self::from_c(vk::ImageViewCreateInfo {
+                s_type: vk::StructureType::IMAGE_VIEW_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::ImageViewCreateFlags::empty(),
+                image: image.raw_handle(),
+                view_type: V::VALUE,
+                format: <I::Params as ImageParameters>::format(),
+                components: vk::ComponentMapping {
+                    r: vk::ComponentSwizzle::IDENTITY,
+                    g: vk::ComponentSwizzle::IDENTITY,
+                    b: vk::ComponentSwizzle::IDENTITY,
+                    a: vk::ComponentSwizzle::IDENTITY,
+                },
+                subresource_range: vk::ImageSubresourceRange {
+                    aspect_mask: A::INCLUDES,
+                    base_mip_level: mip_levels.start,
+                    level_count: mip_levels.len() as u32,
+                    base_array_layer: array_layers.start,
+                    layer_count: array_layers.len() as u32,
+                },
+            }))
+        }
+    }
+}
@@ -1,30 +1,43 @@
+use std::any::Any;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
-use super::ApplicationInfo;
+use super::extension_chain::{self, includes, is_any, ExtensionList, SafeExtends};
+#[cfg(VK_EXT_debug_utils)]
+use super::DebugUtilsMessengerCreateInfoEXT;
+use super::{ApplicationInfo, LayerProperties};
 
+use crate::error::VulkanError;
+use crate::layers::{check_available, Layers};
 use crate::type_conversions::ConvertWrapper;
 
 use vk_safe_sys as vk;
 
 use vk::context::Extensions;
+use vk::{StructExtends, Stype};
 
 /// Info for creating an instance
 ///
 /// Those familiar with Vulkan will know that this is where you normally indicate what Extensions and Layers you want to use. In vk-safe
-/// Extensions are indicated with [vk::instance_context] and passed into [ApplicationInfo]. Layers are not currently supported, but are planned.
+/// Extensions are indicated with [vk::instance_context] and passed into [ApplicationInfo]. Layers are enabled with
+/// [`enable_layers`](InstanceCreateInfo::enable_layers).
 ///
-/// Currently, the only thing you need to create this structure is an instance of [ApplicationInfo].
-/// In future, support will be added for using p_next for additional functionality.
+/// Structures which extend VkInstanceCreateInfo (e.g. [`ValidationFeaturesEXT`](crate::vk::ValidationFeaturesEXT)) can be added
+/// with [`push_next`](InstanceCreateInfo::push_next), except for debug messengers, which are added with
+/// [`push_debug_utils_messenger`](InstanceCreateInfo::push_debug_utils_messenger).
 ///
 /// See also
 /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkInstanceCreateInfo.html>
-pub struct InstanceCreateInfo<'a, C> {
+pub struct InstanceCreateInfo<'a, C, N> {
     pub(crate) inner: vk::InstanceCreateInfo,
+    // kept alive by the created Instance until it is destroyed (e.g. debug messenger callbacks)
+    pub(crate) keep_alive: Vec<Arc<dyn Any + Send + Sync>>,
     _config: PhantomData<C>,
+    _next: PhantomData<N>,
     _refs: PhantomData<&'a ()>,
 }
 
-impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
+impl<'a, C: Extensions> InstanceCreateInfo<'a, C, ()> {
     /// Create InstanceCreateInfo from [ApplicationInfo]
     pub fn new(app_info: &'a ApplicationInfo<'a, C>) -> Self {
         check_vuids::check_vuids!(InstanceCreateInfo);
@@ -38,7 +51,7 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "VK_EXT_debug_report"
             }
 
-            // TODO: p_next not currently supported
+            // checked in push_next
         }
 
         #[allow(unused_labels)]
@@ -50,7 +63,7 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "VK_EXT_debug_utils"
             }
 
-            // TODO: p_next not currently supported
+            // checked in push_debug_utils_messenger
         }
 
         #[allow(unused_labels)]
@@ -61,7 +74,7 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "member must be either VK_EXPORT_METAL_OBJECT_TYPE_METAL_DEVICE_BIT_EXT or VK_EXPORT_METAL_OBJECT_TYPE_METAL_COMMAND_QUEUE_BIT_EXT"
             }
 
-            // TODO: p_next not currently supported
+            // TODO: VkExportMetalObjectCreateInfoEXT in the pNext chain is not checked
         }
 
         #[allow(unused_labels)]
@@ -84,7 +97,7 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "VK_LUNARG_direct_driver_loading"
             }
 
-            // TODO: p_next not currently supported
+            // checked in push_next
         }
 
         #[allow(unused_labels)]
@@ -107,7 +120,8 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "VkValidationFeaturesEXT, or VkValidationFlagsEXT"
             }
 
-            // TODO: p_next not currently supported
+            // push_next only takes structures which implement StructExtends<VkInstanceCreateInfo>,
+            // and push_debug_utils_messenger adds VkDebugUtilsMessengerCreateInfoEXT
         }
 
         #[allow(unused_labels)]
@@ -118,7 +132,7 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "of structures of type VkDebugUtilsMessengerCreateInfoEXT or VkExportMetalObjectCreateInfoEXT"
             }
 
-            // TODO: p_next not currently supported
+            // checked at compile time in push_next
         }
 
         #[allow(unused_labels)]
@@ -150,7 +164,7 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
             "of enabledLayerCount null-terminated UTF-8 strings"
             }
 
-            // TODO: layers not currently supported
+            // set by enable_layers from the null terminated names of Layers
         }
 
         #[allow(unused_labels)]
@@ -181,8 +195,276 @@ impl<'a, C: Extensions> InstanceCreateInfo<'a, C> {
                     .expect("list of extensions len bigger than u32::MAX"),
                 pp_enabled_extension_names: extensions.as_ptr().cast(),
             },
+            keep_alive: Vec::new(),
             _config: PhantomData,
+            _next: PhantomData,
             _refs: PhantomData,
         }
     }
 }
+
+impl<'a, C: Extensions, N: ExtensionList> InstanceCreateInfo<'a, C, N> {
+    /// Enable instance layers
+    ///
+    /// `available` must be the layers from [`enumerate_instance_layer_properties`](crate::vk::Entry::enumerate_instance_layer_properties).
+    /// Returns [`VulkanError::LayerNotPresent`] if any layer in `layers` is not available. Enabling layers
+    /// again replaces the previously enabled layers.
+    ///
+    /// See the [`layers`](mod@crate::layers) module for more details.
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// # use vk::traits::*;
+    /// # vk::instance_context!(InstanceContext: VERSION_1_0);
+    /// # fn tst(entry: vk::Entry) -> Result<(), vk::VulkanError> {
+    /// let available = entry.enumerate_instance_layer_properties().auto_get_enumerate()?;
+    ///
+    /// let app_info = vk::ApplicationInfo::new(InstanceContext);
+    /// let instance_info = vk::InstanceCreateInfo::new(&app_info)
+    ///     .enable_layers(&available, vk::layers!("VK_LAYER_KHRONOS_validation"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_layers<L: Layers>(
+        self,
+        available: &[LayerProperties<()>],
+        layers: L,
+    ) -> Result<Self, VulkanError> {
+        let _ = layers;
+        check_available::<L>(available)?;
+        let mut inner = self.inner;
+        inner.enabled_layer_count = L::RAW_NAMES.len().try_into()?;
+        inner.pp_enabled_layer_names = if L::RAW_NAMES.is_empty() {
+            std::ptr::null()
+        } else {
+            L::RAW_NAMES.as_ptr()
+        };
+        Ok(Self { inner, ..self })
+    }
+
+    /// Add an extension structure to the pNext chain
+    ///
+    /// `next` can be any structure which extends VkInstanceCreateInfo and is
+    /// [`SafeExtends`](crate::vk::SafeExtends) (i.e. the wrappers provided by vk-safe), and is
+    /// borrowed for as long as the InstanceCreateInfo is used. Adding a structure which is
+    /// already in the chain fails to compile. Other structures can be added with
+    /// [`push_next_unchecked`](InstanceCreateInfo::push_next_unchecked).
+    ///
+    /// Adding a structure which is provided by an extension (e.g. VkValidationFeaturesEXT by
+    /// `EXT_validation_features`) which is not in the instance context fails to compile.
+    /// Debug messengers are added with
+    /// [`push_debug_utils_messenger`](InstanceCreateInfo::push_debug_utils_messenger).
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// # vk::instance_context!(InstanceContext: VERSION_1_0 + EXT_validation_features);
+    /// let enabled = [vk::ValidationFeatureEnable::BestPractices];
+    /// let mut validation_features = vk::ValidationFeaturesEXT::new(&enabled, &[]).unwrap();
+    ///
+    /// let app_info = vk::ApplicationInfo::new(InstanceContext);
+    /// let instance_info =
+    ///     vk::InstanceCreateInfo::new(&app_info).push_next(&mut validation_features);
+    /// ```
+    pub fn push_next<E: SafeExtends<vk::InstanceCreateInfo>>(
+        self,
+        next: &'a mut E,
+    ) -> InstanceCreateInfo<'a, C, (E, N)> {
+        not_debug_utils_messenger::<E>();
+        self.push::<E, E>(next)
+    }
+
+    /// Add any extension structure to the pNext chain
+    ///
+    /// The same as [`push_next`](InstanceCreateInfo::push_next), but for any structure which
+    /// extends VkInstanceCreateInfo, including raw structures with pointers and handles.
+    ///
+    /// # Safety
+    /// Any pointers and handles in `next` (other than `p_next`) must be valid for
+    /// vkCreateInstance, and pointers must stay valid for as long as the InstanceCreateInfo is
+    /// used.
+    pub unsafe fn push_next_unchecked<E: StructExtends<vk::InstanceCreateInfo> + Stype>(
+        self,
+        next: &'a mut E,
+    ) -> InstanceCreateInfo<'a, C, (E, N)> {
+        not_debug_utils_messenger::<E>();
+        self.push::<E, E>(next)
+    }
+
+    /// Add a debug messenger to the pNext chain
+    ///
+    /// The callback reports messages from [`create_instance`](crate::vk::Entry::create_instance),
+    /// and from destroying the Instance, and is kept alive by the Instance. More than one debug
+    /// messenger can be added. Adding a debug messenger without `EXT_debug_utils` in the instance
+    /// context fails to compile.
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// # vk::instance_context!(InstanceContext: VERSION_1_0 + EXT_debug_utils);
+    /// use vk::DebugUtilsMessageSeverityFlagsEXT::*;
+    /// use vk::DebugUtilsMessageTypeFlagsEXT::*;
+    ///
+    /// let mut messenger_info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+    ///     DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+    ///     DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+    ///     |message: &vk::DebugUtilsMessage| eprintln!("{}", message.message),
+    /// );
+    ///
+    /// let app_info = vk::ApplicationInfo::new(InstanceContext);
+    /// let instance_info =
+    ///     vk::InstanceCreateInfo::new(&app_info).push_debug_utils_messenger(&mut messenger_info);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use vk_safe::vk;
+    /// # vk::instance_context!(InstanceContext: VERSION_1_0);
+    /// # use vk::DebugUtilsMessageSeverityFlagsEXT::*;
+    /// # use vk::DebugUtilsMessageTypeFlagsEXT::*;
+    /// # let mut messenger_info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+    /// #     DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+    /// #     DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+    /// #     |message: &vk::DebugUtilsMessage| eprintln!("{}", message.message),
+    /// # );
+    /// let app_info = vk::ApplicationInfo::new(InstanceContext);
+    /// // EXT_debug_utils is not in InstanceContext
+    /// let instance_info =
+    ///     vk::InstanceCreateInfo::new(&app_info).push_debug_utils_messenger(&mut messenger_info);
+    /// ```
+    #[cfg(VK_EXT_debug_utils)]
+    pub fn push_debug_utils_messenger(
+        self,
+        next: &'a mut DebugUtilsMessengerCreateInfoEXT,
+    ) -> InstanceCreateInfo<'a, C, (DebugUtilsMessengerCreateInfoEXT, N)> {
+        let callback = next.callback.clone();
+        let mut info = self.push::<DebugUtilsMessengerCreateInfoEXT, _>(&mut next.inner);
+        info.keep_alive.push(Arc::new(callback));
+        info
+    }
+
+    /// Add `next` to the pNext chain, as `E` in the list of structures
+    fn push<E: Stype, R: StructExtends<vk::InstanceCreateInfo> + Stype>(
+        self,
+        next: &'a mut R,
+    ) -> InstanceCreateInfo<'a, C, (E, N)> {
+        let _ = <N as CheckNext<E>>::VALID;
+        const {
+            let mut i = 0;
+            while i < REQUIRED_EXTENSIONS.len() {
+                let (s_type, extension, error) = REQUIRED_EXTENSIONS[i];
+                assert!(
+                    !E::S_TYPE.is(s_type) || has_extension::<C>(extension),
+                    "{}",
+                    error
+                );
+                i += 1;
+            }
+        }
+        let mut inner = self.inner;
+        inner.p_next = extension_chain::push_next::<vk::InstanceCreateInfo, R>(inner.p_next, next)
+            .cast_const();
+        InstanceCreateInfo {
+            inner,
+            keep_alive: self.keep_alive,
+            _config: PhantomData,
+            _next: PhantomData,
+            _refs: PhantomData,
+        }
+    }
+}
+
+/// Debug messengers must be added with push_debug_utils_messenger, which keeps the callback alive
+fn not_debug_utils_messenger<E: Stype>() {
+    const {
+        assert!(
+            !E::S_TYPE.is(vk::DebugUtilsMessengerCreateInfoEXT::S_TYPE),
+            "add debug messengers with push_debug_utils_messenger"
+        )
+    }
+}
+
+/// Check if the extension `name` (e.g. "EXT_debug_utils") is in the context `C`
+const fn has_extension<C: Extensions>(name: &str) -> bool {
+    let mut i = 0;
+    while i < C::NAMES.len() {
+        if str_eq(C::NAMES[i], name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// `a == b` for const
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Structures which require an extension to be in the pNext chain, and the compile error if the
+/// extension is not in the instance context
+/// (VUID_VkInstanceCreateInfo_pNext_04925, VUID_VkInstanceCreateInfo_pNext_04926, VUID_VkInstanceCreateInfo_pNext)
+///
+/// VkLayerSettingsCreateInfoEXT is newer than the VUIDs checked by vk-safe, and requires
+/// `VK_EXT_layer_settings` as well.
+const REQUIRED_EXTENSIONS: &[(vk::StructureType, &str, &str)] = &[
+    (
+        vk::DebugReportCallbackCreateInfoEXT::S_TYPE,
+        "EXT_debug_report",
+        "EXT_debug_report must be in the instance context to add VkDebugReportCallbackCreateInfoEXT",
+    ),
+    (
+        vk::DebugUtilsMessengerCreateInfoEXT::S_TYPE,
+        "EXT_debug_utils",
+        "EXT_debug_utils must be in the instance context to add VkDebugUtilsMessengerCreateInfoEXT",
+    ),
+    (
+        vk::DirectDriverLoadingListLUNARG::S_TYPE,
+        "LUNARG_direct_driver_loading",
+        "LUNARG_direct_driver_loading must be in the instance context to add VkDirectDriverLoadingListLUNARG",
+    ),
+    (
+        vk::ValidationFeaturesEXT::S_TYPE,
+        "EXT_validation_features",
+        "EXT_validation_features must be in the instance context to add VkValidationFeaturesEXT",
+    ),
+    (
+        vk::ValidationFlagsEXT::S_TYPE,
+        "EXT_validation_flags",
+        "EXT_validation_flags must be in the instance context to add VkValidationFlagsEXT",
+    ),
+    #[cfg(VK_EXT_layer_settings)]
+    (
+        vk::LayerSettingsCreateInfoEXT::S_TYPE,
+        "EXT_layer_settings",
+        "EXT_layer_settings must be in the instance context to add VkLayerSettingsCreateInfoEXT",
+    ),
+];
+
+/// Structures which can be in the pNext chain more than once
+/// (VUID_VkInstanceCreateInfo_sType_unique)
+///
+/// VkExportMetalObjectCreateInfoEXT is also allowed more than once, but is only available on
+/// Apple platforms, so it is treated as unique.
+const MULTIPLE_ALLOWED: [vk::StructureType; 1] = [vk::DebugUtilsMessengerCreateInfoEXT::S_TYPE];
+
+/// Check the VUIDs which depend on the structures in the pNext chain `N`, when adding `E`
+trait CheckNext<E> {
+    const VALID: ();
+}
+
+impl<N: ExtensionList, E: Stype> CheckNext<E> for N {
+    const VALID: () = {
+        if includes::<N>(E::S_TYPE) && !is_any(E::S_TYPE, &MULTIPLE_ALLOWED) {
+            panic!("violated VUID_VkInstanceCreateInfo_sType_unique")
+        }
+    };
+}
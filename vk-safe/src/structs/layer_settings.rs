@@ -0,0 +1,161 @@
+use super::extension_chain::private::SafeExtends;
+
+use crate::error::VulkanError;
+use crate::type_conversions::ConvertWrapper;
+use crate::VkStr;
+
+use vk_safe_sys as vk;
+
+use vk::{StructExtends, Stype};
+
+// VK_EXT_layer_settings is newer than the version of Vulkan which vk-safe is currently checked
+// against (1.3.268), so there are no VUIDs to check yet.
+
+/// Type of the values of a layer setting
+///
+/// Implemented for `i32`, `i64`, `u32`, `u64`, `f32`, and `f64`. Use [`LayerSettingEXT::bool`]
+/// for boolean settings.
+pub unsafe trait LayerSettingValue: Copy {
+    /// VkLayerSettingTypeEXT of the value
+    #[doc(hidden)]
+    const TYPE: vk::LayerSettingTypeEXT;
+}
+
+unsafe impl LayerSettingValue for i32 {
+    const TYPE: vk::LayerSettingTypeEXT = vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_INT32_EXT;
+}
+
+unsafe impl LayerSettingValue for i64 {
+    const TYPE: vk::LayerSettingTypeEXT = vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_INT64_EXT;
+}
+
+unsafe impl LayerSettingValue for u32 {
+    const TYPE: vk::LayerSettingTypeEXT = vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_UINT32_EXT;
+}
+
+unsafe impl LayerSettingValue for u64 {
+    const TYPE: vk::LayerSettingTypeEXT = vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_UINT64_EXT;
+}
+
+unsafe impl LayerSettingValue for f32 {
+    const TYPE: vk::LayerSettingTypeEXT = vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_FLOAT32_EXT;
+}
+
+unsafe impl LayerSettingValue for f64 {
+    const TYPE: vk::LayerSettingTypeEXT = vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_FLOAT64_EXT;
+}
+
+struct_wrapper!(
+/// A setting of a layer
+///
+/// Settings with string values are not currently supported.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkLayerSettingEXT.html>
+LayerSettingEXT<'a,>
+impl Deref, Debug, Clone, Copy
+);
+
+impl<'a> LayerSettingEXT<'a> {
+    /// Set the setting `setting_name` of the layer `layer_name` to `values`
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// let setting = vk::LayerSettingEXT::new(
+    ///     vk_safe::vk_str!("VK_LAYER_KHRONOS_validation"),
+    ///     vk_safe::vk_str!("duplicate_message_limit"),
+    ///     &[3u32],
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn new<V: LayerSettingValue>(
+        layer_name: VkStr<'a>,
+        setting_name: VkStr<'a>,
+        values: &'a [V],
+    ) -> Result<Self, VulkanError> {
+        Ok(Self::from_c(vk::LayerSettingEXT {
+            p_layer_name: layer_name.as_ptr(),
+            p_setting_name: setting_name.as_ptr(),
+            ty: V::TYPE,
+            value_count: values.len().try_into()?,
+            p_values: values.as_ptr().cast(),
+        }))
+    }
+
+    /// Set the boolean setting `setting_name` of the layer `layer_name` to `value`
+    ///
+    /// ```
+    /// # use vk_safe::vk;
+    /// let setting = vk::LayerSettingEXT::bool(
+    ///     vk_safe::vk_str!("VK_LAYER_KHRONOS_validation"),
+    ///     vk_safe::vk_str!("validate_best_practices"),
+    ///     true,
+    /// );
+    /// ```
+    pub fn bool(layer_name: VkStr<'a>, setting_name: VkStr<'a>, value: bool) -> Self {
+        let value: &'static vk::Bool32 = if value { &vk::TRUE } else { &vk::FALSE };
+        Self::from_c(vk::LayerSettingEXT {
+            p_layer_name: layer_name.as_ptr(),
+            p_setting_name: setting_name.as_ptr(),
+            ty: vk::LayerSettingTypeEXT::LAYER_SETTING_TYPE_BOOL32_EXT,
+            value_count: 1,
+            p_values: (value as *const vk::Bool32).cast(),
+        })
+    }
+}
+
+struct_wrapper!(
+/// Settings for layers
+///
+/// To be added to the pNext chain of [`InstanceCreateInfo`](crate::vk::InstanceCreateInfo) with
+/// [`push_next`](crate::vk::InstanceCreateInfo::push_next). Requires `EXT_layer_settings` in
+/// the instance context, which is provided by the layers (e.g. the validation layer) that read
+/// the settings.
+///
+/// ```
+/// # use vk_safe::vk;
+/// # vk::instance_context!(InstanceContext: VERSION_1_0 + EXT_layer_settings);
+/// let settings = [
+///     vk::LayerSettingEXT::bool(
+///         vk_safe::vk_str!("VK_LAYER_KHRONOS_validation"),
+///         vk_safe::vk_str!("validate_sync"),
+///         true,
+///     ),
+///     vk::LayerSettingEXT::bool(
+///         vk_safe::vk_str!("VK_LAYER_KHRONOS_validation"),
+///         vk_safe::vk_str!("validate_best_practices"),
+///         true,
+///     ),
+/// ];
+/// let mut layer_settings = vk::LayerSettingsCreateInfoEXT::new(&settings).unwrap();
+///
+/// let app_info = vk::ApplicationInfo::new(InstanceContext);
+/// let instance_info = vk::InstanceCreateInfo::new(&app_info).push_next(&mut layer_settings);
+/// ```
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkLayerSettingsCreateInfoEXT.html>
+LayerSettingsCreateInfoEXT<'a,>
+impl Deref, Debug
+);
+
+impl<'a> LayerSettingsCreateInfoEXT<'a> {
+    /// Create LayerSettingsCreateInfoEXT with `settings`
+    pub fn new(settings: &'a [LayerSettingEXT<'a>]) -> Result<Self, VulkanError> {
+        Ok(Self::from_c(vk::LayerSettingsCreateInfoEXT {
+            s_type: vk::LayerSettingsCreateInfoEXT::S_TYPE,
+            p_next: std::ptr::null(),
+            setting_count: settings.len().try_into()?,
+            // LayerSettingEXT is repr(transparent) of vk::LayerSettingEXT
+            p_settings: settings.as_ptr().cast(),
+        }))
+    }
+}
+
+// SAFETY: LayerSettingsCreateInfoEXT is repr(transparent) of vk::LayerSettingsCreateInfoEXT
+unsafe impl Stype for LayerSettingsCreateInfoEXT<'_> {
+    const S_TYPE: vk::StructureType = vk::LayerSettingsCreateInfoEXT::S_TYPE;
+}
+
+unsafe impl StructExtends<vk::InstanceCreateInfo> for LayerSettingsCreateInfoEXT<'_> {}
+
+// SAFETY: LayerSettingsCreateInfoEXT borrows the settings (and their names and values) it points to
+unsafe impl SafeExtends<vk::InstanceCreateInfo> for LayerSettingsCreateInfoEXT<'_> {}
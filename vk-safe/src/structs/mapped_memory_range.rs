@@ -1,18 +1,58 @@
+use std::num::NonZeroU64;
+
+use crate::error::VulkanError;
+use crate::flags::Excludes;
 use crate::type_conversions::ConvertWrapper;
 use crate::vk::{DeviceMemory, MappedMemory};
 
 use vk_safe_sys as vk;
 
+use vk::flag_types::MemoryPropertyFlags::HOST_COHERENT_BIT;
+
 struct_wrapper!(
 /// A range of memory for flushing or invalidating
 ///
+/// Can only be created for memory which is not `HOST_COHERENT`, since
+/// coherent memory never needs to be flushed or invalidated.
+///
 /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkMappedMemoryRange.html>
 MappedMemoryRange<'a, S,>
 );
 
 impl<'a, S> MappedMemoryRange<'a, S> {
     /// Make a range to that represents the entire [`MappedMemory`]
-    pub fn whole_range<M: DeviceMemory<Device = S>>(mapped_memory: &'a MappedMemory<M>) -> Self {
+    pub fn whole_range<M: DeviceMemory<Device = S, PropertyFlags: Excludes<HOST_COHERENT_BIT>>>(
+        mapped_memory: &'a MappedMemory<M>,
+    ) -> Self {
+        // VUIDs are checked in make_range
+        unsafe { Self::make_range(mapped_memory, mapped_memory.offset(), vk::WHOLE_SIZE) }
+    }
+
+    /// Make a range that represents part of a [`MappedMemory`]
+    ///
+    /// `offset` is relative to the beginning of the mapping (i.e. the same as indexing
+    /// [`MappedMemory::as_slice`]), and must be a multiple of `nonCoherentAtomSize`.
+    /// `size` must be a multiple of `nonCoherentAtomSize`, or reach exactly to the end
+    /// of the memory object. The range must be within the mapping.
+    pub fn sub_range<M: DeviceMemory<Device = S, PropertyFlags: Excludes<HOST_COHERENT_BIT>>>(
+        mapped_memory: &'a MappedMemory<M>,
+        offset: vk::DeviceSize,
+        size: NonZeroU64,
+    ) -> Result<Self, VulkanError> {
+        let memory_offset = mapped_memory.check_sub_range(offset, size.get())?;
+        // VUIDs are checked in make_range
+        unsafe { Ok(Self::make_range(mapped_memory, memory_offset, size.get())) }
+    }
+
+    /// offset is relative to the beginning of the memory object
+    ///
+    /// caller must ensure that the offset and size are valid for the MappedMemory
+    /// (i.e. either WHOLE_SIZE from the beginning of the mapping, or checked by MappedMemory::check_sub_range)
+    unsafe fn make_range<M: DeviceMemory<Device = S>>(
+        mapped_memory: &'a MappedMemory<M>,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+    ) -> Self {
         check_vuids::check_vuids!(MappedMemoryRange);
 
         #[allow(unused_labels)]
@@ -33,7 +73,7 @@ impl<'a, S> MappedMemoryRange<'a, S> {
             "within the currently mapped range of memory"
             }
 
-            // this function always sets VK_WHOLE_SIZE
+            // checked by MappedMemory::check_sub_range
         }
 
         #[allow(unused_labels)]
@@ -44,7 +84,7 @@ impl<'a, S> MappedMemoryRange<'a, S> {
             "of memory"
             }
 
-            // this function always sets 0 offset
+            // whole_range uses the offset of the mapping
         }
 
         #[allow(unused_labels)]
@@ -54,7 +94,8 @@ impl<'a, S> MappedMemoryRange<'a, S> {
             "offset must be a multiple of VkPhysicalDeviceLimits::nonCoherentAtomSize"
             }
 
-            // this function always sets 0 offset
+            // the offset of the mapping is a multiple of nonCoherentAtomSize (ensured by MemoryMapRange)
+            // sub ranges are checked by MappedMemory::check_sub_range
         }
 
         #[allow(unused_labels)]
@@ -66,8 +107,8 @@ impl<'a, S> MappedMemoryRange<'a, S> {
             "of the memory object, or be equal to the end of the memory object"
             }
 
-            // TODO: all mappings currently map the whole range of the memory object
-            // however, if this changes in future, then this needs to be considered more carefully
+            // MemoryMapRange ensures the end of the mapping is either a multiple of nonCoherentAtomSize
+            // or the end of the memory object
         }
 
         #[allow(unused_labels)]
@@ -78,7 +119,7 @@ impl<'a, S> MappedMemoryRange<'a, S> {
             "or offset plus size must equal the size of memory"
             }
 
-            // this function always sets VK_WHOLE_SIZE
+            // checked by MappedMemory::check_sub_range
         }
 
         #[allow(unused_labels)]
@@ -116,8 +157,8 @@ impl<'a, S> MappedMemoryRange<'a, S> {
                 s_type: vk::StructureType::MAPPED_MEMORY_RANGE,
                 p_next: std::ptr::null(),
                 memory: mapped_memory.handle(),
-                offset: 0,
-                size: vk::WHOLE_SIZE,
+                offset,
+                size,
             })
         }
     }
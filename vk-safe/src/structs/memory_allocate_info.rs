@@ -59,6 +59,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "and VkBufferCollectionBufferCreateInfoFUCHSIA::index, respectively, of the VkBufferCollectionBufferCreateInfoFUCHSIA"
             "structure used to create the VkMemoryDedicatedAllocateInfo::buffer"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -73,6 +74,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "and VkBufferCollectionImageCreateInfoFUCHSIA::index, respectively, of the VkBufferCollectionImageCreateInfoFUCHSIA"
             "structure used to create the VkMemoryDedicatedAllocateInfo::image"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -85,6 +87,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "must match VkMemoryRequirements::size value retrieved by vkGetImageMemoryRequirements"
             "or vkGetBufferMemoryRequirements for image-based or buffer-based collections respectively"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -97,6 +100,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "pNext chain must include a VkMemoryDedicatedAllocateInfo structure with either its"
             "image or buffer field set to a value other than VK_NULL_HANDLE"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -110,6 +114,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "with a VkBufferCollectionImageCreateInfoFUCHSIA structure chained to its VkImageCreateInfo::pNext"
             "pointer"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -123,6 +128,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "with a VkBufferCollectionBufferCreateInfoFUCHSIA structure chained to its VkBufferCreateInfo::pNext"
             "pointer"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -134,6 +140,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "If the parameters define an import operation from an VkBufferCollectionFUCHSIA, memoryTypeIndex"
             "must be from VkBufferCollectionPropertiesFUCHSIA as retrieved by vkGetBufferCollectionPropertiesFUCHSIA"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_buffer_collection)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -221,6 +228,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "or a global share handle created outside of the Vulkan API, the value of memoryTypeIndex"
             "must be one of those returned by vkGetMemoryWin32HandlePropertiesKHR"
             }
+            check_vuids::requires! {"(VK_KHR_external_memory_win32)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -259,6 +267,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "descriptor created outside of the Vulkan API, the value of memoryTypeIndex must be"
             "one of those returned by vkGetMemoryFdPropertiesKHR"
             }
+            check_vuids::requires! {"(VK_KHR_external_memory_fd)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -281,6 +290,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "If the parameters define an import operation and the external handle is a host pointer,"
             "the value of memoryTypeIndex must be one of those returned by vkGetMemoryHostPointerPropertiesEXT"
             }
+            check_vuids::requires! {"(VK_EXT_external_memory_host)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -292,6 +302,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "If the parameters define an import operation and the external handle is a host pointer,"
             "allocationSize must be an integer multiple of VkPhysicalDeviceExternalMemoryHostPropertiesEXT::minImportedHostPointerAlignment"
             }
+            check_vuids::requires! {"(VK_EXT_external_memory_host)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -316,6 +327,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "the pNext chain must not include a VkMemoryDedicatedAllocateInfo structure with either"
             "its image or buffer field set to a value other than VK_NULL_HANDLE"
             }
+            check_vuids::requires! {"(VK_EXT_external_memory_host)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -328,6 +340,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "allocationSize must be the size returned by vkGetAndroidHardwareBufferPropertiesANDROID"
             "for the Android hardware buffer"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -342,6 +355,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "must have a AHardwareBuffer_Desc::format of AHARDWAREBUFFER_FORMAT_BLOB and a AHardwareBuffer_Desc::usage"
             "that includes AHARDWAREBUFFER_USAGE_GPU_DATA_BUFFER"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -354,6 +368,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "memoryTypeIndex must be one of those returned by vkGetAndroidHardwareBufferPropertiesANDROID"
             "for the Android hardware buffer"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -367,6 +382,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "included in its handleTypes member, and the pNext chain includes a VkMemoryDedicatedAllocateInfo"
             "structure with image not equal to VK_NULL_HANDLE, then allocationSize must be 0"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -379,6 +395,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "and the pNext does not include a VkMemoryDedicatedAllocateInfo structure, allocationSize"
             "must be greater than 0"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -391,6 +408,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "and the pNext chain includes a VkMemoryDedicatedAllocateInfo structure with buffer"
             "set to a valid VkBuffer object, allocationSize must be greater than 0"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -405,6 +423,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "must include at least one of AHARDWAREBUFFER_USAGE_GPU_FRAMEBUFFER, AHARDWAREBUFFER_USAGE_GPU_SAMPLED_IMAGE"
             "or AHARDWAREBUFFER_USAGE_GPU_DATA_BUFFER"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -419,6 +438,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "returned by vkGetAndroidHardwareBufferPropertiesANDROID in VkAndroidHardwareBufferFormatPropertiesANDROID::format"
             "for the Android hardware buffer"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -432,6 +452,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "image that is not VK_NULL_HANDLE, the width, height, and array layer dimensions of"
             "image and the Android hardware buffer&#8217;s AHardwareBuffer_Desc must be identical"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -446,6 +467,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "includes AHARDWAREBUFFER_USAGE_GPU_MIPMAP_COMPLETE, the image must have a complete"
             "mipmap chain"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -460,6 +482,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "does not include AHARDWAREBUFFER_USAGE_GPU_MIPMAP_COMPLETE, the image must have exactly"
             "one mipmap level"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -474,6 +497,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "in AHardwareBuffer Usage Equivalence, and if there is a corresponding AHARDWAREBUFFER_USAGE"
             "bit listed that bit must be included in the Android hardware buffer&#8217;s AHardwareBuffer_Desc::usage"
             }
+            check_vuids::requires! {"(VK_ANDROID_external_memory_android_hardware_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -486,6 +510,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "VkPhysicalDeviceExternalMemoryScreenBufferFeaturesQNX::screenBufferImport must be"
             "enabled"
             }
+            check_vuids::requires! {"(VK_QNX_external_memory_screen_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -498,6 +523,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "allocationSize must be the size returned by vkGetScreenBufferPropertiesQNX for the"
             "QNX Screen buffer"
             }
+            check_vuids::requires! {"(VK_QNX_external_memory_screen_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -510,6 +536,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "memoryTypeIndex must be one of those returned by vkGetScreenBufferPropertiesQNX for"
             "the QNX Screen buffer"
             }
+            check_vuids::requires! {"(VK_QNX_external_memory_screen_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -522,6 +549,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "buffer, and the pNext chain includes a VkMemoryDedicatedAllocateInfo with image that"
             "is not VK_NULL_HANDLE, the QNX Screen&#8217;s buffer must be a valid QNX Screen buffer"
             }
+            check_vuids::requires! {"(VK_QNX_external_memory_screen_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -536,6 +564,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "returned by vkGetScreenBufferPropertiesQNX in VkScreenBufferFormatPropertiesQNX::format"
             "for the QNX Screen buffer"
             }
+            check_vuids::requires! {"(VK_QNX_external_memory_screen_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -549,6 +578,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "image that is not VK_NULL_HANDLE, the width, height, and array layer dimensions of"
             "image and the QNX Screen buffer&#8217;s _screen_buffer must be identical"
             }
+            check_vuids::requires! {"(VK_QNX_external_memory_screen_buffer)"}
 
             // TODO: import and export operation not currently supported
         }
@@ -617,6 +647,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "field of the VkMemoryZirconHandlePropertiesFUCHSIA structure populated by a call to"
             "vkGetMemoryZirconHandlePropertiesFUCHSIA"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_external_memory)"}
 
             // TODO: p_next not currently supported
         }
@@ -628,6 +659,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "If the parameters define an import operation and the external handle type is VK_EXTERNAL_MEMORY_HANDLE_TYPE_ZIRCON_VMO_BIT_FUCHSIA,"
             "the value of allocationSize must be greater than 0"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_external_memory)"}
 
             // TODO: p_next not currently supported
         }
@@ -641,6 +673,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "by zx_vmo_get_size(handle) where handle is the VMO handle to the imported external"
             "memory"
             }
+            check_vuids::requires! {"(VK_FUCHSIA_external_memory)"}
 
             // TODO: p_next not currently supported
         }
@@ -652,6 +685,7 @@ impl<S, P, H> MemoryAllocateInfo<S, P, H> {
             "If the pNext chain includes a VkExportMetalObjectCreateInfoEXT structure, its exportObjectType"
             "member must be VK_EXPORT_METAL_OBJECT_TYPE_METAL_BUFFER_BIT_EXT"
             }
+            check_vuids::requires! {"(VK_EXT_metal_objects)"}
 
             // TODO: p_next not currently supported
         }
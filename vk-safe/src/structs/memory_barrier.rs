@@ -0,0 +1,73 @@
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::AccessFlags;
+
+struct_wrapper!(
+/// A global memory barrier
+///
+/// used with [`cmd_pipeline_barrier`](crate::vk::_CommandBuffer::cmd_pipeline_barrier)
+///
+/// The access masks are provided as types, so that they can be checked at compile time
+/// against the pipeline stages of the barrier.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkMemoryBarrier.html>
+MemoryBarrier<SrcAccess, DstAccess,>
+impl Deref, Debug
+);
+
+impl<SA: AccessFlags, DA: AccessFlags> MemoryBarrier<SA, DA> {
+    pub fn new(_src_access: SA, _dst_access: DA) -> Self {
+        check_vuids::check_vuids!(MemoryBarrier);
+
+        #[allow(unused_labels)]
+        'VUID_VkMemoryBarrier_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_MEMORY_BARRIER"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkMemoryBarrier_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pNext must be NULL"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkMemoryBarrier_srcAccessMask_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "srcAccessMask must be a valid combination of VkAccessFlagBits values"
+            }
+
+            // ensured by AccessFlags type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkMemoryBarrier_dstAccessMask_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "dstAccessMask must be a valid combination of VkAccessFlagBits values"
+            }
+
+            // ensured by AccessFlags type
+        }
+
+        unsafe {
+            Self::from_c(vk::MemoryBarrier {
+                s_type: vk::StructureType::MEMORY_BARRIER,
+                p_next: std::ptr::null(),
+                src_access_mask: SA::INCLUDES,
+                dst_access_mask: DA::INCLUDES,
+            })
+        }
+    }
+}
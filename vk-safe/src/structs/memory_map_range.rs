@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+use std::num::NonZeroU64;
+
+use super::PhysicalDeviceProperties;
+
+use crate::error::VulkanError;
+
+use vk_safe_sys as vk;
+
+/// Offset and size of the range of [`DeviceMemory`](crate::vk::DeviceMemory) to map
+///
+/// The range is always aligned to `VkPhysicalDeviceLimits::nonCoherentAtomSize` of the
+/// PhysicalDevice `S`. This ensures that the whole mapped range (or any aligned sub range)
+/// can be used with [`MappedMemoryRange`](crate::vk::MappedMemoryRange) for flushing
+/// or invalidating.
+///
+/// The range is checked against the size of the memory object when mapping.
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryMapRange<S> {
+    pub(crate) offset: vk::DeviceSize,
+    pub(crate) size: vk::DeviceSize,
+    pub(crate) non_coherent_atom_size: vk::DeviceSize,
+    _scope: PhantomData<S>,
+}
+
+impl<S> MemoryMapRange<S> {
+    /// Range that represents the entire memory object
+    pub fn whole(properties: &PhysicalDeviceProperties<S>) -> Self {
+        Self {
+            offset: 0,
+            size: vk::WHOLE_SIZE,
+            non_coherent_atom_size: properties.limits.non_coherent_atom_size,
+            _scope: PhantomData,
+        }
+    }
+
+    /// Range from `offset` to the end of the memory object
+    ///
+    /// `offset` must be a multiple of `nonCoherentAtomSize`
+    pub fn from_offset(
+        properties: &PhysicalDeviceProperties<S>,
+        offset: vk::DeviceSize,
+    ) -> Result<Self, VulkanError> {
+        let non_coherent_atom_size = properties.limits.non_coherent_atom_size;
+        if offset % non_coherent_atom_size != 0 {
+            Err(VulkanError::MisalignedMemoryRange)?
+        }
+        Ok(Self {
+            offset,
+            size: vk::WHOLE_SIZE,
+            non_coherent_atom_size,
+            _scope: PhantomData,
+        })
+    }
+
+    /// Range of `size` bytes starting at `offset`
+    ///
+    /// `offset` must be a multiple of `nonCoherentAtomSize`. `size` must either be a multiple
+    /// of `nonCoherentAtomSize`, or reach exactly to the end of the memory object (checked when mapping).
+    pub fn new(
+        properties: &PhysicalDeviceProperties<S>,
+        offset: vk::DeviceSize,
+        size: NonZeroU64,
+    ) -> Result<Self, VulkanError> {
+        let mut range = Self::from_offset(properties, offset)?;
+        range.size = size.get();
+        Ok(range)
+    }
+
+    /// Check the range against the size of the memory object
+    ///
+    /// returns the real size (in bytes) of the range
+    pub(crate) fn checked_size(
+        &self,
+        allocation_size: vk::DeviceSize,
+    ) -> Result<vk::DeviceSize, VulkanError> {
+        if self.offset >= allocation_size {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        }
+        let max_size = allocation_size - self.offset;
+        if self.size == vk::WHOLE_SIZE {
+            Ok(max_size)
+        } else if self.size > max_size {
+            Err(VulkanError::MemoryRangeOutOfBounds)?
+        } else if self.size % self.non_coherent_atom_size != 0 && self.size != max_size {
+            Err(VulkanError::MisalignedMemoryRange)?
+        } else {
+            Ok(self.size)
+        }
+    }
+}
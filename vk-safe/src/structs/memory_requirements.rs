@@ -0,0 +1,40 @@
+use super::physical_device_memory_properties::{MemoryTypeChoice, PhysicalDeviceMemoryProperties};
+
+use vk_safe_sys as vk;
+
+struct_wrapper!(
+/// Memory requirements of a *specific* resource
+///
+/// `Scope` is the PhysicalDevice of the Device which the resource belongs to, and
+/// `Resource` is the type of the *specific* resource (e.g. a [`Buffer`](crate::vk::Buffer))
+/// which these requirements were queried for. Since resources are created with a unique
+/// [`Tag`](crate::scope::Tag), the requirements can only be used with the resource they were
+/// queried for.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkMemoryRequirements.html>
+MemoryRequirements<Scope, Resource,>
+impl Deref, Debug, Clone, Copy
+);
+
+impl<S, R> MemoryRequirements<S, R> {
+    /// Check if the memory type at `memory_type_index` is allowed for the resource
+    pub fn supports_memory_type(&self, memory_type_index: u32) -> bool {
+        memory_type_index < u32::BITS && self.inner.memory_type_bits & (1 << memory_type_index) != 0
+    }
+
+    /// find the first memory type that satisfies the given MemoryPropertyFlags and MemoryHeapFlags,
+    /// and which is allowed for the resource
+    ///
+    /// This is the same as [`PhysicalDeviceMemoryProperties::find_ty`], but only considers memory types
+    /// allowed by `memoryTypeBits`.
+    ///
+    /// **⚠️ VK_MEMORY_PROPERTY_DEVICE_COHERENT_BIT_AMD is not supported**
+    pub fn find_ty<P: vk::flag_traits::MemoryPropertyFlags, H: vk::flag_traits::MemoryHeapFlags>(
+        &self,
+        memory_properties: &PhysicalDeviceMemoryProperties<S>,
+        property_flags: P,
+        heap_flags: H,
+    ) -> Option<MemoryTypeChoice<S, P, H>> {
+        memory_properties.find_ty_in(self.inner.memory_type_bits, property_flags, heap_flags)
+    }
+}
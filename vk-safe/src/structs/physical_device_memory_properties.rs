@@ -86,6 +86,20 @@ impl<S> PhysicalDeviceMemoryProperties<S> {
         H: vk::flag_traits::MemoryHeapFlags,
     >(
         &'a self,
+        property_flags: P,
+        heap_flags: H,
+    ) -> Option<MemoryTypeChoice<S, P, H>> {
+        self.find_ty_in(u32::MAX, property_flags, heap_flags)
+    }
+
+    /// find the first memory type that satisfies the given MemoryPropertyFlags and MemoryHeapFlags, and
+    /// which is allowed by `memory_type_bits` (bit `i` is set if memory type `i` is allowed)
+    pub(crate) fn find_ty_in<
+        P: vk::flag_traits::MemoryPropertyFlags,
+        H: vk::flag_traits::MemoryHeapFlags,
+    >(
+        &self,
+        memory_type_bits: u32,
         _property_flags: P,
         _heap_flags: H,
     ) -> Option<MemoryTypeChoice<S, P, H>> {
@@ -94,6 +108,10 @@ impl<S> PhysicalDeviceMemoryProperties<S> {
         }
 
         for (index, ty) in self.memory_types().iter().enumerate() {
+            if memory_type_bits & (1 << index) == 0 {
+                continue;
+            }
+
             let heap = self.memory_heaps()[ty.heap_index as usize];
 
             // index should be a safe to cast since we assume the number of memory types to enumerate is valid
@@ -0,0 +1,419 @@
+use super::{PhysicalDeviceProperties, PushConstantRange};
+
+use crate::error::VulkanError;
+use crate::handles::descriptor_set_layout::{DescriptorCounts, DescriptorSetLayout};
+
+use vk_safe_sys as vk;
+
+/// Info for creating a [`PipelineLayout`](crate::vk::PipelineLayout)
+///
+/// used with [`create_pipeline_layout`](crate::vk::create_pipeline_layout)
+///
+/// The total number of descriptors in the DescriptorSetLayouts, and the push constant ranges,
+/// are checked against the limits of the PhysicalDevice `S`.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPipelineLayoutCreateInfo.html>
+#[derive(Debug)]
+pub struct PipelineLayoutCreateInfo<'a, S, L, const N: usize> {
+    pub(crate) set_layouts: [&'a L; N],
+    pub(crate) push_constant_ranges: &'a [PushConstantRange<S>],
+}
+
+impl<'a, S, L: DescriptorSetLayout, const N: usize> PipelineLayoutCreateInfo<'a, S, L, N> {
+    /// Create info for a layout with the provided DescriptorSetLayouts and push constant ranges
+    ///
+    /// The DescriptorSetLayout at index `i` of `set_layouts` is used for set `i`. No two
+    /// push constant ranges can include the same shader stage.
+    pub fn new(
+        properties: &PhysicalDeviceProperties<S>,
+        set_layouts: [&'a L; N],
+        push_constant_ranges: &'a [PushConstantRange<S>],
+    ) -> Result<Self, VulkanError> {
+        check_vuids::check_vuids!(PipelineLayoutCreateInfo);
+
+        use vk::DescriptorType as Type;
+
+        let limits = &properties.limits;
+        let mut counts = DescriptorCounts::default();
+        for set_layout in set_layouts {
+            counts.add(set_layout.descriptor_counts());
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_setLayoutCount_00286: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "setLayoutCount must be less than or equal to VkPhysicalDeviceLimits::maxBoundDescriptorSets"
+            }
+
+            if N > limits.max_bound_descriptor_sets as usize {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03016: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_SAMPLER and VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER"
+            "accessible to any given shader stage across all elements of pSetLayouts must be less"
+            "than or equal to VkPhysicalDeviceLimits::maxPerStageDescriptorSamplers"
+            }
+
+            if counts.max_per_stage(&[Type::SAMPLER, Type::COMBINED_IMAGE_SAMPLER])
+                > limits.max_per_stage_descriptor_samplers
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03017: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER and VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC"
+            "accessible to any given shader stage across all elements of pSetLayouts must be less"
+            "than or equal to VkPhysicalDeviceLimits::maxPerStageDescriptorUniformBuffers"
+            }
+
+            if counts.max_per_stage(&[Type::UNIFORM_BUFFER, Type::UNIFORM_BUFFER_DYNAMIC])
+                > limits.max_per_stage_descriptor_uniform_buffers
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03018: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_STORAGE_BUFFER and VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC"
+            "accessible to any given shader stage across all elements of pSetLayouts must be less"
+            "than or equal to VkPhysicalDeviceLimits::maxPerStageDescriptorStorageBuffers"
+            }
+
+            if counts.max_per_stage(&[Type::STORAGE_BUFFER, Type::STORAGE_BUFFER_DYNAMIC])
+                > limits.max_per_stage_descriptor_storage_buffers
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_06939: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER, VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE,"
+            "VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER, VK_DESCRIPTOR_TYPE_SAMPLE_WEIGHT_IMAGE_QCOM,"
+            "and VK_DESCRIPTOR_TYPE_BLOCK_MATCH_IMAGE_QCOM accessible to any given shader stage"
+            "across all elements of pSetLayouts must be less than or equal to VkPhysicalDeviceLimits::maxPerStageDescriptorSampledImages"
+            }
+
+            if counts.max_per_stage(&[
+                Type::COMBINED_IMAGE_SAMPLER,
+                Type::SAMPLED_IMAGE,
+                Type::UNIFORM_TEXEL_BUFFER,
+            ]) > limits.max_per_stage_descriptor_sampled_images
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03020: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_STORAGE_IMAGE, and VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER"
+            "accessible to any given shader stage across all elements of pSetLayouts must be less"
+            "than or equal to VkPhysicalDeviceLimits::maxPerStageDescriptorStorageImages"
+            }
+
+            if counts.max_per_stage(&[Type::STORAGE_IMAGE, Type::STORAGE_TEXEL_BUFFER])
+                > limits.max_per_stage_descriptor_storage_images
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03021: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT accessible to"
+            "any given shader stage across all elements of pSetLayouts must be less than or equal"
+            "to VkPhysicalDeviceLimits::maxPerStageDescriptorInputAttachments"
+            }
+
+            if counts.max_per_stage(&[Type::INPUT_ATTACHMENT])
+                > limits.max_per_stage_descriptor_input_attachments
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_pSetLayouts_03022: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors with a descriptorType of VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER,"
+            "VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE, VK_DESCRIPTOR_TYPE_STORAGE_IMAGE, VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER,"
+            "VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER, VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER, VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,"
+            "VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC, VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC,"
+            "and VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT accessible to any given shader stage across"
+            "all elements of pSetLayouts must be less than or equal to VkPhysicalDeviceLimits::maxPerStageResources"
+            }
+
+            if counts.max_per_stage(&[
+                Type::COMBINED_IMAGE_SAMPLER,
+                Type::SAMPLED_IMAGE,
+                Type::STORAGE_IMAGE,
+                Type::UNIFORM_TEXEL_BUFFER,
+                Type::STORAGE_TEXEL_BUFFER,
+                Type::UNIFORM_BUFFER,
+                Type::STORAGE_BUFFER,
+                Type::UNIFORM_BUFFER_DYNAMIC,
+                Type::STORAGE_BUFFER_DYNAMIC,
+                Type::INPUT_ATTACHMENT,
+            ]) > limits.max_per_stage_resources
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03028: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_SAMPLER and VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER"
+            "accessible across all shader stages and across all elements of pSetLayouts must be"
+            "less than or equal to VkPhysicalDeviceLimits::maxDescriptorSetSamplers"
+            }
+
+            if counts.total(&[Type::SAMPLER, Type::COMBINED_IMAGE_SAMPLER])
+                > limits.max_descriptor_set_samplers
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03029: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER accessible across"
+            "all shader stages and across all elements of pSetLayouts must be less than or equal"
+            "to VkPhysicalDeviceLimits::maxDescriptorSetUniformBuffers"
+            }
+
+            if counts.total(&[Type::UNIFORM_BUFFER]) > limits.max_descriptor_set_uniform_buffers {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03030: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC accessible"
+            "across all shader stages and across all elements of pSetLayouts must be less than"
+            "or equal to VkPhysicalDeviceLimits::maxDescriptorSetUniformBuffersDynamic"
+            }
+
+            if counts.total(&[Type::UNIFORM_BUFFER_DYNAMIC])
+                > limits.max_descriptor_set_uniform_buffers_dynamic
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03031: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_STORAGE_BUFFER accessible across"
+            "all shader stages and across all elements of pSetLayouts must be less than or equal"
+            "to VkPhysicalDeviceLimits::maxDescriptorSetStorageBuffers"
+            }
+
+            if counts.total(&[Type::STORAGE_BUFFER]) > limits.max_descriptor_set_storage_buffers {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03032: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC accessible"
+            "across all shader stages and across all elements of pSetLayouts must be less than"
+            "or equal to VkPhysicalDeviceLimits::maxDescriptorSetStorageBuffersDynamic"
+            }
+
+            if counts.total(&[Type::STORAGE_BUFFER_DYNAMIC])
+                > limits.max_descriptor_set_storage_buffers_dynamic
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03033: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER, VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE,"
+            "and VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER accessible across all shader stages and"
+            "across all elements of pSetLayouts must be less than or equal to VkPhysicalDeviceLimits::maxDescriptorSetSampledImages"
+            }
+
+            if counts.total(&[
+                Type::COMBINED_IMAGE_SAMPLER,
+                Type::SAMPLED_IMAGE,
+                Type::UNIFORM_TEXEL_BUFFER,
+            ]) > limits.max_descriptor_set_sampled_images
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03034: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_STORAGE_IMAGE, and VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER"
+            "accessible across all shader stages and across all elements of pSetLayouts must be"
+            "less than or equal to VkPhysicalDeviceLimits::maxDescriptorSetStorageImages"
+            }
+
+            if counts.total(&[Type::STORAGE_IMAGE, Type::STORAGE_TEXEL_BUFFER])
+                > limits.max_descriptor_set_storage_images
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_descriptorType_03035: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The total number of descriptors in descriptor set layouts created without the VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT"
+            "bit set with a descriptorType of VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT accessible across"
+            "all shader stages and across all elements of pSetLayouts must be less than or equal"
+            "to VkPhysicalDeviceLimits::maxDescriptorSetInputAttachments"
+            }
+
+            if counts.total(&[Type::INPUT_ATTACHMENT]) > limits.max_descriptor_set_input_attachments
+            {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_pPushConstantRanges_00292: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Any two elements of pPushConstantRanges must not include the same stage in stageFlags"
+            }
+
+            for (i, a) in push_constant_ranges.iter().enumerate() {
+                if push_constant_ranges[i + 1..]
+                    .iter()
+                    .any(|b| a.stage_flags.any_of(b.stage_flags))
+                {
+                    Err(VulkanError::InvalidPushConstantRange)?
+                }
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_pSetLayouts_00293: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pSetLayouts must not contain more than one descriptor set layout that was created"
+            "with VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR set"
+            }
+
+            // DescriptorSetLayouts are always created with empty flags
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_graphicsPipelineLibrary_06753: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If graphicsPipelineLibrary is not enabled, elements of pSetLayouts must be valid VkDescriptorSetLayout"
+            "objects"
+            }
+
+            // ensured by DescriptorSetLayout creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_PIPELINE_LAYOUT_CREATE_INFO"
+            }
+
+            // set in create_pipeline_layout
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pNext must be NULL"
+            }
+
+            // set in create_pipeline_layout
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkPipelineLayoutCreateFlagBits values"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_pSetLayouts_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If setLayoutCount is not 0, pSetLayouts must be a valid pointer to an array of setLayoutCount"
+            "valid or VK_NULL_HANDLE VkDescriptorSetLayout handles"
+            }
+
+            // ensured by DescriptorSetLayout creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineLayoutCreateInfo_pPushConstantRanges_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pushConstantRangeCount is not 0, pPushConstantRanges must be a valid pointer to"
+            "an array of pushConstantRangeCount valid VkPushConstantRange structures"
+            }
+
+            // ensured by PushConstantRange creation
+        }
+
+        Ok(Self {
+            set_layouts,
+            push_constant_ranges,
+        })
+    }
+}
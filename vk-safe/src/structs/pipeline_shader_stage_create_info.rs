@@ -0,0 +1,258 @@
+use crate::handles::shader_module::ShaderModule;
+use crate::handles::Handle;
+use crate::type_conversions::ConvertWrapper;
+use crate::VkStr;
+
+use vk_safe_sys as vk;
+
+use vk::flag_types::ShaderStageFlags::COMPUTE_BIT;
+
+struct_wrapper!(
+/// A shader stage of a Pipeline
+///
+/// The ShaderModule is borrowed until the Pipeline is created. The stage is provided as
+/// a type, so that it can be checked against the type of Pipeline.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPipelineShaderStageCreateInfo.html>
+PipelineShaderStageCreateInfo<'a, Device, Stage,>
+impl Clone, Copy, Deref, Debug
+);
+
+impl<'a, D> PipelineShaderStageCreateInfo<'a, D, COMPUTE_BIT> {
+    /**
+    Compute shader stage
+
+    used with [`ComputePipelineCreateInfo`](crate::vk::ComputePipelineCreateInfo)
+
+    `entry_point` is the name of the entry point of the shader in `module`.
+
+    # Safety
+    `entry_point` must be the name of an `OpEntryPoint` in `module` with the `GLCompute`
+    execution model.
+
+    The shader must not exceed the compute limits of the PhysicalDevice
+    (e.g. `maxComputeWorkGroupSize`, `maxComputeWorkGroupInvocations`, and `maxComputeSharedMemorySize`).
+    */
+    pub unsafe fn compute<M: ShaderModule<Device = D>>(
+        module: &'a M,
+        entry_point: VkStr<'a>,
+    ) -> Self {
+        check_vuids::check_vuids!(PipelineShaderStageCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_stage_00704: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the geometryShader feature is not enabled, stage must not be VK_SHADER_STAGE_GEOMETRY_BIT"
+            }
+
+            // compute stage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_stage_00705: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the tessellationShader feature is not enabled, stage must not be VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT"
+            "or VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT"
+            }
+
+            // compute stage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_stage_02091: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the meshShader feature is not enabled, stage must not be VK_SHADER_STAGE_MESH_BIT_EXT"
+            }
+
+            // compute stage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_stage_02092: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the taskShader feature is not enabled, stage must not be VK_SHADER_STAGE_TASK_BIT_EXT"
+            }
+
+            // compute stage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_stage_00706: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "stage must not be VK_SHADER_STAGE_ALL_GRAPHICS, or VK_SHADER_STAGE_ALL"
+            }
+
+            // compute stage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_pName_00707: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pName must be the name of an OpEntryPoint in module with an execution model that matches"
+            "stage"
+            }
+
+            // promised by caller
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_maxClipDistances_00708: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the identified entry point includes any variable in its interface that is declared"
+            "with the ClipDistance BuiltIn decoration, that variable must not have an array size"
+            "greater than VkPhysicalDeviceLimits::maxClipDistances"
+            }
+
+            // compute shaders do not have ClipDistance in their interface
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_maxCullDistances_00709: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the identified entry point includes any variable in its interface that is declared"
+            "with the CullDistance BuiltIn decoration, that variable must not have an array size"
+            "greater than VkPhysicalDeviceLimits::maxCullDistances"
+            }
+
+            // compute shaders do not have CullDistance in their interface
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_maxCombinedClipAndCullDistances_00710: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the identified entry point includes variables in its interface that are declared"
+            "with the ClipDistance BuiltIn decoration and variables in its interface that are declared"
+            "with the CullDistance BuiltIn decoration, those variables must not have array sizes"
+            "which sum to more than VkPhysicalDeviceLimits::maxCombinedClipAndCullDistances"
+            }
+
+            // compute shaders do not have ClipDistance or CullDistance in their interface
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_maxSampleMaskWords_00711: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the identified entry point includes any variable in its interface that is declared"
+            "with the SampleMask BuiltIn decoration, that variable must not have an array size"
+            "greater than VkPhysicalDeviceLimits::maxSampleMaskWords"
+            }
+
+            // compute shaders do not have SampleMask in their interface
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_flags_02784: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags has the VK_PIPELINE_SHADER_STAGE_CREATE_ALLOW_VARYING_SUBGROUP_SIZE_BIT flag"
+            "set, the subgroupSizeControl feature must be enabled"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_flags_02785: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If flags has the VK_PIPELINE_SHADER_STAGE_CREATE_REQUIRE_FULL_SUBGROUPS_BIT flag set,"
+            "the computeFullSubgroups feature must be enabled"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkPipelineShaderStageCreateFlagBits values"
+            }
+
+            // flags are always empty
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_stage_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "stage must be a valid VkShaderStageFlagBits value"
+            }
+
+            // compute stage
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_module_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If module is not VK_NULL_HANDLE, module must be a valid VkShaderModule handle"
+            }
+
+            // ensured by ShaderModule creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_pName_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "pName must be a null-terminated UTF-8 string"
+            }
+
+            // ensured by VkStr
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPipelineShaderStageCreateInfo_pSpecializationInfo_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pSpecializationInfo is not NULL, pSpecializationInfo must be a valid pointer to"
+            "a valid VkSpecializationInfo structure"
+            }
+
+            // TODO: specialization constants are not supported yet; always NULL
+        }
+
+        unsafe {
+            Self::from_c(vk::PipelineShaderStageCreateInfo {
+                s_type: vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::PipelineShaderStageCreateFlags::empty(),
+                stage: vk::ShaderStageFlags::COMPUTE_BIT,
+                module: module.raw_handle(),
+                p_name: entry_point.as_ptr(),
+                p_specialization_info: std::ptr::null(),
+            })
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::PhysicalDeviceProperties;
+
+use crate::error::VulkanError;
+use crate::type_conversions::ConvertWrapper;
+
+use std::ops::Range;
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::ShaderStageFlags;
+
+struct_wrapper!(
+/// A range of push constants
+///
+/// used with [`PipelineLayoutCreateInfo`](crate::vk::PipelineLayoutCreateInfo)
+///
+/// The range is checked against `maxPushConstantsSize` of the PhysicalDevice `S`.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPushConstantRange.html>
+PushConstantRange<S,>
+impl Clone, Copy, Deref, Debug
+);
+
+impl<S> PushConstantRange<S> {
+    /// A range of push constants accessible to the shader `stages`
+    ///
+    /// `range` is in bytes, and must be non-empty and a multiple of 4 bytes.
+    pub fn new<F: ShaderStageFlags>(
+        properties: &PhysicalDeviceProperties<S>,
+        _stages: F,
+        range: Range<u32>,
+    ) -> Result<Self, VulkanError> {
+        check_vuids::check_vuids!(PushConstantRange);
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_offset_00294: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "offset must be less than VkPhysicalDeviceLimits::maxPushConstantsSize"
+            }
+
+            if range.start >= properties.limits.max_push_constants_size {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_offset_00295: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "offset must be a multiple of 4"
+            }
+
+            if range.start % 4 != 0 {
+                Err(VulkanError::InvalidPushConstantRange)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_size_00296: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "size must be greater than 0"
+            }
+
+            if range.is_empty() {
+                Err(VulkanError::InvalidPushConstantRange)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_size_00297: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "size must be a multiple of 4"
+            }
+
+            // offset is a multiple of 4
+            if range.end % 4 != 0 {
+                Err(VulkanError::InvalidPushConstantRange)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_size_00298: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "size must be less than or equal to VkPhysicalDeviceLimits::maxPushConstantsSize minus"
+            "offset"
+            }
+
+            if range.end > properties.limits.max_push_constants_size {
+                Err(VulkanError::LimitExceeded)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_stageFlags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "stageFlags must be a valid combination of VkShaderStageFlagBits values"
+            }
+
+            // ensured by ShaderStageFlags type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkPushConstantRange_stageFlags_requiredbitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "stageFlags must not be 0"
+            }
+
+            const {
+                assert!(
+                    F::INCLUDES.is_not_empty(),
+                    "push constant range must be accessible to at least one stage"
+                );
+            }
+        }
+
+        unsafe {
+            Ok(Self::from_c(vk::PushConstantRange {
+                stage_flags: F::INCLUDES,
+                offset: range.start,
+                size: range.end - range.start,
+            }))
+        }
+    }
+}
@@ -0,0 +1,421 @@
+use super::PhysicalDeviceProperties;
+
+use std::marker::PhantomData;
+
+use crate::error::VulkanError;
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+use vk::enum_traits::{BorderColor, CompareOp, Filter, SamplerAddressMode, SamplerMipmapMode};
+
+struct_wrapper!(
+/// Info for creating a Sampler
+///
+/// used with [`create_sampler`](crate::vk::create_sampler)
+///
+/// Create with the filters, mipmap mode and address mode (used for all of U, V and W).
+/// The other parameters are set to defaults which can be changed with the builder methods:
+/// - LOD: no bias, `0.0..=VK_LOD_CLAMP_NONE`
+/// - anisotropy: disabled
+/// - compare: disabled
+/// - border color: `FLOAT_TRANSPARENT_BLACK`
+///
+/// Parameters which depend on the PhysicalDevice `S` are checked against its limits. Features
+/// which must be enabled on the Device are tracked by `Required`, and checked by
+/// [`create_sampler`](crate::vk::create_sampler) against the [`Features`](crate::vk::Device::Features)
+/// of the Device.
+///
+/// Unnormalized coordinates are not supported at this time.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkSamplerCreateInfo.html>
+SamplerCreateInfo<S, Required,>
+impl Deref, Debug, Clone, Copy
+);
+
+crate::features!(
+    /// Features required by a [`SamplerCreateInfo`] with anisotropic filtering enabled
+    pub AnisotropyFeatures: sampler_anisotropy
+);
+
+impl<S> SamplerCreateInfo<S, ()> {
+    pub fn new<Mag: Filter, Min: Filter, Mip: SamplerMipmapMode, A: SamplerAddressMode>(
+        _mag_filter: Mag,
+        _min_filter: Min,
+        _mipmap_mode: Mip,
+        _address_mode: A,
+    ) -> Self {
+        check_vuids::check_vuids!(SamplerCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_mipLodBias_01069: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The absolute value of mipLodBias must be less than or equal to VkPhysicalDeviceLimits::maxSamplerLodBias"
+            }
+
+            // default is 0.0, checked in Self::lod
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_maxLod_01973: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "maxLod must be greater than or equal to minLod"
+            }
+
+            // default is 0.0..=VK_LOD_CLAMP_NONE, checked in Self::lod
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_anisotropyEnable_01070: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the samplerAnisotropy feature is not enabled, anisotropyEnable must be VK_FALSE"
+            }
+
+            // default is VK_FALSE, Self::anisotropy adds the feature to the required features,
+            // which are checked against the enabled features in create_sampler
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_anisotropyEnable_01071: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If anisotropyEnable is VK_TRUE, maxAnisotropy must be between 1.0 and VkPhysicalDeviceLimits::maxSamplerAnisotropy,"
+            "inclusive"
+            }
+
+            // default is VK_FALSE, checked in Self::anisotropy
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_minFilter_01645: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sampler Y′CBCR conversion is enabled and the potential format features of the"
+            "sampler Y′CBCR conversion do not support VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT,"
+            "minFilter and magFilter must be equal to the sampler Y′CBCR conversion&#8217;s chromaFilter"
+            }
+
+            // sampler Y′CBCR conversion is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_unnormalizedCoordinates_01072: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If unnormalizedCoordinates is VK_TRUE, minFilter and magFilter must be equal"
+            }
+
+            // unnormalizedCoordinates is always VK_FALSE
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_unnormalizedCoordinates_01073: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If unnormalizedCoordinates is VK_TRUE, mipmapMode must be VK_SAMPLER_MIPMAP_MODE_NEAREST"
+            }
+
+            // unnormalizedCoordinates is always VK_FALSE
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_unnormalizedCoordinates_01074: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If unnormalizedCoordinates is VK_TRUE, minLod and maxLod must be zero"
+            }
+
+            // unnormalizedCoordinates is always VK_FALSE
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_unnormalizedCoordinates_01075: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If unnormalizedCoordinates is VK_TRUE, addressModeU and addressModeV must each be"
+            "either VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_EDGE or VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_BORDER"
+            }
+
+            // unnormalizedCoordinates is always VK_FALSE
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_unnormalizedCoordinates_01076: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If unnormalizedCoordinates is VK_TRUE, anisotropyEnable must be VK_FALSE"
+            }
+
+            // unnormalizedCoordinates is always VK_FALSE
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_unnormalizedCoordinates_01077: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If unnormalizedCoordinates is VK_TRUE, compareEnable must be VK_FALSE"
+            }
+
+            // unnormalizedCoordinates is always VK_FALSE
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_addressModeU_01078: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any of addressModeU, addressModeV or addressModeW are VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_BORDER,"
+            "borderColor must be a valid VkBorderColor value"
+            }
+
+            // ensured by BorderColor type, see Self::border_color
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_addressModeU_01646: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If sampler Y′CBCR conversion is enabled, addressModeU, addressModeV, and addressModeW"
+            "must be VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_EDGE, anisotropyEnable must be VK_FALSE,"
+            "and unnormalizedCoordinates must be VK_FALSE"
+            }
+
+            // sampler Y′CBCR conversion is not supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_addressModeU_01079: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If samplerMirrorClampToEdge is not enabled, and if the VK_KHR_sampler_mirror_clamp_to_edge"
+            "extension is not enabled, addressModeU, addressModeV and addressModeW must not be"
+            "VK_SAMPLER_ADDRESS_MODE_MIRROR_CLAMP_TO_EDGE"
+            }
+
+            // samplerMirrorClampToEdge is a Vulkan 1.2 feature, which is not tracked at this time,
+            // so assume the feature is not enabled
+            const {
+                assert!(
+                    !A::VALUE.is(vk::sampler_address_mode::MIRROR_CLAMP_TO_EDGE),
+                    "MIRROR_CLAMP_TO_EDGE is not supported by vk-safe at this time"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_compareEnable_01080: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If compareEnable is VK_TRUE, compareOp must be a valid VkCompareOp value"
+            }
+
+            // ensured by CompareOp type, see Self::compare
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_magFilter_01081: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If either magFilter or minFilter is VK_FILTER_CUBIC_EXT, anisotropyEnable must be"
+            "VK_FALSE"
+            }
+
+            // TODO: VK_EXT_filter_cubic is not supported yet
+            const {
+                assert!(
+                    !Mag::VALUE.is(vk::filter::CUBIC_EXT) && !Min::VALUE.is(vk::filter::CUBIC_EXT),
+                    "CUBIC_EXT filter is not supported by vk-safe at this time"
+                );
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_SAMPLER_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_flags_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be a valid combination of VkSamplerCreateFlagBits values"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_magFilter_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "magFilter must be a valid VkFilter value"
+            }
+
+            // ensured by Filter type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_minFilter_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "minFilter must be a valid VkFilter value"
+            }
+
+            // ensured by Filter type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_mipmapMode_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "mipmapMode must be a valid VkSamplerMipmapMode value"
+            }
+
+            // ensured by SamplerMipmapMode type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_addressModeU_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "addressModeU must be a valid VkSamplerAddressMode value"
+            }
+
+            // ensured by SamplerAddressMode type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_addressModeV_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "addressModeV must be a valid VkSamplerAddressMode value"
+            }
+
+            // ensured by SamplerAddressMode type
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSamplerCreateInfo_addressModeW_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "addressModeW must be a valid VkSamplerAddressMode value"
+            }
+
+            // ensured by SamplerAddressMode type
+        }
+
+        unsafe {
+            Self::from_c(vk::SamplerCreateInfo {
+                s_type: vk::StructureType::SAMPLER_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::SamplerCreateFlags::empty(),
+                mag_filter: Mag::VALUE,
+                min_filter: Min::VALUE,
+                mipmap_mode: Mip::VALUE,
+                address_mode_u: A::VALUE,
+                address_mode_v: A::VALUE,
+                address_mode_w: A::VALUE,
+                mip_lod_bias: 0.0,
+                anisotropy_enable: vk::FALSE,
+                max_anisotropy: 1.0,
+                compare_enable: vk::FALSE,
+                compare_op: vk::CompareOp::NEVER,
+                min_lod: 0.0,
+                max_lod: vk::LOD_CLAMP_NONE,
+                border_color: vk::BorderColor::FLOAT_TRANSPARENT_BLACK,
+                unnormalized_coordinates: vk::FALSE,
+            })
+        }
+    }
+
+    /// Enable anisotropic filtering
+    ///
+    /// `max_anisotropy` must be between 1.0 and `maxSamplerAnisotropy` (inclusive).
+    ///
+    /// The `samplerAnisotropy` feature must be enabled on the Device which the Sampler is
+    /// created with, which is checked at compile time by [`create_sampler`](crate::vk::create_sampler).
+    pub fn anisotropy(
+        self,
+        properties: &PhysicalDeviceProperties<S>,
+        max_anisotropy: f32,
+    ) -> Result<SamplerCreateInfo<S, AnisotropyFeatures>, VulkanError> {
+        // written so that NaN is rejected
+        if !(1.0 <= max_anisotropy && max_anisotropy <= properties.limits.max_sampler_anisotropy) {
+            Err(VulkanError::LimitExceeded)?
+        }
+        let mut inner = self.inner;
+        inner.anisotropy_enable = vk::TRUE;
+        inner.max_anisotropy = max_anisotropy;
+        Ok(SamplerCreateInfo {
+            inner,
+            lifetimes: PhantomData,
+            types: PhantomData,
+        })
+    }
+}
+
+impl<S, R> SamplerCreateInfo<S, R> {
+    /// Set the LOD bias and clamping range
+    ///
+    /// `mip_lod_bias` must be within `±maxSamplerLodBias`, and `min_lod` must not
+    /// be greater than `max_lod`.
+    pub fn lod(
+        mut self,
+        properties: &PhysicalDeviceProperties<S>,
+        mip_lod_bias: f32,
+        min_lod: f32,
+        max_lod: f32,
+    ) -> Result<Self, VulkanError> {
+        // written so that NaN is rejected
+        if !(mip_lod_bias.abs() <= properties.limits.max_sampler_lod_bias) {
+            Err(VulkanError::LimitExceeded)?
+        }
+        // written so that NaN is rejected
+        if !(min_lod <= max_lod) {
+            Err(VulkanError::InvalidLodRange)?
+        }
+        self.inner.mip_lod_bias = mip_lod_bias;
+        self.inner.min_lod = min_lod;
+        self.inner.max_lod = max_lod;
+        Ok(self)
+    }
+
+    /// Enable comparison against a reference value during lookups
+    pub fn compare<C: CompareOp>(mut self, _compare_op: C) -> Self {
+        self.inner.compare_enable = vk::TRUE;
+        self.inner.compare_op = C::VALUE;
+        self
+    }
+
+    /// Set the border color used with `CLAMP_TO_BORDER`
+    ///
+    /// **⚠️ Custom border colors are not supported**
+    pub fn border_color<B: BorderColor>(mut self, _border_color: B) -> Self {
+        const {
+            assert!(
+                !B::VALUE.is(vk::border_color::FLOAT_CUSTOM_EXT)
+                    && !B::VALUE.is(vk::border_color::INT_CUSTOM_EXT),
+                "custom border colors are not supported by vk-safe at this time"
+            );
+        }
+        self.inner.border_color = B::VALUE;
+        self
+    }
+}
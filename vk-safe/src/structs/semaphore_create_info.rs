@@ -0,0 +1,77 @@
+use crate::type_conversions::ConvertWrapper;
+
+use vk_safe_sys as vk;
+
+struct_wrapper!(
+/// Info for creating a [`Semaphore`](crate::vk::Semaphore)
+///
+/// Only binary Semaphores are supported at this time.
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkSemaphoreCreateInfo.html>
+SemaphoreCreateInfo
+impl Deref, Debug
+);
+
+impl SemaphoreCreateInfo {
+    pub fn new() -> Self {
+        check_vuids::check_vuids!(SemaphoreCreateInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkSemaphoreCreateInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSemaphoreCreateInfo_pNext_pNext: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each pNext member of any structure (including this one) in the pNext chain must be"
+            "either NULL or a pointer to a valid instance of VkExportMetalObjectCreateInfoEXT,"
+            "VkExportSemaphoreCreateInfo, VkExportSemaphoreWin32HandleInfoKHR, VkImportMetalSharedEventInfoEXT,"
+            "or VkSemaphoreTypeCreateInfo"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSemaphoreCreateInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique, with the exception"
+            "of structures of type VkExportMetalObjectCreateInfoEXT"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSemaphoreCreateInfo_flags_zerobitmask: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "flags must be 0"
+            }
+
+            // set to empty below
+        }
+
+        unsafe {
+            Self::from_c(vk::SemaphoreCreateInfo {
+                s_type: vk::StructureType::SEMAPHORE_CREATE_INFO,
+                p_next: std::ptr::null(),
+                flags: vk::SemaphoreCreateFlags::empty(),
+            })
+        }
+    }
+}
+
+impl Default for SemaphoreCreateInfo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
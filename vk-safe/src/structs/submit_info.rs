@@ -0,0 +1,493 @@
+use crate::enumerations::CommandBufferLevel::PRIMARY;
+use crate::handles::command_buffer::_CommandBuffer;
+use crate::handles::command_buffer::command_buffer_state::Executable;
+use crate::handles::semaphore::{Semaphore, SignaledSemaphore};
+
+pub(crate) use private::{OneTimeSubmit, SignalSemaphores, SubmitCommandBuffers, WaitSemaphores};
+
+use vk_safe_sys as vk;
+
+use vk::flag_traits::{CommandBufferUsageFlags, PipelineStageFlags};
+
+/// Info for submitting CommandBuffers to a Queue
+///
+/// used with [`queue_submit`](crate::vk::Queue::queue_submit)
+///
+/// Unlike most other "Info" structs, SubmitInfo takes ownership of the CommandBuffers and
+/// Semaphores. They are held by the resulting [`Submission`](crate::vk::Submission), and
+/// returned once the submitted work has completed.
+///
+/// Use [`new`](Self::new) for CommandBuffers which can be submitted again, and
+/// [`new_one_time`](Self::new_one_time) for CommandBuffers which were begun with
+/// `ONE_TIME_SUBMIT_BIT`. Semaphores to wait on and to signal are added with
+/// [`wait_semaphores`](Self::wait_semaphores) and [`signal_semaphores`](Self::signal_semaphores).
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkSubmitInfo.html>
+#[derive(Debug)]
+pub struct SubmitInfo<C, W = (), S = ()> {
+    pub(crate) command_buffers: C,
+    pub(crate) wait_semaphores: W,
+    pub(crate) signal_semaphores: S,
+}
+
+impl<'a, D, P, U: CommandBufferUsageFlags, const N: usize>
+    SubmitInfo<[_CommandBuffer<'a, D, P, PRIMARY, Executable<U>>; N]>
+{
+    /// Submit info for CommandBuffers which can be submitted again
+    ///
+    /// The CommandBuffers are returned in the [`Executable`] state when the submission completes.
+    pub fn new(command_buffers: [_CommandBuffer<'a, D, P, PRIMARY, Executable<U>>; N]) -> Self {
+        const {
+            assert!(
+                U::INCLUDES.excludes(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT_BIT),
+                "CommandBuffers begun with ONE_TIME_SUBMIT_BIT must use SubmitInfo::new_one_time"
+            );
+        }
+
+        Self::new_inner(command_buffers)
+    }
+}
+
+impl<'a, D, P, U: CommandBufferUsageFlags, const N: usize>
+    SubmitInfo<OneTimeSubmit<[_CommandBuffer<'a, D, P, PRIMARY, Executable<U>>; N]>>
+{
+    /// Submit info for CommandBuffers which were begun with `ONE_TIME_SUBMIT_BIT`
+    ///
+    /// The CommandBuffers are returned in the [`Invalid`](crate::vk::command_buffer_state::Invalid)
+    /// state when the submission completes.
+    pub fn new_one_time(
+        command_buffers: [_CommandBuffer<'a, D, P, PRIMARY, Executable<U>>; N],
+    ) -> Self {
+        const {
+            assert!(
+                U::INCLUDES.contains(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT_BIT),
+                "SubmitInfo::new_one_time is only for CommandBuffers begun with ONE_TIME_SUBMIT_BIT"
+            );
+        }
+
+        Self::new_inner(OneTimeSubmit(command_buffers))
+    }
+}
+
+impl<C> SubmitInfo<C> {
+    fn new_inner(command_buffers: C) -> Self {
+        check_vuids::check_vuids!(SubmitInfo);
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pCommandBuffers_00075: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each element of pCommandBuffers must not have been allocated with VK_COMMAND_BUFFER_LEVEL_SECONDARY"
+            }
+
+            // only PRIMARY CommandBuffers are accepted
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04090: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the geometryShader feature is not enabled, pWaitDstStageMask must not contain"
+            "VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT"
+            }
+
+            // checked in wait_semaphores
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04091: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the tessellationShader feature is not enabled, pWaitDstStageMask must not contain"
+            "VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT or VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT"
+            }
+
+            // checked in wait_semaphores
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04092: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the conditionalRendering feature is not enabled, pWaitDstStageMask must not contain"
+            "VK_PIPELINE_STAGE_CONDITIONAL_RENDERING_BIT_EXT"
+            }
+
+            // checked in wait_semaphores (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04093: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the fragmentDensityMap feature is not enabled, pWaitDstStageMask must not contain"
+            "VK_PIPELINE_STAGE_FRAGMENT_DENSITY_PROCESS_BIT_EXT"
+            }
+
+            // checked in wait_semaphores (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04094: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the transformFeedback feature is not enabled, pWaitDstStageMask must not contain"
+            "VK_PIPELINE_STAGE_TRANSFORM_FEEDBACK_BIT_EXT"
+            }
+
+            // checked in wait_semaphores (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04095: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the meshShader feature is not enabled, pWaitDstStageMask must not contain VK_PIPELINE_STAGE_MESH_SHADER_BIT_EXT"
+            }
+
+            // checked in wait_semaphores (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04096: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the taskShader feature is not enabled, pWaitDstStageMask must not contain VK_PIPELINE_STAGE_TASK_SHADER_BIT_EXT"
+            }
+
+            // checked in wait_semaphores (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_04097: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If neither the shadingRateImage or attachmentFragmentShadingRate are enabled, pWaitDstStageMask"
+            "must not contain VK_PIPELINE_STAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR"
+            }
+
+            // checked in wait_semaphores (only core 1.0 stages are allowed)
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_03937: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the synchronization2 feature is not enabled, pWaitDstStageMask must not be 0"
+            }
+
+            // checked in wait_semaphores
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_00078: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each element of pWaitDstStageMask must not include VK_PIPELINE_STAGE_HOST_BIT"
+            }
+
+            // checked in wait_semaphores
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitSemaphores_03239: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If any element of pWaitSemaphores or pSignalSemaphores was created with a VkSemaphoreType"
+            "of VK_SEMAPHORE_TYPE_TIMELINE, then the pNext chain must include a VkTimelineSemaphoreSubmitInfo"
+            "structure"
+            }
+
+            // only binary Semaphores are supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pCommandBuffers_06193: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If pCommandBuffers contains any resumed render pass instances, they must be suspended"
+            "by a render pass instance earlier in submission order within pCommandBuffers"
+            }
+
+            // render passes are not supported at this time
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_SUBMIT_INFO"
+            }
+
+            // set in queue_submit
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_sType_unique: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "The sType value of each struct in the pNext chain must be unique"
+            }
+
+            // TODO: p_next not currently supported
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitSemaphores_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If waitSemaphoreCount is not 0, pWaitSemaphores must be a valid pointer to an array"
+            "of waitSemaphoreCount valid VkSemaphore handles"
+            }
+
+            // ensured by Semaphore creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pWaitDstStageMask_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If waitSemaphoreCount is not 0, pWaitDstStageMask must be a valid pointer to an array"
+            "of waitSemaphoreCount valid combinations of VkPipelineStageFlagBits values"
+            }
+
+            // one stage mask is provided for each wait Semaphore
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pCommandBuffers_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If commandBufferCount is not 0, pCommandBuffers must be a valid pointer to an array"
+            "of commandBufferCount valid VkCommandBuffer handles"
+            }
+
+            // ensured by CommandBuffer allocation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_pSignalSemaphores_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If signalSemaphoreCount is not 0, pSignalSemaphores must be a valid pointer to an"
+            "array of signalSemaphoreCount valid VkSemaphore handles"
+            }
+
+            // ensured by Semaphore creation
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkSubmitInfo_commonparent: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "Each of the elements of pCommandBuffers, the elements of pSignalSemaphores, and the"
+            "elements of pWaitSemaphores that are valid handles of non-ignored parameters must"
+            "have been created, allocated, or retrieved from the same VkDevice"
+            }
+
+            // ensured in queue_submit, where everything must have the same Device as the Queue
+        }
+
+        Self {
+            command_buffers,
+            wait_semaphores: (),
+            signal_semaphores: (),
+        }
+    }
+}
+
+impl<C, S> SubmitInfo<C, (), S> {
+    /// Wait on signaled Semaphores before the CommandBuffers execute `stage`
+    ///
+    /// Must use the [`flags!`](crate::flags!) macro to declare the stage flags. The same
+    /// stage flags are used for each Semaphore. The Semaphores are returned unsignaled when
+    /// the submission completes.
+    pub fn wait_semaphores<Sem: Semaphore, P: PipelineStageFlags, const N: usize>(
+        self,
+        semaphores: [SignaledSemaphore<Sem>; N],
+        stage: P,
+    ) -> SubmitInfo<C, ([SignaledSemaphore<Sem>; N], P), S> {
+        const {
+            assert!(P::INCLUDES.is_not_empty(), "wait stage must not be empty");
+            assert!(
+                P::INCLUDES.excludes(vk::PipelineStageFlags::HOST_BIT),
+                "wait stage must not include HOST_BIT"
+            );
+            // the Device of the command buffers is not known here, so assume the geometryShader
+            // and tessellationShader features are not enabled
+            assert!(
+                P::INCLUDES.excludes(
+                    vk::PipelineStageFlags::GEOMETRY_SHADER_BIT
+                        .or(vk::PipelineStageFlags::TESSELLATION_CONTROL_SHADER_BIT)
+                        .or(vk::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER_BIT)
+                ),
+                "geometry and tessellation stages require features which are not enabled"
+            );
+            assert!(
+                P::INCLUDES.subset_of(crate::handles::queue::CORE_PIPELINE_STAGES),
+                "only core Vulkan 1.0 pipeline stages are supported"
+            );
+        }
+
+        SubmitInfo {
+            command_buffers: self.command_buffers,
+            wait_semaphores: (semaphores, stage),
+            signal_semaphores: self.signal_semaphores,
+        }
+    }
+}
+
+impl<C, W> SubmitInfo<C, W, ()> {
+    /// Signal unsignaled Semaphores once the CommandBuffers have completed execution
+    ///
+    /// The Semaphores are returned as [`SignaledSemaphore`] when the submission completes.
+    pub fn signal_semaphores<Sem: Semaphore, const N: usize>(
+        self,
+        semaphores: [Sem; N],
+    ) -> SubmitInfo<C, W, [Sem; N]> {
+        SubmitInfo {
+            command_buffers: self.command_buffers,
+            wait_semaphores: self.wait_semaphores,
+            signal_semaphores: semaphores,
+        }
+    }
+}
+
+mod private {
+    use crate::enumerations::CommandBufferLevel::PRIMARY;
+    use crate::handles::command_buffer::_CommandBuffer;
+    use crate::handles::command_buffer::command_buffer_state::{Executable, Invalid};
+    use crate::handles::command_pool::CommandPool;
+    use crate::handles::semaphore::{Semaphore, SignaledSemaphore};
+    use crate::handles::Handle;
+
+    use vk_safe_sys as vk;
+
+    use vk::flag_traits::PipelineStageFlags;
+
+    /// CommandBuffers of a [`SubmitInfo`](super::SubmitInfo)
+    pub trait SubmitCommandBuffers<D> {
+        type Pool: CommandPool;
+        /// The CommandBuffers after the submission completes
+        type Completed;
+        type RawHandles: AsRef<[vk::CommandBuffer]>;
+        fn raw_handles(&self) -> Self::RawHandles;
+        /// The caller must ensure that the submission has completed
+        unsafe fn complete(self) -> Self::Completed;
+    }
+
+    impl<'a, D, P: CommandPool, U, const N: usize> SubmitCommandBuffers<D>
+        for [_CommandBuffer<'a, D, P, PRIMARY, Executable<U>>; N]
+    {
+        type Pool = P;
+        type Completed = Self;
+        type RawHandles = [vk::CommandBuffer; N];
+        fn raw_handles(&self) -> Self::RawHandles {
+            self.each_ref().map(|cb| cb.raw_handle())
+        }
+        unsafe fn complete(self) -> Self::Completed {
+            // the CommandBuffers return to the executable state
+            self
+        }
+    }
+
+    /// CommandBuffers which were begun with `ONE_TIME_SUBMIT_BIT`
+    ///
+    /// Made by [`SubmitInfo::new_one_time`](super::SubmitInfo::new_one_time)
+    #[derive(Debug)]
+    pub struct OneTimeSubmit<C>(pub(super) C);
+
+    impl<'a, D, P: CommandPool, U, const N: usize> SubmitCommandBuffers<D>
+        for OneTimeSubmit<[_CommandBuffer<'a, D, P, PRIMARY, Executable<U>>; N]>
+    {
+        type Pool = P;
+        type Completed = [_CommandBuffer<'a, D, P, PRIMARY, Invalid>; N];
+        type RawHandles = [vk::CommandBuffer; N];
+        fn raw_handles(&self) -> Self::RawHandles {
+            self.0.each_ref().map(|cb| cb.raw_handle())
+        }
+        unsafe fn complete(self) -> Self::Completed {
+            // the CommandBuffers were begun with ONE_TIME_SUBMIT_BIT, so the completed
+            // submission moves them to the invalid state
+            self.0.map(|cb| unsafe { cb.transition() })
+        }
+    }
+
+    /// Semaphores to wait on in a [`SubmitInfo`](super::SubmitInfo)
+    pub trait WaitSemaphores<D> {
+        /// All stages which are waited on
+        const STAGES: vk::PipelineStageFlags;
+        /// The Semaphores after the submission completes
+        type Completed;
+        type RawHandles: AsRef<[vk::Semaphore]>;
+        type RawStages: AsRef<[vk::PipelineStageFlags]>;
+        fn raw_handles(&self) -> Self::RawHandles;
+        fn raw_stages(&self) -> Self::RawStages;
+        /// The caller must ensure that the submission has completed
+        unsafe fn complete(self) -> Self::Completed;
+    }
+
+    impl<D> WaitSemaphores<D> for () {
+        const STAGES: vk::PipelineStageFlags = vk::PipelineStageFlags::empty();
+        type Completed = ();
+        type RawHandles = [vk::Semaphore; 0];
+        type RawStages = [vk::PipelineStageFlags; 0];
+        fn raw_handles(&self) -> Self::RawHandles {
+            []
+        }
+        fn raw_stages(&self) -> Self::RawStages {
+            []
+        }
+        unsafe fn complete(self) -> Self::Completed {}
+    }
+
+    impl<D, S: Semaphore<Device = D>, P: PipelineStageFlags, const N: usize> WaitSemaphores<D>
+        for ([SignaledSemaphore<S>; N], P)
+    {
+        const STAGES: vk::PipelineStageFlags = P::INCLUDES;
+        type Completed = [S; N];
+        type RawHandles = [vk::Semaphore; N];
+        type RawStages = [vk::PipelineStageFlags; N];
+        fn raw_handles(&self) -> Self::RawHandles {
+            self.0.each_ref().map(|s| s.raw_handle())
+        }
+        fn raw_stages(&self) -> Self::RawStages {
+            [P::INCLUDES; N]
+        }
+        unsafe fn complete(self) -> Self::Completed {
+            // the wait operations have completed, which unsignals the Semaphores
+            self.0.map(|s| s.into_inner())
+        }
+    }
+
+    /// Semaphores to signal in a [`SubmitInfo`](super::SubmitInfo)
+    pub trait SignalSemaphores<D> {
+        /// The Semaphores after the submission completes
+        type Completed;
+        type RawHandles: AsRef<[vk::Semaphore]>;
+        fn raw_handles(&self) -> Self::RawHandles;
+        /// The caller must ensure that the submission has completed
+        unsafe fn complete(self) -> Self::Completed;
+    }
+
+    impl<D> SignalSemaphores<D> for () {
+        type Completed = ();
+        type RawHandles = [vk::Semaphore; 0];
+        fn raw_handles(&self) -> Self::RawHandles {
+            []
+        }
+        unsafe fn complete(self) -> Self::Completed {}
+    }
+
+    impl<D, S: Semaphore<Device = D>, const N: usize> SignalSemaphores<D> for [S; N] {
+        type Completed = [SignaledSemaphore<S>; N];
+        type RawHandles = [vk::Semaphore; N];
+        fn raw_handles(&self) -> Self::RawHandles {
+            self.each_ref().map(|s| s.raw_handle())
+        }
+        unsafe fn complete(self) -> Self::Completed {
+            // the signal operations have completed
+            self.map(|s| SignaledSemaphore::new(s))
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use super::extension_chain::private::SafeExtends;
+
+use crate::error::VulkanError;
+use crate::type_conversions::ConvertWrapper;
+
+use std::marker::PhantomData;
+
+use vk_safe_sys as vk;
+
+use vk::{StructExtends, Stype};
+
+/// A validation feature to enable
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkValidationFeatureEnableEXT.html>
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationFeatureEnable {
+    /// GPU-assisted validation (e.g. of descriptor indexing and buffer device addresses)
+    GpuAssisted = 0,
+    /// reserve a descriptor set binding slot for GPU-assisted validation
+    GpuAssistedReserveBindingSlot = 1,
+    /// warnings for usage which is valid but not recommended
+    BestPractices = 2,
+    /// debugPrintfEXT in shaders
+    DebugPrintf = 3,
+    /// synchronization validation (e.g. data races between commands)
+    SynchronizationValidation = 4,
+}
+
+/// A validation feature to disable
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkValidationFeatureDisableEXT.html>
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationFeatureDisable {
+    /// all validation
+    All = 0,
+    /// shader validation
+    Shaders = 1,
+    /// thread safety validation
+    ThreadSafety = 2,
+    /// validation of parameters of commands
+    ApiParameters = 3,
+    /// object lifetime validation
+    ObjectLifetimes = 4,
+    /// core validation checks
+    CoreChecks = 5,
+    /// handle wrapping
+    UniqueHandles = 6,
+    /// caching of shader validation results
+    ShaderValidationCache = 7,
+}
+
+struct_wrapper!(
+/// Enable or disable features of the validation layer
+///
+/// To be added to the pNext chain of [`InstanceCreateInfo`](crate::vk::InstanceCreateInfo) with
+/// [`push_next`](crate::vk::InstanceCreateInfo::push_next). Requires `EXT_validation_features` in
+/// the instance context, which is provided by the validation layer.
+///
+/// ```
+/// # use vk_safe::vk;
+/// # vk::instance_context!(InstanceContext: VERSION_1_0 + EXT_validation_features);
+/// use vk::ValidationFeatureEnable::*;
+///
+/// let enabled = [GpuAssisted, BestPractices];
+/// let mut validation_features = vk::ValidationFeaturesEXT::new(&enabled, &[]).unwrap();
+///
+/// let app_info = vk::ApplicationInfo::new(InstanceContext);
+/// let instance_info =
+///     vk::InstanceCreateInfo::new(&app_info).push_next(&mut validation_features);
+/// ```
+///
+/// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkValidationFeaturesEXT.html>
+ValidationFeaturesEXT<'a,>
+impl Deref, Debug
+);
+
+impl<'a> ValidationFeaturesEXT<'a> {
+    /// Enable the validation features in `enabled`, and disable the validation features in `disabled`
+    ///
+    /// Returns [`VulkanError::IncompatibleValidationFeatures`] if `enabled` includes features which
+    /// cannot be used together.
+    pub fn new(
+        enabled: &'a [ValidationFeatureEnable],
+        disabled: &'a [ValidationFeatureDisable],
+    ) -> Result<Self, VulkanError> {
+        check_vuids::check_vuids!(ValidationFeaturesEXT);
+
+        #[allow(unused_labels)]
+        'VUID_VkValidationFeaturesEXT_pEnabledValidationFeatures_02967: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pEnabledValidationFeatures array contains VK_VALIDATION_FEATURE_ENABLE_GPU_ASSISTED_RESERVE_BINDING_SLOT_EXT,"
+            "then it must also contain VK_VALIDATION_FEATURE_ENABLE_GPU_ASSISTED_EXT"
+            }
+
+            if enabled.contains(&ValidationFeatureEnable::GpuAssistedReserveBindingSlot)
+                && !enabled.contains(&ValidationFeatureEnable::GpuAssisted)
+            {
+                Err(VulkanError::IncompatibleValidationFeatures)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkValidationFeaturesEXT_pEnabledValidationFeatures_02968: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If the pEnabledValidationFeatures array contains VK_VALIDATION_FEATURE_ENABLE_DEBUG_PRINTF_EXT,"
+            "then it must not contain VK_VALIDATION_FEATURE_ENABLE_GPU_ASSISTED_EXT"
+            }
+
+            if enabled.contains(&ValidationFeatureEnable::DebugPrintf)
+                && enabled.contains(&ValidationFeatureEnable::GpuAssisted)
+            {
+                Err(VulkanError::IncompatibleValidationFeatures)?
+            }
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkValidationFeaturesEXT_sType_sType: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "sType must be VK_STRUCTURE_TYPE_VALIDATION_FEATURES_EXT"
+            }
+
+            // set below
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkValidationFeaturesEXT_pEnabledValidationFeatures_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If enabledValidationFeatureCount is not 0, pEnabledValidationFeatures must be a valid"
+            "pointer to an array of enabledValidationFeatureCount valid VkValidationFeatureEnableEXT"
+            "values"
+            }
+
+            // rust slice of ValidationFeatureEnable, which only has valid values
+        }
+
+        #[allow(unused_labels)]
+        'VUID_VkValidationFeaturesEXT_pDisabledValidationFeatures_parameter: {
+            check_vuids::version! {"1.3.268"}
+            check_vuids::description! {
+            "If disabledValidationFeatureCount is not 0, pDisabledValidationFeatures must be a valid"
+            "pointer to an array of disabledValidationFeatureCount valid VkValidationFeatureDisableEXT"
+            "values"
+            }
+
+            // rust slice of ValidationFeatureDisable, which only has valid values
+        }
+
+        Ok(Self::from_c(vk::ValidationFeaturesEXT {
+            s_type: vk::ValidationFeaturesEXT::S_TYPE,
+            p_next: std::ptr::null(),
+            enabled_validation_feature_count: enabled.len().try_into()?,
+            // ValidationFeatureEnable is repr(i32) with the values of VkValidationFeatureEnableEXT
+            p_enabled_validation_features: enabled.as_ptr().cast(),
+            disabled_validation_feature_count: disabled.len().try_into()?,
+            // ValidationFeatureDisable is repr(i32) with the values of VkValidationFeatureDisableEXT
+            p_disabled_validation_features: disabled.as_ptr().cast(),
+        }))
+    }
+}
+
+// SAFETY: ValidationFeaturesEXT is repr(transparent) of vk::ValidationFeaturesEXT
+unsafe impl Stype for ValidationFeaturesEXT<'_> {
+    const S_TYPE: vk::StructureType = vk::ValidationFeaturesEXT::S_TYPE;
+}
+
+unsafe impl StructExtends<vk::InstanceCreateInfo> for ValidationFeaturesEXT<'_> {}
+
+// SAFETY: ValidationFeaturesEXT borrows the arrays it points to
+unsafe impl SafeExtends<vk::InstanceCreateInfo> for ValidationFeaturesEXT<'_> {}
@@ -20,6 +20,11 @@ impl<'a> VkStr<'a> {
     pub const fn as_ptr(&self) -> *const c_char {
         self.0.as_ptr().cast()
     }
+
+    /// get the str without the null terminator
+    pub fn as_str(&self) -> &'a str {
+        &self.0[..self.0.len() - 1]
+    }
 }
 
 impl std::cmp::PartialEq<vk_safe_sys::VkStrRaw> for VkStr<'_> {
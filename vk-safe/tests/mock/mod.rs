@@ -0,0 +1,2684 @@
+//! In-process mock Vulkan implementation
+//!
+//! [`MockIcd`] provides function loaders, in the form of [`vk::FunctionLoader`], for the commands
+//! of the mock. The loader of the exported symbols provides `vkGetInstanceProcAddr`, from which an
+//! [`Entry`] is made, so that vk-safe can be tested without a GPU or Vulkan loader.
+//! `vkGetInstanceProcAddr` and `vkGetDeviceProcAddr` use the loaders of the global, instance and
+//! device commands. The mock serves the physical devices, queue families and memory types
+//! described by a [`MockConfig`], and tracks every object that is created, so that tests can
+//! check that everything is destroyed.
+//!
+//! Errors can be injected with [`MockIcd::inject`], which makes the next call of a command
+//! return the given result instead of doing anything. Injecting `VK_INCOMPLETE` for an
+//! enumeration command makes the next count query report one item less than is available,
+//! as if an item was added between the two calls of the enumeration.
+//!
+//! `vkCreateInstance` fails with `VK_ERROR_LAYER_NOT_PRESENT` if an enabled layer is not in
+//! [`MockConfig::instance_layers`], and records the enabled layers, which can be checked with
+//! [`MockIcd::enabled_layers`].
+//!
+//! `vkCreateDebugUtilsMessengerEXT` registers the callback of a debug messenger, and
+//! [`MockIcd::report`] sends a message (e.g. a validation error) to the registered callbacks
+//! which select the message severity and type. Debug messengers in the pNext chain of
+//! `VkInstanceCreateInfo` are sent an info message from `vkCreateInstance` and `vkDestroyInstance`.
+//! Names given to objects with `vkSetDebugUtilsObjectNameEXT` are included in the reported
+//! messages, and can be checked with [`MockIcd::object_name`], as can tags with
+//! [`MockIcd::object_tag`]. Queue labels are recorded, and can be checked with [`MockIcd::labels`].
+//!
+//! The sType of each structure in the pNext chain of `VkInstanceCreateInfo` and
+//! `VkDeviceCreateInfo` (and of the base structure of physical device queries) is recorded,
+//! and can be checked with [`MockIcd::p_next_chain`]. Queries with a pNext chain write the output structures which
+//! are known by the mock (`VkPhysicalDeviceVulkan12Features`, `VkPhysicalDeviceDriverProperties`
+//! and `VkQueueFamilyGlobalPriorityPropertiesKHR`), and leave any others as they are.
+//!
+//! The features reported by `vkGetPhysicalDeviceFeatures` are the features of the
+//! [`MockPhysicalDevice`]. `vkCreateDevice` fails with `VK_ERROR_FEATURE_NOT_PRESENT` if a
+//! feature in `pEnabledFeatures` is not supported, and records the enabled features, which
+//! can be checked with [`MockIcd::enabled_features`], and the enabled extensions, which can be
+//! checked with [`MockIcd::enabled_device_extensions`].
+//!
+//! Buffers can be bound to memory of any type, and images only to `DEVICE_LOCAL` memory types.
+//! Binding memory which does not meet the memory requirements panics, and the memory and
+//! offset which an object is bound to can be checked with [`MockIcd::binding`].
+//!
+//! Command buffers go through the states of the Vulkan command buffer lifecycle, and using a
+//! command buffer in the wrong state panics. The commands which are recorded in a command buffer
+//! can be checked with [`MockIcd::recorded`]. `vkQueueSubmit` executes the command buffers
+//! immediately: image layout transitions are applied (and the old layout must be the current
+//! layout of the image, or `VK_IMAGE_LAYOUT_UNDEFINED`), waited semaphores must be signaled and
+//! are unsignaled, and the signal semaphores and fence are signaled. The layout of an image can
+//! be checked with [`MockIcd::image_layout`]. `vkWaitForFences` panics if a fence is not signaled,
+//! unless `VK_TIMEOUT` is injected.
+//!
+//! When a command is given `VkAllocationCallbacks`, the mock allocates some host memory for
+//! the created object with the callbacks, and frees it with the callbacks that are given when
+//! the object is destroyed. Destroying an object with or without callbacks, differently from
+//! when it was created, panics.
+//!
+//! The state of the mock is thread local, and only one MockIcd can exist per thread at a time.
+//! Commands which are not implemented by the mock can be loaded (so that any version can be
+//! loaded) but abort the process if called.
+
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::marker::PhantomData;
+
+use vk_safe::raw as vk;
+use vk_safe::vk::Entry;
+
+/// Configuration of the mock implementation
+#[derive(Clone)]
+pub struct MockConfig {
+    /// Version returned by vkEnumerateInstanceVersion
+    pub api_version: (u32, u32, u32),
+    /// Names of the supported instance extensions
+    pub instance_extensions: Vec<&'static str>,
+    /// Names of the available instance layers
+    pub instance_layers: Vec<&'static str>,
+    pub physical_devices: Vec<MockPhysicalDevice>,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            api_version: (1, 3, 0),
+            instance_extensions: vec![],
+            instance_layers: vec![],
+            physical_devices: vec![MockPhysicalDevice::default()],
+        }
+    }
+}
+
+/// Configuration of a mock PhysicalDevice
+#[derive(Clone)]
+pub struct MockPhysicalDevice {
+    pub name: &'static str,
+    /// Names of the supported device extensions
+    pub extensions: Vec<&'static str>,
+    /// Flags and queue count of each queue family
+    pub queue_families: Vec<(vk::QueueFlags, u32)>,
+    /// Property flags and heap index of each memory type
+    pub memory_types: Vec<(vk::MemoryPropertyFlags, u32)>,
+    /// Size and flags of each memory heap
+    pub memory_heaps: Vec<(vk::DeviceSize, vk::MemoryHeapFlags)>,
+    /// Supported features
+    pub features: vk::PhysicalDeviceFeatures,
+}
+
+impl Default for MockPhysicalDevice {
+    fn default() -> Self {
+        Self {
+            name: "Mock Device",
+            extensions: vec![],
+            queue_families: vec![(
+                vk::QueueFlags::GRAPHICS_BIT
+                    | vk::QueueFlags::COMPUTE_BIT
+                    | vk::QueueFlags::TRANSFER_BIT,
+                2,
+            )],
+            memory_types: vec![
+                (vk::MemoryPropertyFlags::DEVICE_LOCAL_BIT, 0),
+                (
+                    vk::MemoryPropertyFlags::HOST_VISIBLE_BIT
+                        | vk::MemoryPropertyFlags::HOST_COHERENT_BIT,
+                    1,
+                ),
+            ],
+            memory_heaps: vec![
+                (1 << 30, vk::MemoryHeapFlags::DEVICE_LOCAL_BIT),
+                (1 << 30, vk::MemoryHeapFlags::empty()),
+            ],
+            // no optional features are supported
+            features: unsafe { std::mem::zeroed() },
+        }
+    }
+}
+
+/// Guard for the mock implementation of the current thread
+///
+/// Creating the MockIcd installs the mock for the current thread, and dropping it
+/// uninstalls the mock.
+pub struct MockIcd {
+    // the state is thread local
+    not_send: PhantomData<*const ()>,
+}
+
+impl MockIcd {
+    pub fn new(config: MockConfig) -> Self {
+        STATE.with_borrow_mut(|state| {
+            assert!(state.is_none(), "a MockIcd already exists on this thread");
+            *state = Some(State::new(config));
+        });
+        Self {
+            not_send: PhantomData,
+        }
+    }
+
+    /// Entry which uses the mock implementation
+    pub fn entry(&self) -> Entry {
+        // SAFETY: the mock commands behave as the respective Vulkan commands for the
+        // usage in the tests
+        unsafe { Entry::from_loader(exported_symbols) }
+            .expect("the mock exports vkGetInstanceProcAddr")
+    }
+
+    /// Make the next call to `command` return `result`
+    ///
+    /// Multiple results can be injected for the same command, and are returned in order.
+    pub fn inject(&self, command: &'static str, result: vk::Result) {
+        with_state(|state| state.injected.entry(command).or_default().push_back(result));
+    }
+
+    /// Number of objects created through the mock which have not been destroyed
+    pub fn live_objects(&self) -> usize {
+        with_state(|state| state.objects.len())
+    }
+
+    /// Number of times `command` was called
+    pub fn calls(&self, command: &'static str) -> usize {
+        with_state(|state| state.calls.get(command).copied().unwrap_or(0))
+    }
+
+    /// sType of each structure in the pNext chain of the create info, in the last call to `command`
+    pub fn p_next_chain(&self, command: &'static str) -> Vec<vk::StructureType> {
+        with_state(|state| {
+            state
+                .p_next_chains
+                .get(command)
+                .cloned()
+                .unwrap_or_default()
+        })
+    }
+
+    /// Features enabled by the last call to vkCreateDevice
+    pub fn enabled_features(&self) -> vk::PhysicalDeviceFeatures {
+        with_state(|state| state.enabled_features)
+    }
+
+    /// Extensions enabled by the last call to vkCreateDevice
+    pub fn enabled_device_extensions(&self) -> Vec<String> {
+        with_state(|state| state.enabled_device_extensions.clone())
+    }
+
+    /// Layers enabled by the last call to vkCreateInstance
+    pub fn enabled_layers(&self) -> Vec<String> {
+        with_state(|state| state.enabled_layers.clone())
+    }
+
+    /// Send a message to the callbacks of the debug messengers which are not destroyed
+    ///
+    /// `objects` are the type and raw handle of each object which the message is about.
+    pub fn report(
+        &self,
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        types: vk::DebugUtilsMessageTypeFlagsEXT,
+        message_id_name: &str,
+        message: &str,
+        objects: &[(vk::ObjectType, u64)],
+    ) {
+        let (messengers, names): (Vec<_>, Vec<_>) = with_state(|state| {
+            (
+                state.messengers.values().copied().collect(),
+                objects
+                    .iter()
+                    .map(|(_, handle)| state.object_names.get(handle).cloned())
+                    .collect(),
+            )
+        });
+        let objects: Vec<_> = objects
+            .iter()
+            .zip(&names)
+            .map(|(&(object_type, handle), name)| (object_type, handle, name.as_deref()))
+            .collect();
+        send_message(
+            &messengers,
+            severity,
+            types,
+            message_id_name,
+            message,
+            &objects,
+        );
+    }
+
+    /// Name given to the object with `handle` by vkSetDebugUtilsObjectNameEXT
+    pub fn object_name(&self, handle: u64) -> Option<String> {
+        with_state(|state| {
+            state
+                .object_names
+                .get(&handle)
+                .map(|name| name.to_str().unwrap().to_string())
+        })
+    }
+
+    /// Tag name and data given to the object with `handle` by vkSetDebugUtilsObjectTagEXT
+    pub fn object_tag(&self, handle: u64) -> Option<(u64, Vec<u8>)> {
+        with_state(|state| state.object_tags.get(&handle).cloned())
+    }
+
+    /// Queue labels in the order they were begun (`"begin <name>"`) and ended (`"end"`)
+    pub fn labels(&self) -> Vec<String> {
+        with_state(|state| state.labels.clone())
+    }
+
+    /// Raw handle of the memory, and the offset, which the buffer or image with `handle` is bound to
+    pub fn binding(&self, handle: u64) -> Option<(u64, vk::DeviceSize)> {
+        with_state(|state| state.bindings.get(&handle).copied())
+    }
+
+    /// Commands recorded in the command buffer with `handle`, since it last began recording
+    pub fn recorded(&self, handle: u64) -> Vec<Recorded> {
+        with_state(|state| {
+            state
+                .command_buffers
+                .get(&handle)
+                .expect("invalid CommandBuffer handle")
+                .recorded
+                .clone()
+        })
+    }
+
+    /// Layout of the image with `handle`, after the submitted layout transitions
+    pub fn image_layout(&self, handle: u64) -> vk::ImageLayout {
+        with_state(|state| {
+            state
+                .image_layouts
+                .get(&handle)
+                .copied()
+                .expect("invalid Image handle")
+        })
+    }
+
+    /// Memory handle, offset and size of each range which was flushed (`"flush"`) or
+    /// invalidated (`"invalidate"`)
+    pub fn mapped_memory_ranges(&self) -> Vec<(&'static str, u64, vk::DeviceSize, vk::DeviceSize)> {
+        with_state(|state| state.mapped_memory_ranges.clone())
+    }
+}
+
+/// A command which is recorded in a command buffer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recorded {
+    /// Number of memory barriers, raw handles of the buffers of the buffer memory barriers, and
+    /// raw handle, old layout and new layout of the image of each image memory barrier
+    PipelineBarrier {
+        memory_barriers: usize,
+        buffers: Vec<u64>,
+        images: Vec<(u64, vk::ImageLayout, vk::ImageLayout)>,
+    },
+    /// Raw handle of the bound pipeline
+    BindPipeline(u64),
+    /// Number of workgroups in each dimension
+    Dispatch([u32; 3]),
+    /// Raw handles of the executed secondary command buffers
+    ExecuteCommands(Vec<u64>),
+}
+
+impl Drop for MockIcd {
+    fn drop(&mut self) {
+        STATE.with_borrow_mut(|state| *state = None);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Object {
+    Instance,
+    Device,
+    Memory,
+    Buffer,
+    Image,
+    ImageView,
+    Sampler,
+    CommandPool,
+    CommandBuffer,
+    Fence,
+    Semaphore,
+    ShaderModule,
+    DescriptorSetLayout,
+    PipelineLayout,
+    Pipeline,
+    DebugUtilsMessenger,
+}
+
+impl Object {
+    fn object_type(self) -> vk::ObjectType {
+        match self {
+            Self::Instance => vk::ObjectType::INSTANCE,
+            Self::Device => vk::ObjectType::DEVICE,
+            Self::Memory => vk::ObjectType::DEVICE_MEMORY,
+            Self::Buffer => vk::ObjectType::BUFFER,
+            Self::Image => vk::ObjectType::IMAGE,
+            Self::ImageView => vk::ObjectType::IMAGE_VIEW,
+            Self::Sampler => vk::ObjectType::SAMPLER,
+            Self::CommandPool => vk::ObjectType::COMMAND_POOL,
+            Self::CommandBuffer => vk::ObjectType::COMMAND_BUFFER,
+            Self::Fence => vk::ObjectType::FENCE,
+            Self::Semaphore => vk::ObjectType::SEMAPHORE,
+            Self::ShaderModule => vk::ObjectType::SHADER_MODULE,
+            Self::DescriptorSetLayout => vk::ObjectType::DESCRIPTOR_SET_LAYOUT,
+            Self::PipelineLayout => vk::ObjectType::PIPELINE_LAYOUT,
+            Self::Pipeline => vk::ObjectType::PIPELINE,
+            Self::DebugUtilsMessenger => vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT,
+        }
+    }
+}
+
+/// Callback of a debug messenger, and the messages it selects
+#[derive(Clone, Copy)]
+struct Messenger {
+    severities: vk::DebugUtilsMessageSeverityFlagsEXT,
+    types: vk::DebugUtilsMessageTypeFlagsEXT,
+    callback: vk::PFN_vkDebugUtilsMessengerCallbackEXT,
+    user_data: *mut c_void,
+}
+
+impl Messenger {
+    fn new(create_info: &vk::DebugUtilsMessengerCreateInfoEXT) -> Self {
+        Self {
+            severities: create_info.message_severity,
+            types: create_info.message_type,
+            callback: create_info.pfn_user_callback,
+            user_data: create_info.p_user_data,
+        }
+    }
+}
+
+/// Backing storage for DeviceMemory, aligned for any mapping
+#[derive(Clone, Copy)]
+#[repr(C, align(64))]
+struct Block([u8; 64]);
+
+/// DeviceMemory which is allocated from a memory type
+struct Allocation {
+    memory_type_index: u32,
+    size: vk::DeviceSize,
+    blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommandBufferState {
+    Initial,
+    Recording,
+    Executable,
+    Invalid,
+}
+
+/// A command buffer, and the commands which are recorded in it
+struct CommandBuffer {
+    pool: u64,
+    level: vk::CommandBufferLevel,
+    state: CommandBufferState,
+    one_time_submit: bool,
+    recorded: Vec<Recorded>,
+}
+
+struct State {
+    config: MockConfig,
+    injected: HashMap<&'static str, VecDeque<vk::Result>>,
+    calls: HashMap<&'static str, usize>,
+    objects: HashMap<u64, Object>,
+    memory: HashMap<u64, Allocation>,
+    devices: HashMap<u64, vk::PhysicalDevice>,
+    memory_requirements: HashMap<u64, vk::MemoryRequirements>,
+    bindings: HashMap<u64, (u64, vk::DeviceSize)>,
+    image_layouts: HashMap<u64, vk::ImageLayout>,
+    command_pools: HashMap<u64, vk::CommandPoolCreateFlags>,
+    command_buffers: HashMap<u64, CommandBuffer>,
+    fences: HashMap<u64, bool>,
+    semaphores: HashMap<u64, bool>,
+    mapped_memory_ranges: Vec<(&'static str, u64, vk::DeviceSize, vk::DeviceSize)>,
+    host_allocations: HashMap<u64, *mut c_void>,
+    p_next_chains: HashMap<&'static str, Vec<vk::StructureType>>,
+    enabled_features: vk::PhysicalDeviceFeatures,
+    enabled_device_extensions: Vec<String>,
+    enabled_layers: Vec<String>,
+    messengers: HashMap<u64, Messenger>,
+    instance_messengers: Vec<Messenger>,
+    object_names: HashMap<u64, CString>,
+    object_tags: HashMap<u64, (u64, Vec<u8>)>,
+    labels: Vec<String>,
+    open_queue_labels: HashMap<u64, usize>,
+    next_handle: u64,
+}
+
+impl State {
+    fn new(config: MockConfig) -> Self {
+        Self {
+            config,
+            injected: HashMap::new(),
+            calls: HashMap::new(),
+            objects: HashMap::new(),
+            memory: HashMap::new(),
+            devices: HashMap::new(),
+            memory_requirements: HashMap::new(),
+            bindings: HashMap::new(),
+            image_layouts: HashMap::new(),
+            command_pools: HashMap::new(),
+            command_buffers: HashMap::new(),
+            fences: HashMap::new(),
+            semaphores: HashMap::new(),
+            mapped_memory_ranges: vec![],
+            host_allocations: HashMap::new(),
+            p_next_chains: HashMap::new(),
+            enabled_features: unsafe { std::mem::zeroed() },
+            enabled_device_extensions: vec![],
+            enabled_layers: vec![],
+            messengers: HashMap::new(),
+            instance_messengers: vec![],
+            object_names: HashMap::new(),
+            object_tags: HashMap::new(),
+            labels: vec![],
+            open_queue_labels: HashMap::new(),
+            next_handle: 0x1000,
+        }
+    }
+
+    /// Record a call, and take the next injected result for the command (if any)
+    fn call(&mut self, command: &'static str) -> Option<vk::Result> {
+        *self.calls.entry(command).or_default() += 1;
+        self.injected.get_mut(command)?.pop_front()
+    }
+
+    /// Record the pNext chain which starts at `p_next` for `command`
+    unsafe fn record_p_next_chain(&mut self, command: &'static str, p_next: *const c_void) {
+        let mut chain = vec![];
+        let mut next: *const vk::BaseInStructure = p_next.cast();
+        while let Some(structure) = unsafe { next.as_ref() } {
+            chain.push(structure.s_type);
+            next = structure.p_next;
+        }
+        self.p_next_chains.insert(command, chain);
+    }
+
+    /// Create an object, with its host memory allocated by `allocator` (if not null)
+    unsafe fn create(
+        &mut self,
+        object: Object,
+        allocator: *const vk::AllocationCallbacks,
+    ) -> Result<u64, vk::Result> {
+        let handle = self.next_handle;
+        if let Some(allocator) = unsafe { allocator.as_ref() } {
+            let memory = unsafe {
+                allocator.pfn_allocation.get_fptr()(
+                    allocator.p_user_data,
+                    HOST_ALLOCATION_SIZE,
+                    HOST_ALLOCATION_ALIGNMENT,
+                    vk::SystemAllocationScope::OBJECT,
+                )
+            };
+            if memory.is_null() {
+                return Err(vk::Result::ERROR_OUT_OF_HOST_MEMORY);
+            }
+            self.host_allocations.insert(handle, memory);
+        }
+        self.next_handle += 1;
+        self.objects.insert(handle, object);
+        Ok(handle)
+    }
+
+    /// Destroy an object, and free its host memory with `allocator`
+    unsafe fn destroy(
+        &mut self,
+        handle: u64,
+        object: Object,
+        allocator: *const vk::AllocationCallbacks,
+    ) {
+        match self.objects.remove(&handle) {
+            Some(o) if o == object => {}
+            _ => panic!("destroying invalid {object:?} handle {handle:#x}"),
+        }
+        self.object_names.remove(&handle);
+        self.object_tags.remove(&handle);
+        self.memory_requirements.remove(&handle);
+        self.bindings.remove(&handle);
+        let allocator = unsafe { allocator.as_ref() };
+        match (self.host_allocations.remove(&handle), allocator) {
+            (None, None) => {}
+            (Some(memory), Some(allocator)) => unsafe {
+                allocator.pfn_free.get_fptr()(allocator.p_user_data, memory)
+            },
+            _ => panic!("{object:?} {handle:#x} destroyed with incompatible allocation callbacks"),
+        }
+    }
+
+    /// Check that `handle` is an object of `object_type`, if the object is tracked
+    fn check_object_type(&self, object_type: vk::ObjectType, handle: u64) {
+        assert!(
+            !object_type.is(vk::ObjectType::UNKNOWN),
+            "objectType must not be VK_OBJECT_TYPE_UNKNOWN"
+        );
+        if let Some(object) = self.objects.get(&handle) {
+            assert!(
+                object.object_type().is(object_type),
+                "{object:?} handle {handle:#x} is not of the given objectType"
+            );
+        }
+    }
+
+    fn physical_device(&self, physical_device: vk::PhysicalDevice) -> &MockPhysicalDevice {
+        let index = (physical_device.handle as usize)
+            .checked_sub(PHYSICAL_DEVICE_BASE)
+            .expect("invalid PhysicalDevice handle");
+        &self.config.physical_devices[index]
+    }
+
+    fn physical_device_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceProperties {
+        let (major, minor, patch) = self.config.api_version;
+        let mock = self.physical_device(physical_device);
+
+        let mut p: vk::PhysicalDeviceProperties = unsafe { std::mem::zeroed() };
+        p.api_version = vk_safe::VkVersion::new(major, minor, patch).raw();
+        p.device_name = c_name(mock.name);
+        p.limits.max_memory_allocation_count = 4096;
+        p.limits.non_coherent_atom_size = NON_COHERENT_ATOM_SIZE;
+        p.limits.min_memory_map_alignment = 64;
+        p.limits.max_bound_descriptor_sets = 4;
+        // the minimum limits which are required by Vulkan
+        p.limits.max_per_stage_descriptor_samplers = 16;
+        p.limits.max_per_stage_descriptor_uniform_buffers = 12;
+        p.limits.max_per_stage_descriptor_storage_buffers = 4;
+        p.limits.max_per_stage_descriptor_sampled_images = 16;
+        p.limits.max_per_stage_descriptor_storage_images = 4;
+        p.limits.max_per_stage_descriptor_input_attachments = 4;
+        p.limits.max_per_stage_resources = 128;
+        p.limits.max_descriptor_set_samplers = 96;
+        p.limits.max_descriptor_set_uniform_buffers = 72;
+        p.limits.max_descriptor_set_uniform_buffers_dynamic = 8;
+        p.limits.max_descriptor_set_storage_buffers = 24;
+        p.limits.max_descriptor_set_storage_buffers_dynamic = 4;
+        p.limits.max_descriptor_set_sampled_images = 96;
+        p.limits.max_descriptor_set_storage_images = 24;
+        p.limits.max_descriptor_set_input_attachments = 4;
+        p.limits.max_push_constants_size = 128;
+        p.limits.max_compute_work_group_count = [65535; 3];
+        p.limits.max_framebuffer_width = 4096;
+        p.limits.max_framebuffer_height = 4096;
+        p.limits.max_sampler_anisotropy = 1.0;
+        p.limits.max_sampler_lod_bias = 2.0;
+        p
+    }
+
+    /// Properties of every supported combination of image parameters
+    fn image_format_properties(&self, image_type: vk::ImageType) -> vk::ImageFormatProperties {
+        let mut p: vk::ImageFormatProperties = unsafe { std::mem::zeroed() };
+        p.max_extent = match image_type {
+            vk::ImageType::TYPE_1D => vk::Extent3D {
+                width: 4096,
+                height: 1,
+                depth: 1,
+            },
+            vk::ImageType::TYPE_2D => vk::Extent3D {
+                width: 4096,
+                height: 4096,
+                depth: 1,
+            },
+            _ => vk::Extent3D {
+                width: 256,
+                height: 256,
+                depth: 256,
+            },
+        };
+        p.max_mip_levels = 13;
+        p.max_array_layers = 256;
+        p.sample_counts = vk::SampleCountFlags::TYPE_1_BIT | vk::SampleCountFlags::TYPE_4_BIT;
+        p.max_resource_size = 1 << 31;
+        p
+    }
+
+    /// Memory types which have all of `property_flags`, for the PhysicalDevice of `device`
+    fn memory_type_bits(&self, device: vk::Device, property_flags: vk::MemoryPropertyFlags) -> u32 {
+        let physical_device = self.devices[&dispatchable_id(device.handle)];
+        self.physical_device(physical_device)
+            .memory_types
+            .iter()
+            .enumerate()
+            .filter(|(_, &(flags, _))| flags.contains(property_flags))
+            .fold(0, |bits, (i, _)| bits | 1 << i)
+    }
+
+    /// Bind memory to a buffer or image, which must meet its memory requirements
+    fn bind(&mut self, handle: u64, memory: u64, offset: vk::DeviceSize) {
+        let requirements = self.memory_requirements[&handle];
+        let allocation = self
+            .memory
+            .get(&memory)
+            .expect("invalid DeviceMemory handle");
+        assert!(
+            self.bindings.insert(handle, (memory, offset)).is_none(),
+            "{handle:#x} is already bound to memory"
+        );
+        assert!(
+            requirements.memory_type_bits & 1 << allocation.memory_type_index != 0,
+            "memory type is not supported by {handle:#x}"
+        );
+        assert!(
+            offset % requirements.alignment == 0,
+            "memoryOffset is not aligned for {handle:#x}"
+        );
+        assert!(
+            offset + requirements.size <= allocation.size,
+            "memory is too small for {handle:#x}"
+        );
+    }
+
+    /// Execute the recorded commands of a command buffer
+    fn execute(&mut self, recorded: &[Recorded]) {
+        for command in recorded {
+            match command {
+                Recorded::PipelineBarrier { images, .. } => {
+                    for &(image, old_layout, new_layout) in images {
+                        let layout = self
+                            .image_layouts
+                            .get_mut(&image)
+                            .expect("invalid Image handle");
+                        assert!(
+                            old_layout.is(vk::ImageLayout::UNDEFINED) || old_layout == *layout,
+                            "oldLayout {old_layout:?} is not the current layout {layout:?} of {image:#x}"
+                        );
+                        *layout = new_layout;
+                    }
+                }
+                Recorded::ExecuteCommands(secondaries) => {
+                    for secondary in secondaries {
+                        let recorded = self.command_buffers[secondary].recorded.clone();
+                        self.execute(&recorded);
+                    }
+                }
+                Recorded::BindPipeline(_) | Recorded::Dispatch(_) => {}
+            }
+        }
+    }
+
+    fn command_buffer(&mut self, command_buffer: vk::CommandBuffer) -> &mut CommandBuffer {
+        self.command_buffers
+            .get_mut(&dispatchable_id(command_buffer.handle))
+            .expect("invalid CommandBuffer handle")
+    }
+
+    /// Record a command, in a command buffer which must be recording
+    fn record(&mut self, command_buffer: vk::CommandBuffer, command: Recorded) {
+        let command_buffer = self.command_buffer(command_buffer);
+        assert_eq!(
+            command_buffer.state,
+            CommandBufferState::Recording,
+            "commands can only be recorded in the recording state"
+        );
+        command_buffer.recorded.push(command);
+    }
+
+    fn queue_family_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> Vec<vk::QueueFamilyProperties> {
+        self.physical_device(physical_device)
+            .queue_families
+            .iter()
+            .map(|&(queue_flags, queue_count)| {
+                let mut p: vk::QueueFamilyProperties = unsafe { std::mem::zeroed() };
+                p.queue_flags = queue_flags;
+                p.queue_count = queue_count;
+                p.timestamp_valid_bits = 64;
+                p.min_image_transfer_granularity = vk::Extent3D {
+                    width: 1,
+                    height: 1,
+                    depth: 1,
+                };
+                p
+            })
+            .collect()
+    }
+
+    fn memory_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceMemoryProperties {
+        let mock = self.physical_device(physical_device);
+
+        let mut p: vk::PhysicalDeviceMemoryProperties = unsafe { std::mem::zeroed() };
+        p.memory_type_count = mock.memory_types.len() as u32;
+        for (ty, &(property_flags, heap_index)) in p.memory_types.iter_mut().zip(&mock.memory_types)
+        {
+            ty.property_flags = property_flags;
+            ty.heap_index = heap_index;
+        }
+        p.memory_heap_count = mock.memory_heaps.len() as u32;
+        for (heap, &(size, flags)) in p.memory_heaps.iter_mut().zip(&mock.memory_heaps) {
+            heap.size = size;
+            heap.flags = flags;
+        }
+        p
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    STATE.with_borrow_mut(|state| f(state.as_mut().expect("no MockIcd on this thread")))
+}
+
+/// Size of the host memory which is allocated for an object with VkAllocationCallbacks
+const HOST_ALLOCATION_SIZE: usize = 64;
+const HOST_ALLOCATION_ALIGNMENT: usize = 8;
+
+/// Alignment and size granularity of the memory requirements of buffers and images
+const BUFFER_ALIGNMENT: vk::DeviceSize = 64;
+const IMAGE_ALIGNMENT: vk::DeviceSize = 256;
+
+/// nonCoherentAtomSize of every mock PhysicalDevice
+const NON_COHERENT_ATOM_SIZE: vk::DeviceSize = 64;
+
+/// First word of SPIR-V code
+const SPIRV_MAGIC: u32 = 0x07230203;
+
+/// PhysicalDevice handles are this value plus the index in the config
+const PHYSICAL_DEVICE_BASE: usize = 0x10;
+
+fn dispatchable(handle: u64) -> *const c_void {
+    handle as usize as *const c_void
+}
+
+fn dispatchable_id(handle: *const c_void) -> u64 {
+    handle as usize as u64
+}
+
+/// Write the output structures in a pNext chain which are known by the mock
+///
+/// Structures which are not known are left as they are.
+unsafe fn write_p_next_chain(p_next: *mut c_void) {
+    let mut next: *mut vk::BaseOutStructure = p_next.cast();
+    while !next.is_null() {
+        let (s_type, p_next) = unsafe { ((*next).s_type, (*next).p_next) };
+        if s_type.is(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES) {
+            let features = next.cast::<vk::PhysicalDeviceVulkan12Features>();
+            unsafe {
+                *features = vk::PhysicalDeviceVulkan12Features {
+                    s_type,
+                    p_next: p_next.cast(),
+                    // only timelineSemaphore is supported
+                    timeline_semaphore: vk::TRUE,
+                    ..std::mem::zeroed()
+                }
+            };
+        } else if s_type.is(vk::StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES) {
+            let properties = unsafe { &mut *next.cast::<vk::PhysicalDeviceDriverProperties>() };
+            properties.driver_id = vk::DriverId::MESA_LLVMPIPE;
+            properties.driver_name = c_name("mock driver");
+            properties.driver_info = c_name("");
+            properties.conformance_version = unsafe { std::mem::zeroed() };
+        } else if s_type.is(vk::StructureType::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR) {
+            let properties =
+                unsafe { &mut *next.cast::<vk::QueueFamilyGlobalPriorityPropertiesKHR>() };
+            // only the default priority is supported
+            properties.priority_count = 1;
+        }
+        next = p_next;
+    }
+}
+
+/// Call the callback of each messenger which selects the severity and types of the message
+///
+/// This must not be called in `with_state`, since the callbacks could use the mock.
+fn send_message(
+    messengers: &[Messenger],
+    severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    types: vk::DebugUtilsMessageTypeFlagsEXT,
+    message_id_name: &str,
+    message: &str,
+    objects: &[(vk::ObjectType, u64, Option<&CStr>)],
+) {
+    let message_id_name = CString::new(message_id_name).unwrap();
+    let message = CString::new(message).unwrap();
+    let objects: Vec<_> = objects
+        .iter()
+        .map(
+            |&(object_type, object_handle, name)| vk::DebugUtilsObjectNameInfoEXT {
+                s_type: vk::StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+                p_next: std::ptr::null(),
+                object_type,
+                object_handle,
+                p_object_name: name.map_or(std::ptr::null(), CStr::as_ptr),
+            },
+        )
+        .collect();
+    let data = vk::DebugUtilsMessengerCallbackDataEXT {
+        s_type: vk::StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+        p_message_id_name: message_id_name.as_ptr(),
+        p_message: message.as_ptr(),
+        object_count: objects.len() as u32,
+        p_objects: objects.as_ptr(),
+        ..unsafe { std::mem::zeroed() }
+    };
+    for messenger in messengers {
+        if (messenger.severities & severity).is_not_empty()
+            && (messenger.types & types).is_not_empty()
+        {
+            let result = unsafe {
+                messenger.callback.get_fptr()(severity, types, &data, messenger.user_data)
+            };
+            assert_eq!(
+                result,
+                vk::FALSE,
+                "debug messenger callback must return VK_FALSE"
+            );
+        }
+    }
+}
+
+/// Info message which is sent to the debug messengers in the pNext chain of VkInstanceCreateInfo
+fn send_instance_message(messengers: &[Messenger], message: &str, instance: vk::Instance) {
+    // SAFETY: valid bits of VkDebugUtilsMessageSeverityFlagBitsEXT and VkDebugUtilsMessageTypeFlagBitsEXT
+    let (info, general) = unsafe {
+        (
+            vk::DebugUtilsMessageSeverityFlagsEXT::from_raw(0x10),
+            vk::DebugUtilsMessageTypeFlagsEXT::from_raw(0x1),
+        )
+    };
+    send_message(
+        messengers,
+        info,
+        general,
+        "mock-instance",
+        message,
+        &[(
+            vk::ObjectType::INSTANCE,
+            dispatchable_id(instance.handle),
+            None,
+        )],
+    );
+}
+
+fn c_name<const N: usize>(name: &str) -> [c_char; N] {
+    assert!(name.len() < N);
+    let mut c_name = [0; N];
+    for (c, b) in c_name.iter_mut().zip(name.bytes()) {
+        *c = b as c_char;
+    }
+    c_name
+}
+
+/// Members of VkPhysicalDeviceFeatures, which are all VkBool32
+fn feature_bits(features: &vk::PhysicalDeviceFeatures) -> &[vk::Bool32] {
+    let len = std::mem::size_of::<vk::PhysicalDeviceFeatures>() / std::mem::size_of::<vk::Bool32>();
+    unsafe {
+        std::slice::from_raw_parts((features as *const vk::PhysicalDeviceFeatures).cast(), len)
+    }
+}
+
+/// Two call enumeration protocol
+///
+/// Injected `INCOMPLETE` makes the count query report one less item.
+unsafe fn enumerate<T: Copy>(
+    injected: Option<vk::Result>,
+    items: &[T],
+    count: *mut u32,
+    out: *mut T,
+) -> vk::Result {
+    let count = unsafe { &mut *count };
+    if out.is_null() {
+        *count = match injected {
+            Some(vk::Result::INCOMPLETE) => items.len().saturating_sub(1),
+            _ => items.len(),
+        } as u32;
+        return vk::Result::SUCCESS;
+    }
+
+    let written = items.len().min(*count as usize);
+    unsafe { std::ptr::copy_nonoverlapping(items.as_ptr(), out, written) };
+    *count = written as u32;
+    if written < items.len() {
+        vk::Result::INCOMPLETE
+    } else {
+        vk::Result::SUCCESS
+    }
+}
+
+fn extension_properties(names: &[&str]) -> Vec<vk::ExtensionProperties> {
+    names
+        .iter()
+        .map(|name| {
+            let mut properties: vk::ExtensionProperties = unsafe { std::mem::zeroed() };
+            properties.extension_name = c_name(name);
+            properties.spec_version = 1;
+            properties
+        })
+        .collect()
+}
+
+fn layer_properties(names: &[&str]) -> Vec<vk::LayerProperties> {
+    names
+        .iter()
+        .map(|name| {
+            let mut properties: vk::LayerProperties = unsafe { std::mem::zeroed() };
+            properties.layer_name = c_name(name);
+            properties.spec_version = vk_safe::VkVersion::new(1, 3, 0).raw();
+            properties.implementation_version = 1;
+            properties.description = c_name("mock layer");
+            properties
+        })
+        .collect()
+}
+
+macro_rules! commands {
+    ( $(#[$doc:meta])* $name:ident; $( $command:ident ),* $(,)? ) => {
+        $(#[$doc])*
+        ///
+        /// This is a [`vk::FunctionLoader`], which gives the command with the name that
+        /// `name` points to (if it is in the list).
+        fn $name(name: *const c_char) -> Option<vk::PFN_vkVoidFunction> {
+            let name = unsafe { CStr::from_ptr(name) };
+            $(
+                if name.to_bytes() == stringify!($command).as_bytes() {
+                    // SAFETY: the caller uses the pointer with the signature of the respective command
+                    return Some(unsafe {
+                        std::mem::transmute::<*const (), vk::PFN_vkVoidFunction>($command as *const ())
+                    });
+                }
+            )*
+            None
+        }
+    };
+}
+
+commands!(
+    /// Commands which are exported by the mock, as if it was a Vulkan library
+    exported_symbols;
+    vkGetInstanceProcAddr,
+);
+
+commands!(
+    /// Commands which are loaded with a null Instance
+    global_command;
+    vkGetInstanceProcAddr,
+    vkEnumerateInstanceVersion,
+    vkEnumerateInstanceExtensionProperties,
+    vkEnumerateInstanceLayerProperties,
+    vkCreateInstance,
+);
+
+commands!(
+    /// Commands which are loaded with an Instance
+    instance_command;
+    vkGetInstanceProcAddr,
+    vkDestroyInstance,
+    vkEnumeratePhysicalDevices,
+    vkGetPhysicalDeviceProperties,
+    vkGetPhysicalDeviceFeatures,
+    vkGetPhysicalDeviceImageFormatProperties,
+    vkGetPhysicalDeviceQueueFamilyProperties,
+    vkGetPhysicalDeviceMemoryProperties,
+    vkEnumerateDeviceExtensionProperties,
+    vkEnumerateDeviceLayerProperties,
+    vkCreateDevice,
+    vkGetDeviceProcAddr,
+    vkCreateDebugUtilsMessengerEXT,
+    vkDestroyDebugUtilsMessengerEXT,
+);
+
+commands!(
+    /// Commands which are loaded with an Instance, if the version of the mock is at least 1.1
+    version_1_1_command;
+    vkGetPhysicalDeviceProperties2,
+    vkGetPhysicalDeviceFeatures2,
+    vkGetPhysicalDeviceQueueFamilyProperties2,
+    vkGetPhysicalDeviceMemoryProperties2,
+);
+
+// the names given by VK_KHR_get_physical_device_properties2 to the same commands
+use vkGetPhysicalDeviceFeatures2 as vkGetPhysicalDeviceFeatures2KHR;
+use vkGetPhysicalDeviceMemoryProperties2 as vkGetPhysicalDeviceMemoryProperties2KHR;
+use vkGetPhysicalDeviceProperties2 as vkGetPhysicalDeviceProperties2KHR;
+use vkGetPhysicalDeviceQueueFamilyProperties2 as vkGetPhysicalDeviceQueueFamilyProperties2KHR;
+
+commands!(
+    /// Commands of VK_KHR_get_physical_device_properties2, which are loaded with an Instance
+    get_physical_device_properties2_khr_command;
+    vkGetPhysicalDeviceProperties2KHR,
+    vkGetPhysicalDeviceFeatures2KHR,
+    vkGetPhysicalDeviceQueueFamilyProperties2KHR,
+    vkGetPhysicalDeviceMemoryProperties2KHR,
+);
+
+commands!(
+    /// Commands which are loaded with a Device (or an Instance)
+    device_command;
+    vkGetDeviceProcAddr,
+    vkDestroyDevice,
+    vkDeviceWaitIdle,
+    vkGetDeviceQueue,
+    vkAllocateMemory,
+    vkFreeMemory,
+    vkMapMemory,
+    vkUnmapMemory,
+    vkFlushMappedMemoryRanges,
+    vkInvalidateMappedMemoryRanges,
+    vkCreateBuffer,
+    vkDestroyBuffer,
+    vkGetBufferMemoryRequirements,
+    vkBindBufferMemory,
+    vkCreateImage,
+    vkDestroyImage,
+    vkGetImageMemoryRequirements,
+    vkBindImageMemory,
+    vkCreateImageView,
+    vkDestroyImageView,
+    vkCreateSampler,
+    vkDestroySampler,
+    vkCreateCommandPool,
+    vkDestroyCommandPool,
+    vkAllocateCommandBuffers,
+    vkBeginCommandBuffer,
+    vkEndCommandBuffer,
+    vkCmdPipelineBarrier,
+    vkCmdBindPipeline,
+    vkCmdDispatch,
+    vkCmdExecuteCommands,
+    vkCreateFence,
+    vkDestroyFence,
+    vkResetFences,
+    vkWaitForFences,
+    vkCreateSemaphore,
+    vkDestroySemaphore,
+    vkQueueSubmit,
+    vkCreateShaderModule,
+    vkDestroyShaderModule,
+    vkCreateDescriptorSetLayout,
+    vkDestroyDescriptorSetLayout,
+    vkCreatePipelineLayout,
+    vkDestroyPipelineLayout,
+    vkCreateComputePipelines,
+    vkDestroyPipeline,
+    vkSetDebugUtilsObjectNameEXT,
+    vkSetDebugUtilsObjectTagEXT,
+    vkQueueBeginDebugUtilsLabelEXT,
+    vkQueueEndDebugUtilsLabelEXT,
+);
+
+fn unimplemented_command() -> vk::PFN_vkVoidFunction {
+    unsafe extern "system" fn unimplemented() {
+        eprintln!("called a Vulkan command which is not implemented by the mock");
+        std::process::abort();
+    }
+    unimplemented
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetInstanceProcAddr(
+    instance: vk::Instance,
+    name: *const c_char,
+) -> Option<vk::PFN_vkVoidFunction> {
+    if instance.handle.is_null() {
+        global_command(name)
+    } else {
+        let (is_instance, version_1_1) = with_state(|state| {
+            (
+                state.objects.get(&dispatchable_id(instance.handle)) == Some(&Object::Instance),
+                state.config.api_version >= (1, 1, 0),
+            )
+        });
+        assert!(is_instance, "invalid Instance handle");
+        if !version_1_1 && version_1_1_command(name).is_some() {
+            return None;
+        }
+        instance_command(name)
+            .or_else(|| version_1_1_command(name))
+            .or_else(|| get_physical_device_properties2_khr_command(name))
+            .or_else(|| device_command(name))
+            .or_else(|| Some(unimplemented_command()))
+    }
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetDeviceProcAddr(
+    device: vk::Device,
+    name: *const c_char,
+) -> Option<vk::PFN_vkVoidFunction> {
+    let is_device = with_state(|state| {
+        state.objects.get(&dispatchable_id(device.handle)) == Some(&Object::Device)
+    });
+    assert!(is_device, "invalid Device handle");
+    device_command(name).or_else(|| Some(unimplemented_command()))
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEnumerateInstanceVersion(api_version: *mut u32) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkEnumerateInstanceVersion") {
+            return result;
+        }
+        let (major, minor, patch) = state.config.api_version;
+        unsafe { *api_version = vk_safe::VkVersion::new(major, minor, patch).raw() };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEnumerateInstanceExtensionProperties(
+    _layer_name: *const c_char,
+    count: *mut u32,
+    properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    with_state(|state| {
+        let injected = state.call("vkEnumerateInstanceExtensionProperties");
+        match injected {
+            Some(vk::Result::INCOMPLETE) | None => {}
+            Some(result) => return result,
+        }
+        let items = extension_properties(&state.config.instance_extensions);
+        unsafe { enumerate(injected, &items, count, properties) }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEnumerateInstanceLayerProperties(
+    count: *mut u32,
+    properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    with_state(|state| {
+        let injected = state.call("vkEnumerateInstanceLayerProperties");
+        match injected {
+            Some(vk::Result::INCOMPLETE) | None => {}
+            Some(result) => return result,
+        }
+        let items = layer_properties(&state.config.instance_layers);
+        unsafe { enumerate(injected, &items, count, properties) }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateInstance(
+    create_info: *const vk::InstanceCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    instance: *mut vk::Instance,
+) -> vk::Result {
+    let result = with_state(|state| {
+        if let Some(result) = state.call("vkCreateInstance") {
+            return result;
+        }
+        let create_info = unsafe { &*create_info };
+        let layer_names = match create_info.enabled_layer_count {
+            0 => &[][..],
+            count => unsafe {
+                std::slice::from_raw_parts(create_info.pp_enabled_layer_names, count as usize)
+            },
+        };
+        let mut layers = vec![];
+        for &name in layer_names {
+            let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap();
+            if !state.config.instance_layers.contains(&name) {
+                return vk::Result::ERROR_LAYER_NOT_PRESENT;
+            }
+            layers.push(name.to_string());
+        }
+        state.enabled_layers = layers;
+        unsafe { state.record_p_next_chain("vkCreateInstance", create_info.p_next) };
+        state.instance_messengers.clear();
+        let mut next: *const vk::BaseInStructure = create_info.p_next.cast();
+        while let Some(structure) = unsafe { next.as_ref() } {
+            if structure
+                .s_type
+                .is(vk::StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT)
+            {
+                let messenger_info =
+                    unsafe { &*next.cast::<vk::DebugUtilsMessengerCreateInfoEXT>() };
+                state
+                    .instance_messengers
+                    .push(Messenger::new(messenger_info));
+            }
+            next = structure.p_next;
+        }
+        let handle = match unsafe { state.create(Object::Instance, allocator) } {
+            Ok(handle) => handle,
+            Err(result) => return result,
+        };
+        unsafe {
+            *instance = vk::Instance {
+                handle: dispatchable(handle),
+            }
+        };
+        vk::Result::SUCCESS
+    });
+    if result == vk::Result::SUCCESS {
+        let messengers = with_state(|state| state.instance_messengers.clone());
+        send_instance_message(&messengers, "vkCreateInstance", unsafe { *instance });
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyInstance(
+    instance: vk::Instance,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    let messengers = with_state(|state| std::mem::take(&mut state.instance_messengers));
+    send_instance_message(&messengers, "vkDestroyInstance", instance);
+    with_state(|state| {
+        state.call("vkDestroyInstance");
+        unsafe {
+            state.destroy(
+                dispatchable_id(instance.handle),
+                Object::Instance,
+                allocator,
+            )
+        };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEnumeratePhysicalDevices(
+    _instance: vk::Instance,
+    count: *mut u32,
+    physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    with_state(|state| {
+        let injected = state.call("vkEnumeratePhysicalDevices");
+        match injected {
+            Some(vk::Result::INCOMPLETE) | None => {}
+            Some(result) => return result,
+        }
+        let items: Vec<_> = (0..state.config.physical_devices.len())
+            .map(|i| vk::PhysicalDevice {
+                handle: (PHYSICAL_DEVICE_BASE + i) as *const c_void,
+            })
+            .collect();
+        unsafe { enumerate(injected, &items, count, physical_devices) }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceProperties(
+    physical_device: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceProperties,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceProperties");
+        unsafe { *properties = state.physical_device_properties(physical_device) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceProperties2(
+    physical_device: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceProperties2,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceProperties2");
+        unsafe {
+            (*properties).properties = state.physical_device_properties(physical_device);
+            state.record_p_next_chain("vkGetPhysicalDeviceProperties2", (*properties).p_next);
+            write_p_next_chain((*properties).p_next);
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceFeatures(
+    physical_device: vk::PhysicalDevice,
+    features: *mut vk::PhysicalDeviceFeatures,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceFeatures");
+        unsafe { *features = state.physical_device(physical_device).features };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceFeatures2(
+    physical_device: vk::PhysicalDevice,
+    features: *mut vk::PhysicalDeviceFeatures2,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceFeatures2");
+        unsafe {
+            (*features).features = state.physical_device(physical_device).features;
+            state.record_p_next_chain("vkGetPhysicalDeviceFeatures2", (*features).p_next);
+            write_p_next_chain((*features).p_next);
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyProperties(
+    physical_device: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::QueueFamilyProperties,
+) {
+    with_state(|state| {
+        let injected = state.call("vkGetPhysicalDeviceQueueFamilyProperties");
+        let items = state.queue_family_properties(physical_device);
+        unsafe { enumerate(injected, &items, count, properties) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyProperties2(
+    physical_device: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::QueueFamilyProperties2,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceQueueFamilyProperties2");
+        let items = state.queue_family_properties(physical_device);
+        if properties.is_null() {
+            unsafe { *count = items.len() as u32 };
+            return;
+        }
+        let written = unsafe { *count as usize }.min(items.len());
+        for (i, item) in items.into_iter().take(written).enumerate() {
+            unsafe {
+                let p = properties.add(i);
+                (*p).queue_family_properties = item;
+                write_p_next_chain((*p).p_next);
+            }
+        }
+        unsafe { *count = written as u32 };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceMemoryProperties(
+    physical_device: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceMemoryProperties");
+        unsafe { *properties = state.memory_properties(physical_device) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceMemoryProperties2(
+    physical_device: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceMemoryProperties2,
+) {
+    with_state(|state| {
+        state.call("vkGetPhysicalDeviceMemoryProperties2");
+        unsafe {
+            (*properties).memory_properties = state.memory_properties(physical_device);
+            state.record_p_next_chain("vkGetPhysicalDeviceMemoryProperties2", (*properties).p_next);
+            write_p_next_chain((*properties).p_next);
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEnumerateDeviceExtensionProperties(
+    physical_device: vk::PhysicalDevice,
+    _layer_name: *const c_char,
+    count: *mut u32,
+    properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    with_state(|state| {
+        let injected = state.call("vkEnumerateDeviceExtensionProperties");
+        match injected {
+            Some(vk::Result::INCOMPLETE) | None => {}
+            Some(result) => return result,
+        }
+        let items = extension_properties(&state.physical_device(physical_device).extensions);
+        unsafe { enumerate(injected, &items, count, properties) }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEnumerateDeviceLayerProperties(
+    _physical_device: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    with_state(|state| {
+        let injected = state.call("vkEnumerateDeviceLayerProperties");
+        match injected {
+            Some(vk::Result::INCOMPLETE) | None => {}
+            Some(result) => return result,
+        }
+        // device layers are deprecated, and always reported as the instance layers
+        let items = layer_properties(&state.config.instance_layers);
+        unsafe { enumerate(injected, &items, count, properties) }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateDevice(
+    physical_device: vk::PhysicalDevice,
+    create_info: *const vk::DeviceCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    device: *mut vk::Device,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkCreateDevice") {
+            return result;
+        }
+        let supported = state.physical_device(physical_device).features;
+        let enabled = match unsafe { (*create_info).p_enabled_features.as_ref() } {
+            Some(enabled) => *enabled,
+            None => unsafe { std::mem::zeroed() },
+        };
+        let unsupported = feature_bits(&enabled)
+            .iter()
+            .zip(feature_bits(&supported))
+            .any(|(&enabled, &supported)| enabled != vk::FALSE && supported == vk::FALSE);
+        if unsupported {
+            return vk::Result::ERROR_FEATURE_NOT_PRESENT;
+        }
+        state.enabled_features = enabled;
+        let extension_names = match unsafe { (*create_info).enabled_extension_count } {
+            0 => &[][..],
+            count => unsafe {
+                std::slice::from_raw_parts(
+                    (*create_info).pp_enabled_extension_names,
+                    count as usize,
+                )
+            },
+        };
+        state.enabled_device_extensions = extension_names
+            .iter()
+            .map(|&name| {
+                unsafe { CStr::from_ptr(name) }
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        unsafe { state.record_p_next_chain("vkCreateDevice", (*create_info).p_next) };
+        let handle = match unsafe { state.create(Object::Device, allocator) } {
+            Ok(handle) => handle,
+            Err(result) => return result,
+        };
+        state.devices.insert(handle, physical_device);
+        unsafe {
+            *device = vk::Device {
+                handle: dispatchable(handle),
+            }
+        };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyDevice(
+    device: vk::Device,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyDevice");
+        unsafe { state.destroy(dispatchable_id(device.handle), Object::Device, allocator) };
+        state.devices.remove(&dispatchable_id(device.handle));
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetDeviceQueue(
+    device: vk::Device,
+    family_index: u32,
+    queue_index: u32,
+    queue: *mut vk::Queue,
+) {
+    with_state(|state| {
+        state.call("vkGetDeviceQueue");
+        // Queues are not created or destroyed, so they are not tracked
+        let handle = (dispatchable_id(device.handle) << 16)
+            | ((family_index as u64) << 8)
+            | queue_index as u64;
+        unsafe {
+            *queue = vk::Queue {
+                handle: dispatchable(handle),
+            }
+        };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkAllocateMemory(
+    _device: vk::Device,
+    allocate_info: *const vk::MemoryAllocateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkAllocateMemory") {
+            return result;
+        }
+        let allocate_info = unsafe { &*allocate_info };
+        let size = allocate_info.allocation_size as usize;
+        let handle = match unsafe { state.create(Object::Memory, allocator) } {
+            Ok(handle) => handle,
+            Err(result) => return result,
+        };
+        state.memory.insert(
+            handle,
+            Allocation {
+                memory_type_index: allocate_info.memory_type_index,
+                size: allocate_info.allocation_size,
+                blocks: vec![Block([0; 64]); size.div_ceil(std::mem::size_of::<Block>())],
+            },
+        );
+        unsafe { *memory = vk::DeviceMemory { handle } };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkFreeMemory(
+    _device: vk::Device,
+    memory: vk::DeviceMemory,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkFreeMemory");
+        unsafe { state.destroy(memory.handle, Object::Memory, allocator) };
+        state.memory.remove(&memory.handle);
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateSampler(
+    _device: vk::Device,
+    _create_info: *const vk::SamplerCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    sampler: *mut vk::Sampler,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkCreateSampler") {
+            return result;
+        }
+        let handle = match unsafe { state.create(Object::Sampler, allocator) } {
+            Ok(handle) => handle,
+            Err(result) => return result,
+        };
+        unsafe { *sampler = vk::Sampler { handle } };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroySampler(
+    _device: vk::Device,
+    sampler: vk::Sampler,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroySampler");
+        unsafe { state.destroy(sampler.handle, Object::Sampler, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkMapMemory(
+    _device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    _size: vk::DeviceSize,
+    _flags: vk::MemoryMapFlags,
+    data: *mut *mut c_void,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkMapMemory") {
+            return result;
+        }
+        let allocation = state
+            .memory
+            .get_mut(&memory.handle)
+            .expect("invalid DeviceMemory handle");
+        unsafe {
+            *data = allocation
+                .blocks
+                .as_mut_ptr()
+                .cast::<u8>()
+                .add(offset as usize)
+                .cast()
+        };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkUnmapMemory(_device: vk::Device, _memory: vk::DeviceMemory) {
+    with_state(|state| {
+        state.call("vkUnmapMemory");
+    })
+}
+
+/// Slice from a pointer and count of a Vulkan structure, where the pointer may be null if the count is 0
+unsafe fn slice<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+    match count {
+        0 => &[],
+        count => unsafe { std::slice::from_raw_parts(ptr, count as usize) },
+    }
+}
+
+/// Create a non-dispatchable object for a `vkCreate*` command
+///
+/// The handle of the created object is written to `handle`.
+unsafe fn create_handle(
+    state: &mut State,
+    command: &'static str,
+    object: Object,
+    allocator: *const vk::AllocationCallbacks,
+    handle: *mut u64,
+) -> vk::Result {
+    if let Some(result) = state.call(command) {
+        return result;
+    }
+    match unsafe { state.create(object, allocator) } {
+        Ok(created) => {
+            unsafe { *handle = created };
+            vk::Result::SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Record the ranges of a `vkFlushMappedMemoryRanges` (`"flush"`) or
+/// `vkInvalidateMappedMemoryRanges` (`"invalidate"`) call
+unsafe fn mapped_memory_ranges(
+    state: &mut State,
+    command: &'static str,
+    kind: &'static str,
+    count: u32,
+    ranges: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    if let Some(result) = state.call(command) {
+        return result;
+    }
+    let ranges = unsafe { slice(ranges, count) };
+    for range in ranges {
+        let allocation = state
+            .memory
+            .get(&range.memory.handle)
+            .expect("invalid DeviceMemory handle");
+        assert!(
+            range.offset % NON_COHERENT_ATOM_SIZE == 0,
+            "offset must be a multiple of nonCoherentAtomSize"
+        );
+        assert!(
+            range.size == vk::WHOLE_SIZE
+                || range.size % NON_COHERENT_ATOM_SIZE == 0
+                || range.offset + range.size == allocation.size,
+            "size must be a multiple of nonCoherentAtomSize, or reach the end of the memory"
+        );
+        state
+            .mapped_memory_ranges
+            .push((kind, range.memory.handle, range.offset, range.size));
+    }
+    vk::Result::SUCCESS
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDeviceWaitIdle(_device: vk::Device) -> vk::Result {
+    with_state(|state| {
+        state
+            .call("vkDeviceWaitIdle")
+            .unwrap_or(vk::Result::SUCCESS)
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkFlushMappedMemoryRanges(
+    _device: vk::Device,
+    count: u32,
+    ranges: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    with_state(|state| unsafe {
+        mapped_memory_ranges(state, "vkFlushMappedMemoryRanges", "flush", count, ranges)
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkInvalidateMappedMemoryRanges(
+    _device: vk::Device,
+    count: u32,
+    ranges: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    with_state(|state| unsafe {
+        mapped_memory_ranges(
+            state,
+            "vkInvalidateMappedMemoryRanges",
+            "invalidate",
+            count,
+            ranges,
+        )
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetPhysicalDeviceImageFormatProperties(
+    _physical_device: vk::PhysicalDevice,
+    _format: vk::Format,
+    image_type: vk::ImageType,
+    _tiling: vk::ImageTiling,
+    _usage: vk::ImageUsageFlags,
+    _flags: vk::ImageCreateFlags,
+    properties: *mut vk::ImageFormatProperties,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkGetPhysicalDeviceImageFormatProperties") {
+            return result;
+        }
+        unsafe { *properties = state.image_format_properties(image_type) };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateBuffer(
+    device: vk::Device,
+    create_info: *const vk::BufferCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    buffer: *mut vk::Buffer,
+) -> vk::Result {
+    with_state(|state| {
+        let result = unsafe {
+            create_handle(
+                state,
+                "vkCreateBuffer",
+                Object::Buffer,
+                allocator,
+                buffer.cast(),
+            )
+        };
+        if result == vk::Result::SUCCESS {
+            // buffers can be bound to any memory type
+            let requirements = vk::MemoryRequirements {
+                size: unsafe { (*create_info).size }.next_multiple_of(BUFFER_ALIGNMENT),
+                alignment: BUFFER_ALIGNMENT,
+                memory_type_bits: state.memory_type_bits(device, vk::MemoryPropertyFlags::empty()),
+            };
+            state
+                .memory_requirements
+                .insert(unsafe { (*buffer).handle }, requirements);
+        }
+        result
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyBuffer(
+    _device: vk::Device,
+    buffer: vk::Buffer,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyBuffer");
+        unsafe { state.destroy(buffer.handle, Object::Buffer, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetBufferMemoryRequirements(
+    _device: vk::Device,
+    buffer: vk::Buffer,
+    requirements: *mut vk::MemoryRequirements,
+) {
+    with_state(|state| {
+        state.call("vkGetBufferMemoryRequirements");
+        unsafe { *requirements = state.memory_requirements[&buffer.handle] };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkBindBufferMemory(
+    _device: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkBindBufferMemory") {
+            return result;
+        }
+        state.bind(buffer.handle, memory.handle, offset);
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateImage(
+    device: vk::Device,
+    create_info: *const vk::ImageCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    image: *mut vk::Image,
+) -> vk::Result {
+    with_state(|state| {
+        let result = unsafe {
+            create_handle(
+                state,
+                "vkCreateImage",
+                Object::Image,
+                allocator,
+                image.cast(),
+            )
+        };
+        if result == vk::Result::SUCCESS {
+            let create_info = unsafe { &*create_info };
+            let extent = create_info.extent;
+            // 4 bytes per texel, and twice the size of the base level for the mip levels
+            let mut size = extent.width as u64
+                * extent.height as u64
+                * extent.depth as u64
+                * create_info.array_layers as u64
+                * 4;
+            if create_info.mip_levels > 1 {
+                size *= 2;
+            }
+            // images can only be bound to DEVICE_LOCAL memory types
+            let requirements = vk::MemoryRequirements {
+                size: size.next_multiple_of(IMAGE_ALIGNMENT),
+                alignment: IMAGE_ALIGNMENT,
+                memory_type_bits: state
+                    .memory_type_bits(device, vk::MemoryPropertyFlags::DEVICE_LOCAL_BIT),
+            };
+            let handle = unsafe { (*image).handle };
+            state.memory_requirements.insert(handle, requirements);
+            state
+                .image_layouts
+                .insert(handle, create_info.initial_layout);
+        }
+        result
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyImage(
+    _device: vk::Device,
+    image: vk::Image,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyImage");
+        unsafe { state.destroy(image.handle, Object::Image, allocator) };
+        state.image_layouts.remove(&image.handle);
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkGetImageMemoryRequirements(
+    _device: vk::Device,
+    image: vk::Image,
+    requirements: *mut vk::MemoryRequirements,
+) {
+    with_state(|state| {
+        state.call("vkGetImageMemoryRequirements");
+        unsafe { *requirements = state.memory_requirements[&image.handle] };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkBindImageMemory(
+    _device: vk::Device,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkBindImageMemory") {
+            return result;
+        }
+        state.bind(image.handle, memory.handle, offset);
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateImageView(
+    _device: vk::Device,
+    create_info: *const vk::ImageViewCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    image_view: *mut vk::ImageView,
+) -> vk::Result {
+    with_state(|state| {
+        let image = unsafe { (*create_info).image.handle };
+        assert!(
+            state.bindings.contains_key(&image),
+            "the Image of an ImageView must be bound to memory"
+        );
+        unsafe {
+            create_handle(
+                state,
+                "vkCreateImageView",
+                Object::ImageView,
+                allocator,
+                image_view.cast(),
+            )
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyImageView(
+    _device: vk::Device,
+    image_view: vk::ImageView,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyImageView");
+        unsafe { state.destroy(image_view.handle, Object::ImageView, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateCommandPool(
+    _device: vk::Device,
+    create_info: *const vk::CommandPoolCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    command_pool: *mut vk::CommandPool,
+) -> vk::Result {
+    with_state(|state| {
+        let result = unsafe {
+            create_handle(
+                state,
+                "vkCreateCommandPool",
+                Object::CommandPool,
+                allocator,
+                command_pool.cast(),
+            )
+        };
+        if result == vk::Result::SUCCESS {
+            state
+                .command_pools
+                .insert(unsafe { (*command_pool).handle }, unsafe {
+                    (*create_info).flags
+                });
+        }
+        result
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyCommandPool(
+    _device: vk::Device,
+    command_pool: vk::CommandPool,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyCommandPool");
+        unsafe { state.destroy(command_pool.handle, Object::CommandPool, allocator) };
+        state.command_pools.remove(&command_pool.handle);
+        // the command buffers are freed with the pool
+        let freed: Vec<u64> = state
+            .command_buffers
+            .iter()
+            .filter(|(_, command_buffer)| command_buffer.pool == command_pool.handle)
+            .map(|(&handle, _)| handle)
+            .collect();
+        for handle in freed {
+            state.command_buffers.remove(&handle);
+            unsafe { state.destroy(handle, Object::CommandBuffer, std::ptr::null()) };
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkAllocateCommandBuffers(
+    _device: vk::Device,
+    allocate_info: *const vk::CommandBufferAllocateInfo,
+    command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkAllocateCommandBuffers") {
+            return result;
+        }
+        let allocate_info = unsafe { &*allocate_info };
+        let pool = allocate_info.command_pool.handle;
+        assert!(
+            state.command_pools.contains_key(&pool),
+            "invalid CommandPool handle"
+        );
+        for i in 0..allocate_info.command_buffer_count as usize {
+            let handle = unsafe { state.create(Object::CommandBuffer, std::ptr::null()) }
+                .expect("no host memory is allocated without VkAllocationCallbacks");
+            state.command_buffers.insert(
+                handle,
+                CommandBuffer {
+                    pool,
+                    level: allocate_info.level,
+                    state: CommandBufferState::Initial,
+                    one_time_submit: false,
+                    recorded: vec![],
+                },
+            );
+            unsafe {
+                *command_buffers.add(i) = vk::CommandBuffer {
+                    handle: dispatchable(handle),
+                }
+            };
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkBeginCommandBuffer(
+    command_buffer: vk::CommandBuffer,
+    begin_info: *const vk::CommandBufferBeginInfo,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkBeginCommandBuffer") {
+            return result;
+        }
+        let begin_info = unsafe { &*begin_info };
+        let command_buffer = state.command_buffer(command_buffer);
+        let pool = command_buffer.pool;
+        assert_ne!(
+            command_buffer.state,
+            CommandBufferState::Recording,
+            "the CommandBuffer is already recording"
+        );
+        let implicit_reset = command_buffer.state != CommandBufferState::Initial;
+        assert!(
+            command_buffer.level.is(vk::CommandBufferLevel::PRIMARY)
+                || !begin_info.p_inheritance_info.is_null(),
+            "a secondary CommandBuffer must be begun with inheritance info"
+        );
+        command_buffer.state = CommandBufferState::Recording;
+        command_buffer.one_time_submit = begin_info
+            .flags
+            .contains(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT_BIT);
+        command_buffer.recorded.clear();
+        if implicit_reset {
+            let flags = state.command_pools[&pool];
+            assert!(
+                flags.contains(vk::CommandPoolCreateFlags::RESET_COMMAND_BUFFER_BIT),
+                "the CommandPool does not allow resetting individual CommandBuffers"
+            );
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkEndCommandBuffer(command_buffer: vk::CommandBuffer) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkEndCommandBuffer") {
+            return result;
+        }
+        let command_buffer = state.command_buffer(command_buffer);
+        assert_eq!(
+            command_buffer.state,
+            CommandBufferState::Recording,
+            "the CommandBuffer is not recording"
+        );
+        command_buffer.state = CommandBufferState::Executable;
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCmdPipelineBarrier(
+    command_buffer: vk::CommandBuffer,
+    _src_stage_mask: vk::PipelineStageFlags,
+    _dst_stage_mask: vk::PipelineStageFlags,
+    _dependency_flags: vk::DependencyFlags,
+    memory_barrier_count: u32,
+    _memory_barriers: *const vk::MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    buffer_memory_barriers: *const vk::BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    image_memory_barriers: *const vk::ImageMemoryBarrier,
+) {
+    with_state(|state| {
+        state.call("vkCmdPipelineBarrier");
+        let buffers = unsafe { slice(buffer_memory_barriers, buffer_memory_barrier_count) }
+            .iter()
+            .map(|barrier| barrier.buffer.handle)
+            .collect();
+        let images: Vec<_> = unsafe { slice(image_memory_barriers, image_memory_barrier_count) }
+            .iter()
+            .map(|barrier| (barrier.image.handle, barrier.old_layout, barrier.new_layout))
+            .collect();
+        for &(image, ..) in &images {
+            assert!(
+                state.bindings.contains_key(&image),
+                "the Image of a barrier must be bound to memory"
+            );
+        }
+        state.record(
+            command_buffer,
+            Recorded::PipelineBarrier {
+                memory_barriers: memory_barrier_count as usize,
+                buffers,
+                images,
+            },
+        );
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCmdBindPipeline(
+    command_buffer: vk::CommandBuffer,
+    bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+) {
+    with_state(|state| {
+        state.call("vkCmdBindPipeline");
+        assert!(
+            bind_point.is(vk::PipelineBindPoint::COMPUTE),
+            "only compute pipelines are supported"
+        );
+        assert_eq!(
+            state.objects.get(&pipeline.handle),
+            Some(&Object::Pipeline),
+            "invalid Pipeline handle"
+        );
+        state.record(command_buffer, Recorded::BindPipeline(pipeline.handle));
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCmdDispatch(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    with_state(|state| {
+        state.call("vkCmdDispatch");
+        let bound = state
+            .command_buffer(command_buffer)
+            .recorded
+            .iter()
+            .any(|recorded| matches!(recorded, Recorded::BindPipeline(_)));
+        assert!(bound, "a compute pipeline must be bound before dispatching");
+        state.record(
+            command_buffer,
+            Recorded::Dispatch([group_count_x, group_count_y, group_count_z]),
+        );
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCmdExecuteCommands(
+    command_buffer: vk::CommandBuffer,
+    count: u32,
+    secondaries: *const vk::CommandBuffer,
+) {
+    with_state(|state| {
+        state.call("vkCmdExecuteCommands");
+        let secondaries = unsafe { slice(secondaries, count) };
+        for &secondary in secondaries {
+            let secondary = state.command_buffer(secondary);
+            assert!(
+                secondary.level.is(vk::CommandBufferLevel::SECONDARY),
+                "only secondary CommandBuffers can be executed"
+            );
+            assert_eq!(
+                secondary.state,
+                CommandBufferState::Executable,
+                "the secondary CommandBuffer is not executable"
+            );
+        }
+        let handles = secondaries
+            .iter()
+            .map(|secondary| dispatchable_id(secondary.handle))
+            .collect();
+        state.record(command_buffer, Recorded::ExecuteCommands(handles));
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateFence(
+    _device: vk::Device,
+    create_info: *const vk::FenceCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    fence: *mut vk::Fence,
+) -> vk::Result {
+    with_state(|state| {
+        let result = unsafe {
+            create_handle(
+                state,
+                "vkCreateFence",
+                Object::Fence,
+                allocator,
+                fence.cast(),
+            )
+        };
+        if result == vk::Result::SUCCESS {
+            let signaled =
+                unsafe { (*create_info).flags }.contains(vk::FenceCreateFlags::SIGNALED_BIT);
+            state.fences.insert(unsafe { (*fence).handle }, signaled);
+        }
+        result
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyFence(
+    _device: vk::Device,
+    fence: vk::Fence,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyFence");
+        unsafe { state.destroy(fence.handle, Object::Fence, allocator) };
+        state.fences.remove(&fence.handle);
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkResetFences(
+    _device: vk::Device,
+    count: u32,
+    fences: *const vk::Fence,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkResetFences") {
+            return result;
+        }
+        for fence in unsafe { slice(fences, count) } {
+            *state
+                .fences
+                .get_mut(&fence.handle)
+                .expect("invalid Fence handle") = false;
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkWaitForFences(
+    _device: vk::Device,
+    count: u32,
+    fences: *const vk::Fence,
+    _wait_all: vk::Bool32,
+    _timeout: u64,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkWaitForFences") {
+            return result;
+        }
+        // all work is executed when it is submitted, so the wait would never end
+        for fence in unsafe { slice(fences, count) } {
+            assert!(
+                state.fences[&fence.handle],
+                "waiting for a Fence which is not signaled"
+            );
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateSemaphore(
+    _device: vk::Device,
+    _create_info: *const vk::SemaphoreCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    semaphore: *mut vk::Semaphore,
+) -> vk::Result {
+    with_state(|state| {
+        let result = unsafe {
+            create_handle(
+                state,
+                "vkCreateSemaphore",
+                Object::Semaphore,
+                allocator,
+                semaphore.cast(),
+            )
+        };
+        if result == vk::Result::SUCCESS {
+            state
+                .semaphores
+                .insert(unsafe { (*semaphore).handle }, false);
+        }
+        result
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroySemaphore(
+    _device: vk::Device,
+    semaphore: vk::Semaphore,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroySemaphore");
+        unsafe { state.destroy(semaphore.handle, Object::Semaphore, allocator) };
+        state.semaphores.remove(&semaphore.handle);
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkQueueSubmit(
+    _queue: vk::Queue,
+    submit_count: u32,
+    submits: *const vk::SubmitInfo,
+    fence: vk::Fence,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkQueueSubmit") {
+            return result;
+        }
+        for submit in unsafe { slice(submits, submit_count) } {
+            for semaphore in unsafe { slice(submit.p_wait_semaphores, submit.wait_semaphore_count) }
+            {
+                let signaled = state
+                    .semaphores
+                    .get_mut(&semaphore.handle)
+                    .expect("invalid Semaphore handle");
+                assert!(*signaled, "waiting on a Semaphore which is not signaled");
+                *signaled = false;
+            }
+            let command_buffers =
+                unsafe { slice(submit.p_command_buffers, submit.command_buffer_count) };
+            for &command_buffer in command_buffers {
+                let command_buffer = state.command_buffer(command_buffer);
+                assert!(
+                    command_buffer.level.is(vk::CommandBufferLevel::PRIMARY),
+                    "only primary CommandBuffers can be submitted"
+                );
+                assert_eq!(
+                    command_buffer.state,
+                    CommandBufferState::Executable,
+                    "the CommandBuffer is not executable"
+                );
+                if command_buffer.one_time_submit {
+                    command_buffer.state = CommandBufferState::Invalid;
+                }
+                let recorded = command_buffer.recorded.clone();
+                state.execute(&recorded);
+            }
+            for semaphore in
+                unsafe { slice(submit.p_signal_semaphores, submit.signal_semaphore_count) }
+            {
+                let signaled = state
+                    .semaphores
+                    .get_mut(&semaphore.handle)
+                    .expect("invalid Semaphore handle");
+                assert!(
+                    !*signaled,
+                    "signaling a Semaphore which is already signaled"
+                );
+                *signaled = true;
+            }
+        }
+        if fence.handle != 0 {
+            let signaled = state
+                .fences
+                .get_mut(&fence.handle)
+                .expect("invalid Fence handle");
+            assert!(!*signaled, "the Fence of a submission must be unsignaled");
+            *signaled = true;
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateShaderModule(
+    _device: vk::Device,
+    create_info: *const vk::ShaderModuleCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    shader_module: *mut vk::ShaderModule,
+) -> vk::Result {
+    with_state(|state| {
+        let create_info = unsafe { &*create_info };
+        assert!(
+            create_info.code_size > 0 && create_info.code_size % 4 == 0,
+            "codeSize must be a multiple of 4"
+        );
+        assert_eq!(
+            unsafe { *create_info.p_code },
+            SPIRV_MAGIC,
+            "pCode must be SPIR-V code"
+        );
+        unsafe {
+            create_handle(
+                state,
+                "vkCreateShaderModule",
+                Object::ShaderModule,
+                allocator,
+                shader_module.cast(),
+            )
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyShaderModule(
+    _device: vk::Device,
+    shader_module: vk::ShaderModule,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyShaderModule");
+        unsafe { state.destroy(shader_module.handle, Object::ShaderModule, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateDescriptorSetLayout(
+    _device: vk::Device,
+    _create_info: *const vk::DescriptorSetLayoutCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    set_layout: *mut vk::DescriptorSetLayout,
+) -> vk::Result {
+    with_state(|state| unsafe {
+        create_handle(
+            state,
+            "vkCreateDescriptorSetLayout",
+            Object::DescriptorSetLayout,
+            allocator,
+            set_layout.cast(),
+        )
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyDescriptorSetLayout(
+    _device: vk::Device,
+    set_layout: vk::DescriptorSetLayout,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyDescriptorSetLayout");
+        unsafe { state.destroy(set_layout.handle, Object::DescriptorSetLayout, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreatePipelineLayout(
+    _device: vk::Device,
+    create_info: *const vk::PipelineLayoutCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    pipeline_layout: *mut vk::PipelineLayout,
+) -> vk::Result {
+    with_state(|state| {
+        let create_info = unsafe { &*create_info };
+        for set_layout in unsafe { slice(create_info.p_set_layouts, create_info.set_layout_count) }
+        {
+            assert_eq!(
+                state.objects.get(&set_layout.handle),
+                Some(&Object::DescriptorSetLayout),
+                "invalid DescriptorSetLayout handle"
+            );
+        }
+        unsafe {
+            create_handle(
+                state,
+                "vkCreatePipelineLayout",
+                Object::PipelineLayout,
+                allocator,
+                pipeline_layout.cast(),
+            )
+        }
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyPipelineLayout(
+    _device: vk::Device,
+    pipeline_layout: vk::PipelineLayout,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyPipelineLayout");
+        unsafe { state.destroy(pipeline_layout.handle, Object::PipelineLayout, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateComputePipelines(
+    _device: vk::Device,
+    _pipeline_cache: vk::PipelineCache,
+    count: u32,
+    create_infos: *const vk::ComputePipelineCreateInfo,
+    allocator: *const vk::AllocationCallbacks,
+    pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkCreateComputePipelines") {
+            return result;
+        }
+        let create_infos = unsafe { slice(create_infos, count) };
+        for (i, create_info) in create_infos.iter().enumerate() {
+            assert_eq!(
+                state.objects.get(&create_info.stage.module.handle),
+                Some(&Object::ShaderModule),
+                "invalid ShaderModule handle"
+            );
+            assert_eq!(
+                state.objects.get(&create_info.layout.handle),
+                Some(&Object::PipelineLayout),
+                "invalid PipelineLayout handle"
+            );
+            assert!(
+                !create_info.stage.p_name.is_null(),
+                "the shader stage must have an entry point"
+            );
+            let handle = match unsafe { state.create(Object::Pipeline, allocator) } {
+                Ok(handle) => handle,
+                Err(result) => return result,
+            };
+            unsafe { *pipelines.add(i) = vk::Pipeline { handle } };
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyPipeline(
+    _device: vk::Device,
+    pipeline: vk::Pipeline,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyPipeline");
+        unsafe { state.destroy(pipeline.handle, Object::Pipeline, allocator) };
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkCreateDebugUtilsMessengerEXT(
+    _instance: vk::Instance,
+    create_info: *const vk::DebugUtilsMessengerCreateInfoEXT,
+    allocator: *const vk::AllocationCallbacks,
+    messenger: *mut vk::DebugUtilsMessengerEXT,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkCreateDebugUtilsMessengerEXT") {
+            return result;
+        }
+        let handle = match unsafe { state.create(Object::DebugUtilsMessenger, allocator) } {
+            Ok(handle) => handle,
+            Err(result) => return result,
+        };
+        state
+            .messengers
+            .insert(handle, Messenger::new(unsafe { &*create_info }));
+        unsafe { *messenger = vk::DebugUtilsMessengerEXT { handle } };
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkDestroyDebugUtilsMessengerEXT(
+    _instance: vk::Instance,
+    messenger: vk::DebugUtilsMessengerEXT,
+    allocator: *const vk::AllocationCallbacks,
+) {
+    with_state(|state| {
+        state.call("vkDestroyDebugUtilsMessengerEXT");
+        unsafe { state.destroy(messenger.handle, Object::DebugUtilsMessenger, allocator) };
+        state.messengers.remove(&messenger.handle);
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkSetDebugUtilsObjectNameEXT(
+    _device: vk::Device,
+    name_info: *const vk::DebugUtilsObjectNameInfoEXT,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkSetDebugUtilsObjectNameEXT") {
+            return result;
+        }
+        let name_info = unsafe { &*name_info };
+        state.check_object_type(name_info.object_type, name_info.object_handle);
+        if name_info.p_object_name.is_null() {
+            state.object_names.remove(&name_info.object_handle);
+        } else {
+            let name = unsafe { CStr::from_ptr(name_info.p_object_name) };
+            state
+                .object_names
+                .insert(name_info.object_handle, name.to_owned());
+        }
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkSetDebugUtilsObjectTagEXT(
+    _device: vk::Device,
+    tag_info: *const vk::DebugUtilsObjectTagInfoEXT,
+) -> vk::Result {
+    with_state(|state| {
+        if let Some(result) = state.call("vkSetDebugUtilsObjectTagEXT") {
+            return result;
+        }
+        let tag_info = unsafe { &*tag_info };
+        state.check_object_type(tag_info.object_type, tag_info.object_handle);
+        assert!(tag_info.tag_size > 0, "tagSize must be greater than 0");
+        let tag =
+            unsafe { std::slice::from_raw_parts(tag_info.p_tag.cast::<u8>(), tag_info.tag_size) };
+        state
+            .object_tags
+            .insert(tag_info.object_handle, (tag_info.tag_name, tag.to_vec()));
+        vk::Result::SUCCESS
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkQueueBeginDebugUtilsLabelEXT(
+    queue: vk::Queue,
+    label_info: *const vk::DebugUtilsLabelEXT,
+) {
+    with_state(|state| {
+        state.call("vkQueueBeginDebugUtilsLabelEXT");
+        let name = unsafe { CStr::from_ptr((*label_info).p_label_name) };
+        state
+            .labels
+            .push(format!("begin {}", name.to_str().unwrap()));
+        *state
+            .open_queue_labels
+            .entry(dispatchable_id(queue.handle))
+            .or_default() += 1;
+    })
+}
+
+#[allow(non_snake_case)]
+unsafe extern "system" fn vkQueueEndDebugUtilsLabelEXT(queue: vk::Queue) {
+    with_state(|state| {
+        state.call("vkQueueEndDebugUtilsLabelEXT");
+        let open = state
+            .open_queue_labels
+            .entry(dispatchable_id(queue.handle))
+            .or_default();
+        assert!(*open > 0, "no outstanding label on the Queue");
+        *open -= 1;
+        state.labels.push("end".to_string());
+    })
+}
@@ -0,0 +1,2398 @@
+//! Tests which run vk-safe against the in-process mock implementation in `mock`
+
+mod mock;
+
+use mock::{MockConfig, MockIcd, MockPhysicalDevice, Recorded};
+
+use vk_safe::enumerator::Enumerated;
+use vk_safe::raw;
+use vk_safe::vk;
+
+use vk::traits::*;
+use vk::{ErrorCode, VulkanError};
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::num::{NonZeroU32, NonZeroU64};
+use std::sync::{Arc, Mutex};
+
+vk::instance_context!(InstanceContext: VERSION_1_0);
+vk::instance_context!(InstanceContext11: VERSION_1_1);
+vk::device_context!(DeviceContext: VERSION_1_0);
+
+#[test]
+fn enumerate_instance_version() {
+    let mock = MockIcd::new(MockConfig {
+        api_version: (1, 2, 0),
+        ..Default::default()
+    });
+
+    let version = mock.entry().enumerate_instance_version().unwrap();
+    assert_eq!(version.parts(), (1, 2, 0));
+}
+
+#[test]
+fn enumerate_instance_extensions_and_layers() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_KHR_surface", "VK_EXT_debug_utils"],
+        instance_layers: vec!["VK_LAYER_mock"],
+        ..Default::default()
+    });
+    let entry = mock.entry();
+
+    let extensions = entry
+        .enumerate_instance_extension_properties(None)
+        .auto_get_enumerate()
+        .unwrap();
+    let names: Vec<_> = extensions.iter().map(|e| e.extension_name()).collect();
+    assert_eq!(names, ["VK_KHR_surface", "VK_EXT_debug_utils"]);
+    // query the count, then get the items
+    assert_eq!(mock.calls("vkEnumerateInstanceExtensionProperties"), 2);
+
+    let layers = entry
+        .enumerate_instance_layer_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    assert_eq!(layers.len(), 1);
+    assert_eq!(layers[0].layer_name(), "VK_LAYER_mock");
+}
+
+#[test]
+fn enumerate_error() {
+    let mock = MockIcd::new(MockConfig::default());
+    mock.inject(
+        "vkEnumerateInstanceExtensionProperties",
+        raw::Result::ERROR_OUT_OF_HOST_MEMORY,
+    );
+
+    let result = mock
+        .entry()
+        .enumerate_instance_extension_properties(None)
+        .auto_get_enumerate();
+    assert!(matches!(
+        result,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_HOST_MEMORY))
+    ));
+}
+
+#[test]
+fn enumerate_partial() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_KHR_surface", "VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+
+    let partial = mock
+        .entry()
+        .enumerate_instance_extension_properties(None)
+        .get_enumerate(Vec::with_capacity(1))
+        .unwrap();
+    assert!(partial.is_partial());
+    let names: Vec<_> = partial
+        .into_inner()
+        .iter()
+        .map(|e| e.extension_name())
+        .collect();
+    assert_eq!(names, ["VK_KHR_surface"]);
+}
+
+#[test]
+fn enumerate_retry_incomplete() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_KHR_surface", "VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+    // the first count query reports one less item than is available
+    mock.inject(
+        "vkEnumerateInstanceExtensionProperties",
+        raw::Result::INCOMPLETE,
+    );
+
+    let extensions = mock
+        .entry()
+        .enumerate_instance_extension_properties(None)
+        .auto_get_enumerate()
+        .unwrap();
+    let names: Vec<_> = extensions.iter().map(|e| e.extension_name()).collect();
+    assert_eq!(names, ["VK_KHR_surface", "VK_EXT_debug_utils"]);
+    // query the count, get the partial items, then query and get again
+    assert_eq!(mock.calls("vkEnumerateInstanceExtensionProperties"), 4);
+
+    let complete = mock
+        .entry()
+        .enumerate_instance_extension_properties(None)
+        .get_enumerate(Vec::with_capacity(2))
+        .unwrap();
+    assert!(matches!(complete, Enumerated::Complete(ref v) if v.len() == 2));
+}
+
+#[test]
+fn create_instance_error() {
+    let mock = MockIcd::new(MockConfig::default());
+    mock.inject("vkCreateInstance", raw::Result::ERROR_INITIALIZATION_FAILED);
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag);
+
+    assert!(matches!(
+        instance,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_INITIALIZATION_FAILED))
+    ));
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn create_instance_with_layers() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_EXT_validation_features", "VK_EXT_layer_settings"],
+        instance_layers: vec!["VK_LAYER_mock", "VK_LAYER_KHRONOS_validation"],
+        ..Default::default()
+    });
+    let entry = mock.entry();
+    let available = entry
+        .enumerate_instance_layer_properties()
+        .auto_get_enumerate()
+        .unwrap();
+
+    vk::instance_context!(
+        ValidationContext: VERSION_1_0 + EXT_validation_features + EXT_layer_settings
+    );
+    vk::layers!(ValidationLayers: "VK_LAYER_KHRONOS_validation");
+
+    let enabled = [
+        vk::ValidationFeatureEnable::GpuAssisted,
+        vk::ValidationFeatureEnable::BestPractices,
+    ];
+    let mut validation_features = vk::ValidationFeaturesEXT::new(&enabled, &[]).unwrap();
+    let settings = [vk::LayerSettingEXT::bool(
+        vk_safe::vk_str!("VK_LAYER_KHRONOS_validation"),
+        vk_safe::vk_str!("validate_sync"),
+        true,
+    )];
+    let mut layer_settings = vk::LayerSettingsCreateInfoEXT::new(&settings).unwrap();
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(ValidationContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info)
+        .enable_layers(&available, ValidationLayers)
+        .unwrap()
+        .push_next(&mut validation_features)
+        .push_next(&mut layer_settings);
+    let instance = entry
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    assert_eq!(mock.enabled_layers(), ["VK_LAYER_KHRONOS_validation"]);
+    let chain = mock.p_next_chain("vkCreateInstance");
+    assert_eq!(chain.len(), 2);
+    // the last structure that is pushed is first in the chain
+    assert!(chain[0].is(raw::StructureType::LAYER_SETTINGS_CREATE_INFO_EXT));
+    assert!(chain[1].is(raw::StructureType::VALIDATION_FEATURES_EXT));
+
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn enable_missing_layer() {
+    let mock = MockIcd::new(MockConfig {
+        instance_layers: vec!["VK_LAYER_mock"],
+        ..Default::default()
+    });
+    let available = mock
+        .entry()
+        .enumerate_instance_layer_properties()
+        .auto_get_enumerate()
+        .unwrap();
+
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info)
+        .enable_layers(&available, vk::layers!("VK_LAYER_KHRONOS_validation"));
+    assert!(matches!(instance_info, Err(VulkanError::LayerNotPresent)));
+
+    // no layers is always fine
+    assert!(vk::InstanceCreateInfo::new(&app_info)
+        .enable_layers(&available, ())
+        .is_ok());
+}
+
+#[test]
+fn incompatible_validation_features() {
+    use vk::ValidationFeatureEnable::*;
+
+    assert!(matches!(
+        vk::ValidationFeaturesEXT::new(&[GpuAssistedReserveBindingSlot], &[]),
+        Err(VulkanError::IncompatibleValidationFeatures)
+    ));
+    assert!(matches!(
+        vk::ValidationFeaturesEXT::new(&[GpuAssisted, DebugPrintf], &[]),
+        Err(VulkanError::IncompatibleValidationFeatures)
+    ));
+    assert!(vk::ValidationFeaturesEXT::new(
+        &[GpuAssisted, GpuAssistedReserveBindingSlot],
+        &[vk::ValidationFeatureDisable::ThreadSafety]
+    )
+    .is_ok());
+}
+
+#[test]
+fn instance_and_physical_devices() {
+    let mock = MockIcd::new(MockConfig {
+        physical_devices: vec![
+            MockPhysicalDevice {
+                name: "First",
+                ..Default::default()
+            },
+            MockPhysicalDevice {
+                name: "Second",
+                queue_families: vec![
+                    (raw::QueueFlags::GRAPHICS_BIT, 1),
+                    (raw::QueueFlags::TRANSFER_BIT, 4),
+                ],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    });
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+    assert_eq!(mock.live_objects(), 1);
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    assert_eq!(physical_devices.len(), 2);
+
+    let mut names = vec![];
+    let mut queue_counts = vec![];
+    for physical_device in physical_devices.iter() {
+        vk::tag!(tag);
+        let physical_device = physical_device.tag(&instance, tag);
+        names.push(
+            physical_device
+                .get_physical_device_properties()
+                .device_name()
+                .to_string(),
+        );
+
+        let queue_families = physical_device
+            .get_physical_device_queue_family_properties()
+            .auto_get_enumerate()
+            .unwrap();
+        queue_counts.push(
+            queue_families
+                .iter()
+                .map(|f| f.queue_count)
+                .collect::<Vec<_>>(),
+        );
+    }
+    assert_eq!(names, ["First", "Second"]);
+    assert_eq!(queue_counts, [vec![2], vec![1, 4]]);
+
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn physical_device_queries2() {
+    let mock = MockIcd::new(MockConfig {
+        physical_devices: vec![MockPhysicalDevice {
+            queue_families: vec![
+                (raw::QueueFlags::GRAPHICS_BIT, 1),
+                (raw::QueueFlags::TRANSFER_BIT, 4),
+            ],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext11);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+
+    // the mock writes the structures, so they can start with anything
+    let mut vulkan_12_features: raw::PhysicalDeviceVulkan12Features = unsafe { std::mem::zeroed() };
+    let features = physical_device
+        .get_physical_device_features2(vk::OutputChain::new().push_next(&mut vulkan_12_features));
+    assert_eq!(features.sampler_anisotropy, raw::FALSE);
+    assert_eq!(vulkan_12_features.timeline_semaphore, raw::TRUE);
+    assert!(
+        mock.p_next_chain("vkGetPhysicalDeviceFeatures2")
+            == [raw::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES]
+    );
+
+    let mut driver_properties: raw::PhysicalDeviceDriverProperties = unsafe { std::mem::zeroed() };
+    let mut id_properties: raw::PhysicalDeviceIDProperties = unsafe { std::mem::zeroed() };
+    let chain = vk::OutputChain::new()
+        .push_next(&mut driver_properties)
+        .push_next(&mut id_properties);
+    let properties = physical_device.get_physical_device_properties2(chain);
+    assert_eq!(properties.device_name(), "Mock Device");
+    assert!(driver_properties.driver_id == raw::DriverId::MESA_LLVMPIPE);
+    assert!(
+        mock.p_next_chain("vkGetPhysicalDeviceProperties2")
+            == [
+                raw::StructureType::PHYSICAL_DEVICE_ID_PROPERTIES,
+                raw::StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+            ]
+    );
+
+    let memory_properties =
+        physical_device.get_physical_device_memory_properties2(vk::OutputChain::new());
+    assert_eq!(memory_properties.memory_types().len(), 2);
+    assert!(mock
+        .p_next_chain("vkGetPhysicalDeviceMemoryProperties2")
+        .is_empty());
+
+    // only the first family gets a chain
+    let mut global_priority: raw::QueueFamilyGlobalPriorityPropertiesKHR =
+        unsafe { std::mem::zeroed() };
+    let chain = vk::OutputChain::new().push_next(&mut global_priority);
+    let queue_families = physical_device.get_physical_device_queue_family_properties2([chain]);
+    assert_eq!(
+        queue_families
+            .iter()
+            .map(|f| f.queue_count)
+            .collect::<Vec<_>>(),
+        [1, 4]
+    );
+    assert_eq!(global_priority.priority_count, 1);
+
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn physical_device_queries2_khr() {
+    // a Vulkan 1.0 implementation only has the queries with the names given by the extension
+    let mock = MockIcd::new(MockConfig {
+        api_version: (1, 0, 0),
+        instance_extensions: vec!["VK_KHR_get_physical_device_properties2"],
+        ..Default::default()
+    });
+
+    vk::instance_context!(Properties2Context: VERSION_1_0 + KHR_get_physical_device_properties2);
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(Properties2Context);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+
+    let features = physical_device.get_physical_device_features2(vk::OutputChain::new());
+    assert_eq!(features.sampler_anisotropy, raw::FALSE);
+    let properties = physical_device.get_physical_device_properties2(vk::OutputChain::new());
+    assert_eq!(properties.device_name(), "Mock Device");
+    let memory_properties =
+        physical_device.get_physical_device_memory_properties2(vk::OutputChain::new());
+    assert_eq!(memory_properties.memory_types().len(), 2);
+    let queue_families =
+        physical_device.get_physical_device_queue_family_properties2([vk::OutputChain::new()]);
+    assert_eq!(
+        queue_families
+            .iter()
+            .map(|f| f.queue_count)
+            .collect::<Vec<_>>(),
+        [2]
+    );
+    assert_eq!(mock.calls("vkGetPhysicalDeviceFeatures2"), 1);
+    assert_eq!(mock.calls("vkGetPhysicalDeviceProperties2"), 1);
+
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn device_memory() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let properties = physical_device.get_physical_device_properties();
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+    assert_eq!(mock.live_objects(), 2);
+
+    let mem_props = physical_device.get_physical_device_memory_properties();
+    let mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + HOST_VISIBLE_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let alloc_info = vk::MemoryAllocateInfo::new(NonZeroU64::new(256).unwrap(), mem_type);
+
+    mock.inject("vkAllocateMemory", raw::Result::ERROR_OUT_OF_DEVICE_MEMORY);
+    let memory = vk::allocate_memory(&device, &alloc_info, ());
+    assert!(matches!(
+        memory,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_DEVICE_MEMORY))
+    ));
+    assert_eq!(mock.live_objects(), 2);
+
+    let memory = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
+    assert_eq!(mock.live_objects(), 3);
+
+    let mut mapped_memory = device
+        .map_memory(memory, vk::MemoryMapRange::whole(&properties))
+        .unwrap();
+    // SAFETY: the memory is not used by the device
+    unsafe {
+        mapped_memory.as_mut_slice().fill(7);
+        assert!(mapped_memory.as_slice().iter().all(|&b| b == 7));
+    }
+    let memory = device.unmap_memory(mapped_memory);
+    assert_eq!(mock.calls("vkUnmapMemory"), 1);
+
+    drop(memory);
+    assert_eq!(mock.live_objects(), 2);
+    drop(device);
+    assert_eq!(mock.live_objects(), 1);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn device_create_info_p_next() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+
+    // push_next sets the sType, so it does not matter what it is set to here
+    let mut protected_memory = raw::PhysicalDeviceProtectedMemoryFeatures {
+        s_type: raw::StructureType::APPLICATION_INFO,
+        p_next: std::ptr::null_mut(),
+        protected_memory: raw::FALSE,
+    };
+    let mut shader_draw_parameters = raw::PhysicalDeviceShaderDrawParametersFeatures {
+        s_type: raw::StructureType::APPLICATION_INFO,
+        p_next: std::ptr::null_mut(),
+        shader_draw_parameters: raw::TRUE,
+    };
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs)
+        .push_next(&mut protected_memory)
+        .push_next(&mut shader_draw_parameters);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    // each structure is added to the front of the chain
+    let chain = mock.p_next_chain("vkCreateDevice");
+    assert!(
+        chain
+            == [
+                raw::StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES,
+                raw::StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+            ]
+    );
+
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+    assert!(protected_memory
+        .s_type
+        .is(raw::StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES));
+}
+
+vk::features!(SamplerFeatures: sampler_anisotropy);
+vk::features!(WideLines: wide_lines);
+
+#[test]
+fn device_features() {
+    let mock = MockIcd::new(MockConfig {
+        physical_devices: vec![MockPhysicalDevice {
+            features: raw::PhysicalDeviceFeatures {
+                sampler_anisotropy: raw::TRUE,
+                ..unsafe { std::mem::zeroed() }
+            },
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let properties = physical_device.get_physical_device_properties();
+    let supported = physical_device.get_physical_device_features();
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+
+    // wideLines is not supported by the mock
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs)
+        .enable_features(&supported, WideLines);
+    assert!(matches!(
+        device_create_info,
+        Err(VulkanError::UnsupportedFeature)
+    ));
+
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs)
+        .enable_features(&supported, SamplerFeatures)
+        .unwrap();
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+    let enabled = mock.enabled_features();
+    assert_eq!(enabled.sampler_anisotropy, raw::TRUE);
+    assert_eq!(enabled.wide_lines, raw::FALSE);
+
+    let sampler_info = vk::SamplerCreateInfo::new(
+        vk::Filter::LINEAR,
+        vk::Filter::LINEAR,
+        vk::SamplerMipmapMode::LINEAR,
+        vk::SamplerAddressMode::REPEAT,
+    )
+    .anisotropy(&properties, 1.0)
+    .unwrap();
+
+    // the LOD bias must be within ±maxSamplerLodBias, which NaN is not
+    assert!(matches!(
+        sampler_info.lod(&properties, 2.5, 0.0, 1.0),
+        Err(VulkanError::LimitExceeded)
+    ));
+    assert!(matches!(
+        sampler_info.lod(&properties, f32::NAN, 0.0, 1.0),
+        Err(VulkanError::LimitExceeded)
+    ));
+    assert!(matches!(
+        sampler_info.lod(&properties, 0.0, 1.0, f32::NAN),
+        Err(VulkanError::InvalidLodRange)
+    ));
+    let sampler_info = sampler_info.lod(&properties, -2.0, 0.0, 1.0).unwrap();
+
+    let sampler = vk::create_sampler(&device, &sampler_info, ()).unwrap();
+    assert_eq!(mock.live_objects(), 3);
+
+    drop(sampler);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn buffers() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let mem_props = physical_device.get_physical_device_memory_properties();
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    let buffer_info = vk::BufferCreateInfo::new(
+        NonZeroU64::new(100).unwrap(),
+        vk::flags!(BufferUsageFlags + TRANSFER_SRC_BIT + STORAGE_BUFFER_BIT),
+    );
+
+    mock.inject("vkCreateBuffer", raw::Result::ERROR_OUT_OF_DEVICE_MEMORY);
+    vk::tag!(buffer_tag);
+    let buffer = vk::create_buffer(&device, &buffer_info, (), buffer_tag);
+    assert!(matches!(
+        buffer,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_DEVICE_MEMORY))
+    ));
+    assert_eq!(mock.live_objects(), 2);
+
+    let mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + DEVICE_LOCAL_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let alloc_info = vk::MemoryAllocateInfo::new(NonZeroU64::new(256).unwrap(), mem_type);
+    let memory = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
+
+    // the mock rounds the size of a buffer up to its alignment of 64 bytes
+    vk::tag!(buffer_tag);
+    let buffer = vk::create_buffer(&device, &buffer_info, (), buffer_tag).unwrap();
+    let requirements = device.get_buffer_memory_requirements(&buffer);
+    assert_eq!((requirements.size, requirements.alignment), (128, 64));
+    assert!(requirements.supports_memory_type(1));
+    let bound = device.bind_buffer_memory(buffer, &memory, 32, &requirements);
+    assert!(matches!(bound, Err(VulkanError::MisalignedMemoryOffset)));
+
+    vk::tag!(buffer_tag);
+    let buffer = vk::create_buffer(&device, &buffer_info, (), buffer_tag).unwrap();
+    let requirements = device.get_buffer_memory_requirements(&buffer);
+    let bound = device.bind_buffer_memory(buffer, &memory, 192, &requirements);
+    assert!(matches!(bound, Err(VulkanError::MemoryRangeOutOfBounds)));
+    // the buffers are destroyed with the errors
+    assert_eq!(mock.live_objects(), 3);
+    assert_eq!(mock.calls("vkBindBufferMemory"), 0);
+
+    vk::tag!(buffer_tag);
+    let buffer = vk::create_buffer(&device, &buffer_info, (), buffer_tag).unwrap();
+    let requirements = device.get_buffer_memory_requirements(&buffer);
+    let bound = device
+        .bind_buffer_memory(buffer, &memory, 128, &requirements)
+        .unwrap();
+    assert_eq!(bound.offset(), 128);
+    assert_eq!(
+        mock.binding(bound.raw_handle().handle),
+        Some((memory.raw_handle().handle, 128))
+    );
+    assert_eq!(mock.live_objects(), 4);
+
+    drop(bound);
+    drop(memory);
+    assert_eq!(mock.live_objects(), 2);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn images() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let properties = physical_device.get_physical_device_properties();
+    let mem_props = physical_device.get_physical_device_memory_properties();
+
+    let image_params = vk::ImageParameters::new(
+        vk::Format::R8G8B8A8_SRGB,
+        vk::ImageType::TYPE_2D,
+        vk::ImageTiling::OPTIMAL,
+        vk::flags!(ImageUsageFlags + COLOR_ATTACHMENT_BIT + TRANSFER_DST_BIT + SAMPLED_BIT),
+        (),
+    );
+    mock.inject(
+        "vkGetPhysicalDeviceImageFormatProperties",
+        raw::Result::ERROR_FORMAT_NOT_SUPPORTED,
+    );
+    let image_format_properties =
+        physical_device.get_physical_device_image_format_properties(image_params);
+    assert!(matches!(
+        image_format_properties,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_FORMAT_NOT_SUPPORTED))
+    ));
+
+    let image_params = vk::ImageParameters::new(
+        vk::Format::R8G8B8A8_SRGB,
+        vk::ImageType::TYPE_2D,
+        vk::ImageTiling::OPTIMAL,
+        vk::flags!(ImageUsageFlags + COLOR_ATTACHMENT_BIT + TRANSFER_DST_BIT + SAMPLED_BIT),
+        (),
+    );
+    let image_format_properties = physical_device
+        .get_physical_device_image_format_properties(image_params)
+        .unwrap();
+    assert_eq!(image_format_properties.max_extent.width, 4096);
+    assert_eq!(image_format_properties.max_mip_levels, 13);
+
+    let extent = vk::Extent3D {
+        width: 64,
+        height: 64,
+        depth: 1,
+    };
+    // a 64x64 image has at most 7 mip levels
+    let image_info = vk::ImageCreateInfo::new(
+        &image_format_properties,
+        &properties,
+        extent,
+        NonZeroU32::new(8).unwrap(),
+        NonZeroU32::new(1).unwrap(),
+        vk::SampleCountFlags::TYPE_1_BIT,
+    );
+    assert!(matches!(image_info, Err(VulkanError::InvalidMipLevels)));
+    let image_info = vk::ImageCreateInfo::new(
+        &image_format_properties,
+        &properties,
+        vk::Extent3D {
+            width: 8192,
+            ..extent
+        },
+        NonZeroU32::new(1).unwrap(),
+        NonZeroU32::new(1).unwrap(),
+        vk::SampleCountFlags::TYPE_1_BIT,
+    );
+    assert!(matches!(image_info, Err(VulkanError::InvalidImageExtent)));
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    let image_info = vk::ImageCreateInfo::new(
+        &image_format_properties,
+        &properties,
+        extent,
+        NonZeroU32::new(1).unwrap(),
+        NonZeroU32::new(1).unwrap(),
+        vk::SampleCountFlags::TYPE_1_BIT,
+    )
+    .unwrap();
+
+    // the mock only allows images in DEVICE_LOCAL memory
+    let host_mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + HOST_VISIBLE_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let host_memory = vk::allocate_memory(
+        &device,
+        &vk::MemoryAllocateInfo::new(NonZeroU64::new(16384).unwrap(), host_mem_type),
+        (),
+    )
+    .unwrap();
+    vk::tag!(image_tag);
+    let image = vk::create_image(&device, &image_info, (), image_tag).unwrap();
+    let requirements = device.get_image_memory_requirements(&image);
+    assert_eq!((requirements.size, requirements.alignment), (16384, 256));
+    assert!(requirements
+        .find_ty(
+            &mem_props,
+            vk::flags!(MemoryPropertyFlags + HOST_VISIBLE_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .is_none());
+    let bound = device.bind_image_memory(image, &host_memory, 0, &requirements);
+    assert!(matches!(bound, Err(VulkanError::IncompatibleMemoryType)));
+    drop(host_memory);
+    assert_eq!(mock.live_objects(), 2);
+
+    vk::tag!(image_tag);
+    let image = vk::create_image(&device, &image_info, (), image_tag).unwrap();
+    let requirements = device.get_image_memory_requirements(&image);
+    let mem_type = requirements
+        .find_ty(
+            &mem_props,
+            vk::flags!(MemoryPropertyFlags + DEVICE_LOCAL_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let memory = vk::allocate_memory(
+        &device,
+        &vk::MemoryAllocateInfo::new(NonZeroU64::new(requirements.size).unwrap(), mem_type),
+        (),
+    )
+    .unwrap();
+    let mut bound_image = device
+        .bind_image_memory(image, &memory, 0, &requirements)
+        .unwrap();
+    let image_handle = bound_image.raw_handle().handle;
+    assert_eq!(
+        mock.binding(image_handle),
+        Some((memory.raw_handle().handle, 0))
+    );
+    assert!(mock
+        .image_layout(image_handle)
+        .is(raw::ImageLayout::UNDEFINED));
+
+    let tracked_image = bound_image.track_layout();
+    assert!(tracked_image.layout().is(raw::ImageLayout::UNDEFINED));
+
+    let image_view_info = vk::ImageViewCreateInfo::new(
+        tracked_image.image(),
+        vk::ImageViewType::TYPE_2D,
+        vk::flags!(ImageAspectFlags + COLOR_BIT),
+        0..2,
+        0..1,
+    );
+    assert!(matches!(
+        image_view_info,
+        Err(VulkanError::InvalidMipLevels)
+    ));
+    let image_view_info = vk::ImageViewCreateInfo::new(
+        tracked_image.image(),
+        vk::ImageViewType::TYPE_2D,
+        vk::flags!(ImageAspectFlags + COLOR_BIT),
+        0..1,
+        0..1,
+    )
+    .unwrap();
+    let image_view = vk::create_image_view(&device, &image_view_info, ()).unwrap();
+    assert_eq!(mock.live_objects(), 5);
+
+    drop(image_view);
+    drop(bound_image);
+    drop(memory);
+    assert_eq!(mock.live_objects(), 2);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn command_buffers() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    vk::tag!(family_tag);
+    let (queue_family_marker, _) = vk::get_device_queues(
+        &device,
+        queue_configs.into_iter().next().unwrap(),
+        &queue_family_properties,
+        vk::flags!(QueueFlags + GRAPHICS_BIT + TRANSFER_BIT + COMPUTE_BIT),
+        family_tag,
+    )
+    .unwrap();
+
+    let command_pool = vk::create_command_pool(
+        &device,
+        &vk::CommandPoolCreateInfo::new(
+            vk::flags!(CommandPoolCreateFlags + RESET_COMMAND_BUFFER_BIT - PROTECTED_BIT),
+            &queue_family_marker,
+        ),
+        (),
+    )
+    .unwrap();
+
+    let command_buffer_info = vk::CommandBufferAllocateInfo::new(
+        &command_pool,
+        vk::CommandBufferLevel::PRIMARY,
+        Vec::with_capacity(2),
+    )
+    .unwrap();
+    let command_buffers = device
+        .allocate_command_buffers(command_buffer_info)
+        .unwrap();
+    assert_eq!(mock.live_objects(), 5);
+
+    // iter only borrows the command buffers
+    let handles: Vec<_> = command_buffers.iter().map(|cb| cb.raw_handle()).collect();
+    assert_eq!(handles.len(), 2);
+    let mut command_buffers = command_buffers.into_iter();
+
+    // the command pool allows resetting individual command buffers, so an
+    // executable command buffer can begin recording again
+    let mut primary = command_buffers
+        .next()
+        .unwrap()
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+        .unwrap()
+        .end_command_buffer()
+        .unwrap()
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+        .unwrap();
+    assert_eq!(mock.calls("vkBeginCommandBuffer"), 2);
+
+    let secondary_buffer_info = vk::CommandBufferAllocateInfo::new(
+        &command_pool,
+        vk::CommandBufferLevel::SECONDARY,
+        Vec::with_capacity(1),
+    )
+    .unwrap();
+    let secondary = device
+        .allocate_command_buffers(secondary_buffer_info)
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    let secondary_handle = secondary.raw_handle().handle as usize as u64;
+
+    let inheritance_info = vk::CommandBufferInheritanceInfo::new();
+    let secondary = secondary
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new_secondary(
+            (),
+            &inheritance_info,
+        ))
+        .unwrap()
+        .end_command_buffer()
+        .unwrap();
+
+    primary.cmd_execute_commands(secondary);
+    let primary = primary.end_command_buffer().unwrap();
+    assert_eq!(
+        mock.recorded(primary.raw_handle().handle as usize as u64),
+        [Recorded::ExecuteCommands(vec![secondary_handle])]
+    );
+
+    mock.inject(
+        "vkEndCommandBuffer",
+        raw::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+    );
+    let recording = command_buffers
+        .next()
+        .unwrap()
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(vk::flags!(
+            CommandBufferUsageFlags + ONE_TIME_SUBMIT_BIT
+        )))
+        .unwrap();
+    assert!(matches!(
+        recording.end_command_buffer(),
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_DEVICE_MEMORY))
+    ));
+
+    // the command buffers are freed with the command pool
+    drop(primary);
+    assert_eq!(mock.live_objects(), 6);
+    drop(command_pool);
+    assert_eq!(mock.live_objects(), 2);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn pipeline_barriers_and_queue_submit() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let properties = physical_device.get_physical_device_properties();
+    let mem_props = physical_device.get_physical_device_memory_properties();
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    let mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + DEVICE_LOCAL_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let alloc_info = vk::MemoryAllocateInfo::new(NonZeroU64::new(1 << 16).unwrap(), mem_type);
+    let memory = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
+
+    vk::tag!(buffer_tag);
+    let buffer_info = vk::BufferCreateInfo::new(
+        NonZeroU64::new(256).unwrap(),
+        vk::flags!(BufferUsageFlags + TRANSFER_DST_BIT),
+    );
+    let buffer = vk::create_buffer(&device, &buffer_info, (), buffer_tag).unwrap();
+    let requirements = device.get_buffer_memory_requirements(&buffer);
+    let bound_buffer = device
+        .bind_buffer_memory(buffer, &memory, 0, &requirements)
+        .unwrap();
+
+    let image_params = vk::ImageParameters::new(
+        vk::Format::R8G8B8A8_SRGB,
+        vk::ImageType::TYPE_2D,
+        vk::ImageTiling::OPTIMAL,
+        vk::flags!(ImageUsageFlags + TRANSFER_DST_BIT + SAMPLED_BIT),
+        (),
+    );
+    let image_format_properties = physical_device
+        .get_physical_device_image_format_properties(image_params)
+        .unwrap();
+    let image_info = vk::ImageCreateInfo::new(
+        &image_format_properties,
+        &properties,
+        vk::Extent3D {
+            width: 64,
+            height: 64,
+            depth: 1,
+        },
+        NonZeroU32::new(1).unwrap(),
+        NonZeroU32::new(1).unwrap(),
+        vk::SampleCountFlags::TYPE_1_BIT,
+    )
+    .unwrap();
+    vk::tag!(image_tag);
+    let image = vk::create_image(&device, &image_info, (), image_tag).unwrap();
+    let requirements = device.get_image_memory_requirements(&image);
+    let mut bound_image = device
+        .bind_image_memory(image, &memory, 1024, &requirements)
+        .unwrap();
+    let image_handle = bound_image.raw_handle().handle;
+    let tracked_image = bound_image.track_layout();
+
+    vk::tag!(family_tag);
+    let (queue_family_marker, mut queues) = vk::get_device_queues(
+        &device,
+        queue_configs.into_iter().next().unwrap(),
+        &queue_family_properties,
+        vk::flags!(QueueFlags + GRAPHICS_BIT + TRANSFER_BIT + COMPUTE_BIT),
+        family_tag,
+    )
+    .unwrap();
+    let mut queue = queues.next().unwrap();
+
+    let command_pool = vk::create_command_pool(
+        &device,
+        &vk::CommandPoolCreateInfo::new(
+            vk::flags!(CommandPoolCreateFlags + RESET_COMMAND_BUFFER_BIT - PROTECTED_BIT),
+            &queue_family_marker,
+        ),
+        (),
+    )
+    .unwrap();
+    let command_buffer_info = vk::CommandBufferAllocateInfo::new(
+        &command_pool,
+        vk::CommandBufferLevel::PRIMARY,
+        Vec::with_capacity(2),
+    )
+    .unwrap();
+    let mut command_buffers = device
+        .allocate_command_buffers(command_buffer_info)
+        .unwrap()
+        .into_iter();
+    let mut primary = command_buffers
+        .next()
+        .unwrap()
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+        .unwrap();
+    let primary_handle = primary.raw_handle().handle as usize as u64;
+
+    // the image barriers are a tuple, which can hold barriers of different types
+    let (transfer_dst_image,) = primary.cmd_pipeline_barrier(
+        vk::flags!(PipelineStageFlags + TOP_OF_PIPE_BIT),
+        vk::flags!(PipelineStageFlags + TRANSFER_BIT),
+        [vk::MemoryBarrier::new(
+            (),
+            vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+        )],
+        [vk::BufferMemoryBarrier::new(
+            &bound_buffer,
+            (),
+            vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+        )],
+        (vk::ImageMemoryBarrier::transition(
+            tracked_image,
+            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+            (),
+            vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+        ),),
+    );
+    assert!(transfer_dst_image
+        .layout()
+        .is(raw::ImageLayout::TRANSFER_DST_OPTIMAL));
+
+    let [shader_read_image] = primary.cmd_pipeline_barrier(
+        vk::flags!(PipelineStageFlags + TRANSFER_BIT),
+        vk::flags!(PipelineStageFlags + COMPUTE_SHADER_BIT),
+        (),
+        (),
+        [vk::ImageMemoryBarrier::transition(
+            transfer_dst_image,
+            vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+            vk::flags!(AccessFlags + TRANSFER_WRITE_BIT),
+            vk::flags!(AccessFlags + SHADER_READ_BIT),
+        )],
+    );
+    let primary = primary.end_command_buffer().unwrap();
+    assert_eq!(
+        mock.recorded(primary_handle),
+        [
+            Recorded::PipelineBarrier {
+                memory_barriers: 1,
+                buffers: vec![bound_buffer.raw_handle().handle],
+                images: vec![(
+                    image_handle,
+                    raw::ImageLayout::UNDEFINED,
+                    raw::ImageLayout::TRANSFER_DST_OPTIMAL
+                )],
+            },
+            Recorded::PipelineBarrier {
+                memory_barriers: 0,
+                buffers: vec![],
+                images: vec![(
+                    image_handle,
+                    raw::ImageLayout::TRANSFER_DST_OPTIMAL,
+                    raw::ImageLayout::SHADER_READ_ONLY_OPTIMAL
+                )],
+            },
+        ]
+    );
+    // the layout only changes when the command buffer is executed
+    assert!(mock
+        .image_layout(image_handle)
+        .is(raw::ImageLayout::UNDEFINED));
+
+    let mut fence = vk::create_fence(&device, &vk::FenceCreateInfo::new(), ()).unwrap();
+    let semaphore = vk::create_semaphore(&device, &vk::SemaphoreCreateInfo::new(), ()).unwrap();
+
+    // the submission is given back if the wait times out
+    let completed = queue
+        .queue_submit(
+            vk::SubmitInfo::new([primary]).signal_semaphores([semaphore]),
+            &mut fence,
+            |_queue, submission| {
+                mock.inject("vkWaitForFences", raw::Result::TIMEOUT);
+                let submission = submission.wait(0).unwrap().unwrap_err();
+                submission.wait(u64::MAX).unwrap().unwrap()
+            },
+        )
+        .unwrap();
+    assert_eq!(mock.calls("vkResetFences"), 1);
+    assert_eq!(mock.calls("vkQueueSubmit"), 1);
+    // the fence is waited on once more when queue_submit returns
+    assert_eq!(mock.calls("vkWaitForFences"), 3);
+    assert!(mock
+        .image_layout(image_handle)
+        .is(raw::ImageLayout::SHADER_READ_ONLY_OPTIMAL));
+
+    // the layout is not changed by a cancelled transition
+    let shader_read_image =
+        vk::ImageMemoryBarrier::transition(shader_read_image, vk::ImageLayout::GENERAL, (), ())
+            .cancel();
+    assert!(shader_read_image
+        .layout()
+        .is(raw::ImageLayout::SHADER_READ_ONLY_OPTIMAL));
+
+    // wait on the signaled semaphore
+    let [primary] = completed.command_buffers;
+    let [semaphore] = completed.signal_semaphores;
+    let completed = queue
+        .queue_submit(
+            vk::SubmitInfo::new([primary]).wait_semaphores(
+                [semaphore],
+                vk::flags!(PipelineStageFlags + ALL_COMMANDS_BIT),
+            ),
+            &mut fence,
+            |_queue, submission| submission.wait(u64::MAX).unwrap().unwrap(),
+        )
+        .unwrap();
+    let [semaphore] = completed.wait_semaphores;
+
+    // a command buffer begun with ONE_TIME_SUBMIT_BIT is invalid after the submission
+    let mut one_time = command_buffers
+        .next()
+        .unwrap()
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(vk::flags!(
+            CommandBufferUsageFlags + ONE_TIME_SUBMIT_BIT
+        )))
+        .unwrap();
+    let one_time_handle = one_time.raw_handle().handle as usize as u64;
+
+    // the image was last transitioned in a different command buffer, which is not known to
+    // execute first, so the transition uses UNDEFINED as the old layout
+    let [general_image] = one_time.cmd_pipeline_barrier(
+        vk::flags!(PipelineStageFlags + TOP_OF_PIPE_BIT),
+        vk::flags!(PipelineStageFlags + COMPUTE_SHADER_BIT),
+        (),
+        (),
+        [vk::ImageMemoryBarrier::transition(
+            shader_read_image,
+            vk::ImageLayout::GENERAL,
+            (),
+            vk::flags!(AccessFlags + SHADER_READ_BIT),
+        )],
+    );
+    assert!(general_image.layout().is(raw::ImageLayout::GENERAL));
+    let one_time = one_time.end_command_buffer().unwrap();
+    assert_eq!(
+        mock.recorded(one_time_handle),
+        [Recorded::PipelineBarrier {
+            memory_barriers: 0,
+            buffers: vec![],
+            images: vec![(
+                image_handle,
+                raw::ImageLayout::UNDEFINED,
+                raw::ImageLayout::GENERAL
+            )],
+        }]
+    );
+
+    let completed = queue
+        .queue_submit(
+            vk::SubmitInfo::new_one_time([one_time]),
+            &mut fence,
+            |_queue, submission| submission.wait(u64::MAX).unwrap().unwrap(),
+        )
+        .unwrap();
+    let [invalid] = completed.command_buffers;
+    // which can begin recording again, since the pool allows resetting it
+    let one_time = invalid
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(vk::flags!(
+            CommandBufferUsageFlags + ONE_TIME_SUBMIT_BIT
+        )))
+        .unwrap()
+        .end_command_buffer()
+        .unwrap();
+
+    mock.inject("vkQueueSubmit", raw::Result::ERROR_DEVICE_LOST);
+    let result = queue.queue_submit(
+        vk::SubmitInfo::new_one_time([one_time]),
+        &mut fence,
+        |_queue, submission| submission.wait(u64::MAX).unwrap().unwrap(),
+    );
+    assert!(matches!(
+        result,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_DEVICE_LOST))
+    ));
+
+    drop(fence);
+    drop(command_pool);
+    drop(bound_image);
+    drop(bound_buffer);
+    drop(memory);
+    assert_eq!(mock.live_objects(), 3);
+    drop(semaphore);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn compute_pipelines() {
+    let mock = MockIcd::new(MockConfig::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let properties = physical_device.get_physical_device_properties();
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    // the header of a SPIR-V module, which is all that the mock checks
+    let spirv_path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("mock_compute.spv");
+    let header: Vec<u8> = [0x07230203u32, 0x00010000, 0, 1, 0]
+        .iter()
+        .flat_map(|word| word.to_ne_bytes())
+        .collect();
+    std::fs::write(&spirv_path, header).unwrap();
+    // SAFETY: the mock does not use the shader code
+    let spirv = unsafe { vk::SpirvBinary::load_from_file_path(&spirv_path).unwrap() };
+    let shader_module = vk::create_shader_module(
+        &device,
+        &vk::ShaderModuleCreateInfo::from_spirv_binary(&spirv),
+        (),
+    )
+    .unwrap();
+
+    let duplicate_bindings = [
+        vk::DescriptorSetLayoutBinding::new(
+            0,
+            vk::DescriptorType::STORAGE_BUFFER,
+            1,
+            vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+        ),
+        vk::DescriptorSetLayoutBinding::new(
+            0,
+            vk::DescriptorType::UNIFORM_BUFFER,
+            1,
+            vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+        ),
+    ];
+    assert!(matches!(
+        vk::DescriptorSetLayoutCreateInfo::new(&duplicate_bindings),
+        Err(VulkanError::DuplicateDescriptorBinding)
+    ));
+    let set_layout_bindings = [vk::DescriptorSetLayoutBinding::new(
+        0,
+        vk::DescriptorType::STORAGE_BUFFER,
+        1,
+        vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+    )];
+    let set_layout = vk::create_descriptor_set_layout(
+        &device,
+        &vk::DescriptorSetLayoutCreateInfo::new(&set_layout_bindings).unwrap(),
+        (),
+    )
+    .unwrap();
+
+    // maxPushConstantsSize of the mock is 128
+    assert!(matches!(
+        vk::PushConstantRange::new(
+            &properties,
+            vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+            0..256
+        ),
+        Err(VulkanError::LimitExceeded)
+    ));
+    let push_constant_ranges = [vk::PushConstantRange::new(
+        &properties,
+        vk::flags!(ShaderStageFlags + COMPUTE_BIT),
+        0..16,
+    )
+    .unwrap()];
+    let pipeline_layout = vk::create_pipeline_layout(
+        &device,
+        &vk::PipelineLayoutCreateInfo::new(&properties, [&set_layout], &push_constant_ranges)
+            .unwrap(),
+        (),
+    )
+    .unwrap();
+
+    // SAFETY: the mock checks the entry point name, and does not run the shader
+    let compute_pipeline_info = unsafe {
+        vk::ComputePipelineCreateInfo::new(
+            vk::PipelineShaderStageCreateInfo::compute(&shader_module, vk_safe::vk_str!("main")),
+            &pipeline_layout,
+        )
+    };
+    let [compute_pipeline] =
+        vk::create_compute_pipelines(&device, &[compute_pipeline_info], ()).unwrap();
+    assert_eq!(mock.live_objects(), 6);
+
+    vk::tag!(family_tag);
+    let (queue_family_marker, mut queues) = vk::get_device_queues(
+        &device,
+        queue_configs.into_iter().next().unwrap(),
+        &queue_family_properties,
+        vk::flags!(QueueFlags + COMPUTE_BIT),
+        family_tag,
+    )
+    .unwrap();
+    let mut queue = queues.next().unwrap();
+
+    let command_pool = vk::create_command_pool(
+        &device,
+        &vk::CommandPoolCreateInfo::new(
+            vk::flags!(CommandPoolCreateFlags - PROTECTED_BIT),
+            &queue_family_marker,
+        ),
+        (),
+    )
+    .unwrap();
+    let command_buffer_info = vk::CommandBufferAllocateInfo::new(
+        &command_pool,
+        vk::CommandBufferLevel::PRIMARY,
+        Vec::with_capacity(1),
+    )
+    .unwrap();
+    let mut primary = device
+        .allocate_command_buffers(command_buffer_info)
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap()
+        .begin_command_buffer(&vk::CommandBufferBeginInfo::new(()))
+        .unwrap();
+    let primary_handle = primary.raw_handle().handle as usize as u64;
+
+    let mut bound_pipeline = primary.cmd_bind_pipeline(&compute_pipeline);
+    // SAFETY: the mock does not run the shader
+    unsafe {
+        // maxComputeWorkGroupCount of the mock is 65535
+        assert!(matches!(
+            bound_pipeline.cmd_dispatch(&properties, 65536, 1, 1),
+            Err(VulkanError::LimitExceeded)
+        ));
+        bound_pipeline.cmd_dispatch(&properties, 4, 1, 1).unwrap();
+    }
+    let primary = primary.end_command_buffer().unwrap();
+    assert_eq!(
+        mock.recorded(primary_handle),
+        [
+            Recorded::BindPipeline(compute_pipeline.raw_handle().handle),
+            Recorded::Dispatch([4, 1, 1]),
+        ]
+    );
+
+    let mut fence = vk::create_fence(&device, &vk::FenceCreateInfo::new(), ()).unwrap();
+    queue
+        .queue_submit(
+            vk::SubmitInfo::new([primary]),
+            &mut fence,
+            |_queue, submission| submission.wait(u64::MAX).unwrap().unwrap(),
+        )
+        .unwrap();
+
+    // SAFETY: the device is only used on this thread
+    unsafe { device.wait_idle().unwrap() };
+    assert_eq!(mock.calls("vkDeviceWaitIdle"), 1);
+
+    drop(fence);
+    drop(command_pool);
+    drop(compute_pipeline);
+    drop(pipeline_layout);
+    drop(set_layout);
+    drop(shader_module);
+    assert_eq!(mock.live_objects(), 2);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn flush_and_invalidate_mapped_memory() {
+    let mock = MockIcd::new(MockConfig {
+        physical_devices: vec![MockPhysicalDevice {
+            memory_types: vec![
+                (raw::MemoryPropertyFlags::DEVICE_LOCAL_BIT, 0),
+                (raw::MemoryPropertyFlags::HOST_VISIBLE_BIT, 1),
+            ],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let properties = physical_device.get_physical_device_properties();
+    let mem_props = physical_device.get_physical_device_memory_properties();
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    // memory which is not host coherent must be flushed / invalidated
+    let mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + HOST_VISIBLE_BIT - HOST_COHERENT_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let alloc_info = vk::MemoryAllocateInfo::new(NonZeroU64::new(200).unwrap(), mem_type);
+    let memory = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
+    let memory_handle = memory.raw_handle().handle;
+
+    let mapped_memory = device
+        .map_memory(memory, vk::MemoryMapRange::whole(&properties))
+        .unwrap();
+
+    // nonCoherentAtomSize of the mock is 64
+    assert!(matches!(
+        vk::MappedMemoryRange::sub_range(&mapped_memory, 0, NonZeroU64::new(10).unwrap()),
+        Err(VulkanError::MisalignedMemoryRange)
+    ));
+    assert!(matches!(
+        vk::MappedMemoryRange::sub_range(&mapped_memory, 32, NonZeroU64::new(64).unwrap()),
+        Err(VulkanError::MisalignedMemoryRange)
+    ));
+    assert!(matches!(
+        vk::MappedMemoryRange::sub_range(&mapped_memory, 128, NonZeroU64::new(128).unwrap()),
+        Err(VulkanError::MemoryRangeOutOfBounds)
+    ));
+
+    let ranges = [
+        vk::MappedMemoryRange::whole_range(&mapped_memory),
+        vk::MappedMemoryRange::sub_range(&mapped_memory, 64, NonZeroU64::new(64).unwrap()).unwrap(),
+        // a range which reaches the end of the memory does not need to be aligned
+        vk::MappedMemoryRange::sub_range(&mapped_memory, 128, NonZeroU64::new(72).unwrap())
+            .unwrap(),
+    ];
+    device.flush_mapped_memory_ranges(&ranges).unwrap();
+    device
+        .invalidate_mapped_memory_ranges(&ranges[1..])
+        .unwrap();
+    assert_eq!(
+        mock.mapped_memory_ranges(),
+        [
+            ("flush", memory_handle, 0, raw::WHOLE_SIZE),
+            ("flush", memory_handle, 64, 64),
+            ("flush", memory_handle, 128, 72),
+            ("invalidate", memory_handle, 64, 64),
+            ("invalidate", memory_handle, 128, 72),
+        ]
+    );
+
+    mock.inject(
+        "vkFlushMappedMemoryRanges",
+        raw::Result::ERROR_OUT_OF_HOST_MEMORY,
+    );
+    assert!(matches!(
+        device.flush_mapped_memory_ranges(&ranges),
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_HOST_MEMORY))
+    ));
+
+    let memory = device.unmap_memory(mapped_memory);
+    drop(memory);
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+/// Host allocator which keeps track of the live allocations
+#[derive(Default)]
+struct TrackingAllocator {
+    allocations: Mutex<HashMap<usize, Layout>>,
+}
+
+impl TrackingAllocator {
+    fn live_allocations(&self) -> usize {
+        self.allocations.lock().unwrap().len()
+    }
+}
+
+unsafe impl vk::HostAllocator for TrackingAllocator {
+    fn allocate(
+        &self,
+        size: usize,
+        alignment: usize,
+        _scope: raw::SystemAllocationScope,
+    ) -> *mut c_void {
+        let Ok(layout) = Layout::from_size_align(size.max(1), alignment) else {
+            return std::ptr::null_mut();
+        };
+        let memory = unsafe { std::alloc::alloc(layout) };
+        if !memory.is_null() {
+            self.allocations
+                .lock()
+                .unwrap()
+                .insert(memory as usize, layout);
+        }
+        memory.cast()
+    }
+
+    unsafe fn reallocate(
+        &self,
+        original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        scope: raw::SystemAllocationScope,
+    ) -> *mut c_void {
+        if original.is_null() {
+            return self.allocate(size, alignment, scope);
+        }
+        if size == 0 {
+            unsafe { self.free(original) };
+            return std::ptr::null_mut();
+        }
+        let memory = self.allocate(size, alignment, scope);
+        if !memory.is_null() {
+            let original_size = self.allocations.lock().unwrap()[&(original as usize)].size();
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    original.cast::<u8>(),
+                    memory.cast::<u8>(),
+                    original_size.min(size),
+                );
+                self.free(original);
+            }
+        }
+        memory
+    }
+
+    unsafe fn free(&self, memory: *mut c_void) {
+        if let Some(layout) = self.allocations.lock().unwrap().remove(&(memory as usize)) {
+            unsafe { std::alloc::dealloc(memory.cast(), layout) };
+        }
+    }
+}
+
+#[test]
+fn allocation_callbacks() {
+    let mock = MockIcd::new(MockConfig::default());
+    let callbacks = vk::AllocationCallbacks::new(TrackingAllocator::default());
+
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, callbacks.clone(), instance_tag)
+        .unwrap();
+    assert_eq!(callbacks.allocator().live_allocations(), 1);
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    // the default allocator of the implementation can be used for a child object
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+    assert_eq!(callbacks.allocator().live_allocations(), 1);
+
+    let mem_props = physical_device.get_physical_device_memory_properties();
+    let mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + HOST_VISIBLE_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let alloc_info = vk::MemoryAllocateInfo::new(NonZeroU64::new(256).unwrap(), mem_type);
+    let memory = vk::allocate_memory(&device, &alloc_info, callbacks.clone()).unwrap();
+    assert_eq!(callbacks.allocator().live_allocations(), 2);
+
+    // each object is destroyed with the callbacks it was created with
+    drop(memory);
+    assert_eq!(callbacks.allocator().live_allocations(), 1);
+    drop(device);
+    drop(instance);
+    assert_eq!(callbacks.allocator().live_allocations(), 0);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+/// Host allocator which panics instead of allocating
+struct PanickingAllocator;
+
+unsafe impl vk::HostAllocator for PanickingAllocator {
+    fn allocate(
+        &self,
+        _size: usize,
+        _alignment: usize,
+        _scope: raw::SystemAllocationScope,
+    ) -> *mut c_void {
+        panic!("allocate")
+    }
+
+    unsafe fn reallocate(
+        &self,
+        _original: *mut c_void,
+        _size: usize,
+        _alignment: usize,
+        _scope: raw::SystemAllocationScope,
+    ) -> *mut c_void {
+        panic!("reallocate")
+    }
+
+    unsafe fn free(&self, _memory: *mut c_void) {
+        panic!("free")
+    }
+}
+
+#[test]
+fn allocation_callbacks_panic() {
+    let mock = MockIcd::new(MockConfig::default());
+    let callbacks = vk::AllocationCallbacks::new(PanickingAllocator);
+
+    // the panic does not unwind into Vulkan, and the allocation fails instead
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(InstanceContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, callbacks.clone(), instance_tag);
+    assert!(matches!(
+        instance,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_HOST_MEMORY))
+    ));
+    assert_eq!(mock.live_objects(), 0);
+
+    vk::tag!(instance_tag);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    // the allocator can panic again
+    let fence = vk::create_fence(&device, &vk::FenceCreateInfo::new(), callbacks);
+    assert!(matches!(
+        fence,
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_HOST_MEMORY))
+    ));
+    assert_eq!(mock.live_objects(), 2);
+
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+/// Severity, VUID, message text and objects of a received debug message
+type Received = (
+    raw::DebugUtilsMessageSeverityFlagsEXT,
+    Option<String>,
+    String,
+    Vec<(raw::ObjectType, u64)>,
+);
+
+/// Debug messenger callback which keeps the messages it receives
+fn receive(messages: &Arc<Mutex<Vec<Received>>>) -> impl vk::DebugUtilsMessengerCallback {
+    let messages = messages.clone();
+    move |message: &vk::DebugUtilsMessage| {
+        messages.lock().unwrap().push((
+            message.severity,
+            message.vuid().map(str::to_string),
+            message.message.to_string(),
+            message
+                .objects
+                .iter()
+                .map(|o| (o.object_type, o.handle))
+                .collect(),
+        ))
+    }
+}
+
+/// Debug messenger info which reports messages of all severities and types to `callback`
+fn report_all(
+    callback: impl vk::DebugUtilsMessengerCallback,
+) -> vk::DebugUtilsMessengerCreateInfoEXT {
+    vk::DebugUtilsMessengerCreateInfoEXT::new(
+        vk::flags!(
+            DebugUtilsMessageSeverityFlagsEXT
+                + DEBUG_UTILS_MESSAGE_SEVERITY_VERBOSE_BIT_EXT
+                + DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT
+                + DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+                + DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT
+        ),
+        vk::flags!(
+            DebugUtilsMessageTypeFlagsEXT
+                + DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT
+                + DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT
+                + DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT
+        ),
+        callback,
+    )
+}
+
+#[test]
+fn debug_utils_messenger() {
+    use raw::DebugUtilsMessageSeverityFlagsEXT as Severity;
+    use raw::DebugUtilsMessageTypeFlagsEXT as Types;
+
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+
+    vk::instance_context!(DebugUtilsContext: VERSION_1_0 + EXT_debug_utils);
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(DebugUtilsContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let messages = Arc::new(Mutex::new(vec![]));
+    let info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+        vk::flags!(
+            DebugUtilsMessageSeverityFlagsEXT
+                + DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+                + DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT
+        ),
+        vk::DebugUtilsMessageTypeFlagsEXT::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        receive(&messages),
+    );
+    let messenger = vk::create_debug_utils_messenger(&instance, &info, ()).unwrap();
+    assert_eq!(mock.live_objects(), 2);
+
+    mock.report(
+        Severity::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+        Types::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        "VUID-vkDestroyBuffer-buffer-00922",
+        "buffer is in use",
+        &[(raw::ObjectType::BUFFER, 0x42)],
+    );
+    // severity or type which is not selected
+    mock.report(
+        Severity::DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT,
+        Types::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        "info",
+        "not reported",
+        &[],
+    );
+    mock.report(
+        Severity::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+        Types::DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT,
+        "perf",
+        "not reported",
+        &[],
+    );
+    mock.report(
+        Severity::DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT,
+        Types::DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT
+            | Types::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        "UNASSIGNED-warning",
+        "warning",
+        &[],
+    );
+
+    assert_eq!(
+        *messages.lock().unwrap(),
+        [
+            (
+                Severity::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+                Some("VUID-vkDestroyBuffer-buffer-00922".to_string()),
+                "buffer is in use".to_string(),
+                vec![(raw::ObjectType::BUFFER, 0x42)],
+            ),
+            (
+                Severity::DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT,
+                None,
+                "warning".to_string(),
+                vec![]
+            ),
+        ]
+    );
+
+    // no messages after the messenger is destroyed
+    drop(messenger);
+    mock.report(
+        Severity::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+        Types::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        "error",
+        "not reported",
+        &[],
+    );
+    assert_eq!(messages.lock().unwrap().len(), 2);
+
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn debug_utils_messenger_in_instance_create_info() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+
+    let messages = Arc::new(Mutex::new(vec![]));
+    let mut messenger_info = report_all(receive(&messages));
+    // a trait object can also be a callback
+    let trait_object: Box<dyn vk::DebugUtilsMessengerCallback> = Box::new(receive(&messages));
+    let mut trait_object_info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+        vk::DebugUtilsMessageSeverityFlagsEXT::DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT,
+        vk::DebugUtilsMessageTypeFlagsEXT::DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT,
+        trait_object,
+    );
+
+    vk::instance_context!(DebugUtilsContext: VERSION_1_0 + EXT_debug_utils);
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(DebugUtilsContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info)
+        .push_debug_utils_messenger(&mut messenger_info)
+        .push_debug_utils_messenger(&mut trait_object_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+    let chain = mock.p_next_chain("vkCreateInstance");
+    assert_eq!(chain.len(), 2);
+    assert!(chain
+        .iter()
+        .all(|s| s.is(raw::StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT)));
+
+    // the Instance keeps the callbacks alive
+    drop(instance_info);
+    drop(messenger_info);
+    drop(trait_object_info);
+
+    let instance_handle = instance.raw_handle().handle as usize as u64;
+    let created = (
+        raw::DebugUtilsMessageSeverityFlagsEXT::DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT,
+        None,
+        "vkCreateInstance".to_string(),
+        vec![(raw::ObjectType::INSTANCE, instance_handle)],
+    );
+    assert_eq!(
+        *messages.lock().unwrap(),
+        [created.clone(), created.clone()]
+    );
+
+    drop(instance);
+    let destroyed = (
+        raw::DebugUtilsMessageSeverityFlagsEXT::DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT,
+        None,
+        "vkDestroyInstance".to_string(),
+        vec![(raw::ObjectType::INSTANCE, instance_handle)],
+    );
+    assert_eq!(
+        messages.lock().unwrap()[2..],
+        [destroyed.clone(), destroyed]
+    );
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn debug_utils_messenger_callback_panic() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+
+    vk::instance_context!(DebugUtilsContext: VERSION_1_0 + EXT_debug_utils);
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(DebugUtilsContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let calls = Arc::new(Mutex::new(0));
+    let info = report_all({
+        let calls = calls.clone();
+        move |message: &vk::DebugUtilsMessage| {
+            *calls.lock().unwrap() += 1;
+            panic!("{}", message.message)
+        }
+    });
+    let messenger = vk::create_debug_utils_messenger(&instance, &info, ()).unwrap();
+
+    // the panic does not unwind into Vulkan, and the callback can be called again
+    for _ in 0..2 {
+        mock.report(
+            raw::DebugUtilsMessageSeverityFlagsEXT::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+            raw::DebugUtilsMessageTypeFlagsEXT::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+            "VUID-test",
+            "callback panic",
+            &[],
+        );
+    }
+    assert_eq!(*calls.lock().unwrap(), 2);
+
+    drop(messenger);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn debug_utils_object_names() {
+    use raw::DebugUtilsMessageSeverityFlagsEXT as Severity;
+    use raw::DebugUtilsMessageTypeFlagsEXT as Types;
+
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+
+    vk::instance_context!(DebugUtilsContext: VERSION_1_0 + EXT_debug_utils);
+    vk::device_context!(DebugUtilsDeviceContext: VERSION_1_0 + EXT_debug_utils);
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(DebugUtilsContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let names = Arc::new(Mutex::new(vec![]));
+    let info = vk::DebugUtilsMessengerCreateInfoEXT::new(
+        vk::DebugUtilsMessageSeverityFlagsEXT::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+        vk::DebugUtilsMessageTypeFlagsEXT::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        {
+            let names = names.clone();
+            move |message: &vk::DebugUtilsMessage| {
+                names.lock().unwrap().extend(
+                    message
+                        .objects
+                        .iter()
+                        .map(|o| (o.handle, o.name.as_deref().map(str::to_string))),
+                )
+            }
+        },
+    );
+    let messenger = vk::create_debug_utils_messenger(&instance, &info, ()).unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DebugUtilsDeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+    // EXT_debug_utils is an instance extension, so it is only a dependency of the device context
+    // and is not enabled on the Device
+    assert!(mock.enabled_device_extensions().is_empty());
+
+    let mem_props = physical_device.get_physical_device_memory_properties();
+    let mem_type = mem_props
+        .find_ty(
+            vk::flags!(MemoryPropertyFlags + DEVICE_LOCAL_BIT),
+            vk::flags!(MemoryHeapFlags - MULTI_INSTANCE_BIT),
+        )
+        .unwrap();
+    let alloc_info = vk::MemoryAllocateInfo::new(NonZeroU64::new(256).unwrap(), mem_type);
+    let mut memory = vk::allocate_memory(&device, &alloc_info, ()).unwrap();
+    let memory_handle = memory.raw_handle().handle;
+
+    device
+        .set_debug_name(&mut memory, vk_safe::vk_str!("vertex memory"))
+        .unwrap();
+    assert_eq!(
+        mock.object_name(memory_handle).as_deref(),
+        Some("vertex memory")
+    );
+
+    device.set_debug_tag(&mut memory, 7, b"tag data").unwrap();
+    assert_eq!(
+        mock.object_tag(memory_handle),
+        Some((7, b"tag data".to_vec()))
+    );
+    assert!(matches!(
+        device.set_debug_tag(&mut memory, 7, &[]),
+        Err(VulkanError::EmptyDebugTag)
+    ));
+
+    mock.inject(
+        "vkSetDebugUtilsObjectNameEXT",
+        raw::Result::ERROR_OUT_OF_HOST_MEMORY,
+    );
+    assert!(matches!(
+        device.set_debug_name(&mut memory, vk_safe::vk_str!("not named")),
+        Err(VulkanError::Vulkan(ErrorCode::ERROR_OUT_OF_HOST_MEMORY))
+    ));
+
+    // the name identifies the object in messages
+    mock.report(
+        Severity::DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+        Types::DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT,
+        "VUID-vkFreeMemory-memory-00677",
+        "memory is in use",
+        &[
+            (raw::ObjectType::DEVICE_MEMORY, memory_handle),
+            (raw::ObjectType::BUFFER, 0x42),
+        ],
+    );
+    assert_eq!(
+        *names.lock().unwrap(),
+        [
+            (memory_handle, Some("vertex memory".to_string())),
+            (0x42, None)
+        ]
+    );
+
+    drop(memory);
+    assert_eq!(mock.object_name(memory_handle), None);
+    drop(device);
+    drop(messenger);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}
+
+#[test]
+fn debug_utils_queue_labels() {
+    let mock = MockIcd::new(MockConfig {
+        instance_extensions: vec!["VK_EXT_debug_utils"],
+        ..Default::default()
+    });
+
+    vk::instance_context!(DebugUtilsContext: VERSION_1_0 + EXT_debug_utils);
+    vk::device_context!(DebugUtilsDeviceContext: VERSION_1_0 + EXT_debug_utils);
+    vk::tag!(instance_tag);
+    let app_info = vk::ApplicationInfo::new(DebugUtilsContext);
+    let instance_info = vk::InstanceCreateInfo::new(&app_info);
+    let instance = mock
+        .entry()
+        .create_instance(&instance_info, (), instance_tag)
+        .unwrap();
+
+    let physical_devices = instance
+        .enumerate_physical_devices()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(tag);
+    let physical_device = physical_devices[0].tag(&instance, tag);
+
+    let queue_family_properties = physical_device
+        .get_physical_device_queue_family_properties()
+        .auto_get_enumerate()
+        .unwrap();
+    vk::tag!(families_config_tag);
+    let priorities = [vk::QueuePriority::default(); 2];
+    let queue_configs: Vec<_> = queue_family_properties
+        .properties_iter(families_config_tag)
+        .map(|p| vk::DeviceQueueCreateInfo::new(&priorities[..p.queue_count as usize], p).unwrap())
+        .collect();
+    let device_create_info = vk::DeviceCreateInfo::new(DebugUtilsDeviceContext, &queue_configs);
+
+    vk::tag!(device_tag);
+    let device = vk::create_device(&physical_device, &device_create_info, (), device_tag).unwrap();
+
+    vk::tag!(family_tag);
+    let (_, mut queues) = vk::get_device_queues(
+        &device,
+        queue_configs.into_iter().next().unwrap(),
+        &queue_family_properties,
+        vk::flags!(QueueFlags + GRAPHICS_BIT),
+        family_tag,
+    )
+    .unwrap();
+    let mut queue = queues.next().unwrap();
+    let mut other_queue = queues.next().unwrap();
+
+    // Queues can be named like any other object
+    device
+        .set_debug_name(&mut queue, vk_safe::vk_str!("graphics queue"))
+        .unwrap();
+    assert_eq!(
+        mock.object_name(queue.raw_handle().handle as usize as u64)
+            .as_deref(),
+        Some("graphics queue")
+    );
+
+    let frame = vk::DebugUtilsLabelEXT::new(vk_safe::vk_str!("frame"));
+    let upload =
+        vk::DebugUtilsLabelEXT::new(vk_safe::vk_str!("upload")).color([0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(upload.color, [0.0, 1.0, 0.0, 1.0]);
+
+    let mut frame_region = queue.begin_label(&frame);
+    let upload_region = frame_region.begin_label(&upload);
+    upload_region.end_label();
+    assert_eq!(mock.labels(), ["begin frame", "begin upload", "end"]);
+    // ended when dropped
+    drop(frame_region);
+    assert_eq!(mock.labels(), ["begin frame", "begin upload", "end", "end"]);
+
+    // a label is not ended on a Queue which was swapped into the region
+    let mut frame_region = queue.begin_label(&frame);
+    std::mem::swap(&mut *frame_region, &mut other_queue);
+    drop(frame_region);
+    assert_eq!(mock.calls("vkQueueEndDebugUtilsLabelEXT"), 2);
+
+    drop(device);
+    drop(instance);
+    assert_eq!(mock.live_objects(), 0);
+}